@@ -321,3 +321,262 @@ pub fn point(stream: TokenStream) -> TokenStream {
     };
     proc_macro::TokenStream::from(output)
 }
+
+/// A block of the matrix built by [`stack!`].
+enum StackBlock {
+    /// A `0` placeholder: a block of zeros.
+    Zero,
+    /// An `I` placeholder: a block with ones on its diagonal and zeros elsewhere.
+    Identity,
+    /// Any other expression, which must evaluate to a matrix.
+    Matrix(Box<Expr>),
+}
+
+impl StackBlock {
+    fn from_expr(expr: Expr) -> Self {
+        match &expr {
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(lit),
+                ..
+            }) if lit.base10_digits() == "0" => Self::Zero,
+            Expr::Path(path) if path.qself.is_none() && path.path.is_ident("I") => Self::Identity,
+            _ => Self::Matrix(Box::new(expr)),
+        }
+    }
+}
+
+/// Construct a matrix by stacking matrices in a block matrix.
+///
+/// **Note: Requires the `macros` feature to be enabled (enabled by default)**.
+///
+/// The syntax is the same as for [`matrix!`], except that each entry is a matrix (or a
+/// reference to a matrix) instead of a scalar. The blocks of a given row of blocks must all have
+/// the same number of rows, and the blocks of a given column of blocks must all have the same
+/// number of columns. The blocks may be any mix of statically-sized and dynamically-sized
+/// matrices or matrix slices: each dimension of the result is a type-level constant whenever it
+/// can be deduced from the types of the blocks.
+///
+/// Two placeholders can be used instead of a matrix, in which case their shape is inferred from
+/// the other blocks of the same row and column of blocks:
+/// - `0` designates a block filled with zeros.
+/// - `I` designates a block with ones on its diagonal and zeros elsewhere.
+///
+/// Each row (resp. column) of blocks must contain at least one block that is not a
+/// placeholder, or an `I` placeholder whose column (resp. row) of blocks has a known number of
+/// columns (resp. rows), in which case this placeholder is assumed to be square.
+///
+/// # Panics
+/// Panics if the dimensions of dynamically-sized blocks are not consistent with the other blocks
+/// of their row or column of blocks.
+///
+/// # Examples
+/// ```
+/// use nalgebra::{stack, Matrix2, Matrix2x1, Matrix3, Matrix4, RowVector2, Vector1};
+///
+/// let a = Matrix2::new(1, 2,
+///                      3, 4);
+/// let b = Matrix2x1::new(5, 6);
+/// let c = RowVector2::new(7, 8);
+///
+/// let m: Matrix3<i32> = stack![a, b;
+///                              c, 0];
+/// assert_eq!(m, Matrix3::new(1, 2, 5,
+///                            3, 4, 6,
+///                            7, 8, 0));
+///
+/// // A KKT-like system with an identity block.
+/// let kkt: Matrix4<i32> = stack![a, I;
+///                                I, 0];
+/// assert_eq!(kkt, Matrix4::new(1, 2, 1, 0,
+///                              3, 4, 0, 1,
+///                              1, 0, 0, 0,
+///                              0, 1, 0, 0));
+/// ```
+///
+/// Blocks of different kinds can be mixed, and references are accepted as well:
+/// ```
+/// use nalgebra::{stack, DMatrix, Matrix2};
+///
+/// let a = Matrix2::new(1.0, 2.0,
+///                      3.0, 4.0);
+/// let d = DMatrix::from_element(2, 3, 5.0);
+///
+/// let m = stack![&a, d.columns(0, 1);
+///                0,  &d.rows(0, 1).columns(0, 1)];
+/// assert_eq!(m.shape(), (3, 3));
+/// assert_eq!(m[(2, 2)], 5.0);
+/// assert_eq!(m[(2, 0)], 0.0);
+/// ```
+#[proc_macro]
+pub fn stack(stream: TokenStream) -> TokenStream {
+    let matrix = parse_macro_input!(stream as Matrix);
+
+    match stack_impl(matrix) {
+        Ok(output) => proc_macro::TokenStream::from(output),
+        Err(err) => proc_macro::TokenStream::from(err.to_compile_error()),
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+fn stack_impl(matrix: Matrix) -> Result<TokenStream2> {
+    let (nrows, ncols) = (matrix.nrows(), matrix.ncols());
+    let span = proc_macro2::Span::call_site();
+
+    if nrows == 0 || ncols == 0 {
+        return Err(Error::new(span, "stack! requires at least one block."));
+    }
+
+    let blocks: Vec<Vec<StackBlock>> = matrix
+        .rows
+        .into_iter()
+        .map(|row| row.into_iter().map(StackBlock::from_expr).collect())
+        .collect();
+
+    let block_ident = |i: usize, j: usize| quote::format_ident!("__na_block_{}_{}", i, j);
+    let row_dim_ident = |i: usize| quote::format_ident!("__na_row_dim_{}", i);
+    let col_dim_ident = |j: usize| quote::format_ident!("__na_col_dim_{}", j);
+    let row_offset_ident = |i: usize| quote::format_ident!("__na_row_offset_{}", i);
+    let col_offset_ident = |j: usize| quote::format_ident!("__na_col_offset_{}", j);
+
+    // The dimensions of each row (resp. column) of blocks are given by its first block that is
+    // not a placeholder. If there is none, they are deduced from an `I` placeholder whose column
+    // (resp. row) of blocks has a known dimension. The dimensions are recorded in the order they
+    // are resolved, so that each one only depends on previous ones.
+    let mut dim_decls = TokenStream2::new();
+    let mut row_known = vec![false; nrows];
+    let mut col_known = vec![false; ncols];
+
+    for i in 0..nrows {
+        if let Some(j) = (0..ncols).find(|&j| matches!(blocks[i][j], StackBlock::Matrix(_))) {
+            let (dim, block) = (row_dim_ident(i), block_ident(i, j));
+            dim_decls.extend(quote! { let #dim = #block.shape_generic().0; });
+            row_known[i] = true;
+        }
+    }
+
+    for j in 0..ncols {
+        if let Some(i) = (0..nrows).find(|&i| matches!(blocks[i][j], StackBlock::Matrix(_))) {
+            let (dim, block) = (col_dim_ident(j), block_ident(i, j));
+            dim_decls.extend(quote! { let #dim = #block.shape_generic().1; });
+            col_known[j] = true;
+        }
+    }
+
+    loop {
+        let mut progress = false;
+
+        for i in 0..nrows {
+            if !row_known[i] {
+                if let Some(j) = (0..ncols)
+                    .find(|&j| matches!(blocks[i][j], StackBlock::Identity) && col_known[j])
+                {
+                    let (dim, other) = (row_dim_ident(i), col_dim_ident(j));
+                    dim_decls.extend(quote! { let #dim = #other; });
+                    row_known[i] = true;
+                    progress = true;
+                }
+            }
+        }
+
+        for j in 0..ncols {
+            if !col_known[j] {
+                if let Some(i) = (0..nrows)
+                    .find(|&i| matches!(blocks[i][j], StackBlock::Identity) && row_known[i])
+                {
+                    let (dim, other) = (col_dim_ident(j), row_dim_ident(i));
+                    dim_decls.extend(quote! { let #dim = #other; });
+                    col_known[j] = true;
+                    progress = true;
+                }
+            }
+        }
+
+        if !progress {
+            break;
+        }
+    }
+
+    if let Some(i) = row_known.iter().position(|known| !known) {
+        let msg = format!(
+            "Unable to infer the number of rows of the row of blocks {i}: it only contains placeholders."
+        );
+        return Err(Error::new(span, msg));
+    }
+
+    if let Some(j) = col_known.iter().position(|known| !known) {
+        let msg = format!(
+            "Unable to infer the number of columns of the column of blocks {j}: it only contains placeholders."
+        );
+        return Err(Error::new(span, msg));
+    }
+
+    // The offsets of each row and column of blocks, and the total dimensions.
+    let mut offset_decls = TokenStream2::new();
+    let mut total_rows = quote! { 0usize };
+    let mut total_row_dim = TokenStream2::new();
+    for i in 0..nrows {
+        let (dim, offset) = (row_dim_ident(i), row_offset_ident(i));
+        offset_decls.extend(quote! { let #offset = #total_rows; });
+        total_rows = quote! { #offset + nalgebra::Dim::value(&#dim) };
+        total_row_dim = if i == 0 {
+            quote! { #dim }
+        } else {
+            quote! { nalgebra::DimAdd::add(#total_row_dim, #dim) }
+        };
+    }
+
+    let mut total_cols = quote! { 0usize };
+    let mut total_col_dim = TokenStream2::new();
+    for j in 0..ncols {
+        let (dim, offset) = (col_dim_ident(j), col_offset_ident(j));
+        offset_decls.extend(quote! { let #offset = #total_cols; });
+        total_cols = quote! { #offset + nalgebra::Dim::value(&#dim) };
+        total_col_dim = if j == 0 {
+            quote! { #dim }
+        } else {
+            quote! { nalgebra::DimAdd::add(#total_col_dim, #dim) }
+        };
+    }
+
+    let mut exprs = Vec::new();
+    let mut idents = Vec::new();
+    let mut copies = TokenStream2::new();
+
+    for (i, row) in blocks.iter().enumerate() {
+        for (j, block) in row.iter().enumerate() {
+            let (row_dim, col_dim) = (row_dim_ident(i), col_dim_ident(j));
+            let (row_offset, col_offset) = (row_offset_ident(i), col_offset_ident(j));
+            let view = quote! {
+                __na_result.generic_slice_mut((#row_offset, #col_offset), (#row_dim, #col_dim))
+            };
+
+            match block {
+                StackBlock::Zero => {}
+                StackBlock::Identity => copies.extend(quote! { #view.fill_with_identity(); }),
+                StackBlock::Matrix(expr) => {
+                    let ident = block_ident(i, j);
+                    copies.extend(quote! { #view.copy_from(#ident); });
+                    exprs.push(expr);
+                    idents.push(ident);
+                }
+            }
+        }
+    }
+
+    // The blocks are evaluated exactly once, in row-major order. Binding them through a `match`
+    // keeps the temporaries they borrow from alive until the end of the macro.
+    Ok(quote! {
+        match (#(&(#exprs),)*) {
+            (#(#idents,)*) => {
+                #dim_decls
+                #offset_decls
+                let mut __na_result = nalgebra::OMatrix::<_, _, _>::zeros_generic(
+                    #total_row_dim,
+                    #total_col_dim,
+                );
+                #copies
+                __na_result
+            }
+        }
+    })
+}
@@ -1,10 +1,10 @@
 use nalgebra::{
-    DMatrix, DVector, Matrix1x2, Matrix1x3, Matrix1x4, Matrix2, Matrix2x1, Matrix2x3, Matrix2x4,
-    Matrix3, Matrix3x1, Matrix3x2, Matrix3x4, Matrix4, Matrix4x1, Matrix4x2, Matrix4x3, Point,
-    Point1, Point2, Point3, Point4, Point5, Point6, SMatrix, SVector, Vector1, Vector2, Vector3,
-    Vector4, Vector5, Vector6,
+    DMatrix, DVector, Dynamic, Matrix1x2, Matrix1x3, Matrix1x4, Matrix2, Matrix2x1, Matrix2x3,
+    Matrix2x4, Matrix3, Matrix3x1, Matrix3x2, Matrix3x4, Matrix4, Matrix4x1, Matrix4x2, Matrix4x3,
+    OMatrix, Point, Point1, Point2, Point3, Point4, Point5, Point6, SMatrix, SVector, Vector1,
+    Vector2, Vector3, Vector4, Vector5, Vector6, U2,
 };
-use nalgebra_macros::{dmatrix, dvector, matrix, point, vector};
+use nalgebra_macros::{dmatrix, dvector, matrix, point, stack, vector};
 
 fn check_statically_same_type<T>(_: &T, _: &T) {}
 
@@ -167,6 +167,14 @@ fn dmatrix_trybuild_tests() {
     t.compile_fail("tests/trybuild/dmatrix_mismatched_dimensions.rs");
 }
 
+#[test]
+fn stack_trybuild_tests() {
+    let t = trybuild::TestCases::new();
+
+    // Verify error message when the shape of a row of blocks cannot be inferred
+    t.compile_fail("tests/trybuild/stack_only_placeholders.rs");
+}
+
 #[test]
 fn matrix_builtin_types() {
     // Check that matrix! compiles for all built-in types
@@ -305,3 +313,58 @@ fn dvector_arbitrary_expressions() {
     let a_expected = DVector::from_column_slice(&[1 + 2, 2 * 3, 4 * f(5 + 6), 7 - 8 * 9]);
     assert_eq_and_type!(a, a_expected);
 }
+
+#[rustfmt::skip]
+#[test]
+fn stack_static() {
+    let a = Matrix2::new(1, 2, 3, 4);
+    let b = Matrix2x1::new(5, 6);
+    let c = Matrix1x2::new(7, 8);
+    let d = Vector1::new(9);
+
+    assert_eq_and_type!(stack![a, b], Matrix2x3::new(1, 2, 5, 3, 4, 6));
+    assert_eq_and_type!(stack![a; c], Matrix3x2::new(1, 2, 3, 4, 7, 8));
+    assert_eq_and_type!(stack![a, b; c, d], Matrix3::new(1, 2, 5, 3, 4, 6, 7, 8, 9));
+    assert_eq_and_type!(stack![&a, &b; &c, &d], Matrix3::new(1, 2, 5, 3, 4, 6, 7, 8, 9));
+    assert_eq_and_type!(stack![a, 0; 0, d], Matrix3::new(1, 2, 0, 3, 4, 0, 0, 0, 9));
+    assert_eq_and_type!(stack![a, I; c, 0], Matrix3x4::new(1, 2, 1, 0, 3, 4, 0, 1, 7, 8, 0, 0));
+
+    // The shape of the identity block is inferred from the other rows and columns of blocks.
+    assert_eq_and_type!(stack![I, b; c, 0],  Matrix3::new(1, 0, 5, 0, 1, 6, 7, 8, 0));
+    assert_eq_and_type!(stack![a, I; I, 0],
+                        Matrix4::new(1, 2, 1, 0, 3, 4, 0, 1, 1, 0, 0, 0, 0, 1, 0, 0));
+}
+
+#[rustfmt::skip]
+#[test]
+fn stack_dynamic() {
+    let a = Matrix2::new(1, 2, 3, 4);
+    let b = DMatrix::from_row_slice(2, 3, &[5, 6, 7, 8, 9, 10]);
+    let c = DMatrix::from_row_slice(1, 2, &[11, 12]);
+
+    // The number of rows is known at compile-time thanks to `a`.
+    let m: OMatrix<i32, U2, Dynamic> = stack![a, b];
+    assert_eq!(m, SMatrix::<i32, 2, 5>::new(1, 2, 5, 6, 7, 3, 4, 8, 9, 10));
+
+    let m = stack![a, b; c, 0];
+    assert_eq!(m, DMatrix::from_row_slice(3, 5, &[1, 2, 5, 6, 7,
+                                                  3, 4, 8, 9, 10,
+                                                  11, 12, 0, 0, 0]));
+
+    let m = stack![b.columns(0, 2), I; 0, c];
+    assert_eq!(m, DMatrix::from_row_slice(3, 4, &[5, 6, 1, 0,
+                                                  8, 9, 0, 1,
+                                                  0, 0, 11, 12]));
+
+    // Blocks can be arbitrary expressions, including temporaries.
+    let m = stack![a * 2, &b.rows(0, 1).columns(1, 2).transpose()];
+    assert_eq!(m, DMatrix::from_row_slice(2, 3, &[2, 4, 6, 6, 8, 7]));
+}
+
+#[test]
+#[should_panic]
+fn stack_dynamic_mismatched_dimensions() {
+    let a = Matrix2::new(1, 2, 3, 4);
+    let b = DMatrix::from_row_slice(3, 1, &[5, 6, 7]);
+    let _ = stack![a, b];
+}
@@ -0,0 +1,8 @@
+use nalgebra::Matrix2;
+use nalgebra_macros::stack;
+
+fn main() {
+    let a = Matrix2::new(1, 2, 3, 4);
+    let _ = stack![a, 0;
+                   0, I];
+}
@@ -1,10 +1,13 @@
 //! Implementation of matrix market io code.
 //!
 //! See the [website](https://math.nist.gov/MatrixMarket/formats.html) or the [paper](https://www.researchgate.net/publication/2630533_The_Matrix_Market_Exchange_Formats_Initial_Design) for more details about matrix market.
+use crate::convert::serial::convert_coo_dense;
 use crate::SparseFormatError;
 use crate::SparseFormatErrorKind;
 use crate::{CooMatrix, CscMatrix, CsrMatrix};
-use nalgebra::Complex;
+use nalgebra::storage::RawStorage;
+use nalgebra::{ClosedAdd, Complex, DMatrix, Dim, Matrix};
+use num_traits::Zero;
 use pest::iterators::Pairs;
 use pest::Parser;
 use std::cmp::PartialEq;
@@ -234,6 +237,23 @@ pub enum MatrixMarketErrorKind {
     /// assert_eq!(matrix_result.unwrap_err().kind(),MatrixMarketErrorKind::NonSquare);
     /// ```
     NonSquare,
+
+    /// Indicates that a matrix does not have the symmetry required by the storage scheme it is
+    /// saved with.
+    ///
+    /// Examples
+    /// --------
+    /// ```rust
+    /// # use nalgebra::matrix;
+    /// # use nalgebra_sparse::io::save_dense_to_matrix_market_str;
+    /// # use nalgebra_sparse::io::{MatrixMarketErrorKind, MatrixMarketStorageScheme};
+    /// let matrix = matrix![1.0, 2.0;
+    ///                      3.0, 4.0];
+    /// let result = save_dense_to_matrix_market_str(&matrix, MatrixMarketStorageScheme::Symmetric);
+    /// assert_eq!(result.is_err(), true);
+    /// assert_eq!(result.unwrap_err().kind(), MatrixMarketErrorKind::NotSymmetric);
+    /// ```
+    NotSymmetric,
 }
 
 impl MatrixMarketError {
@@ -291,6 +311,9 @@ impl fmt::Display for MatrixMarketError {
             MatrixMarketErrorKind::NonSquare => {
                 write!(f, "NonSquare,")?;
             }
+            MatrixMarketErrorKind::NotSymmetric => {
+                write!(f, "NotSymmetric,")?;
+            }
         }
         write!(f, " message: {}", self.message)
     }
@@ -372,18 +395,23 @@ enum DataType {
     Pattern,
     Integer,
 }
-#[derive(Debug, PartialEq)]
-enum StorageScheme {
+/// The storage scheme of a matrix market file, describing which entries of the matrix are stored.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MatrixMarketStorageScheme {
+    /// Only the lower triangle of a symmetric matrix is stored.
     Symmetric,
+    /// All the entries of the matrix are stored.
     General,
+    /// Only the strictly lower triangle of a skew-symmetric matrix is stored.
     Skew,
+    /// Only the lower triangle of a hermitian matrix is stored.
     Hermitian,
 }
 #[derive(Debug, PartialEq)]
 struct Typecode {
     sparsity: Sparsity,
     datatype: DataType,
-    storagescheme: StorageScheme,
+    storagescheme: MatrixMarketStorageScheme,
 }
 
 impl FromStr for Sparsity {
@@ -418,15 +446,27 @@ impl FromStr for DataType {
     }
 }
 
-impl FromStr for StorageScheme {
+impl MatrixMarketStorageScheme {
+    /// The keyword of this storage scheme in a matrix market header.
+    fn keyword(&self) -> &'static str {
+        match self {
+            MatrixMarketStorageScheme::Symmetric => "symmetric",
+            MatrixMarketStorageScheme::General => "general",
+            MatrixMarketStorageScheme::Skew => "skew-symmetric",
+            MatrixMarketStorageScheme::Hermitian => "hermitian",
+        }
+    }
+}
+
+impl FromStr for MatrixMarketStorageScheme {
     type Err = MatrixMarketError;
     /// Assumes that `word` is already lower case.
     fn from_str(word: &str) -> Result<Self, Self::Err> {
         match word {
-            "skew-symmetric" => Ok(StorageScheme::Skew),
-            "general" => Ok(StorageScheme::General),
-            "symmetric" => Ok(StorageScheme::Symmetric),
-            "hermitian" => Ok(StorageScheme::Hermitian),
+            "skew-symmetric" => Ok(MatrixMarketStorageScheme::Skew),
+            "general" => Ok(MatrixMarketStorageScheme::General),
+            "symmetric" => Ok(MatrixMarketStorageScheme::Symmetric),
+            "hermitian" => Ok(MatrixMarketStorageScheme::Hermitian),
             _ => Err(MatrixMarketError::from_kind_and_message(
                 MatrixMarketErrorKind::ParsingError,
                 format!("keyword {} is unknown", word),
@@ -446,7 +486,7 @@ fn typecode_precheck(tc: &Typecode) -> Result<(), MatrixMarketError> {
     match tc {
         Typecode {
             datatype: DataType::Real,
-            storagescheme: StorageScheme::Hermitian,
+            storagescheme: MatrixMarketStorageScheme::Hermitian,
             ..
         } => Err(MatrixMarketError::from_kind_and_message(
             MatrixMarketErrorKind::InvalidHeader,
@@ -454,7 +494,7 @@ fn typecode_precheck(tc: &Typecode) -> Result<(), MatrixMarketError> {
         )),
         Typecode {
             datatype: DataType::Integer,
-            storagescheme: StorageScheme::Hermitian,
+            storagescheme: MatrixMarketStorageScheme::Hermitian,
             ..
         } => Err(MatrixMarketError::from_kind_and_message(
             MatrixMarketErrorKind::InvalidHeader,
@@ -462,7 +502,7 @@ fn typecode_precheck(tc: &Typecode) -> Result<(), MatrixMarketError> {
         )),
         Typecode {
             datatype: DataType::Pattern,
-            storagescheme: StorageScheme::Hermitian,
+            storagescheme: MatrixMarketStorageScheme::Hermitian,
             ..
         } => Err(MatrixMarketError::from_kind_and_message(
             MatrixMarketErrorKind::InvalidHeader,
@@ -470,7 +510,7 @@ fn typecode_precheck(tc: &Typecode) -> Result<(), MatrixMarketError> {
         )),
         Typecode {
             datatype: DataType::Pattern,
-            storagescheme: StorageScheme::Skew,
+            storagescheme: MatrixMarketStorageScheme::Skew,
             ..
         } => Err(MatrixMarketError::from_kind_and_message(
             MatrixMarketErrorKind::InvalidHeader,
@@ -908,7 +948,7 @@ where
     // used when constructing dense matrix.
     // If it's sparse matrix, it has no effect.
     let mut current_dense_coordinate: (usize, usize) = (0, 0);
-    if header_type.storagescheme == StorageScheme::Skew {
+    if header_type.storagescheme == MatrixMarketStorageScheme::Skew {
         // for skew dense matrix, the first element starts from (1,0)
         current_dense_coordinate = (1, 0);
     }
@@ -1013,12 +1053,12 @@ where
         let (r, c, d) = entry;
 
         match header_type.storagescheme {
-            StorageScheme::General => {
+            MatrixMarketStorageScheme::General => {
                 rows.push(r);
                 cols.push(c);
                 data.push(d);
             }
-            StorageScheme::Symmetric => {
+            MatrixMarketStorageScheme::Symmetric => {
                 check_lower_triangle(r, c)?;
                 rows.push(r);
                 cols.push(c);
@@ -1030,7 +1070,7 @@ where
                     data.push(d);
                 }
             }
-            StorageScheme::Skew => {
+            MatrixMarketStorageScheme::Skew => {
                 check_lower_triangle(r, c)?;
                 rows.push(r);
                 cols.push(c);
@@ -1049,7 +1089,7 @@ where
                 cols.push(r);
                 data.push(d.negative()?);
             }
-            StorageScheme::Hermitian => {
+            MatrixMarketStorageScheme::Hermitian => {
                 check_lower_triangle(r, c)?;
                 rows.push(r);
                 cols.push(c);
@@ -1078,6 +1118,69 @@ where
     )?)
 }
 
+/// Parses a Matrix Market file at the given path as a `DMatrix`.
+///
+/// Both the `array` and the `coordinate` formats are supported. Entries missing from a
+/// `coordinate` file are set to zero, and duplicate entries are summed. The storage scheme of the
+/// file is expanded, so that e.g. a `symmetric` file yields the full symmetric matrix.
+///
+/// See [load_coo_from_matrix_market_file] for the restrictions on the scalar type.
+///
+/// Errors
+/// --------
+///
+/// See [MatrixMarketErrorKind] for a list of possible error conditions.
+///
+/// Examples
+/// --------
+/// ```no_run
+/// use nalgebra_sparse::io::load_dense_from_matrix_market_file;
+/// // Use e.g. `i32` for integer matrices
+/// let matrix = load_dense_from_matrix_market_file::<f64,_>("path/to/matrix.mtx").unwrap();
+/// ```
+pub fn load_dense_from_matrix_market_file<T, P: AsRef<Path>>(
+    path: P,
+) -> Result<DMatrix<T>, MatrixMarketError>
+where
+    T: MatrixMarketScalar + Zero + ClosedAdd,
+{
+    let file = fs::read_to_string(path)?;
+    load_dense_from_matrix_market_str(&file)
+}
+
+/// Parses a Matrix Market file described by the given string as a `DMatrix`.
+///
+/// See [load_dense_from_matrix_market_file] for more information.
+///
+/// Errors
+/// --------
+///
+/// See [MatrixMarketErrorKind] for a list of possible error conditions.
+///
+/// Examples
+/// --------
+/// ```
+/// # use nalgebra::matrix;
+/// use nalgebra_sparse::io::load_dense_from_matrix_market_str;
+/// let str = r#"
+/// %%matrixmarket matrix array real symmetric
+/// 2 2
+/// 1.0
+/// 2.0
+/// 3.0
+/// "#;
+/// let matrix = load_dense_from_matrix_market_str::<f64>(str).unwrap();
+/// assert_eq!(matrix, matrix![1.0, 2.0;
+///                            2.0, 3.0]);
+/// ```
+pub fn load_dense_from_matrix_market_str<T>(data: &str) -> Result<DMatrix<T>, MatrixMarketError>
+where
+    T: MatrixMarketScalar + Zero + ClosedAdd,
+{
+    let coo = load_coo_from_matrix_market_str(data)?;
+    Ok(convert_coo_dense(&coo))
+}
+
 #[inline]
 /// do a quick check it the entry is in the lower triangle part of the matrix
 fn check_lower_triangle(r: usize, c: usize) -> Result<(), MatrixMarketError> {
@@ -1117,7 +1220,7 @@ fn parse_header(inner: &mut Pairs<'_, Rule>) -> Typecode {
             .unwrap()
             .as_str()
             .to_ascii_lowercase()
-            .parse::<StorageScheme>()
+            .parse::<MatrixMarketStorageScheme>()
             .unwrap(),
     }
 }
@@ -1127,7 +1230,7 @@ fn parse_header(inner: &mut Pairs<'_, Rule>) -> Typecode {
 /// Parse a pest structure to sparse shape information, including 3 int, which are number of rows, cols and non-zeros.
 fn parse_sparse_shape(
     inner: &mut Pairs<'_, Rule>,
-    storagescheme: &StorageScheme,
+    storagescheme: &MatrixMarketStorageScheme,
 ) -> Result<(usize, usize, usize), MatrixMarketError> {
     // unwrap() in this function are guaranteed by parsing the data
     let shape_inner = inner.next().unwrap();
@@ -1144,7 +1247,7 @@ fn parse_sparse_shape(
     let nnz = inner.next().unwrap().as_str().parse::<usize>().unwrap();
 
     // check for square matrix, when it's not a general matrix
-    if *storagescheme != StorageScheme::General && r != c {
+    if *storagescheme != MatrixMarketStorageScheme::General && r != c {
         return Err(MatrixMarketError::from_kind_and_message(MatrixMarketErrorKind::NonSquare, format!("(Skew-)Symmetric or hermitian matrix should be square matrix, but it has dimension {} and {}", r, c)));
     }
 
@@ -1154,7 +1257,7 @@ fn parse_sparse_shape(
 /// Parse a pest structure to dense shape information, including 2 int, which are number of rows, cols.
 fn parse_dense_shape(
     inner: &mut Pairs<'_, Rule>,
-    storagescheme: &StorageScheme,
+    storagescheme: &MatrixMarketStorageScheme,
 ) -> Result<(usize, usize, usize), MatrixMarketError> {
     // unwrap() in this function are guaranteed by parsing the data
     let shape_inner = inner.next().unwrap();
@@ -1169,26 +1272,26 @@ fn parse_dense_shape(
     let c = inner.next().unwrap().as_str().parse::<usize>().unwrap();
 
     // check for square matrix, when it's not a general matrix
-    if *storagescheme != StorageScheme::General && r != c {
+    if *storagescheme != MatrixMarketStorageScheme::General && r != c {
         return Err(MatrixMarketError::from_kind_and_message(MatrixMarketErrorKind::NonSquare, format!("(Skew-)Symmetric or hermitian matrix should be square matrix, but it has dimension {} and {}", r, c)));
     }
 
     let n: usize;
     // Calculate the number of entries in the dense matrix
     match storagescheme {
-        StorageScheme::General => {
+        MatrixMarketStorageScheme::General => {
             // general matrix should contain r*c entries
             n = r * c;
         }
-        StorageScheme::Symmetric | StorageScheme::Hermitian => {
+        MatrixMarketStorageScheme::Symmetric | MatrixMarketStorageScheme::Hermitian => {
             // it must be square matrix, so r==c is true here
             // Symmetric or Hermitian should contain 1+2...+r  = r*(r+1)/2 entries
             n = r * (r + 1) / 2;
         }
-        StorageScheme::Skew => {
+        MatrixMarketStorageScheme::Skew => {
             // it must be square matrix, so r==c is true here
             // Skew-Symmetric should contain 1+2...+r-1  = r*(r-1)/2 entries
-            n = r * (r - 1) / 2;
+            n = r * r.saturating_sub(1) / 2;
         }
     }
 
@@ -1391,13 +1494,13 @@ fn parse_sparse_coordinate(
 fn next_dense_coordinate(
     current_dense_coordinate: &mut (usize, usize),
     shape: (usize, usize, usize),
-    storagescheme: &StorageScheme,
+    storagescheme: &MatrixMarketStorageScheme,
 ) {
     // matrix market is column based format.
     // so it follows the order (0,0) -> (1,0) -> ... -> (row, 0) -> (0,1) -> ... ->(row,col)
     // current_dense_coordinate is (row, column)
     match storagescheme {
-        StorageScheme::General => {
+        MatrixMarketStorageScheme::General => {
             if current_dense_coordinate.0 < shape.0 - 1 {
                 current_dense_coordinate.0 += 1
             } else {
@@ -1406,7 +1509,7 @@ fn next_dense_coordinate(
                 current_dense_coordinate.1 += 1;
             }
         }
-        StorageScheme::Symmetric | StorageScheme::Hermitian => {
+        MatrixMarketStorageScheme::Symmetric | MatrixMarketStorageScheme::Hermitian => {
             if current_dense_coordinate.0 < shape.0 - 1 {
                 current_dense_coordinate.0 += 1
             } else {
@@ -1416,7 +1519,7 @@ fn next_dense_coordinate(
                 current_dense_coordinate.0 = current_dense_coordinate.1;
             }
         }
-        StorageScheme::Skew => {
+        MatrixMarketStorageScheme::Skew => {
             if current_dense_coordinate.0 < shape.0 - 1 {
                 current_dense_coordinate.0 += 1;
             } else {
@@ -1549,3 +1652,246 @@ where
     }
     Ok(())
 }
+
+/// Save a dense matrix as a Matrix Market format string.
+///
+/// The exporter writes the matrix into the `array` format, with the given storage scheme. See
+/// [save_dense_to_matrix_market] for more information.
+///
+/// Errors
+/// --------
+///
+/// See [save_dense_to_matrix_market] for a list of possible error conditions.
+///
+/// Examples
+/// --------
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # use nalgebra::matrix;
+/// use nalgebra_sparse::io::{save_dense_to_matrix_market_str, MatrixMarketStorageScheme};
+/// let expected_str = r#"%%matrixmarket matrix array integer skew-symmetric
+/// % matrixmarket file generated by nalgebra-sparse.
+/// 3 3
+/// 1
+/// 2
+/// 3
+/// "#;
+/// let matrix = matrix![0, -1, -2;
+///                      1,  0, -3;
+///                      2,  3,  0];
+/// let generated_matrixmarket_str =
+///     save_dense_to_matrix_market_str(&matrix, MatrixMarketStorageScheme::Skew)?;
+/// assert_eq!(expected_str, generated_matrixmarket_str);
+/// # Ok(()) }
+/// ```
+pub fn save_dense_to_matrix_market_str<T, R, C, S>(
+    matrix: &Matrix<T, R, C, S>,
+    storagescheme: MatrixMarketStorageScheme,
+) -> Result<String, MatrixMarketError>
+where
+    T: MatrixMarketScalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    let mut bytes = Vec::<u8>::new();
+    save_dense_to_matrix_market(&mut bytes, matrix, storagescheme)?;
+
+    Ok(String::from_utf8(bytes)
+        .expect("Unexpected non UTF-8 data was generated when export to matrix market string"))
+}
+
+/// Save a dense matrix to a Matrix Market format file.
+///
+/// The exporter saves the matrix with the `array` matrix market format, with the given storage
+/// scheme. See [save_dense_to_matrix_market] for more information.
+///
+/// Errors
+/// --------
+///
+/// See [save_dense_to_matrix_market] for a list of possible error conditions.
+///
+/// Examples
+/// --------
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # use nalgebra::matrix;
+/// use nalgebra_sparse::io::{save_dense_to_matrix_market_file, MatrixMarketStorageScheme};
+/// let matrix = matrix![1.0, 2.0;
+///                      2.0, 3.0];
+/// save_dense_to_matrix_market_file(&matrix, "path/to/matrix.mtx", MatrixMarketStorageScheme::Symmetric)?;
+/// # Ok(()) }
+/// ```
+pub fn save_dense_to_matrix_market_file<T, R, C, S, P>(
+    matrix: &Matrix<T, R, C, S>,
+    path: P,
+    storagescheme: MatrixMarketStorageScheme,
+) -> Result<(), MatrixMarketError>
+where
+    T: MatrixMarketScalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+    P: AsRef<Path>,
+{
+    // Check the matrix before creating the file, so that no partial file is left behind.
+    check_dense_storage_scheme(matrix, storagescheme)?;
+    let file = File::create(path)?;
+    let mut file = BufWriter::new(file);
+    write_dense_matrix_market(&mut file, matrix, storagescheme)?;
+    file.flush()?;
+    Ok(())
+}
+
+/// Save a dense matrix to an [std::io::Write] instance.
+///
+/// The matrix is written in column-major order into the `array` matrix market format. With the
+/// `symmetric` and `hermitian` storage schemes, only the lower triangle of the matrix is written,
+/// and with the `skew-symmetric` storage scheme only its strictly lower triangle is written. The
+/// matrix is checked beforehand to actually have the symmetry required by the storage scheme, so
+/// that loading the file back yields the same matrix.
+///
+/// This is the most general dense save functionality. See [save_dense_to_matrix_market_file] and
+/// [save_dense_to_matrix_market_str] for higher-level functionality.
+///
+/// Errors
+/// --------
+///
+/// - [InvalidHeader](MatrixMarketErrorKind::InvalidHeader) if the scalar type can't be stored in
+///   the `array` format with the given storage scheme, e.g. a real `hermitian` matrix.
+/// - [NonSquare](MatrixMarketErrorKind::NonSquare) if the storage scheme isn't `general` and the
+///   matrix isn't square.
+/// - [DiagonalError](MatrixMarketErrorKind::DiagonalError) if the diagonal of a skew-symmetric
+///   matrix isn't zero, or the diagonal of a hermitian matrix isn't real.
+/// - [NotSymmetric](MatrixMarketErrorKind::NotSymmetric) if the matrix doesn't otherwise have the
+///   symmetry required by the storage scheme.
+/// - [IOError](MatrixMarketErrorKind::IOError) if writing fails.
+pub fn save_dense_to_matrix_market<T, R, C, S, W>(
+    mut w: W,
+    matrix: &Matrix<T, R, C, S>,
+    storagescheme: MatrixMarketStorageScheme,
+) -> Result<(), MatrixMarketError>
+where
+    T: MatrixMarketScalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+    W: Write,
+{
+    check_dense_storage_scheme(matrix, storagescheme)?;
+    write_dense_matrix_market(&mut w, matrix, storagescheme)?;
+    Ok(())
+}
+
+/// Check that the matrix can be saved in the `array` format with the given storage scheme.
+fn check_dense_storage_scheme<T, R, C, S>(
+    matrix: &Matrix<T, R, C, S>,
+    storagescheme: MatrixMarketStorageScheme,
+) -> Result<(), MatrixMarketError>
+where
+    T: MatrixMarketScalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    // unwrap() is guaranteed by the typename of the supported scalars
+    typecode_precheck(&Typecode {
+        sparsity: Sparsity::Dense,
+        datatype: T::typename().parse::<DataType>().unwrap(),
+        storagescheme,
+    })?;
+
+    let (nrows, ncols) = matrix.shape();
+    if storagescheme == MatrixMarketStorageScheme::General {
+        return Ok(());
+    }
+    if nrows != ncols {
+        return Err(MatrixMarketError::from_kind_and_message(MatrixMarketErrorKind::NonSquare, format!("(Skew-)Symmetric or hermitian matrix should be square matrix, but it has dimension {} and {}", nrows, ncols)));
+    }
+
+    for c in 0..ncols {
+        let d = matrix[(c, c)].clone();
+        let diagonal_ok = match storagescheme {
+            MatrixMarketStorageScheme::Skew => d.clone().negative()? == d,
+            MatrixMarketStorageScheme::Hermitian => d.clone().conjugate()? == d,
+            _ => true,
+        };
+        if !diagonal_ok {
+            return Err(MatrixMarketError::from_kind_and_message(
+                MatrixMarketErrorKind::DiagonalError,
+                format!(
+                    "The diagonal element in row(and column) {} can't be stored in a {} matrix",
+                    c + 1,
+                    storagescheme.keyword()
+                ),
+            ));
+        }
+
+        for r in c + 1..nrows {
+            let lower = matrix[(r, c)].clone();
+            let mirrored = match storagescheme {
+                MatrixMarketStorageScheme::Skew => lower.negative()?,
+                MatrixMarketStorageScheme::Hermitian => lower.conjugate()?,
+                _ => lower,
+            };
+            if matrix[(c, r)] != mirrored {
+                return Err(MatrixMarketError::from_kind_and_message(
+                    MatrixMarketErrorKind::NotSymmetric,
+                    format!(
+                        "Entry: row {} col {} doesn't match its mirrored entry in a {} matrix",
+                        c + 1,
+                        r + 1,
+                        storagescheme.keyword()
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Write the matrix in the `array` format, without checking it against the storage scheme.
+fn write_dense_matrix_market<T, R, C, S, W>(
+    mut w: W,
+    matrix: &Matrix<T, R, C, S>,
+    storagescheme: MatrixMarketStorageScheme,
+) -> Result<(), std::io::Error>
+where
+    T: MatrixMarketScalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+    W: Write,
+{
+    // write header
+    writeln!(
+        w,
+        "%%matrixmarket matrix array {} {}",
+        T::typename(),
+        storagescheme.keyword()
+    )?;
+
+    //write comment
+    writeln!(w, "% matrixmarket file generated by nalgebra-sparse.")?;
+
+    // write shape information
+    writeln!(w, "{} {}", matrix.nrows(), matrix.ncols())?;
+
+    // write the stored entries in column-major order
+    let mut buffer = String::new();
+    for c in 0..matrix.ncols() {
+        let first_row = match storagescheme {
+            MatrixMarketStorageScheme::General => 0,
+            MatrixMarketStorageScheme::Symmetric | MatrixMarketStorageScheme::Hermitian => c,
+            MatrixMarketStorageScheme::Skew => c + 1,
+        };
+        for r in first_row..matrix.nrows() {
+            buffer.clear();
+            matrix[(r, c)]
+                .write_matrix_market(&mut buffer)
+                .expect("Unexpected format error was generated when write to String");
+            writeln!(w, "{}", buffer)?;
+        }
+    }
+    Ok(())
+}
@@ -26,6 +26,11 @@
 //! [load_coo_from_matrix_market_str], or similarly write to a string with
 //! [save_to_matrix_market_str].
 //!
+//! Dense matrices can be loaded as a [DMatrix](nalgebra::DMatrix) with
+//! [load_dense_from_matrix_market_file] and [load_dense_from_matrix_market_str], and saved in the
+//! `array` format with [save_dense_to_matrix_market_file] and [save_dense_to_matrix_market_str],
+//! optionally storing only part of the matrix according to a [MatrixMarketStorageScheme].
+//!
 //! Our implementation is based on the [format description](https://math.nist.gov/MatrixMarket/formats.html)
 //! on the Matrix Market website and the
 //! [following NIST whitepaper](https://math.nist.gov/MatrixMarket/reports/MMformat.ps):
@@ -34,8 +39,11 @@
 //! > "*The Matrix Market Exchange Formats: Initial Design.*" (1996).
 
 pub use self::matrix_market::{
-    load_coo_from_matrix_market_file, load_coo_from_matrix_market_str, save_to_matrix_market,
-    save_to_matrix_market_file, save_to_matrix_market_str, MatrixMarketError,
-    MatrixMarketErrorKind, MatrixMarketExport, MatrixMarketScalar,
+    load_coo_from_matrix_market_file, load_coo_from_matrix_market_str,
+    load_dense_from_matrix_market_file, load_dense_from_matrix_market_str,
+    save_dense_to_matrix_market, save_dense_to_matrix_market_file, save_dense_to_matrix_market_str,
+    save_to_matrix_market, save_to_matrix_market_file, save_to_matrix_market_str,
+    MatrixMarketError, MatrixMarketErrorKind, MatrixMarketExport, MatrixMarketScalar,
+    MatrixMarketStorageScheme,
 };
 mod matrix_market;
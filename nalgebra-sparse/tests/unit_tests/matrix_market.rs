@@ -1,9 +1,12 @@
 use matrixcompare::assert_matrix_eq;
 use nalgebra::matrix;
-use nalgebra::Complex;
+use nalgebra::proptest::matrix as dense_matrix;
+use nalgebra::{Complex, DMatrix};
 use nalgebra_sparse::io::{
-    load_coo_from_matrix_market_file, load_coo_from_matrix_market_str, save_to_matrix_market_file,
-    save_to_matrix_market_str,
+    load_coo_from_matrix_market_file, load_coo_from_matrix_market_str,
+    load_dense_from_matrix_market_file, load_dense_from_matrix_market_str,
+    save_dense_to_matrix_market_file, save_dense_to_matrix_market_str, save_to_matrix_market_file,
+    save_to_matrix_market_str, MatrixMarketErrorKind, MatrixMarketStorageScheme,
 };
 use nalgebra_sparse::proptest::coo_no_duplicates;
 use nalgebra_sparse::CooMatrix;
@@ -446,6 +449,157 @@ fn test_matrixmarket_write_complex() {
     assert_eq!(matrixmarket_str, expected);
 }
 
+#[test]
+#[rustfmt::skip]
+fn test_matrixmarket_load_dense_from_array_and_coordinate() {
+    let array_str = r#"
+%%MatrixMarket matrix array real skew-symmetric
+3 3
+1.0
+2.0
+3.0
+"#;
+    let coordinate_str = r#"
+%%MatrixMarket matrix coordinate real general
+3 3 4
+2 1 1.0
+3 1 2.0
+3 2 1.0
+3 2 2.0
+"#;
+    let expected = matrix![
+        0.0, -1.0, -2.0;
+        1.0,  0.0, -3.0;
+        2.0,  3.0,  0.0;
+    ];
+    let dense: DMatrix<f64> = load_dense_from_matrix_market_str(array_str).unwrap();
+    assert_eq!(dense, expected);
+    let skew_part = load_dense_from_matrix_market_str::<f64>(coordinate_str).unwrap();
+    assert_eq!(skew_part, expected.lower_triangle());
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_matrixmarket_write_dense_general() {
+    let matrix = matrix![
+        1.0, 2.0, 3.0;
+        4.0, 5.0, 6.0;
+    ];
+    let expected = r#"%%matrixmarket matrix array real general
+% matrixmarket file generated by nalgebra-sparse.
+2 3
+1
+4
+2
+5
+3
+6
+"#;
+    let matrixmarket_str =
+        save_dense_to_matrix_market_str(&matrix, MatrixMarketStorageScheme::General).unwrap();
+    assert_eq!(matrixmarket_str, expected);
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_matrixmarket_write_dense_symmetric() {
+    let matrix = matrix![
+        1, 2, 3;
+        2, 4, 5;
+        3, 5, 6;
+    ];
+    let expected = r#"%%matrixmarket matrix array integer symmetric
+% matrixmarket file generated by nalgebra-sparse.
+3 3
+1
+2
+3
+4
+5
+6
+"#;
+    let matrixmarket_str =
+        save_dense_to_matrix_market_str(&matrix, MatrixMarketStorageScheme::Symmetric).unwrap();
+    assert_eq!(matrixmarket_str, expected);
+    let loaded = load_dense_from_matrix_market_str::<i32>(&matrixmarket_str).unwrap();
+    assert_eq!(loaded, matrix);
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_matrixmarket_write_dense_hermitian() {
+    let matrix = matrix![
+        C64{re:1.0,im:0.0}, C64{re:2.0,im:-3.0};
+        C64{re:2.0,im:3.0}, C64{re:4.0,im:0.0};
+    ];
+    let expected = r#"%%matrixmarket matrix array complex hermitian
+% matrixmarket file generated by nalgebra-sparse.
+2 2
+1 0
+2 3
+4 0
+"#;
+    let matrixmarket_str =
+        save_dense_to_matrix_market_str(&matrix, MatrixMarketStorageScheme::Hermitian).unwrap();
+    assert_eq!(matrixmarket_str, expected);
+    let loaded = load_dense_from_matrix_market_str::<C64>(&matrixmarket_str).unwrap();
+    assert_eq!(loaded, matrix);
+}
+
+#[test]
+#[rustfmt::skip]
+fn test_matrixmarket_write_dense_invalid_storage_scheme() {
+    let save = |m: &DMatrix<f64>, scheme| {
+        save_dense_to_matrix_market_str(m, scheme).unwrap_err().kind()
+    };
+    let non_square = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(save(&non_square, MatrixMarketStorageScheme::Symmetric), MatrixMarketErrorKind::NonSquare);
+
+    let non_symmetric = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(save(&non_symmetric, MatrixMarketStorageScheme::Symmetric), MatrixMarketErrorKind::NotSymmetric);
+
+    let symmetric = DMatrix::from_row_slice(2, 2, &[0.0, 2.0, 2.0, 0.0]);
+    assert_eq!(save(&symmetric, MatrixMarketStorageScheme::Skew), MatrixMarketErrorKind::NotSymmetric);
+    assert_eq!(save(&symmetric, MatrixMarketStorageScheme::Hermitian), MatrixMarketErrorKind::InvalidHeader);
+
+    let nonzero_diagonal = DMatrix::from_row_slice(2, 2, &[1.0, -2.0, 2.0, 0.0]);
+    assert_eq!(save(&nonzero_diagonal, MatrixMarketStorageScheme::Skew), MatrixMarketErrorKind::DiagonalError);
+
+    let complex_diagonal = matrix![C64{re:1.0,im:1.0}];
+    let err = save_dense_to_matrix_market_str(&complex_diagonal, MatrixMarketStorageScheme::Hermitian).unwrap_err();
+    assert_eq!(err.kind(), MatrixMarketErrorKind::DiagonalError);
+}
+
+proptest! {
+    #[test]
+    fn dense_matrix_market_roundtrip_str(matrix in dense_matrix(-10 ..= 10, 0 ..= 10, 0 ..= 10)) {
+        let generated_matrixmarket_string =
+            save_dense_to_matrix_market_str(&matrix, MatrixMarketStorageScheme::General).unwrap();
+        let generated_matrix: DMatrix<i32> = load_dense_from_matrix_market_str(&generated_matrixmarket_string).unwrap();
+        prop_assert_eq!(generated_matrix, matrix);
+    }
+}
+
+proptest! {
+    #[test]
+    fn dense_matrix_market_roundtrip_file(matrix in (0usize ..= 10).prop_flat_map(|n| dense_matrix(-10 ..= 10, n, n))) {
+        let symmetric = &matrix + matrix.transpose();
+        let skew = &matrix - matrix.transpose();
+        let temp_dir = tempdir().expect("Unable to create temporary directory");
+        let file_path = temp_dir.path().join("temp.mtx");
+        for (m, scheme) in [
+            (&matrix, MatrixMarketStorageScheme::General),
+            (&symmetric, MatrixMarketStorageScheme::Symmetric),
+            (&skew, MatrixMarketStorageScheme::Skew),
+        ] {
+            save_dense_to_matrix_market_file(m, &file_path, scheme).unwrap();
+            let generated_matrix: DMatrix<i32> = load_dense_from_matrix_market_file(&file_path).unwrap();
+            prop_assert_eq!(&generated_matrix, m);
+        }
+        temp_dir.close().expect("Unable to delete temporary directory");
+    }
+}
+
 proptest! {
     #[test]
     fn coo_matrix_market_roundtrip_str(coo in coo_no_duplicates(-10 ..= 10, 0 ..= 10, 0..= 10, 100)) {
@@ -1,6 +1,10 @@
-#[cfg(any(feature = "alloc", feature = "std"))]
-use crate::base::dimension::Dynamic;
-use crate::base::dimension::{U1, U2, U3, U4, U5, U6};
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{Dynamic, U1, U2, U3, U4, U5, U6};
+use crate::base::inline_storage::InlineStorage;
+use crate::base::row_major_allocator::RowMajorAllocator;
+use crate::base::row_major_storage::RowMajorArrayStorage;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::row_major_storage::RowMajorVecStorage;
 use crate::base::storage::Owned;
 #[cfg(any(feature = "std", feature = "alloc"))]
 use crate::base::vec_storage::VecStorage;
@@ -267,6 +271,60 @@ pub type Matrix6x4<T> = Matrix<T, U6, U4, ArrayStorage<T, 6, 4>>;
 /// **Because this is an alias, not all its methods are listed here. See the [`Matrix`](crate::base::Matrix) type too.**
 pub type Matrix6x5<T> = Matrix<T, U6, U5, ArrayStorage<T, 6, 5>>;
 
+/*
+ *
+ *
+ * Row-major matrices.
+ *
+ *
+ */
+
+/// An owned row-major matrix with `R` rows and `C` columns.
+///
+/// Operations returning a new owned matrix produce a column-major `OMatrix`, even if their inputs
+/// are row-major: use `to_row_major` to convert their result.
+///
+/// **Because this is an alias, not all its methods are listed here. See the [`Matrix`](crate::base::Matrix) type too.**
+pub type RowMajorOMatrix<T, R, C> =
+    Matrix<T, R, C, <RowMajorAllocator as Allocator<T, R, C>>::Buffer>;
+
+/// A statically sized row-major matrix with `R` rows and `C` columns.
+///
+/// **Because this is an alias, not all its methods are listed here. See the [`Matrix`](crate::base::Matrix) type too.**
+pub type RowMajorSMatrix<T, const R: usize, const C: usize> =
+    Matrix<T, Const<R>, Const<C>, RowMajorArrayStorage<T, R, C>>;
+
+/// A dynamically sized row-major matrix.
+///
+/// **Because this is an alias, not all its methods are listed here. See the [`Matrix`](crate::base::Matrix) type too.**
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub type RowMajorDMatrix<T> = Matrix<T, Dynamic, Dynamic, RowMajorVecStorage<T, Dynamic, Dynamic>>;
+
+/*
+ *
+ *
+ * Inline matrices.
+ *
+ *
+ */
+
+/// A matrix with `R` rows and `C` columns stored inline, with a fixed capacity of `N` components.
+///
+/// **Because this is an alias, not all its methods are listed here. See the [`Matrix`](crate::base::Matrix) type too.**
+pub type InlineOMatrix<T, R, C, const N: usize> = Matrix<T, R, C, InlineStorage<T, R, C, N>>;
+
+/// A dynamically sized matrix stored inline, with a fixed capacity of `N` components.
+///
+/// **Because this is an alias, not all its methods are listed here. See the [`Matrix`](crate::base::Matrix) type too.**
+pub type InlineDMatrix<T, const N: usize> =
+    Matrix<T, Dynamic, Dynamic, InlineStorage<T, Dynamic, Dynamic, N>>;
+
+/// A dynamically sized column vector stored inline, with a fixed capacity of `N` components.
+///
+/// **Because this is an alias, not all its methods are listed here. See the [`Matrix`](crate::base::Matrix) type too.**
+pub type InlineDVector<T, const N: usize> =
+    Matrix<T, Dynamic, U1, InlineStorage<T, Dynamic, U1, N>>;
+
 /*
  *
  *
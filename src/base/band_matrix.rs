@@ -0,0 +1,331 @@
+//! Storage of banded matrices.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
+use std::ops::{Index, IndexMut, Mul};
+
+use num::{One, Zero};
+use simba::scalar::{ClosedAdd, ClosedMul};
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{Dim, Dynamic};
+use crate::base::storage::{Storage, StorageMut};
+use crate::base::{DMatrix, DefaultAllocator, Matrix, OMatrix, Scalar, Vector};
+
+/// A dynamically sized matrix with nonzero components only close to its diagonal.
+///
+/// The component `(i, j)` can be nonzero only if `j <= i + kl` and `i <= j + ku`, where `kl` and
+/// `ku` are the lower and upper bandwidths. Only those components are stored, column by column,
+/// following the LAPACK general band (`gb`) layout: the storage is a `(kl + ku + 1) x ncols`
+/// column-major array where the component `(i, j)` is at the row `ku + i - j` of the column `j`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BandMatrix<T> {
+    data: Vec<T>,
+    nrows: usize,
+    ncols: usize,
+    kl: usize,
+    ku: usize,
+}
+
+impl<T: Scalar> BandMatrix<T> {
+    /// Creates a band matrix with `kl` subdiagonals and `ku` superdiagonals, all set to zero.
+    #[inline]
+    pub fn zeros(nrows: usize, ncols: usize, kl: usize, ku: usize) -> Self
+    where
+        T: Zero,
+    {
+        Self {
+            data: vec![T::zero(); (kl + ku + 1) * ncols],
+            nrows,
+            ncols,
+            kl,
+            ku,
+        }
+    }
+
+    /// Copies the band of `m` with `kl` subdiagonals and `ku` superdiagonals into a band
+    /// matrix.
+    ///
+    /// The components of `m` outside of this band are never read.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{BandMatrix, Matrix3};
+    /// let m = Matrix3::new(1.0, 2.0, 0.0,
+    ///                      3.0, 4.0, 5.0,
+    ///                      0.0, 6.0, 7.0);
+    /// let band = BandMatrix::from_matrix(&m, 1, 1);
+    /// assert_eq!(band[(2, 1)], 6.0);
+    /// assert_eq!(band.to_matrix(), m);
+    /// ```
+    #[inline]
+    pub fn from_matrix<R: Dim, C: Dim, S: Storage<T, R, C>>(
+        m: &Matrix<T, R, C, S>,
+        kl: usize,
+        ku: usize,
+    ) -> Self
+    where
+        T: Zero,
+    {
+        let (nrows, ncols) = m.shape();
+        let mut res = Self::zeros(nrows, ncols, kl, ku);
+
+        for j in 0..ncols {
+            for i in res.band_rows(j) {
+                res[(i, j)] = m[(i, j)].clone();
+            }
+        }
+
+        res
+    }
+
+    /// Creates a square tridiagonal matrix from its subdiagonal, diagonal, and superdiagonal.
+    ///
+    /// Panics if `sub` and `sup` do not have exactly one component less than `diag`.
+    #[inline]
+    pub fn tridiagonal(sub: &[T], diag: &[T], sup: &[T]) -> Self
+    where
+        T: Zero,
+    {
+        let n = diag.len();
+        assert!(
+            sub.len() + 1 == n.max(1) && sup.len() + 1 == n.max(1),
+            "Tridiagonal matrix: the sub- and super-diagonals must have one component less than the diagonal."
+        );
+
+        let mut res = Self::zeros(n, n, 1, 1);
+        for i in 0..n {
+            res[(i, i)] = diag[i].clone();
+            if i + 1 < n {
+                res[(i + 1, i)] = sub[i].clone();
+                res[(i, i + 1)] = sup[i].clone();
+            }
+        }
+
+        res
+    }
+
+    /// The number of rows of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    /// The number of columns of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    /// The number of rows and columns of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+
+    /// The number of subdiagonals of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn lower_bandwidth(&self) -> usize {
+        self.kl
+    }
+
+    /// The number of superdiagonals of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn upper_bandwidth(&self) -> usize {
+        self.ku
+    }
+
+    /// Whether this matrix is square with at most one subdiagonal and one superdiagonal.
+    #[inline]
+    #[must_use]
+    pub fn is_tridiagonal(&self) -> bool {
+        self.nrows == self.ncols && self.kl <= 1 && self.ku <= 1
+    }
+
+    /// The LAPACK band storage of this matrix, in column-major order.
+    ///
+    /// The components of the storage located outside of the matrix, e.g., above the first row,
+    /// are never read.
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// The range of rows of the components of the `j-th` column that are within the band.
+    #[inline]
+    pub(crate) fn band_rows(&self, j: usize) -> std::ops::Range<usize> {
+        j.saturating_sub(self.ku)..(j + self.kl + 1).min(self.nrows)
+    }
+
+    #[inline]
+    fn band_index(&self, i: usize, j: usize) -> Option<usize> {
+        if i < self.nrows && j < self.ncols && i <= j + self.kl && j <= i + self.ku {
+            Some(j * (self.kl + self.ku + 1) + self.ku + i - j)
+        } else {
+            None
+        }
+    }
+
+    /// A reference to the component `(i, j)`, or `None` if it is outside of the band or of the
+    /// matrix.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.band_index(i, j).map(|id| &self.data[id])
+    }
+
+    /// A mutable reference to the component `(i, j)`, or `None` if it is outside of the band or
+    /// of the matrix.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        self.band_index(i, j).map(move |id| &mut self.data[id])
+    }
+
+    /// Converts this band matrix into a regular dense matrix.
+    #[inline]
+    #[must_use]
+    pub fn to_matrix(&self) -> DMatrix<T>
+    where
+        T: Zero,
+    {
+        DMatrix::from_fn(self.nrows, self.ncols, |i, j| {
+            self.get(i, j).cloned().unwrap_or_else(T::zero)
+        })
+    }
+
+    /// Computes `y = alpha * self * x + beta * y`.
+    #[inline]
+    pub(crate) fn gemv_to<D2: Dim, S2, D3: Dim, S3>(
+        &self,
+        y: &mut Vector<T, D2, S2>,
+        alpha: T,
+        x: &Vector<T, D3, S3>,
+        beta: T,
+    ) where
+        T: Zero + One + ClosedAdd + ClosedMul,
+        S2: StorageMut<T, D2>,
+        S3: Storage<T, D3>,
+    {
+        assert!(
+            y.nrows() == self.nrows && x.nrows() == self.ncols,
+            "Band gemv: dimensions mismatch."
+        );
+
+        if beta.is_zero() {
+            y.fill(T::zero());
+        } else {
+            *y *= beta;
+        }
+
+        for j in 0..self.ncols {
+            let ax_j = alpha.clone() * x[j].clone();
+            for i in self.band_rows(j) {
+                y[i] += self[(i, j)].clone() * ax_j.clone();
+            }
+        }
+    }
+}
+
+impl<T: Scalar> Index<(usize, usize)> for BandMatrix<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        self.get(i, j)
+            .expect("Band matrix index out of the band or of the matrix.")
+    }
+}
+
+impl<T: Scalar> IndexMut<(usize, usize)> for BandMatrix<T> {
+    #[inline]
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        self.get_mut(i, j)
+            .expect("Band matrix index out of the band or of the matrix.")
+    }
+}
+
+impl<T: Scalar + Zero> From<BandMatrix<T>> for DMatrix<T> {
+    #[inline]
+    fn from(band: BandMatrix<T>) -> Self {
+        band.to_matrix()
+    }
+}
+
+impl<'a, T: Scalar + Zero> From<&'a DMatrix<T>> for BandMatrix<T> {
+    /// Stores the smallest band containing all the nonzero components of `m`.
+    #[inline]
+    fn from(m: &'a DMatrix<T>) -> Self {
+        let (mut kl, mut ku) = (0, 0);
+
+        for j in 0..m.ncols() {
+            for i in 0..m.nrows() {
+                if !m[(i, j)].is_zero() {
+                    kl = kl.max(i.saturating_sub(j));
+                    ku = ku.max(j.saturating_sub(i));
+                }
+            }
+        }
+
+        Self::from_matrix(m, kl, ku)
+    }
+}
+
+impl<'b, T, R2: Dim, C2: Dim, S2> Mul<&'b Matrix<T, R2, C2, S2>> for &BandMatrix<T>
+where
+    T: Scalar + Zero + One + ClosedAdd + ClosedMul,
+    S2: Storage<T, R2, C2>,
+    DefaultAllocator: Allocator<T, Dynamic, C2>,
+{
+    type Output = OMatrix<T, Dynamic, C2>;
+
+    #[inline]
+    fn mul(self, rhs: &'b Matrix<T, R2, C2, S2>) -> Self::Output {
+        let ncols = rhs.shape_generic().1;
+        let mut res = OMatrix::zeros_generic(Dynamic::new(self.nrows), ncols);
+
+        for j in 0..rhs.ncols() {
+            self.gemv_to(&mut res.column_mut(j), T::one(), &rhs.column(j), T::zero());
+        }
+
+        res
+    }
+}
+
+/// # Band BLAS
+impl<T, D: Dim, S: StorageMut<T, D>> Vector<T, D, S>
+where
+    T: Scalar + Zero + One + ClosedAdd + ClosedMul,
+{
+    /// Computes `self = alpha * a * x + beta * self`, where `a` is a band matrix.
+    ///
+    /// This takes `O(nrows * (kl + ku))` operations. If `beta` is zero, `self` is never read.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{BandMatrix, DVector};
+    /// let a = BandMatrix::tridiagonal(&[1.0, 1.0], &[2.0, 2.0, 2.0], &[-1.0, -1.0]);
+    /// let x = DVector::from_vec(vec![1.0, 2.0, 3.0]);
+    /// let mut y = DVector::from_element(3, 1.0);
+    /// y.gemv_band(2.0, &a, &x, 1.0);
+    /// assert_eq!(y, DVector::from_vec(vec![1.0, 5.0, 17.0]));
+    /// ```
+    #[inline]
+    pub fn gemv_band<D3: Dim, S3>(
+        &mut self,
+        alpha: T,
+        a: &BandMatrix<T>,
+        x: &Vector<T, D3, S3>,
+        beta: T,
+    ) where
+        S3: Storage<T, D3>,
+    {
+        a.gemv_to(self, alpha, x, beta)
+    }
+}
@@ -11,7 +11,8 @@ use crate::base::dimension::{Const, Dim, Dynamic, U1, U2, U3, U4};
 use crate::base::storage::{Storage, StorageMut};
 use crate::base::uninit::Init;
 use crate::base::{
-    DVectorSlice, DefaultAllocator, Matrix, Scalar, SquareMatrix, Vector, VectorSlice,
+    DVectorSlice, DefaultAllocator, Matrix, Scalar, ShapeMismatch, SquareMatrix, Vector,
+    VectorSlice,
 };
 
 /// # Dot/scalar product
@@ -745,6 +746,60 @@ where
         unsafe { gemm_uninit(Init, self, alpha, a, b, beta) }
     }
 
+    /// Computes `self = alpha * a * b + beta * self` like `.gemm`, or returns an error if the
+    /// shapes of `a`, `b` and `self` are incompatible.
+    ///
+    /// If the number of columns of `a` differs from the number of rows of `b`, the error
+    /// contains the shapes of `a` and `b`. Otherwise, if the shape of `self` differs from the
+    /// shape of `a * b`, the error contains these two shapes. `self` is left unchanged on error.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, ShapeMismatch};
+    /// let mut mat1 = DMatrix::zeros(2, 4);
+    /// let mat2 = DMatrix::from_element(2, 3, 1.0);
+    /// let mat3 = DMatrix::from_element(3, 4, 2.0);
+    ///
+    /// assert_eq!(mat1.try_gemm(1.0, &mat2, &mat3, 0.0), Ok(()));
+    /// assert_eq!(mat1, DMatrix::from_element(2, 4, 6.0));
+    ///
+    /// let err = mat1.try_gemm(1.0, &mat3, &mat2, 0.0);
+    /// assert_eq!(err, Err(ShapeMismatch::new((3, 4), (2, 3))));
+    ///
+    /// let mut mat4 = DMatrix::zeros(4, 2);
+    /// let err = mat4.try_gemm(1.0, &mat2, &mat3, 0.0);
+    /// assert_eq!(err, Err(ShapeMismatch::new((4, 2), (2, 4))));
+    /// ```
+    #[inline]
+    pub fn try_gemm<R2: Dim, C2: Dim, R3: Dim, C3: Dim, SB, SC>(
+        &mut self,
+        alpha: T,
+        a: &Matrix<T, R2, C2, SB>,
+        b: &Matrix<T, R3, C3, SC>,
+        beta: T,
+    ) -> Result<(), ShapeMismatch>
+    where
+        T: One,
+        SB: Storage<T, R2, C2>,
+        SC: Storage<T, R3, C3>,
+        ShapeConstraint: SameNumberOfRows<R1, R2>
+            + SameNumberOfColumns<C1, C3>
+            + AreMultipliable<R2, C2, R3, C3>,
+    {
+        if a.ncols() != b.nrows() {
+            return Err(ShapeMismatch::new(a.shape(), b.shape()));
+        }
+
+        let product_shape = (a.nrows(), b.ncols());
+
+        if self.shape() != product_shape {
+            return Err(ShapeMismatch::new(self.shape(), product_shape));
+        }
+
+        self.gemm(alpha, a, b, beta);
+        Ok(())
+    }
+
     /// Computes `self = alpha * a.transpose() * b + beta * self`, where `a, b, self` are matrices.
     /// `alpha` and `beta` are scalar.
     ///
@@ -1203,3 +1258,631 @@ where
         self.quadform_with_workspace(&mut work, alpha, mid, rhs, beta)
     }
 }
+
+/// The side on which the triangular or symmetric operand of a level-3 BLAS operation appears.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// The triangular or symmetric matrix `a` is the left operand, e.g., `a * b`.
+    Left,
+    /// The triangular or symmetric matrix `a` is the right operand, e.g., `b * a`.
+    Right,
+}
+
+/// The triangular part of a matrix read or written by a level-3 BLAS operation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Uplo {
+    /// The lower-triangular part, including the diagonal.
+    Lower,
+    /// The upper-triangular part, including the diagonal.
+    Upper,
+}
+
+/// The operation applied to the triangular operand of a level-3 BLAS operation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Transposition {
+    /// The matrix is used as-is.
+    NoTranspose,
+    /// The matrix is transposed.
+    Transpose,
+    /// The matrix is transposed and conjugated.
+    Adjoint,
+}
+
+/// The diagonal of the triangular operand of a level-3 BLAS operation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Diag {
+    /// The diagonal is read from the matrix.
+    NonUnit,
+    /// The diagonal is never read and assumed to be filled with ones.
+    Unit,
+}
+
+/// Computes `m = beta * m` without reading `m` if `beta` is zero.
+#[inline(always)]
+fn scale_or_fill_zero<T, R: Dim, C: Dim, S>(m: &mut Matrix<T, R, C, S>, beta: T)
+where
+    T: Scalar + Zero + ClosedMul,
+    S: StorageMut<T, R, C>,
+{
+    if beta.is_zero() {
+        m.fill(T::zero());
+    } else {
+        *m *= beta;
+    }
+}
+
+/// The square matrix `op(a)` (or its transpose if `flip` is `true`) of a triangular level-3 BLAS
+/// operation, with its entries read lazily from `a`.
+struct TriangularOperand<'a, T, D: Dim, S> {
+    a: &'a SquareMatrix<T, D, S>,
+    lower: bool,
+    transpose: bool,
+    conjugate: bool,
+    unit: bool,
+}
+
+impl<'a, T: SimdComplexField, D: Dim, S: Storage<T, D, D>> TriangularOperand<'a, T, D, S> {
+    fn new(
+        a: &'a SquareMatrix<T, D, S>,
+        uplo: Uplo,
+        transposition: Transposition,
+        diag: Diag,
+        flip: bool,
+    ) -> Self {
+        assert!(
+            a.is_square(),
+            "Triangular operand: the matrix must be square."
+        );
+
+        let transpose = (transposition != Transposition::NoTranspose) != flip;
+
+        TriangularOperand {
+            a,
+            // The triangle holding the non-zero entries after transposition.
+            lower: (uplo == Uplo::Lower) != transpose,
+            transpose,
+            conjugate: transposition == Transposition::Adjoint,
+            unit: diag == Diag::Unit,
+        }
+    }
+
+    #[inline(always)]
+    fn get(&self, i: usize, k: usize) -> T {
+        if i == k && self.unit {
+            return T::one();
+        }
+
+        let (i, k) = if self.transpose { (k, i) } else { (i, k) };
+        let val = unsafe { self.a.get_unchecked((i, k)).clone() };
+
+        if self.conjugate {
+            val.simd_conjugate()
+        } else {
+            val
+        }
+    }
+
+    /// The range of the off-diagonal non-zero columns of the `i`-th row.
+    #[inline(always)]
+    fn off_diagonal(&self, i: usize) -> core::ops::Range<usize> {
+        if self.lower {
+            0..i
+        } else {
+            i + 1..self.a.nrows()
+        }
+    }
+
+    /// Computes `x = self * x` where `x` is a row or column vector.
+    fn mul_to<R2: Dim, C2: Dim, S2: StorageMut<T, R2, C2>>(&self, x: &mut Matrix<T, R2, C2, S2>) {
+        let dim = self.a.nrows();
+
+        // Process the rows in an order such that the components of `x` used by each row have
+        // not been overwritten yet.
+        let mut step = |i: usize| {
+            let mut res = self.get(i, i) * x[i].clone();
+            for k in self.off_diagonal(i) {
+                res += self.get(i, k) * x[k].clone();
+            }
+            x[i] = res;
+        };
+
+        if self.lower {
+            (0..dim).rev().for_each(&mut step)
+        } else {
+            (0..dim).for_each(&mut step)
+        }
+    }
+
+    /// Solves `self * y = x` and stores `y` into `x`, where `x` is a row or column vector.
+    fn solve_to<R2: Dim, C2: Dim, S2: StorageMut<T, R2, C2>>(&self, x: &mut Matrix<T, R2, C2, S2>) {
+        let dim = self.a.nrows();
+
+        // Process the rows in an order such that the components of `x` used by each row have
+        // already been solved for.
+        let mut step = |i: usize| {
+            let mut res = x[i].clone();
+            for k in self.off_diagonal(i) {
+                res -= self.get(i, k) * x[k].clone();
+            }
+            x[i] = if self.unit { res } else { res / self.get(i, i) };
+        };
+
+        if self.lower {
+            (0..dim).for_each(&mut step)
+        } else {
+            (0..dim).rev().for_each(&mut step)
+        }
+    }
+}
+
+/// # Level-3 BLAS functions
+impl<T, R1: Dim, C1: Dim, S: StorageMut<T, R1, C1>> Matrix<T, R1, C1, S>
+where
+    T: Scalar + Zero + One + ClosedAdd + ClosedMul,
+{
+    #[inline(always)]
+    fn xxrkx<R2: Dim, C2: Dim, SB>(
+        &mut self,
+        alpha: T,
+        a: &Matrix<T, R2, C2, SB>,
+        beta: T,
+        uplo: Uplo,
+        conjugate: impl Fn(T) -> T,
+    ) where
+        SB: Storage<T, R2, C2>,
+        ShapeConstraint: DimEq<R1, R2> + DimEq<C1, R2>,
+    {
+        let dim = self.nrows();
+
+        assert!(
+            self.is_square(),
+            "Rank-k update: the input matrix must be square."
+        );
+        assert_eq!(dim, a.nrows(), "Rank-k update: dimensions mismatch.");
+
+        for j in 0..dim {
+            let rows = match uplo {
+                Uplo::Lower => j..dim,
+                Uplo::Upper => 0..j + 1,
+            };
+            let mut column = self.slice_range_mut(rows.clone(), j);
+            scale_or_fill_zero(&mut column, beta.clone());
+
+            for k in 0..a.ncols() {
+                let val = unsafe { conjugate(a.get_unchecked((j, k)).clone()) };
+                column.axpy(
+                    alpha.clone() * val,
+                    &a.slice_range(rows.clone(), k),
+                    T::one(),
+                );
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn xxrkx_tr(&mut self, alpha: T, beta: T, uplo: Uplo, dot: impl Fn(usize, usize) -> T) {
+        let dim = self.nrows();
+
+        assert!(
+            self.is_square(),
+            "Rank-k update: the input matrix must be square."
+        );
+
+        for j in 0..dim {
+            let rows = match uplo {
+                Uplo::Lower => j..dim,
+                Uplo::Upper => 0..j + 1,
+            };
+
+            for i in rows {
+                let val = alpha.clone() * dot(i, j);
+                let res = unsafe { self.get_unchecked_mut((i, j)) };
+
+                if beta.is_zero() {
+                    *res = val;
+                } else {
+                    *res = val + beta.clone() * res.clone();
+                }
+            }
+        }
+    }
+
+    /// Computes the symmetric rank-k update `self = alpha * a * a.transpose() + beta * self`.
+    ///
+    /// Only the triangular part of `self` selected by `uplo` (including the diagonal) is
+    /// read/written. If `beta` is zero, `self` is never read.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Matrix2x3, Uplo};
+    /// let mut mat = Matrix2::identity();
+    /// let a = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let expected = a * a.transpose() * 10.0 + mat * 5.0;
+    /// mat.m12 = 99999.99999; // This component is on the upper-triangular part and will not be read/written.
+    ///
+    /// mat.syrk(10.0, &a, 5.0, Uplo::Lower);
+    /// assert_relative_eq!(mat.lower_triangle(), expected.lower_triangle());
+    /// assert_eq!(mat.m12, 99999.99999); // This was untouched.
+    /// ```
+    #[inline]
+    pub fn syrk<R2: Dim, C2: Dim, SB>(
+        &mut self,
+        alpha: T,
+        a: &Matrix<T, R2, C2, SB>,
+        beta: T,
+        uplo: Uplo,
+    ) where
+        SB: Storage<T, R2, C2>,
+        ShapeConstraint: DimEq<R1, R2> + DimEq<C1, R2>,
+    {
+        self.xxrkx(alpha, a, beta, uplo, |e| e)
+    }
+
+    /// Computes the symmetric rank-k update `self = alpha * a.transpose() * a + beta * self`.
+    ///
+    /// Only the triangular part of `self` selected by `uplo` (including the diagonal) is
+    /// read/written. If `beta` is zero, `self` is never read.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Matrix3x2, Uplo};
+    /// let mut mat = Matrix2::identity();
+    /// let a = Matrix3x2::new(1.0, 4.0,
+    ///                        2.0, 5.0,
+    ///                        3.0, 6.0);
+    /// let expected = a.transpose() * a * 10.0 + mat * 5.0;
+    /// mat.m21 = 99999.99999; // This component is on the lower-triangular part and will not be read/written.
+    ///
+    /// mat.syrk_tr(10.0, &a, 5.0, Uplo::Upper);
+    /// assert_relative_eq!(mat.upper_triangle(), expected.upper_triangle());
+    /// assert_eq!(mat.m21, 99999.99999); // This was untouched.
+    /// ```
+    #[inline]
+    pub fn syrk_tr<R2: Dim, C2: Dim, SB>(
+        &mut self,
+        alpha: T,
+        a: &Matrix<T, R2, C2, SB>,
+        beta: T,
+        uplo: Uplo,
+    ) where
+        SB: Storage<T, R2, C2>,
+        ShapeConstraint: DimEq<R1, C2> + DimEq<C1, C2>,
+    {
+        assert_eq!(
+            self.nrows(),
+            a.ncols(),
+            "Rank-k update: dimensions mismatch."
+        );
+        self.xxrkx_tr(alpha, beta, uplo, |i, j| a.column(i).dot(&a.column(j)))
+    }
+
+    /// Computes the hermitian rank-k update `self = alpha * a * a.adjoint() + beta * self`.
+    ///
+    /// Only the triangular part of `self` selected by `uplo` (including the diagonal) is
+    /// read/written. If `beta` is zero, `self` is never read.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2, Complex, Uplo};
+    /// let mut mat = Matrix2::identity();
+    /// let a = Matrix2::new(Complex::new(1.0, 3.0), Complex::new(2.0, 4.0),
+    ///                      Complex::new(5.0, 7.0), Complex::new(6.0, 8.0));
+    /// let expected = a * a.adjoint() * Complex::new(10.0, 0.0) + mat * Complex::new(5.0, 0.0);
+    ///
+    /// mat.herk(Complex::new(10.0, 0.0), &a, Complex::new(5.0, 0.0), Uplo::Lower);
+    /// assert_eq!(mat.lower_triangle(), expected.lower_triangle());
+    /// ```
+    #[inline]
+    pub fn herk<R2: Dim, C2: Dim, SB>(
+        &mut self,
+        alpha: T,
+        a: &Matrix<T, R2, C2, SB>,
+        beta: T,
+        uplo: Uplo,
+    ) where
+        T: SimdComplexField,
+        SB: Storage<T, R2, C2>,
+        ShapeConstraint: DimEq<R1, R2> + DimEq<C1, R2>,
+    {
+        self.xxrkx(alpha, a, beta, uplo, SimdComplexField::simd_conjugate)
+    }
+
+    /// Computes the hermitian rank-k update `self = alpha * a.adjoint() * a + beta * self`.
+    ///
+    /// Only the triangular part of `self` selected by `uplo` (including the diagonal) is
+    /// read/written. If `beta` is zero, `self` is never read.
+    #[inline]
+    pub fn herk_ad<R2: Dim, C2: Dim, SB>(
+        &mut self,
+        alpha: T,
+        a: &Matrix<T, R2, C2, SB>,
+        beta: T,
+        uplo: Uplo,
+    ) where
+        T: SimdComplexField,
+        SB: Storage<T, R2, C2>,
+        ShapeConstraint: DimEq<R1, C2> + DimEq<C1, C2>,
+    {
+        assert_eq!(
+            self.nrows(),
+            a.ncols(),
+            "Rank-k update: dimensions mismatch."
+        );
+        self.xxrkx_tr(alpha, beta, uplo, |i, j| a.column(i).dotc(&a.column(j)))
+    }
+
+    #[inline(always)]
+    fn xxmx<D2: Dim, R3: Dim, C3: Dim, SA, SB>(
+        &mut self,
+        alpha: T,
+        a: &SquareMatrix<T, D2, SA>,
+        b: &Matrix<T, R3, C3, SB>,
+        beta: T,
+        side: Side,
+        uplo: Uplo,
+        conjugate: impl Fn(T) -> T,
+    ) where
+        SA: Storage<T, D2, D2>,
+        SB: Storage<T, R3, C3>,
+        ShapeConstraint: SameNumberOfRows<R1, R3> + SameNumberOfColumns<C1, C3>,
+    {
+        let (nrows, ncols) = self.shape();
+        let dim = a.nrows();
+
+        assert!(
+            a.is_square(),
+            "Symmetric multiplication: the symmetric matrix must be square."
+        );
+        assert_eq!(
+            (nrows, ncols),
+            b.shape(),
+            "Symmetric multiplication: dimensions mismatch for addition."
+        );
+        assert_eq!(
+            dim,
+            if side == Side::Left { nrows } else { ncols },
+            "Symmetric multiplication: dimensions mismatch for multiplication."
+        );
+
+        let sym = |i: usize, k: usize| {
+            let stored = match uplo {
+                Uplo::Lower => i >= k,
+                Uplo::Upper => i <= k,
+            };
+
+            unsafe {
+                if stored {
+                    a.get_unchecked((i, k)).clone()
+                } else {
+                    conjugate(a.get_unchecked((k, i)).clone())
+                }
+            }
+        };
+
+        for j in 0..ncols {
+            for i in 0..nrows {
+                let mut val = T::zero();
+
+                for k in 0..dim {
+                    val += unsafe {
+                        match side {
+                            Side::Left => sym(i, k) * b.get_unchecked((k, j)).clone(),
+                            Side::Right => b.get_unchecked((i, k)).clone() * sym(k, j),
+                        }
+                    };
+                }
+
+                let res = unsafe { self.get_unchecked_mut((i, j)) };
+
+                if beta.is_zero() {
+                    *res = alpha.clone() * val;
+                } else {
+                    *res = alpha.clone() * val + beta.clone() * res.clone();
+                }
+            }
+        }
+    }
+
+    /// Computes `self = alpha * a * b + beta * self` if `side` is `Side::Left`, or
+    /// `self = alpha * b * a + beta * self` if `side` is `Side::Right`, where `a` is a
+    /// **symmetric** matrix.
+    ///
+    /// Only the triangular part of `a` selected by `uplo` (including the diagonal) is read.
+    /// If `beta` is zero, `self` is never read.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Matrix2x3, Side, Uplo};
+    /// let mut mat = Matrix2x3::repeat(1.0);
+    /// let a = Matrix2::new(1.0, 2.0,
+    ///                      2.0, 3.0);
+    /// let b = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let expected = a * b * 10.0 + mat * 5.0;
+    /// let mut lower = a;
+    /// lower.m12 = 99999.99999; // This component is on the upper-triangular part and will not be read.
+    ///
+    /// mat.symm(10.0, &lower, &b, 5.0, Side::Left, Uplo::Lower);
+    /// assert_relative_eq!(mat, expected);
+    /// ```
+    #[inline]
+    pub fn symm<D2: Dim, R3: Dim, C3: Dim, SA, SB>(
+        &mut self,
+        alpha: T,
+        a: &SquareMatrix<T, D2, SA>,
+        b: &Matrix<T, R3, C3, SB>,
+        beta: T,
+        side: Side,
+        uplo: Uplo,
+    ) where
+        SA: Storage<T, D2, D2>,
+        SB: Storage<T, R3, C3>,
+        ShapeConstraint: SameNumberOfRows<R1, R3> + SameNumberOfColumns<C1, C3>,
+    {
+        self.xxmx(alpha, a, b, beta, side, uplo, |e| e)
+    }
+
+    /// Computes `self = alpha * a * b + beta * self` if `side` is `Side::Left`, or
+    /// `self = alpha * b * a + beta * self` if `side` is `Side::Right`, where `a` is an
+    /// **hermitian** matrix.
+    ///
+    /// Only the triangular part of `a` selected by `uplo` (including the diagonal) is read.
+    /// If `beta` is zero, `self` is never read.
+    #[inline]
+    pub fn hemm<D2: Dim, R3: Dim, C3: Dim, SA, SB>(
+        &mut self,
+        alpha: T,
+        a: &SquareMatrix<T, D2, SA>,
+        b: &Matrix<T, R3, C3, SB>,
+        beta: T,
+        side: Side,
+        uplo: Uplo,
+    ) where
+        T: SimdComplexField,
+        SA: Storage<T, D2, D2>,
+        SB: Storage<T, R3, C3>,
+        ShapeConstraint: SameNumberOfRows<R1, R3> + SameNumberOfColumns<C1, C3>,
+    {
+        self.xxmx(
+            alpha,
+            a,
+            b,
+            beta,
+            side,
+            uplo,
+            SimdComplexField::simd_conjugate,
+        )
+    }
+
+    /// Computes `self = alpha * op(a) * self` if `side` is `Side::Left`, or
+    /// `self = alpha * self * op(a)` if `side` is `Side::Right`, where `a` is a **triangular**
+    /// matrix and `op` is selected by `transposition`.
+    ///
+    /// Only the triangular part of `a` selected by `uplo` is read. Its diagonal is not read
+    /// either if `diag` is `Diag::Unit`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Matrix2x3, Diag, Side, Transposition, Uplo};
+    /// let mut mat = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                              4.0, 5.0, 6.0);
+    /// let a = Matrix2::new(2.0, 0.0,
+    ///                      1.0, 3.0);
+    /// let expected = a.transpose() * mat * 10.0;
+    ///
+    /// mat.trmm(10.0, &a, Side::Left, Uplo::Lower, Transposition::Transpose, Diag::NonUnit);
+    /// assert_relative_eq!(mat, expected);
+    /// ```
+    pub fn trmm<D2: Dim, SA>(
+        &mut self,
+        alpha: T,
+        a: &SquareMatrix<T, D2, SA>,
+        side: Side,
+        uplo: Uplo,
+        transposition: Transposition,
+        diag: Diag,
+    ) where
+        T: SimdComplexField,
+        SA: Storage<T, D2, D2>,
+    {
+        self.xtrxmx(a, side, uplo, transposition, diag, false);
+        *self *= alpha;
+    }
+
+    /// Solves `op(a) * x = alpha * self` if `side` is `Side::Left`, or
+    /// `x * op(a) = alpha * self` if `side` is `Side::Right`, and stores `x` into `self`, where
+    /// `a` is a **triangular** matrix and `op` is selected by `transposition`.
+    ///
+    /// Only the triangular part of `a` selected by `uplo` is read. Its diagonal is not read
+    /// either if `diag` is `Diag::Unit`. No check is performed to ensure that `a` is
+    /// invertible: a zero on its diagonal results in non-finite entries.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2, Matrix3x2, Diag, Side, Transposition, Uplo};
+    /// let b = Matrix3x2::new(1.0, 2.0,
+    ///                        3.0, 4.0,
+    ///                        5.0, 6.0);
+    /// let a = Matrix2::new(2.0, 1.0,
+    ///                      0.0, 4.0);
+    /// let mut x = b;
+    ///
+    /// x.trsm(1.0, &a, Side::Right, Uplo::Upper, Transposition::NoTranspose, Diag::NonUnit);
+    /// assert_relative_eq!(x * a, b);
+    /// ```
+    pub fn trsm<D2: Dim, SA>(
+        &mut self,
+        alpha: T,
+        a: &SquareMatrix<T, D2, SA>,
+        side: Side,
+        uplo: Uplo,
+        transposition: Transposition,
+        diag: Diag,
+    ) where
+        T: SimdComplexField,
+        SA: Storage<T, D2, D2>,
+    {
+        *self *= alpha;
+        self.xtrxmx(a, side, uplo, transposition, diag, true);
+    }
+
+    #[inline(always)]
+    fn xtrxmx<D2: Dim, SA>(
+        &mut self,
+        a: &SquareMatrix<T, D2, SA>,
+        side: Side,
+        uplo: Uplo,
+        transposition: Transposition,
+        diag: Diag,
+        solve: bool,
+    ) where
+        T: SimdComplexField,
+        SA: Storage<T, D2, D2>,
+    {
+        // Multiplying (or solving) by `a` on the right amounts to multiplying (or solving) each
+        // row of `self` by the transpose of `a` on the left.
+        let op = TriangularOperand::new(a, uplo, transposition, diag, side == Side::Right);
+        let dim = a.nrows();
+
+        match side {
+            Side::Left => {
+                assert_eq!(
+                    dim,
+                    self.nrows(),
+                    "Triangular multiplication: dimensions mismatch."
+                );
+
+                for j in 0..self.ncols() {
+                    let mut column = self.column_mut(j);
+                    if solve {
+                        op.solve_to(&mut column)
+                    } else {
+                        op.mul_to(&mut column)
+                    }
+                }
+            }
+            Side::Right => {
+                assert_eq!(
+                    dim,
+                    self.ncols(),
+                    "Triangular multiplication: dimensions mismatch."
+                );
+
+                for i in 0..self.nrows() {
+                    let mut row = self.row_mut(i);
+                    if solve {
+                        op.solve_to(&mut row)
+                    } else {
+                        op.mul_to(&mut row)
+                    }
+                }
+            }
+        }
+    }
+}
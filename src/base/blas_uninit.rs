@@ -24,6 +24,123 @@ use crate::base::uninit::InitStatus;
 use crate::base::{Matrix, Scalar, Vector};
 use std::any::TypeId;
 
+/// The minimum number of multiply-adds of a product before it gets split among the threads of
+/// the rayon thread pool.
+#[cfg(feature = "rayon")]
+const PAR_MIN_WORK: usize = 1 << 18;
+
+/// The minimum number of rows or columns of the output of a product handled by a single thread.
+#[cfg(feature = "rayon")]
+const PAR_MIN_BLOCK_DIM: usize = 16;
+
+/// The signature shared by `matrixmultiply::sgemm` and `matrixmultiply::dgemm`.
+#[cfg(feature = "rayon")]
+type GemmKernel<N> = unsafe fn(
+    usize,
+    usize,
+    usize,
+    N,
+    *const N,
+    isize,
+    isize,
+    *const N,
+    isize,
+    isize,
+    N,
+    *mut N,
+    isize,
+    isize,
+);
+
+/// Computes `c = alpha * a * b + beta * c`, where `c` is `m × n` and `a` is `m × k`, by splitting
+/// the rows or the columns of `c` into disjoint blocks handed to `kernel` on the rayon thread pool.
+///
+/// Returns `false` without doing anything if the product is too small to benefit from it.
+///
+/// # Safety
+/// The pointers and strides must satisfy the requirements of `kernel`, and the elements of `c`
+/// must not alias each other.
+#[cfg(feature = "rayon")]
+#[allow(clippy::too_many_arguments)]
+unsafe fn par_gemm<N: Copy + Send + Sync>(
+    kernel: GemmKernel<N>,
+    m: usize,
+    k: usize,
+    n: usize,
+    alpha: N,
+    (a, rsa, csa): (*const N, isize, isize),
+    (b, rsb, csb): (*const N, isize, isize),
+    beta: N,
+    (c, rsc, csc): (*mut N, isize, isize),
+) -> bool {
+    use rayon::prelude::*;
+
+    let split_rows = m >= n;
+    let len = if split_rows { m } else { n };
+    let nblocks = rayon::current_num_threads().min(len / PAR_MIN_BLOCK_DIM);
+
+    if m * n * k < PAR_MIN_WORK || nblocks < 2 {
+        return false;
+    }
+
+    let block_len = (len + nblocks - 1) / nblocks;
+    // Raw pointers are not `Send`, so we pass their addresses around instead.
+    let (a, b, c) = (a as usize, b as usize, c as usize);
+
+    (0..nblocks).into_par_iter().for_each(|i| {
+        let start = i * block_len;
+        let size = block_len.min(len.saturating_sub(start));
+        let (a, b, c) = (a as *const N, b as *const N, c as *mut N);
+
+        if size == 0 {
+            return;
+        }
+
+        // SAFETY: each block writes to a disjoint set of rows (or columns) of `c`.
+        unsafe {
+            if split_rows {
+                let start = start as isize;
+                kernel(
+                    size,
+                    k,
+                    n,
+                    alpha,
+                    a.offset(start * rsa),
+                    rsa,
+                    csa,
+                    b,
+                    rsb,
+                    csb,
+                    beta,
+                    c.offset(start * rsc),
+                    rsc,
+                    csc,
+                )
+            } else {
+                let start = start as isize;
+                kernel(
+                    m,
+                    k,
+                    size,
+                    alpha,
+                    a,
+                    rsa,
+                    csa,
+                    b.offset(start * csb),
+                    rsb,
+                    csb,
+                    beta,
+                    c.offset(start * csc),
+                    rsc,
+                    csc,
+                )
+            }
+        }
+    });
+
+    true
+}
+
 // # Safety
 // The content of `y` must only contain values for which
 // `Status::assume_init_mut` is sound.
@@ -96,6 +213,7 @@ pub unsafe fn axcpy_uninit<Status, T, D1: Dim, D2: Dim, SA, SB>(
 {
     assert_eq!(y.nrows(), x.nrows(), "Axcpy: mismatched vector shapes.");
 
+    let len = x.nrows();
     let rstride1 = y.strides().0;
     let rstride2 = x.strides().0;
 
@@ -105,9 +223,9 @@ pub unsafe fn axcpy_uninit<Status, T, D1: Dim, D2: Dim, SA, SB>(
     let x = x.data.as_slice_unchecked();
 
     if !b.is_zero() {
-        array_axcpy(status, y, a, x, c, b, rstride1, rstride2, x.len());
+        array_axcpy(status, y, a, x, c, b, rstride1, rstride2, len);
     } else {
-        array_axc(status, y, a, x, c, rstride1, rstride2, x.len());
+        array_axc(status, y, a, x, c, rstride1, rstride2, len);
     }
 }
 
@@ -153,6 +271,46 @@ pub unsafe fn gemv_uninit<Status, T, D1: Dim, R2: Dim, C2: Dim, D3: Dim, SA, SB,
         return;
     }
 
+    #[cfg(feature = "rayon")]
+    {
+        // Large dynamically-sized products are split by blocks of rows among threads.
+        if D1::is::<Dynamic>() || R2::is::<Dynamic>() || C2::is::<Dynamic>() {
+            let (rsa, csa) = a.strides();
+            let (rsx, csx) = x.strides();
+            let (rsy, csy) = y.strides();
+
+            if TypeId::of::<T>() == TypeId::of::<f32>() {
+                if par_gemm::<f32>(
+                    matrixmultiply::sgemm,
+                    nrows2,
+                    ncols2,
+                    1,
+                    mem::transmute_copy(&alpha),
+                    (a.data.ptr() as *const f32, rsa as isize, csa as isize),
+                    (x.data.ptr() as *const f32, rsx as isize, csx as isize),
+                    mem::transmute_copy(&beta),
+                    (y.data.ptr_mut() as *mut f32, rsy as isize, csy as isize),
+                ) {
+                    return;
+                }
+            } else if TypeId::of::<T>() == TypeId::of::<f64>() {
+                if par_gemm::<f64>(
+                    matrixmultiply::dgemm,
+                    nrows2,
+                    ncols2,
+                    1,
+                    mem::transmute_copy(&alpha),
+                    (a.data.ptr() as *const f64, rsa as isize, csa as isize),
+                    (x.data.ptr() as *const f64, rsx as isize, csx as isize),
+                    mem::transmute_copy(&beta),
+                    (y.data.ptr_mut() as *mut f64, rsy as isize, csy as isize),
+                ) {
+                    return;
+                }
+            }
+        }
+    }
+
     // TODO: avoid bound checks.
     let col2 = a.column(0);
     let val = x.vget_unchecked(0).clone();
@@ -262,6 +420,23 @@ pub unsafe fn gemm_uninit<
                     let (rsb, csb) = b.strides();
                     let (rsc, csc) = y.strides();
 
+                    #[cfg(feature = "rayon")]
+                    {
+                        if par_gemm::<f32>(
+                            matrixmultiply::sgemm,
+                            nrows2,
+                            ncols2,
+                            ncols3,
+                            mem::transmute_copy(&alpha),
+                            (a.data.ptr() as *const f32, rsa as isize, csa as isize),
+                            (b.data.ptr() as *const f32, rsb as isize, csb as isize),
+                            mem::transmute_copy(&beta),
+                            (y.data.ptr_mut() as *mut f32, rsc as isize, csc as isize),
+                        ) {
+                            return;
+                        }
+                    }
+
                     matrixmultiply::sgemm(
                         nrows2,
                         ncols2,
@@ -284,6 +459,23 @@ pub unsafe fn gemm_uninit<
                     let (rsb, csb) = b.strides();
                     let (rsc, csc) = y.strides();
 
+                    #[cfg(feature = "rayon")]
+                    {
+                        if par_gemm::<f64>(
+                            matrixmultiply::dgemm,
+                            nrows2,
+                            ncols2,
+                            ncols3,
+                            mem::transmute_copy(&alpha),
+                            (a.data.ptr() as *const f64, rsa as isize, csa as isize),
+                            (b.data.ptr() as *const f64, rsb as isize, csb as isize),
+                            mem::transmute_copy(&beta),
+                            (y.data.ptr_mut() as *mut f64, rsc as isize, csc as isize),
+                        ) {
+                            return;
+                        }
+                    }
+
                     matrixmultiply::dgemm(
                         nrows2,
                         ncols2,
@@ -3,15 +3,15 @@
 use num::{Signed, Zero};
 use std::ops::{Add, Mul};
 
-use simba::scalar::{ClosedDiv, ClosedMul};
+use simba::scalar::{ClosedDiv, ClosedMul, ClosedSub};
 use simba::simd::SimdPartialOrd;
 
 use crate::base::allocator::{Allocator, SameShapeAllocator};
 use crate::base::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
-use crate::base::dimension::Dim;
+use crate::base::dimension::{Dim, U1};
 use crate::base::storage::{Storage, StorageMut};
-use crate::base::{DefaultAllocator, Matrix, MatrixSum, OMatrix, Scalar};
-use crate::ClosedAdd;
+use crate::base::{DefaultAllocator, Matrix, MatrixSum, OMatrix, Scalar, Vector};
+use crate::{ClosedAdd, ComplexField};
 
 /// The type of the result of a matrix component-wise operation.
 pub type MatrixComponentOp<T, R1, C1, R2, C2> = MatrixSum<T, R1, C1, R2, C2>;
@@ -351,3 +351,709 @@ impl<T: Scalar, R1: Dim, C1: Dim, SA: Storage<T, R1, C1>> Matrix<T, R1, C1, SA>
         }
     }
 }
+
+macro_rules! broadcast_binop_impl(
+    ($($binop_col: ident, $binop_col_assign: ident, $binop_row: ident, $binop_row_assign: ident,
+       $Trait: ident . $op_assign: ident, $desc_col: expr, $desc_col_assign: expr, $desc_row: expr, $desc_row_assign: expr);* $(;)*) => {$(
+        #[doc = $desc_col]
+        #[inline]
+        #[must_use]
+        pub fn $binop_col<R2, SB>(&self, rhs: &Vector<T, R2, SB>) -> OMatrix<T, R1, C1>
+            where T: $Trait,
+                  R2: Dim,
+                  SB: Storage<T, R2>,
+                  DefaultAllocator: Allocator<T, R1, C1>,
+                  ShapeConstraint: SameNumberOfRows<R1, R2> {
+            let mut res = self.clone_owned();
+            res.$binop_col_assign(rhs);
+            res
+        }
+
+        #[doc = $desc_col_assign]
+        #[inline]
+        pub fn $binop_col_assign<R2, SB>(&mut self, rhs: &Vector<T, R2, SB>)
+            where T: $Trait,
+                  R2: Dim,
+                  SA: StorageMut<T, R1, C1>,
+                  SB: Storage<T, R2>,
+                  ShapeConstraint: SameNumberOfRows<R1, R2> {
+            assert_eq!(self.nrows(), rhs.nrows(), "Column broadcasting: mismatched number of rows.");
+
+            for j in 0 .. self.ncols() {
+                for i in 0 .. self.nrows() {
+                    unsafe {
+                        self.get_unchecked_mut((i, j)).$op_assign(rhs.vget_unchecked(i).clone());
+                    }
+                }
+            }
+        }
+
+        #[doc = $desc_row]
+        #[inline]
+        #[must_use]
+        pub fn $binop_row<C2, SB>(&self, rhs: &Matrix<T, U1, C2, SB>) -> OMatrix<T, R1, C1>
+            where T: $Trait,
+                  C2: Dim,
+                  SB: Storage<T, U1, C2>,
+                  DefaultAllocator: Allocator<T, R1, C1>,
+                  ShapeConstraint: SameNumberOfColumns<C1, C2> {
+            let mut res = self.clone_owned();
+            res.$binop_row_assign(rhs);
+            res
+        }
+
+        #[doc = $desc_row_assign]
+        #[inline]
+        pub fn $binop_row_assign<C2, SB>(&mut self, rhs: &Matrix<T, U1, C2, SB>)
+            where T: $Trait,
+                  C2: Dim,
+                  SA: StorageMut<T, R1, C1>,
+                  SB: Storage<T, U1, C2>,
+                  ShapeConstraint: SameNumberOfColumns<C1, C2> {
+            assert_eq!(self.ncols(), rhs.ncols(), "Row broadcasting: mismatched number of columns.");
+
+            for j in 0 .. self.ncols() {
+                let val = unsafe { rhs.get_unchecked((0, j)).clone() };
+
+                for i in 0 .. self.nrows() {
+                    unsafe {
+                        self.get_unchecked_mut((i, j)).$op_assign(val.clone());
+                    }
+                }
+            }
+        }
+    )*}
+);
+
+/// # Broadcasting operations
+impl<T: Scalar, R1: Dim, C1: Dim, SA: Storage<T, R1, C1>> Matrix<T, R1, C1, SA> {
+    broadcast_binop_impl!(
+        add_column_broadcast, add_column_broadcast_assign,
+        add_row_broadcast, add_row_broadcast_assign, ClosedAdd.add_assign,
+        r"
+        Adds `rhs` to each column of `self`.
+
+        `rhs` must have as many rows as `self`: this is checked at compile-time if both dimensions
+        are known statically, and at runtime otherwise.
+
+        # Example
+
+        ```
+        # use nalgebra::{Matrix2, Vector2};
+        let m = Matrix2::new(1.0, 2.0,
+                             3.0, 4.0);
+        let v = Vector2::new(10.0, 20.0);
+        assert_eq!(m.add_column_broadcast(&v), Matrix2::new(11.0, 12.0, 23.0, 24.0));
+        ```
+        ",
+        r"
+        Adds `rhs` to each column of `self` in-place.
+
+        `rhs` must have as many rows as `self`: this is checked at compile-time if both dimensions
+        are known statically, and at runtime otherwise.
+
+        # Example
+
+        ```
+        # use nalgebra::{Matrix2, Vector2};
+        let mut m = Matrix2::new(1.0, 2.0,
+                                 3.0, 4.0);
+        m.add_column_broadcast_assign(&Vector2::new(10.0, 20.0));
+        assert_eq!(m, Matrix2::new(11.0, 12.0, 23.0, 24.0));
+        ```
+        ",
+        r"
+        Adds `rhs` to each row of `self`.
+
+        `rhs` must have as many columns as `self`: this is checked at compile-time if both dimensions
+        are known statically, and at runtime otherwise.
+
+        # Example
+
+        ```
+        # use nalgebra::{Matrix2, RowVector2};
+        let m = Matrix2::new(1.0, 2.0,
+                             3.0, 4.0);
+        let v = RowVector2::new(10.0, 20.0);
+        assert_eq!(m.add_row_broadcast(&v), Matrix2::new(11.0, 22.0, 13.0, 24.0));
+        ```
+        ",
+        r"
+        Adds `rhs` to each row of `self` in-place.
+
+        `rhs` must have as many columns as `self`: this is checked at compile-time if both dimensions
+        are known statically, and at runtime otherwise.
+
+        # Example
+
+        ```
+        # use nalgebra::{Matrix2, RowVector2};
+        let mut m = Matrix2::new(1.0, 2.0,
+                                 3.0, 4.0);
+        m.add_row_broadcast_assign(&RowVector2::new(10.0, 20.0));
+        assert_eq!(m, Matrix2::new(11.0, 22.0, 13.0, 24.0));
+        ```
+        ";
+        sub_column_broadcast, sub_column_broadcast_assign,
+        sub_row_broadcast, sub_row_broadcast_assign, ClosedSub.sub_assign,
+        r"
+        Subtracts `rhs` from each column of `self`.
+
+        `rhs` must have as many rows as `self`: this is checked at compile-time if both dimensions
+        are known statically, and at runtime otherwise.
+
+        # Example
+
+        ```
+        # use nalgebra::{Matrix2, Vector2};
+        let m = Matrix2::new(1.0, 2.0,
+                             3.0, 4.0);
+        let v = Vector2::new(10.0, 20.0);
+        assert_eq!(m.sub_column_broadcast(&v), Matrix2::new(-9.0, -8.0, -17.0, -16.0));
+        ```
+        ",
+        r"
+        Subtracts `rhs` from each column of `self` in-place.
+
+        `rhs` must have as many rows as `self`: this is checked at compile-time if both dimensions
+        are known statically, and at runtime otherwise.
+
+        # Example
+
+        ```
+        # use nalgebra::{Matrix2, Vector2};
+        let mut m = Matrix2::new(1.0, 2.0,
+                                 3.0, 4.0);
+        m.sub_column_broadcast_assign(&Vector2::new(10.0, 20.0));
+        assert_eq!(m, Matrix2::new(-9.0, -8.0, -17.0, -16.0));
+        ```
+        ",
+        r"
+        Subtracts `rhs` from each row of `self`.
+
+        `rhs` must have as many columns as `self`: this is checked at compile-time if both dimensions
+        are known statically, and at runtime otherwise.
+
+        # Example
+
+        ```
+        # use nalgebra::{Matrix2, RowVector2};
+        let m = Matrix2::new(1.0, 2.0,
+                             3.0, 4.0);
+        let v = RowVector2::new(10.0, 20.0);
+        assert_eq!(m.sub_row_broadcast(&v), Matrix2::new(-9.0, -18.0, -7.0, -16.0));
+        ```
+        ",
+        r"
+        Subtracts `rhs` from each row of `self` in-place.
+
+        `rhs` must have as many columns as `self`: this is checked at compile-time if both dimensions
+        are known statically, and at runtime otherwise.
+
+        # Example
+
+        ```
+        # use nalgebra::{Matrix2, RowVector2};
+        let mut m = Matrix2::new(1.0, 2.0,
+                                 3.0, 4.0);
+        m.sub_row_broadcast_assign(&RowVector2::new(10.0, 20.0));
+        assert_eq!(m, Matrix2::new(-9.0, -18.0, -7.0, -16.0));
+        ```
+        ";
+        component_mul_column_broadcast, component_mul_column_broadcast_assign,
+        component_mul_row_broadcast, component_mul_row_broadcast_assign, ClosedMul.mul_assign,
+        r"
+        Multiplies each column of `self` component-wise by `rhs`.
+
+        `rhs` must have as many rows as `self`: this is checked at compile-time if both dimensions
+        are known statically, and at runtime otherwise.
+
+        # Example
+
+        ```
+        # use nalgebra::{Matrix2, Vector2};
+        let m = Matrix2::new(1.0, 2.0,
+                             3.0, 4.0);
+        let v = Vector2::new(10.0, 20.0);
+        assert_eq!(m.component_mul_column_broadcast(&v), Matrix2::new(10.0, 20.0, 60.0, 80.0));
+        ```
+        ",
+        r"
+        Multiplies each column of `self` component-wise by `rhs` in-place.
+
+        `rhs` must have as many rows as `self`: this is checked at compile-time if both dimensions
+        are known statically, and at runtime otherwise.
+
+        # Example
+
+        ```
+        # use nalgebra::{Matrix2, Vector2};
+        let mut m = Matrix2::new(1.0, 2.0,
+                                 3.0, 4.0);
+        m.component_mul_column_broadcast_assign(&Vector2::new(10.0, 20.0));
+        assert_eq!(m, Matrix2::new(10.0, 20.0, 60.0, 80.0));
+        ```
+        ",
+        r"
+        Multiplies each row of `self` component-wise by `rhs`.
+
+        `rhs` must have as many columns as `self`: this is checked at compile-time if both dimensions
+        are known statically, and at runtime otherwise.
+
+        # Example
+
+        ```
+        # use nalgebra::{Matrix2, RowVector2};
+        let m = Matrix2::new(1.0, 2.0,
+                             3.0, 4.0);
+        let v = RowVector2::new(10.0, 20.0);
+        assert_eq!(m.component_mul_row_broadcast(&v), Matrix2::new(10.0, 40.0, 30.0, 80.0));
+        ```
+        ",
+        r"
+        Multiplies each row of `self` component-wise by `rhs` in-place.
+
+        `rhs` must have as many columns as `self`: this is checked at compile-time if both dimensions
+        are known statically, and at runtime otherwise.
+
+        # Example
+
+        ```
+        # use nalgebra::{Matrix2, RowVector2};
+        let mut m = Matrix2::new(1.0, 2.0,
+                                 3.0, 4.0);
+        m.component_mul_row_broadcast_assign(&RowVector2::new(10.0, 20.0));
+        assert_eq!(m, Matrix2::new(10.0, 40.0, 30.0, 80.0));
+        ```
+        ";
+        component_div_column_broadcast, component_div_column_broadcast_assign,
+        component_div_row_broadcast, component_div_row_broadcast_assign, ClosedDiv.div_assign,
+        r"
+        Divides each column of `self` component-wise by `rhs`.
+
+        `rhs` must have as many rows as `self`: this is checked at compile-time if both dimensions
+        are known statically, and at runtime otherwise.
+
+        # Example
+
+        ```
+        # use nalgebra::{Matrix2, Vector2};
+        let m = Matrix2::new(1.0, 2.0,
+                             3.0, 4.0);
+        let v = Vector2::new(10.0, 20.0);
+        assert_eq!(m.component_div_column_broadcast(&v), Matrix2::new(0.1, 0.2, 0.15, 0.2));
+        ```
+        ",
+        r"
+        Divides each column of `self` component-wise by `rhs` in-place.
+
+        `rhs` must have as many rows as `self`: this is checked at compile-time if both dimensions
+        are known statically, and at runtime otherwise.
+
+        # Example
+
+        ```
+        # use nalgebra::{Matrix2, Vector2};
+        let mut m = Matrix2::new(1.0, 2.0,
+                                 3.0, 4.0);
+        m.component_div_column_broadcast_assign(&Vector2::new(10.0, 20.0));
+        assert_eq!(m, Matrix2::new(0.1, 0.2, 0.15, 0.2));
+        ```
+        ",
+        r"
+        Divides each row of `self` component-wise by `rhs`.
+
+        `rhs` must have as many columns as `self`: this is checked at compile-time if both dimensions
+        are known statically, and at runtime otherwise.
+
+        # Example
+
+        ```
+        # use nalgebra::{Matrix2, RowVector2};
+        let m = Matrix2::new(1.0, 2.0,
+                             3.0, 4.0);
+        let v = RowVector2::new(10.0, 20.0);
+        assert_eq!(m.component_div_row_broadcast(&v), Matrix2::new(0.1, 0.1, 0.3, 0.2));
+        ```
+        ",
+        r"
+        Divides each row of `self` component-wise by `rhs` in-place.
+
+        `rhs` must have as many columns as `self`: this is checked at compile-time if both dimensions
+        are known statically, and at runtime otherwise.
+
+        # Example
+
+        ```
+        # use nalgebra::{Matrix2, RowVector2};
+        let mut m = Matrix2::new(1.0, 2.0,
+                                 3.0, 4.0);
+        m.component_div_row_broadcast_assign(&RowVector2::new(10.0, 20.0));
+        assert_eq!(m, Matrix2::new(0.1, 0.1, 0.3, 0.2));
+        ```
+        ";
+    );
+}
+
+macro_rules! comparison_impl(
+    ($($name: ident, $op: tt, $desc: expr);* $(;)*) => {$(
+        #[doc = $desc]
+        #[inline]
+        #[must_use]
+        pub fn $name<R2, C2, SB>(&self, rhs: &Matrix<T, R2, C2, SB>) -> OMatrix<bool, R1, C1>
+            where T: PartialOrd,
+                  R2: Dim, C2: Dim,
+                  SB: Storage<T, R2, C2>,
+                  DefaultAllocator: Allocator<bool, R1, C1>,
+                  ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2> {
+            self.zip_map_mask(rhs, |a, b| a $op b)
+        }
+    )*}
+);
+
+/// # Componentwise comparisons
+impl<T: Scalar, R1: Dim, C1: Dim, SA: Storage<T, R1, C1>> Matrix<T, R1, C1, SA> {
+    /// Applies `f` to each pair of components of `self` and `rhs` at the same position.
+    fn zip_map_mask<R2, C2, SB>(
+        &self,
+        rhs: &Matrix<T, R2, C2, SB>,
+        f: impl Fn(&T, &T) -> bool,
+    ) -> OMatrix<bool, R1, C1>
+    where
+        R2: Dim,
+        C2: Dim,
+        SB: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<bool, R1, C1>,
+    {
+        assert_eq!(
+            self.shape(),
+            rhs.shape(),
+            "Componentwise comparison: mismatched matrix dimensions."
+        );
+        let (nrows, ncols) = self.shape_generic();
+
+        // Safety: all indices are in range.
+        OMatrix::from_fn_generic(nrows, ncols, |i, j| unsafe {
+            f(self.get_unchecked((i, j)), rhs.get_unchecked((i, j)))
+        })
+    }
+
+    comparison_impl!(
+        lt_elementwise, <, r"
+        Componentwise `self < rhs`.
+
+        This is named `lt_elementwise` so it does not shadow `PartialOrd::lt`, which compares
+        whole matrices.
+
+        # Example
+
+        ```
+        # use nalgebra::Vector3;
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let b = Vector3::new(2.0, 2.0, 2.0);
+        assert_eq!(a.lt_elementwise(&b), Vector3::new(true, false, false));
+        ```
+        ";
+        le_elementwise, <=, r"
+        Componentwise `self <= rhs`.
+
+        This is named `le_elementwise` so it does not shadow `PartialOrd::le`, which compares
+        whole matrices.
+
+        # Example
+
+        ```
+        # use nalgebra::Vector3;
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let b = Vector3::new(2.0, 2.0, 2.0);
+        assert_eq!(a.le_elementwise(&b), Vector3::new(true, true, false));
+        ```
+        ";
+        gt_elementwise, >, r"
+        Componentwise `self > rhs`.
+
+        This is named `gt_elementwise` so it does not shadow `PartialOrd::gt`, which compares
+        whole matrices.
+
+        # Example
+
+        ```
+        # use nalgebra::Vector3;
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let b = Vector3::new(2.0, 2.0, 2.0);
+        assert_eq!(a.gt_elementwise(&b), Vector3::new(false, false, true));
+        ```
+        ";
+        ge_elementwise, >=, r"
+        Componentwise `self >= rhs`.
+
+        This is named `ge_elementwise` so it does not shadow `PartialOrd::ge`, which compares
+        whole matrices.
+
+        # Example
+
+        ```
+        # use nalgebra::Vector3;
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let b = Vector3::new(2.0, 2.0, 2.0);
+        assert_eq!(a.ge_elementwise(&b), Vector3::new(false, true, true));
+        ```
+        ";
+    );
+
+    /// Componentwise `self == rhs`.
+    ///
+    /// This is named `eq_elementwise` so it does not shadow `PartialEq::eq`, which compares
+    /// whole matrices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let a = Vector3::new(1.0, 2.0, 3.0);
+    /// let b = Vector3::new(2.0, 2.0, 2.0);
+    /// assert_eq!(a.eq_elementwise(&b), Vector3::new(false, true, false));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn eq_elementwise<R2, C2, SB>(&self, rhs: &Matrix<T, R2, C2, SB>) -> OMatrix<bool, R1, C1>
+    where
+        R2: Dim,
+        C2: Dim,
+        SB: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<bool, R1, C1>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        self.zip_map_mask(rhs, |a, b| a == b)
+    }
+
+    /// Returns a mask of the finite components of `self`, i.e., the components that are
+    /// neither infinite nor NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let v = Vector3::new(1.0, f64::INFINITY, f64::NAN);
+    /// assert_eq!(v.is_finite(), Vector3::new(true, false, false));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_finite(&self) -> OMatrix<bool, R1, C1>
+    where
+        T: ComplexField,
+        DefaultAllocator: Allocator<bool, R1, C1>,
+    {
+        self.map(|e| e.is_finite())
+    }
+
+    /// Returns a mask of the NaN components of `self`.
+    ///
+    /// A complex component is NaN if its real or imaginary part is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let v = Vector3::new(1.0, f64::INFINITY, f64::NAN);
+    /// assert_eq!(v.is_nan(), Vector3::new(false, false, true));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_nan(&self) -> OMatrix<bool, R1, C1>
+    where
+        T: ComplexField,
+        DefaultAllocator: Allocator<bool, R1, C1>,
+    {
+        self.map(|e| {
+            // The 1-norm is NaN if, and only if, one of the parts is NaN.
+            let norm = e.norm1();
+            norm.partial_cmp(&norm).is_none()
+        })
+    }
+}
+
+/// # Boolean masks
+impl<R1: Dim, C1: Dim, SA: Storage<bool, R1, C1>> Matrix<bool, R1, C1, SA> {
+    /// Returns `true` if at least one component of this mask is `true`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// assert!(Vector3::new(false, true, false).any());
+    /// assert!(!Vector3::new(false, false, false).any());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn any(&self) -> bool {
+        self.iter().any(|e| *e)
+    }
+
+    /// Returns `true` if all the components of this mask are `true`.
+    ///
+    /// This is `true` for an empty mask.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// assert!(Vector3::new(true, true, true).all());
+    /// assert!(!Vector3::new(true, false, true).all());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn all(&self) -> bool {
+        self.iter().all(|e| *e)
+    }
+
+    /// Returns the number of components of this mask that are `true`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// assert_eq!(Vector3::new(true, false, true).count(), 2);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.iter().filter(|e| **e).count()
+    }
+
+    /// Builds a matrix taking its components from `a` where this mask is `true`, and from `b`
+    /// where it is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let v = Vector3::new(-1.0, 2.0, -3.0);
+    /// let zeros = Vector3::zeros();
+    /// // Clamp the negative components to zero.
+    /// assert_eq!(v.lt_elementwise(&zeros).select(&zeros, &v), Vector3::new(0.0, 2.0, 0.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn select<T, R2, C2, SB, R3, C3, SC>(
+        &self,
+        a: &Matrix<T, R2, C2, SB>,
+        b: &Matrix<T, R3, C3, SC>,
+    ) -> OMatrix<T, R1, C1>
+    where
+        T: Scalar,
+        R2: Dim,
+        C2: Dim,
+        R3: Dim,
+        C3: Dim,
+        SB: Storage<T, R2, C2>,
+        SC: Storage<T, R3, C3>,
+        DefaultAllocator: Allocator<T, R1, C1>,
+        ShapeConstraint: SameNumberOfRows<R1, R2>
+            + SameNumberOfColumns<C1, C2>
+            + SameNumberOfRows<R1, R3>
+            + SameNumberOfColumns<C1, C3>,
+    {
+        assert_eq!(
+            self.shape(),
+            a.shape(),
+            "Mask selection: mismatched matrix dimensions."
+        );
+        assert_eq!(
+            self.shape(),
+            b.shape(),
+            "Mask selection: mismatched matrix dimensions."
+        );
+        let (nrows, ncols) = self.shape_generic();
+
+        // Safety: all indices are in range.
+        OMatrix::from_fn_generic(nrows, ncols, |i, j| unsafe {
+            if *self.get_unchecked((i, j)) {
+                a.get_unchecked((i, j)).clone()
+            } else {
+                b.get_unchecked((i, j)).clone()
+            }
+        })
+    }
+}
+
+/// # Masked assignment
+impl<T: Scalar, R1: Dim, C1: Dim, SA: StorageMut<T, R1, C1>> Matrix<T, R1, C1, SA> {
+    /// Sets to `val` all the components of `self` where `mask` is `true`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let mut v = Vector3::new(1.0, f64::NAN, 3.0);
+    /// v.fill_masked(&v.is_nan(), 0.0);
+    /// assert_eq!(v, Vector3::new(1.0, 0.0, 3.0));
+    /// ```
+    #[inline]
+    pub fn fill_masked<R2, C2, SM>(&mut self, mask: &Matrix<bool, R2, C2, SM>, val: T)
+    where
+        R2: Dim,
+        C2: Dim,
+        SM: Storage<bool, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        assert_eq!(
+            self.shape(),
+            mask.shape(),
+            "Masked fill: mismatched matrix dimensions."
+        );
+
+        for (e, m) in self.iter_mut().zip(mask.iter()) {
+            if *m {
+                *e = val.clone();
+            }
+        }
+    }
+
+    /// Copies into `self` the components of `src` where `mask` is `true`, leaving the other
+    /// components of `self` unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let mut v = Vector3::new(1.0, 2.0, 3.0);
+    /// let w = Vector3::new(10.0, 20.0, 30.0);
+    /// v.copy_from_masked(&Vector3::new(true, false, true), &w);
+    /// assert_eq!(v, Vector3::new(10.0, 2.0, 30.0));
+    /// ```
+    #[inline]
+    pub fn copy_from_masked<R2, C2, SM, R3, C3, SB>(
+        &mut self,
+        mask: &Matrix<bool, R2, C2, SM>,
+        src: &Matrix<T, R3, C3, SB>,
+    ) where
+        R2: Dim,
+        C2: Dim,
+        R3: Dim,
+        C3: Dim,
+        SM: Storage<bool, R2, C2>,
+        SB: Storage<T, R3, C3>,
+        ShapeConstraint: SameNumberOfRows<R1, R2>
+            + SameNumberOfColumns<C1, C2>
+            + SameNumberOfRows<R1, R3>
+            + SameNumberOfColumns<C1, C3>,
+    {
+        assert_eq!(
+            self.shape(),
+            mask.shape(),
+            "Masked copy: mismatched matrix dimensions."
+        );
+        assert_eq!(
+            self.shape(),
+            src.shape(),
+            "Masked copy: mismatched matrix dimensions."
+        );
+
+        for (e, (m, s)) in self.iter_mut().zip(mask.iter().zip(src.iter())) {
+            if *m {
+                *e = s.clone();
+            }
+        }
+    }
+}
@@ -2,6 +2,8 @@
 use alloc::vec::Vec;
 use simba::scalar::{SubsetOf, SupersetOf};
 use std::borrow::{Borrow, BorrowMut};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use std::convert::TryFrom;
 use std::convert::{AsMut, AsRef, From, Into};
 
 use simba::simd::{PrimitiveSimdValue, SimdValue};
@@ -20,7 +22,7 @@ use crate::base::{
     MatrixSliceMut, OMatrix, Scalar,
 };
 #[cfg(any(feature = "std", feature = "alloc"))]
-use crate::base::{DVector, RowDVector, VecStorage};
+use crate::base::{DMatrix, DVector, RowDVector, ShapeMismatch, VecStorage};
 use crate::base::{SliceStorage, SliceStorageMut};
 use crate::constraint::DimEq;
 use crate::{IsNotStaticOne, RowSVector, SMatrix, SVector, VectorSlice, VectorSliceMut};
@@ -246,10 +248,10 @@ macro_rules! impl_from_into_asref_borrow_2D(
         $Ref:ident.$ref:ident(), $Mut:ident.$mut:ident()
     ) => {
         impl<T: Scalar, S> $Ref<[[T; $SZRows]; $SZCols]> for Matrix<T, $NRows, $NCols, S>
-        where S: RawStorage<T, $NRows, $NCols> + IsContiguous {
+        where S: RawStorage<T, $NRows, $NCols, RStride = U1> + IsContiguous {
             #[inline]
             fn $ref(&self) -> &[[T; $SZRows]; $SZCols] {
-                // Safety: OK thanks to the IsContiguous trait.
+                // Safety: OK thanks to the IsContiguous trait and the column-major strides.
                 unsafe {
                     &*(self.data.ptr() as *const [[T; $SZRows]; $SZCols])
                 }
@@ -257,10 +259,10 @@ macro_rules! impl_from_into_asref_borrow_2D(
         }
 
         impl<T: Scalar, S> $Mut<[[T; $SZRows]; $SZCols]> for Matrix<T, $NRows, $NCols, S>
-        where S: RawStorageMut<T, $NRows, $NCols> + IsContiguous {
+        where S: RawStorageMut<T, $NRows, $NCols, RStride = U1> + IsContiguous {
             #[inline]
             fn $mut(&mut self) -> &mut [[T; $SZRows]; $SZCols] {
-                // Safety: OK thanks to the IsContiguous trait.
+                // Safety: OK thanks to the IsContiguous trait and the column-major strides.
                 unsafe {
                     &mut *(self.data.ptr_mut() as *mut [[T; $SZRows]; $SZCols])
                 }
@@ -497,6 +499,22 @@ impl<'a, T: Scalar> From<Vec<T>> for RowDVector<T> {
     }
 }
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar, const R: usize, const C: usize> TryFrom<DMatrix<T>> for SMatrix<T, R, C> {
+    type Error = ShapeMismatch;
+
+    /// Converts a dynamically-sized matrix into a statically-sized one, or returns an error
+    /// containing both shapes if the matrix does not have `R` rows and `C` columns.
+    #[inline]
+    fn try_from(mat: DMatrix<T>) -> Result<Self, ShapeMismatch> {
+        if mat.shape() != (R, C) {
+            return Err(ShapeMismatch::new(mat.shape(), (R, C)));
+        }
+
+        Ok(Self::from_iterator(Vec::from(mat.data)))
+    }
+}
+
 impl<'a, T: Scalar + Copy, R: Dim, C: Dim, S: RawStorage<T, R, C> + IsContiguous>
     From<&'a Matrix<T, R, C, S>> for &'a [T]
 {
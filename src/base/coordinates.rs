@@ -30,9 +30,9 @@ macro_rules! coords_impl(
 );
 
 macro_rules! deref_impl(
-    ($R: ty, $C: ty; $Target: ident) => {
+    (@impl $R: ty, $C: ty; $Target: ident; $($Stride: tt)*) => {
         impl<T: Scalar, S> Deref for Matrix<T, $R, $C, S>
-            where S: RawStorage<T, $R, $C> + IsContiguous {
+            where S: RawStorage<T, $R, $C, $($Stride)*> + IsContiguous {
             type Target = $Target<T>;
 
             #[inline]
@@ -43,14 +43,22 @@ macro_rules! deref_impl(
         }
 
         impl<T: Scalar, S> DerefMut for Matrix<T, $R, $C, S>
-            where S: RawStorageMut<T, $R, $C> + IsContiguous {
+            where S: RawStorageMut<T, $R, $C, $($Stride)*> + IsContiguous {
             #[inline]
             fn deref_mut(&mut self) -> &mut Self::Target {
                 // Safety: this is OK because of the IsContiguous trait.
                 unsafe { &mut *(self.data.ptr_mut() as *mut Self::Target) }
             }
         }
-    }
+    };
+    // The components of a matrix with several rows and columns are laid out like the fields of
+    // the target type only if the storage is column-major.
+    ($R: ty, $C: ty; $Target: ident; column_major) => {
+        deref_impl!(@impl $R, $C; $Target; RStride = U1);
+    };
+    ($R: ty, $C: ty; $Target: ident) => {
+        deref_impl!(@impl $R, $C; $Target;);
+    };
 );
 
 /*
@@ -205,32 +213,32 @@ deref_impl!(U1, U4; XYZW);
 deref_impl!(U1, U5; XYZWA);
 deref_impl!(U1, U6; XYZWAB);
 
-deref_impl!(U2, U2; M2x2);
-deref_impl!(U2, U3; M2x3);
-deref_impl!(U2, U4; M2x4);
-deref_impl!(U2, U5; M2x5);
-deref_impl!(U2, U6; M2x6);
-
-deref_impl!(U3, U2; M3x2);
-deref_impl!(U3, U3; M3x3);
-deref_impl!(U3, U4; M3x4);
-deref_impl!(U3, U5; M3x5);
-deref_impl!(U3, U6; M3x6);
-
-deref_impl!(U4, U2; M4x2);
-deref_impl!(U4, U3; M4x3);
-deref_impl!(U4, U4; M4x4);
-deref_impl!(U4, U5; M4x5);
-deref_impl!(U4, U6; M4x6);
-
-deref_impl!(U5, U2; M5x2);
-deref_impl!(U5, U3; M5x3);
-deref_impl!(U5, U4; M5x4);
-deref_impl!(U5, U5; M5x5);
-deref_impl!(U5, U6; M5x6);
-
-deref_impl!(U6, U2; M6x2);
-deref_impl!(U6, U3; M6x3);
-deref_impl!(U6, U4; M6x4);
-deref_impl!(U6, U5; M6x5);
-deref_impl!(U6, U6; M6x6);
+deref_impl!(U2, U2; M2x2; column_major);
+deref_impl!(U2, U3; M2x3; column_major);
+deref_impl!(U2, U4; M2x4; column_major);
+deref_impl!(U2, U5; M2x5; column_major);
+deref_impl!(U2, U6; M2x6; column_major);
+
+deref_impl!(U3, U2; M3x2; column_major);
+deref_impl!(U3, U3; M3x3; column_major);
+deref_impl!(U3, U4; M3x4; column_major);
+deref_impl!(U3, U5; M3x5; column_major);
+deref_impl!(U3, U6; M3x6; column_major);
+
+deref_impl!(U4, U2; M4x2; column_major);
+deref_impl!(U4, U3; M4x3; column_major);
+deref_impl!(U4, U4; M4x4; column_major);
+deref_impl!(U4, U5; M4x5; column_major);
+deref_impl!(U4, U6; M4x6; column_major);
+
+deref_impl!(U5, U2; M5x2; column_major);
+deref_impl!(U5, U3; M5x3; column_major);
+deref_impl!(U5, U4; M5x4; column_major);
+deref_impl!(U5, U5; M5x5; column_major);
+deref_impl!(U5, U6; M5x6; column_major);
+
+deref_impl!(U6, U2; M6x2; column_major);
+deref_impl!(U6, U3; M6x3; column_major);
+deref_impl!(U6, U4; M6x4; column_major);
+deref_impl!(U6, U5; M6x5; column_major);
+deref_impl!(U6, U6; M6x6; column_major);
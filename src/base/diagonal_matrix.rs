@@ -0,0 +1,294 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use std::ops::Mul;
+
+use num::{One, Zero};
+use simba::scalar::{ClosedDiv, ClosedMul};
+
+use crate::base::allocator::Allocator;
+use crate::base::constraint::{DimEq, SameNumberOfRows, ShapeConstraint};
+use crate::base::dimension::{Dim, DimName, U1};
+use crate::base::storage::{Storage, StorageMut};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector, Scalar};
+
+/// A square matrix with nonzero components only on its diagonal.
+///
+/// Only the diagonal is stored, so that multiplying a matrix by a diagonal matrix, i.e., scaling
+/// its rows or columns, takes `O(nrows * ncols)` operations.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, D>,
+         OVector<T, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, D>,
+         OVector<T, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiagonalMatrix<T: Scalar, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    diagonal: OVector<T, D>,
+}
+
+impl<T: Scalar + Copy, D: Dim> Copy for DiagonalMatrix<T, D>
+where
+    DefaultAllocator: Allocator<T, D>,
+    OVector<T, D>: Copy,
+{
+}
+
+impl<T: Scalar, D: DimName> DiagonalMatrix<T, D>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    /// Creates a new statically-sized identity diagonal matrix.
+    #[inline]
+    pub fn identity() -> Self
+    where
+        T: Zero + One,
+    {
+        Self::identity_generic(D::name())
+    }
+}
+
+impl<T: Scalar, D: Dim> DiagonalMatrix<T, D>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    /// Creates a diagonal matrix from its diagonal.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DiagonalMatrix, Matrix2x3, Vector2};
+    /// let d = DiagonalMatrix::new(Vector2::new(2.0, 3.0));
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// assert_eq!(&d * &m, Matrix2x3::new(2.0, 4.0, 6.0,
+    ///                                    12.0, 15.0, 18.0));
+    /// assert_eq!(&d * &m, d.to_dense() * m);
+    /// ```
+    #[inline]
+    pub fn new(diagonal: OVector<T, D>) -> Self {
+        Self { diagonal }
+    }
+
+    /// Creates a new identity diagonal matrix with the given dimension.
+    #[inline]
+    pub fn identity_generic(dim: D) -> Self
+    where
+        T: Zero + One,
+    {
+        Self::new(OVector::repeat_generic(dim, U1::name(), T::one()))
+    }
+
+    /// The number of rows (and columns) of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn dim(&self) -> usize {
+        self.diagonal.len()
+    }
+
+    /// The diagonal of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn diagonal(&self) -> &OVector<T, D> {
+        &self.diagonal
+    }
+
+    /// The diagonal of this matrix, mutably.
+    #[inline]
+    #[must_use]
+    pub fn diagonal_mut(&mut self) -> &mut OVector<T, D> {
+        &mut self.diagonal
+    }
+
+    /// Retrieves the diagonal of this matrix.
+    #[inline]
+    pub fn into_inner(self) -> OVector<T, D> {
+        self.diagonal
+    }
+
+    /// Converts this diagonal matrix into a regular dense matrix.
+    #[inline]
+    #[must_use]
+    pub fn to_dense(&self) -> OMatrix<T, D, D>
+    where
+        T: Zero,
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        OMatrix::from_diagonal(&self.diagonal)
+    }
+
+    /// The determinant of this matrix, i.e., the product of its diagonal components.
+    #[inline]
+    #[must_use]
+    pub fn determinant(&self) -> T
+    where
+        T: One + ClosedMul,
+    {
+        self.diagonal
+            .iter()
+            .fold(T::one(), |acc, e| acc * e.clone())
+    }
+
+    /// Computes the inverse of this matrix, i.e., the diagonal matrix of the inverses of its
+    /// diagonal components.
+    ///
+    /// Returns `None` if any diagonal component is zero.
+    #[inline]
+    #[must_use]
+    pub fn try_inverse(&self) -> Option<Self>
+    where
+        T: Zero + One + ClosedDiv,
+    {
+        if self.diagonal.iter().any(|e| e.is_zero()) {
+            None
+        } else {
+            Some(Self::new(self.diagonal.map(|e| T::one() / e)))
+        }
+    }
+
+    /// Multiplies in-place the `i`-th row of `rhs` by the `i`-th diagonal component of `self`,
+    /// i.e., computes `rhs = self * rhs`.
+    #[inline]
+    pub fn scale_rows_mut<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<T, R2, C2, S2>)
+    where
+        T: ClosedMul,
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            rhs.nrows(),
+            self.dim(),
+            "Diagonal matrix multiplication: dimensions mismatch."
+        );
+
+        for mut col in rhs.column_iter_mut() {
+            col.zip_apply(&self.diagonal, |e, d| *e *= d);
+        }
+    }
+
+    /// Multiplies in-place the `j`-th column of `lhs` by the `j`-th diagonal component of
+    /// `self`, i.e., computes `lhs = lhs * self`.
+    #[inline]
+    pub fn scale_columns_mut<R2: Dim, C2: Dim, S2>(&self, lhs: &mut Matrix<T, R2, C2, S2>)
+    where
+        T: ClosedMul,
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: DimEq<C2, D>,
+    {
+        assert_eq!(
+            lhs.ncols(),
+            self.dim(),
+            "Diagonal matrix multiplication: dimensions mismatch."
+        );
+
+        for (mut col, d) in lhs.column_iter_mut().zip(self.diagonal.iter()) {
+            col *= d.clone();
+        }
+    }
+}
+
+impl<'b, T, D: Dim, R2: Dim, C2: Dim, S2> Mul<&'b Matrix<T, R2, C2, S2>> for &DiagonalMatrix<T, D>
+where
+    T: Scalar + ClosedMul,
+    S2: Storage<T, R2, C2>,
+    DefaultAllocator: Allocator<T, D> + Allocator<T, R2, C2>,
+    ShapeConstraint: SameNumberOfRows<R2, D>,
+{
+    type Output = OMatrix<T, R2, C2>;
+
+    #[inline]
+    fn mul(self, rhs: &'b Matrix<T, R2, C2, S2>) -> Self::Output {
+        let mut res = rhs.clone_owned();
+        self.scale_rows_mut(&mut res);
+        res
+    }
+}
+
+impl<T, D: Dim, R2: Dim, C2: Dim, S2> Mul<Matrix<T, R2, C2, S2>> for &DiagonalMatrix<T, D>
+where
+    T: Scalar + ClosedMul,
+    S2: StorageMut<T, R2, C2>,
+    DefaultAllocator: Allocator<T, D>,
+    ShapeConstraint: SameNumberOfRows<R2, D>,
+{
+    type Output = Matrix<T, R2, C2, S2>;
+
+    #[inline]
+    fn mul(self, mut rhs: Matrix<T, R2, C2, S2>) -> Self::Output {
+        self.scale_rows_mut(&mut rhs);
+        rhs
+    }
+}
+
+impl<'b, T, D: Dim, R1: Dim, C1: Dim, S1> Mul<&'b DiagonalMatrix<T, D>> for &Matrix<T, R1, C1, S1>
+where
+    T: Scalar + ClosedMul,
+    S1: Storage<T, R1, C1>,
+    DefaultAllocator: Allocator<T, D> + Allocator<T, R1, C1>,
+    ShapeConstraint: DimEq<C1, D>,
+{
+    type Output = OMatrix<T, R1, C1>;
+
+    #[inline]
+    fn mul(self, rhs: &'b DiagonalMatrix<T, D>) -> Self::Output {
+        let mut res = self.clone_owned();
+        rhs.scale_columns_mut(&mut res);
+        res
+    }
+}
+
+impl<'b, T, D: Dim, R1: Dim, C1: Dim, S1> Mul<&'b DiagonalMatrix<T, D>> for Matrix<T, R1, C1, S1>
+where
+    T: Scalar + ClosedMul,
+    S1: StorageMut<T, R1, C1>,
+    DefaultAllocator: Allocator<T, D>,
+    ShapeConstraint: DimEq<C1, D>,
+{
+    type Output = Matrix<T, R1, C1, S1>;
+
+    #[inline]
+    fn mul(mut self, rhs: &'b DiagonalMatrix<T, D>) -> Self::Output {
+        rhs.scale_columns_mut(&mut self);
+        self
+    }
+}
+
+impl<'b, T, D: Dim> Mul<&'b DiagonalMatrix<T, D>> for &DiagonalMatrix<T, D>
+where
+    T: Scalar + ClosedMul,
+    DefaultAllocator: Allocator<T, D>,
+{
+    type Output = DiagonalMatrix<T, D>;
+
+    #[inline]
+    fn mul(self, rhs: &'b DiagonalMatrix<T, D>) -> Self::Output {
+        DiagonalMatrix::new(self.diagonal.component_mul(&rhs.diagonal))
+    }
+}
+
+impl<T: Scalar, D: Dim> From<OVector<T, D>> for DiagonalMatrix<T, D>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    #[inline]
+    fn from(diagonal: OVector<T, D>) -> Self {
+        Self::new(diagonal)
+    }
+}
+
+impl<T: Scalar + Zero, D: Dim> From<DiagonalMatrix<T, D>> for OMatrix<T, D, D>
+where
+    DefaultAllocator: Allocator<T, D> + Allocator<T, D, D>,
+{
+    #[inline]
+    fn from(diagonal: DiagonalMatrix<T, D>) -> Self {
+        diagonal.to_dense()
+    }
+}
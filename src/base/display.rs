@@ -0,0 +1,460 @@
+//! Configurable textual representations of matrices.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{format, string::String, vec::Vec};
+use std::fmt;
+
+use crate::base::dimension::Dim;
+use crate::base::storage::RawStorage;
+use crate::base::{Matrix, Scalar};
+
+/// The layout used by [`MatrixDisplay`] to print a matrix.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Style {
+    /// The boxed multi-line layout of the `Display` implementation of `Matrix`.
+    Boxed,
+    /// A single line with rows separated by semicolons, e.g., `[1, 2; 3, 4]`.
+    Compact,
+    /// A Markdown table whose header contains the column indices.
+    Markdown,
+    /// A LaTeX `bmatrix` environment.
+    Latex,
+    /// A NumPy array literal, e.g., `np.array([[1, 2], [3, 4]])`.
+    NumPy,
+    /// A MATLAB matrix literal, e.g., `[1 2; 3 4]`.
+    Matlab,
+    /// An invocation of the `matrix!` macro of this crate, e.g., `matrix![1, 2; 3, 4]`.
+    ///
+    /// Floating-point components should be printed with a precision so that integral values are
+    /// valid floating-point literals.
+    Rust,
+}
+
+/// The alignment of the components of a matrix within their column.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    /// Components are aligned on the left of their column.
+    Left,
+    /// Components are aligned on the right of their column.
+    Right,
+    /// Components are centered within their column.
+    Center,
+}
+
+/// A builder of textual representations of a matrix, created by `Matrix::display`.
+///
+/// Only the components actually printed are formatted, so that displaying a few components of a
+/// very large matrix with [`max_rows`](Self::max_rows) and [`max_cols`](Self::max_cols) is cheap.
+///
+/// # Example
+/// ```
+/// # use nalgebra::Matrix2x3;
+/// # use nalgebra::display::Style;
+/// let m = Matrix2x3::new(1.0, 2.5, 3.0,
+///                        4.0, 5.0, 6.25);
+/// assert_eq!(m.display().style(Style::Compact).to_string(), "[1, 2.5, 3; 4, 5, 6.25]");
+/// assert_eq!(
+///     m.display().style(Style::NumPy).precision(1).max_cols(2).to_string(),
+///     "np.array([[1.0,   …, 3.0],\n          [4.0,   …, 6.2]])"
+/// );
+/// ```
+#[derive(Copy, Clone)]
+pub struct MatrixDisplay<'a, T, R, C, S> {
+    matrix: &'a Matrix<T, R, C, S>,
+    format_component: fn(&T, Option<usize>) -> String,
+    precision: Option<usize>,
+    max_rows: Option<usize>,
+    max_cols: Option<usize>,
+    alignment: Alignment,
+    style: Style,
+}
+
+fn format_display<T: fmt::Display>(val: &T, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => format!("{:.1$}", val, precision),
+        None => format!("{}", val),
+    }
+}
+
+fn format_lower_exp<T: fmt::LowerExp>(val: &T, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => format!("{:.1$e}", val, precision),
+        None => format!("{:e}", val),
+    }
+}
+
+/// The delimiters of the styles printing one row per line.
+struct Delimiters {
+    open: &'static str,
+    close: &'static str,
+    row_open: &'static str,
+    row_close: &'static str,
+    sep: &'static str,
+    row_sep: &'static str,
+    indent: &'static str,
+}
+
+const LATEX: Delimiters = Delimiters {
+    open: "\\begin{bmatrix}\n  ",
+    close: "\n\\end{bmatrix}",
+    row_open: "",
+    row_close: "",
+    sep: " & ",
+    row_sep: " \\\\",
+    indent: "  ",
+};
+
+const NUMPY: Delimiters = Delimiters {
+    open: "np.array([",
+    close: "])",
+    row_open: "[",
+    row_close: "]",
+    sep: ", ",
+    row_sep: ",",
+    indent: "          ",
+};
+
+const MATLAB: Delimiters = Delimiters {
+    open: "[",
+    close: "]",
+    row_open: "",
+    row_close: "",
+    sep: " ",
+    row_sep: "",
+    indent: " ",
+};
+
+const RUST: Delimiters = Delimiters {
+    open: "matrix![",
+    close: "]",
+    row_open: "",
+    row_close: "",
+    sep: ", ",
+    row_sep: ";",
+    indent: "        ",
+};
+
+/// The indices of the rows (or columns) to print, `None` standing for the elided ones.
+fn visible_indices(len: usize, max: Option<usize>) -> Vec<Option<usize>> {
+    match max {
+        Some(max) if len > max => {
+            let tail = max / 2;
+            let head = max - tail;
+            (0..head)
+                .map(Some)
+                .chain(std::iter::once(None))
+                .chain((len - tail..len).map(Some))
+                .collect()
+        }
+        _ => (0..len).map(Some).collect(),
+    }
+}
+
+impl<'a, T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> MatrixDisplay<'a, T, R, C, S> {
+    pub(crate) fn new(matrix: &'a Matrix<T, R, C, S>) -> Self
+    where
+        T: fmt::Display,
+    {
+        Self {
+            matrix,
+            format_component: format_display::<T>,
+            precision: None,
+            max_rows: None,
+            max_cols: None,
+            alignment: Alignment::Right,
+            style: Style::Boxed,
+        }
+    }
+
+    /// Prints each component with `precision` digits after the decimal point.
+    ///
+    /// If unset, the precision of the formatter is used, e.g., `3` with `{:.3}`.
+    #[inline]
+    #[must_use]
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Prints each component in scientific notation.
+    #[inline]
+    #[must_use]
+    pub fn scientific(mut self) -> Self
+    where
+        T: fmt::LowerExp,
+    {
+        self.format_component = format_lower_exp::<T>;
+        self
+    }
+
+    /// Prints at most `max_rows` rows: the remaining rows in the middle of the matrix are elided
+    /// and replaced by a single row of `…`.
+    #[inline]
+    #[must_use]
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Prints at most `max_cols` columns: the remaining columns in the middle of the matrix are
+    /// elided and replaced by a single column of `…`.
+    #[inline]
+    #[must_use]
+    pub fn max_cols(mut self, max_cols: usize) -> Self {
+        self.max_cols = Some(max_cols);
+        self
+    }
+
+    /// Sets the alignment of the components within their column. Defaults to
+    /// `Alignment::Right`.
+    ///
+    /// This has no effect with `Style::Compact`.
+    #[inline]
+    #[must_use]
+    pub fn align(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets the layout of the output. Defaults to `Style::Boxed`.
+    #[inline]
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Prints every row and column. This is the default.
+    #[inline]
+    #[must_use]
+    pub fn unlimited(mut self) -> Self {
+        self.max_rows = None;
+        self.max_cols = None;
+        self
+    }
+
+    fn ellipses(&self) -> (&'static str, &'static str, &'static str) {
+        match self.style {
+            Style::Latex => ("\\cdots", "\\vdots", "\\ddots"),
+            _ => ("…", "…", "…"),
+        }
+    }
+
+    /// The formatted components to print, row by row, with elided rows and columns.
+    fn cells(&self, precision: Option<usize>) -> Vec<Vec<String>> {
+        let (nrows, ncols) = self.matrix.shape();
+        let rows = visible_indices(nrows, self.max_rows);
+        let cols = visible_indices(ncols, self.max_cols);
+        let (hdots, vdots, ddots) = self.ellipses();
+
+        rows.iter()
+            .map(|i| {
+                cols.iter()
+                    .map(|j| match (i, j) {
+                        (Some(i), Some(j)) => {
+                            (self.format_component)(&self.matrix[(*i, *j)], precision)
+                        }
+                        (Some(_), None) => String::from(hdots),
+                        (None, Some(_)) => String::from(vdots),
+                        (None, None) => String::from(ddots),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn padded(&self, cell: &str, width: usize) -> String {
+        match self.alignment {
+            Alignment::Left => format!("{:<1$}", cell, width),
+            Alignment::Right => format!("{:>1$}", cell, width),
+            Alignment::Center => format!("{:^1$}", cell, width),
+        }
+    }
+
+    fn write_padded(&self, f: &mut fmt::Formatter<'_>, cell: &str, width: usize) -> fmt::Result {
+        write!(f, "{}", self.padded(cell, width))
+    }
+
+    /// Writes the rows of `cells` surrounded by the given delimiters.
+    fn write_rows(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        cells: &[Vec<String>],
+        width: usize,
+        delimiters: &Delimiters,
+    ) -> fmt::Result {
+        // Commas stick to the component they follow, as in hand-written literals.
+        let (suffix, sep) = match delimiters.sep.strip_prefix(',') {
+            Some(sep) => (",", sep),
+            None => ("", delimiters.sep),
+        };
+
+        write!(f, "{}", delimiters.open)?;
+        for (i, row) in cells.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}\n{}", delimiters.row_sep, delimiters.indent)?;
+            }
+
+            let mut line = String::from(delimiters.row_open);
+            for (j, cell) in row.iter().enumerate() {
+                if j + 1 < row.len() {
+                    let cell = format!("{}{}", cell, suffix);
+                    line.push_str(&self.padded(&cell, width + suffix.len()));
+                    line.push_str(sep);
+                } else {
+                    line.push_str(&self.padded(cell, width));
+                }
+            }
+
+            // Avoid trailing whitespaces at the end of lines.
+            if delimiters.row_close.is_empty() {
+                line.truncate(line.trim_end().len());
+            }
+            write!(f, "{}{}", line, delimiters.row_close)?;
+        }
+        write!(f, "{}", delimiters.close)
+    }
+
+    fn write_boxed(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        cells: &[Vec<String>],
+        width: usize,
+    ) -> fmt::Result {
+        let ncols = cells[0].len();
+        let inner_width = (width + 1) * ncols - 1;
+
+        writeln!(f)?;
+        writeln!(f, "  ┌ {:>1$} ┐", "", inner_width)?;
+        for row in cells {
+            write!(f, "  │")?;
+            for cell in row {
+                write!(f, " ")?;
+                self.write_padded(f, cell, width)?;
+            }
+            writeln!(f, " │")?;
+        }
+        writeln!(f, "  └ {:>1$} ┘", "", inner_width)?;
+        writeln!(f)
+    }
+
+    fn write_markdown(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        cells: &[Vec<String>],
+        width: usize,
+    ) -> fmt::Result {
+        let cols = visible_indices(self.matrix.ncols(), self.max_cols);
+        let width = width.max(3);
+
+        let header: Vec<String> = cols
+            .iter()
+            .map(|j| match j {
+                Some(j) => format!("{}", j),
+                None => String::from("…"),
+            })
+            .collect();
+        let width = header.iter().fold(width, |w, h| w.max(h.chars().count()));
+
+        write!(f, "|")?;
+        for h in &header {
+            write!(f, " ")?;
+            self.write_padded(f, h, width)?;
+            write!(f, " |")?;
+        }
+
+        write!(f, "\n|")?;
+        let dashes = "-".repeat(width);
+        for _ in &header {
+            match self.alignment {
+                Alignment::Left => write!(f, " :{} |", &dashes[1..])?,
+                Alignment::Right => write!(f, " {}: |", &dashes[1..])?,
+                Alignment::Center => write!(f, " :{}: |", &dashes[2..])?,
+            }
+        }
+
+        for row in cells {
+            write!(f, "\n|")?;
+            for cell in row {
+                write!(f, " ")?;
+                self.write_padded(f, cell, width)?;
+                write!(f, " |")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> fmt::Display
+    for MatrixDisplay<'a, T, R, C, S>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (nrows, ncols) = self.matrix.shape();
+
+        if nrows == 0 || ncols == 0 {
+            return match self.style {
+                Style::Boxed => write!(f, "[ ]"),
+                Style::Compact | Style::Markdown => write!(f, "[]"),
+                Style::Latex => write!(f, "\\begin{{bmatrix}}\\end{{bmatrix}}"),
+                Style::NumPy => write!(f, "np.empty(({}, {}))", nrows, ncols),
+                Style::Matlab => write!(f, "zeros({}, {})", nrows, ncols),
+                Style::Rust => write!(f, "matrix![]"),
+            };
+        }
+
+        let cells = self.cells(self.precision.or_else(|| f.precision()));
+        let width = cells
+            .iter()
+            .flatten()
+            .map(|cell| cell.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        match self.style {
+            Style::Boxed => self.write_boxed(f, &cells, width),
+            Style::Markdown => self.write_markdown(f, &cells, width),
+            Style::Compact => {
+                write!(f, "[")?;
+                for (i, row) in cells.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{}", row.join(", "))?;
+                }
+                write!(f, "]")
+            }
+            Style::Latex => self.write_rows(f, &cells, width, &LATEX),
+            Style::NumPy => self.write_rows(f, &cells, width, &NUMPY),
+            Style::Matlab => self.write_rows(f, &cells, width, &MATLAB),
+            Style::Rust => self.write_rows(f, &cells, width, &RUST),
+        }
+    }
+}
+
+/// # Formatting
+impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Creates a builder of configurable textual representations of this matrix.
+    ///
+    /// Without further configuration, the result is displayed exactly like `self`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// # use nalgebra::display::Style;
+    /// let m = DMatrix::from_fn(100, 100, |i, j| (i * 100 + j) as f64);
+    /// assert_eq!(
+    ///     m.display().max_rows(2).max_cols(2).style(Style::Compact).to_string(),
+    ///     "[0, …, 99; …, …, …; 9900, …, 9999]"
+    /// );
+    /// assert_eq!(m.display().to_string(), m.to_string());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn display(&self) -> MatrixDisplay<'_, T, R, C, S>
+    where
+        T: fmt::Display,
+    {
+        MatrixDisplay::new(self)
+    }
+}
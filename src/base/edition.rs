@@ -1,16 +1,22 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
 use num::{One, Zero};
 use std::cmp;
 #[cfg(any(feature = "std", feature = "alloc"))]
+use std::cmp::Ordering;
+#[cfg(any(feature = "std", feature = "alloc"))]
 use std::iter::ExactSizeIterator;
 use std::ptr;
 
 use crate::base::allocator::{Allocator, Reallocator};
 use crate::base::constraint::{DimEq, SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
-#[cfg(any(feature = "std", feature = "alloc"))]
-use crate::base::dimension::Dynamic;
-use crate::base::dimension::{Const, Dim, DimAdd, DimDiff, DimMin, DimMinimum, DimSub, DimSum, U1};
-use crate::base::storage::{RawStorage, RawStorageMut, ReshapableStorage};
+use crate::base::dimension::{
+    Const, Dim, DimAdd, DimDiff, DimMin, DimMinimum, DimSub, DimSum, Dynamic, U1,
+};
+use crate::base::storage::{IsContiguous, RawStorage, RawStorageMut, ReshapableStorage};
 use crate::base::{DefaultAllocator, Matrix, OMatrix, RowVector, Scalar, Vector};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::{MatrixSlice, VectorSlice};
 use crate::{Storage, UninitMatrix};
 use std::mem::MaybeUninit;
 
@@ -104,6 +110,86 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         // Safety: res is now fully initialized.
         unsafe { res.assume_init() }
     }
+
+    /// Creates a new matrix with the rows of `self` reordered by the permutation `perm`.
+    ///
+    /// The `i`-th row of the result is the `perm[i]`-th row of `self`, so this is the same as
+    /// `.select_rows(perm)` except that the dimensions of `self` are preserved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `perm` is not a permutation of `0..self.nrows()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3x2;
+    /// let m = Matrix3x2::new(1, 2,
+    ///                        3, 4,
+    ///                        5, 6);
+    /// let perm = [2, 0, 1];
+    /// assert_eq!(m.permute_rows(&perm), Matrix3x2::new(5, 6, 1, 2, 3, 4));
+    /// assert_eq!(m.permute_rows(&perm), m.select_rows(&perm));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[must_use]
+    pub fn permute_rows(&self, perm: &[usize]) -> OMatrix<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        assert_is_permutation(perm, self.nrows());
+        let (nrows, ncols) = self.shape_generic();
+        let mut res = Matrix::uninit(nrows, ncols);
+
+        for j in 0..ncols.value() {
+            for (destination, source) in perm.iter().enumerate() {
+                // Safety: all indices are in range.
+                unsafe {
+                    *res.get_unchecked_mut((destination, j)) =
+                        MaybeUninit::new(self.get_unchecked((*source, j)).clone());
+                }
+            }
+        }
+
+        // Safety: res is now fully initialized.
+        unsafe { res.assume_init() }
+    }
+
+    /// Creates a new matrix with the columns of `self` reordered by the permutation `perm`.
+    ///
+    /// The `j`-th column of the result is the `perm[j]`-th column of `self`, so this is the same
+    /// as `.select_columns(perm)` except that the dimensions of `self` are preserved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `perm` is not a permutation of `0..self.ncols()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    /// let perm = [1, 2, 0];
+    /// assert_eq!(m.permute_columns(&perm), Matrix2x3::new(2, 3, 1, 5, 6, 4));
+    /// assert_eq!(m.permute_columns(&perm), m.select_columns(&perm));
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[must_use]
+    pub fn permute_columns(&self, perm: &[usize]) -> OMatrix<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        assert_is_permutation(perm, self.ncols());
+        let (nrows, ncols) = self.shape_generic();
+        let mut res = Matrix::uninit(nrows, ncols);
+
+        for (destination, source) in perm.iter().enumerate() {
+            res.column_mut(destination)
+                .zip_apply(&self.column(*source), |out, e| *out = MaybeUninit::new(e));
+        }
+
+        // Safety: res is now fully initialized.
+        unsafe { res.assume_init() }
+    }
 }
 
 /// # Set rows, columns, and diagonal
@@ -335,6 +421,188 @@ impl<T: Scalar, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> Matrix<T, R, C, S> {
     }
 }
 
+/// # In-place permutation and sorting
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> Matrix<T, R, C, S> {
+    /// Reorders the rows of this matrix in-place by the permutation `perm`.
+    ///
+    /// After this call, the `i`-th row of `self` is its former `perm[i]`-th row. This is the
+    /// in-place version of `.permute_rows`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `perm` is not a permutation of `0..self.nrows()`.
+    pub fn permute_rows_mut(&mut self, perm: &[usize]) {
+        assert_is_permutation(perm, self.nrows());
+        apply_permutation(perm, |i, j| self.swap_rows(i, j));
+    }
+
+    /// Reorders the columns of this matrix in-place by the permutation `perm`.
+    ///
+    /// After this call, the `j`-th column of `self` is its former `perm[j]`-th column. This is
+    /// the in-place version of `.permute_columns`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `perm` is not a permutation of `0..self.ncols()`.
+    pub fn permute_columns_mut(&mut self, perm: &[usize]) {
+        assert_is_permutation(perm, self.ncols());
+        apply_permutation(perm, |i, j| self.swap_columns(i, j));
+    }
+
+    /// Sorts the columns of this matrix in-place with the comparator function `compare`.
+    ///
+    /// This sort is stable: columns comparing equal keep their relative order.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let mut m = Matrix2x3::new(3, 1, 2,
+    ///                            0, 5, 4);
+    /// // Sort the columns by their first component.
+    /// m.sort_columns_by(|a, b| a[0].cmp(&b[0]));
+    /// assert_eq!(m, Matrix2x3::new(1, 2, 3, 5, 4, 0));
+    /// ```
+    pub fn sort_columns_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(
+            VectorSlice<'_, T, R, S::RStride, S::CStride>,
+            VectorSlice<'_, T, R, S::RStride, S::CStride>,
+        ) -> Ordering,
+    {
+        let mut perm: Vec<usize> = (0..self.ncols()).collect();
+        perm.sort_by(|a, b| compare(self.column(*a), self.column(*b)));
+        self.permute_columns_mut(&perm);
+    }
+
+    /// Sorts the rows of this matrix in-place with the comparator function `compare`.
+    ///
+    /// This sort is stable: rows comparing equal keep their relative order.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3x2;
+    /// let mut m = Matrix3x2::new(3, 0,
+    ///                            1, 5,
+    ///                            1, 4);
+    /// // Sort the rows lexicographically.
+    /// m.sort_rows_by(|a, b| a.iter().cmp(b.iter()));
+    /// assert_eq!(m, Matrix3x2::new(1, 4, 1, 5, 3, 0));
+    /// ```
+    pub fn sort_rows_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(
+            MatrixSlice<'_, T, U1, C, S::RStride, S::CStride>,
+            MatrixSlice<'_, T, U1, C, S::RStride, S::CStride>,
+        ) -> Ordering,
+    {
+        let mut perm: Vec<usize> = (0..self.nrows()).collect();
+        perm.sort_by(|a, b| compare(self.row(*a), self.row(*b)));
+        self.permute_rows_mut(&perm);
+    }
+}
+
+/// # Sorting vectors
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar, D: Dim, S: RawStorage<T, D>> Vector<T, D, S> {
+    /// Returns the permutation that sorts this vector in increasing order.
+    ///
+    /// The `i`-th element of the result is the index of the `i`-th smallest component of `self`,
+    /// so the sorted vector can be obtained with `.select_rows(&perm)` or `.permute_rows(&perm)`.
+    /// This sort is stable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if two components cannot be compared, e.g., if one of them is NaN.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector4;
+    /// let v = Vector4::new(3.0, -1.0, 2.0, -1.0);
+    /// let perm = v.argsort();
+    /// assert_eq!(perm, vec![1, 3, 2, 0]);
+    /// assert_eq!(v.permute_rows(&perm), Vector4::new(-1.0, -1.0, 2.0, 3.0));
+    /// ```
+    #[must_use]
+    pub fn argsort(&self) -> Vec<usize>
+    where
+        T: PartialOrd,
+    {
+        self.argsort_by(|a, b| {
+            a.partial_cmp(b)
+                .expect("argsort: the vector components must be comparable.")
+        })
+    }
+
+    /// Returns the permutation that sorts this vector with the comparator function `compare`.
+    ///
+    /// See `.argsort` for the meaning of the returned permutation. This sort is stable.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Vector3;
+    /// let v = Vector3::new(3, -1, 2);
+    /// // Sort in decreasing order.
+    /// assert_eq!(v.argsort_by(|a, b| b.cmp(a)), vec![0, 2, 1]);
+    /// ```
+    #[must_use]
+    pub fn argsort_by<F>(&self, mut compare: F) -> Vec<usize>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut perm: Vec<usize> = (0..self.len()).collect();
+        // Safety: all indices are in range.
+        perm.sort_by(|a, b| unsafe { compare(self.vget_unchecked(*a), self.vget_unchecked(*b)) });
+        perm
+    }
+}
+
+/// Panics if `perm` is not a permutation of `0..len`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn assert_is_permutation(perm: &[usize], len: usize) {
+    assert_eq!(
+        perm.len(),
+        len,
+        "The permutation must have as many indices as permuted rows or columns."
+    );
+
+    let mut seen = vec![false; len];
+
+    for &i in perm {
+        assert!(
+            i < len && !seen[i],
+            "Invalid permutation: the index {} is out of bounds or repeated.",
+            i
+        );
+        seen[i] = true;
+    }
+}
+
+/// Applies the permutation `perm` with transpositions: after this call, the element at the
+/// position `i` is the former element at the position `perm[i]`.
+///
+/// The permutation must have been checked with `assert_is_permutation`.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn apply_permutation(perm: &[usize], mut swap: impl FnMut(usize, usize)) {
+    let mut done = vec![false; perm.len()];
+
+    for start in 0..perm.len() {
+        let mut i = start;
+
+        // Walk the cycle containing `start`, moving each element to its final position.
+        while !done[i] {
+            done[i] = true;
+            let next = perm[i];
+
+            if next != start {
+                swap(i, next);
+            }
+
+            i = next;
+        }
+    }
+}
+
 /*
  *
  * TODO: specialize all the following for slices.
@@ -690,13 +958,55 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         C: DimAdd<D>,
         DefaultAllocator: Reallocator<T, R, C, R, DimSum<C, D>>,
     {
-        let m = self.into_owned();
-        let (nrows, ncols) = m.shape_generic();
-        let mut res = Matrix::from_data(DefaultAllocator::reallocate_copy(
-            nrows,
-            ncols.add(ninsert),
-            m.data,
-        ));
+        self.into_owned()
+            .insert_columns_generic_uninitialized_in::<DefaultAllocator, D>(i, ninsert)
+    }
+
+    /// Inserts a column filled with `val` at the `i-th` position, reallocating the buffer of
+    /// `self` with its allocator `A`.
+    ///
+    /// Unlike `insert_column`, this does not need the `DefaultAllocator`, e.g., it works for
+    /// matrices backed by an `InlineAllocator` without any heap allocator.
+    #[inline]
+    pub fn insert_column_in<A>(
+        self,
+        i: usize,
+        val: T,
+    ) -> Matrix<T, R, DimSum<C, U1>, <A as Allocator<T, R, DimSum<C, U1>>>::Buffer>
+    where
+        C: DimAdd<U1>,
+        A: Reallocator<T, R, C, R, DimSum<C, U1>> + Allocator<T, R, C, Buffer = S>,
+    {
+        let mut res =
+            unsafe { self.insert_columns_generic_uninitialized_in::<A, U1>(i, Const::<1>) };
+        res.fixed_columns_mut::<1>(i)
+            .fill_with(|| MaybeUninit::new(val.clone()));
+
+        // Safety: the result is now fully initialized. The added column has
+        //         been initialized by the `fill_with` above, and the rest have
+        //         been initialized by `insert_columns_generic_uninitialized_in`.
+        Matrix::from_data(unsafe { <A as Allocator<T, R, DimSum<C, U1>>>::assume_init(res.data) })
+    }
+
+    /// Inserts `ninsert.value()` columns starting at the `i-th` place of this matrix, reallocating
+    /// its buffer with `A`.
+    ///
+    /// # Safety
+    /// The output matrix has all its elements initialized except for the the components of the
+    /// added columns.
+    #[inline]
+    unsafe fn insert_columns_generic_uninitialized_in<A, D>(
+        self,
+        i: usize,
+        ninsert: D,
+    ) -> Matrix<MaybeUninit<T>, R, DimSum<C, D>, <A as Allocator<T, R, DimSum<C, D>>>::BufferUninit>
+    where
+        D: Dim,
+        C: DimAdd<D>,
+        A: Reallocator<T, R, C, R, DimSum<C, D>> + Allocator<T, R, C, Buffer = S>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let mut res = Matrix::from_data(A::reallocate_copy(nrows, ncols.add(ninsert), self.data));
 
         assert!(i <= ncols.value(), "Column insertion index out of range.");
 
@@ -784,13 +1094,66 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
         R: DimAdd<D>,
         DefaultAllocator: Reallocator<T, R, C, DimSum<R, D>, C>,
     {
-        let m = self.into_owned();
-        let (nrows, ncols) = m.shape_generic();
-        let mut res = Matrix::from_data(DefaultAllocator::reallocate_copy(
-            nrows.add(ninsert),
-            ncols,
-            m.data,
-        ));
+        self.into_owned()
+            .insert_rows_generic_uninitialized_in::<DefaultAllocator, D>(i, ninsert)
+    }
+
+    /// Inserts a row filled with `val` at the `i-th` position, reallocating the buffer of `self`
+    /// with its allocator `A`.
+    ///
+    /// Unlike `insert_row`, this does not need the `DefaultAllocator`, e.g., it works for
+    /// matrices backed by an `InlineAllocator` without any heap allocator.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Dynamic, InlineAllocator, InlineDMatrix, InlineStorage, Matrix};
+    /// let m: InlineDMatrix<i32, 8> = Matrix::from_data(InlineStorage::from_row_slice(
+    ///     Dynamic::new(2),
+    ///     Dynamic::new(2),
+    ///     &[1, 2, 3, 4],
+    /// ));
+    /// let m = m.insert_row_in::<InlineAllocator<8>>(1, 0);
+    /// assert_eq!(m.shape(), (3, 2));
+    /// assert_eq!(m.as_slice(), &[1, 0, 3, 2, 0, 4]);
+    /// ```
+    #[inline]
+    pub fn insert_row_in<A>(
+        self,
+        i: usize,
+        val: T,
+    ) -> Matrix<T, DimSum<R, U1>, C, <A as Allocator<T, DimSum<R, U1>, C>>::Buffer>
+    where
+        R: DimAdd<U1>,
+        A: Reallocator<T, R, C, DimSum<R, U1>, C> + Allocator<T, R, C, Buffer = S>,
+    {
+        let mut res = unsafe { self.insert_rows_generic_uninitialized_in::<A, U1>(i, Const::<1>) };
+        res.fixed_rows_mut::<1>(i)
+            .fill_with(|| MaybeUninit::new(val.clone()));
+
+        // Safety: the result is now fully initialized. The added row has
+        //         been initialized by the `fill_with` above, and the rest have
+        //         been initialized by `insert_rows_generic_uninitialized_in`.
+        Matrix::from_data(unsafe { <A as Allocator<T, DimSum<R, U1>, C>>::assume_init(res.data) })
+    }
+
+    /// Inserts `ninsert.value()` rows at the `i-th` place of this matrix, reallocating its buffer
+    /// with `A`.
+    ///
+    /// # Safety
+    /// The added rows values are not initialized.
+    #[inline]
+    unsafe fn insert_rows_generic_uninitialized_in<A, D>(
+        self,
+        i: usize,
+        ninsert: D,
+    ) -> Matrix<MaybeUninit<T>, DimSum<R, D>, C, <A as Allocator<T, DimSum<R, D>, C>>::BufferUninit>
+    where
+        D: Dim,
+        R: DimAdd<D>,
+        A: Reallocator<T, R, C, DimSum<R, D>, C> + Allocator<T, R, C, Buffer = S>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let mut res = Matrix::from_data(A::reallocate_copy(nrows.add(ninsert), ncols, self.data));
 
         assert!(i <= nrows.value(), "Row insertion index out of range.");
 
@@ -875,9 +1238,59 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     ) -> OMatrix<T, R2, C2>
     where
         DefaultAllocator: Reallocator<T, R, C, R2, C2>,
+    {
+        self.into_owned()
+            .resize_generic_in::<DefaultAllocator, R2, C2>(new_nrows, new_ncols, val)
+    }
+
+    /// Resizes this matrix so that it contains `new_nrows` rows and `new_ncols` columns,
+    /// reallocating its buffer with its allocator `A`.
+    ///
+    /// Unlike `resize`, this does not need the `DefaultAllocator`, e.g., it works for matrices
+    /// backed by an `InlineAllocator` without any heap allocator.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Dynamic, InlineAllocator, InlineDMatrix, InlineStorage, Matrix};
+    /// let m: InlineDMatrix<i32, 8> = Matrix::from_data(InlineStorage::from_row_slice(
+    ///     Dynamic::new(2),
+    ///     Dynamic::new(2),
+    ///     &[1, 2, 3, 4],
+    /// ));
+    /// let m = m.resize_in::<InlineAllocator<8>>(3, 1, 0);
+    /// assert_eq!(m.as_slice(), &[1, 3, 0]);
+    /// ```
+    pub fn resize_in<A>(
+        self,
+        new_nrows: usize,
+        new_ncols: usize,
+        val: T,
+    ) -> Matrix<T, Dynamic, Dynamic, <A as Allocator<T, Dynamic, Dynamic>>::Buffer>
+    where
+        S: RawStorageMut<T, R, C> + IsContiguous,
+        A: Reallocator<T, R, C, Dynamic, Dynamic> + Allocator<T, R, C, Buffer = S>,
+    {
+        self.resize_generic_in::<A, _, _>(Dynamic::new(new_nrows), Dynamic::new(new_ncols), val)
+    }
+
+    /// Resizes `self` such that it has dimensions `new_nrows × new_ncols`, reallocating its
+    /// buffer with its allocator `A`.
+    ///
+    /// The values are copied such that `self[(i, j)] == result[(i, j)]`. If the result has more
+    /// rows and/or columns than `self`, then the extra rows or columns are filled with `val`.
+    #[inline]
+    pub fn resize_generic_in<A, R2: Dim, C2: Dim>(
+        self,
+        new_nrows: R2,
+        new_ncols: C2,
+        val: T,
+    ) -> Matrix<T, R2, C2, <A as Allocator<T, R2, C2>>::Buffer>
+    where
+        S: RawStorageMut<T, R, C> + IsContiguous,
+        A: Reallocator<T, R, C, R2, C2> + Allocator<T, R, C, Buffer = S>,
     {
         let (nrows, ncols) = self.shape();
-        let mut data = self.into_owned();
+        let mut data = self;
 
         if new_nrows.value() == nrows {
             if new_ncols.value() < ncols {
@@ -892,7 +1305,7 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
                 };
             }
 
-            let res = unsafe { DefaultAllocator::reallocate_copy(new_nrows, new_ncols, data.data) };
+            let res = unsafe { A::reallocate_copy(new_nrows, new_ncols, data.data) };
             let mut res = Matrix::from_data(res);
 
             if new_ncols.value() > ncols {
@@ -902,7 +1315,7 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
 
             // Safety: the result is now fully initialized by `reallocate_copy` and
             //         `fill_with` (if the output has more columns than the input).
-            unsafe { res.assume_init() }
+            Matrix::from_data(unsafe { <A as Allocator<T, R2, C2>>::assume_init(res.data) })
         } else {
             let mut res;
 
@@ -915,13 +1328,9 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
                         new_nrows.value(),
                         nrows - new_nrows.value(),
                     );
-                    res = Matrix::from_data(DefaultAllocator::reallocate_copy(
-                        new_nrows, new_ncols, data.data,
-                    ));
+                    res = Matrix::from_data(A::reallocate_copy(new_nrows, new_ncols, data.data));
                 } else {
-                    res = Matrix::from_data(DefaultAllocator::reallocate_copy(
-                        new_nrows, new_ncols, data.data,
-                    ));
+                    res = Matrix::from_data(A::reallocate_copy(new_nrows, new_ncols, data.data));
                     extend_rows(
                         &mut res.as_mut_slice(),
                         nrows,
@@ -944,7 +1353,7 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
 
             // Safety: the result is now fully initialized by `reallocate_copy` and
             //         `fill_with` (whenever applicable).
-            unsafe { res.assume_init() }
+            Matrix::from_data(unsafe { <A as Allocator<T, R2, C2>>::assume_init(res.data) })
         }
     }
 
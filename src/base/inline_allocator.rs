@@ -0,0 +1,113 @@
+//! A matrix data storage allocator that never allocates on the heap.
+//!
+//! Every buffer has a fixed capacity known at compile-time, which makes this allocator usable for
+//! matrices with dimensions only known at run-time in environments without any heap allocator.
+//!
+//! Operations returning a new owned matrix allocate it with the `DefaultAllocator`, which requires
+//! the `alloc` feature for dynamically sized matrices. Without a heap allocator, use their `_in`
+//! counterparts taking the allocator as a type parameter instead, e.g.,
+//! `m.resize_in::<InlineAllocator<16>>(4, 4, 0.0)`, `insert_row_in`, `add_in`, `mul_in`,
+//! `transpose_in`, `clone_owned_in` or `cholesky_in`, or the in-place methods like `+=`, `gemm`,
+//! `insert_row_in_place` and `lu_solve_in_place`.
+
+use std::mem::MaybeUninit;
+use std::ptr;
+
+use crate::base::allocator::{Allocator, Reallocator};
+use crate::base::dimension::Dim;
+use crate::base::inline_storage::{assert_capacity, InlineStorage};
+use crate::base::storage::{RawStorage, RawStorageMut};
+use crate::base::Scalar;
+
+/*
+ *
+ * Allocator.
+ *
+ */
+/// An allocator based on `InlineStorage` with a fixed capacity of `N` components.
+///
+/// Allocating a buffer for a matrix with more than `N` components panics.
+///
+/// This allocator must be passed explicitly to the `_in` methods, e.g.,
+/// `insert_row_in::<InlineAllocator<N>>(i, val)`.
+#[derive(Copy, Clone, Debug)]
+pub struct InlineAllocator<const N: usize>;
+
+impl<T: Scalar, R: Dim, C: Dim, const N: usize> Allocator<T, R, C> for InlineAllocator<N> {
+    type Buffer = InlineStorage<T, R, C, N>;
+    type BufferUninit = InlineStorage<MaybeUninit<T>, R, C, N>;
+
+    #[inline]
+    fn allocate_uninit(nrows: R, ncols: C) -> InlineStorage<MaybeUninit<T>, R, C, N> {
+        assert_capacity::<N>(nrows.value() * ncols.value());
+
+        // SAFETY:
+        // * An uninitialized `[MaybeUninit<_>; _]` is valid.
+        // * Any `MaybeUninit<T>` is a valid, initialized, component of the returned buffer.
+        unsafe { InlineStorage::from_raw_parts(MaybeUninit::uninit().assume_init(), nrows, ncols) }
+    }
+
+    #[inline]
+    unsafe fn assume_init(
+        uninit: InlineStorage<MaybeUninit<T>, R, C, N>,
+    ) -> InlineStorage<T, R, C, N> {
+        let (data, nrows, ncols) = uninit.into_raw_parts();
+
+        // Safety:
+        // * The caller guarantees that the first `nrows * ncols` elements are initialized.
+        // * `MaybeUninit<MaybeUninit<T>>` and `MaybeUninit<T>` have the same layout.
+        // * `MaybeUninit` does not drop, so there are no double-frees.
+        InlineStorage::from_raw_parts(
+            (&data as *const _ as *const [MaybeUninit<T>; N]).read(),
+            nrows,
+            ncols,
+        )
+    }
+
+    #[inline]
+    fn allocate_from_iterator<I: IntoIterator<Item = T>>(
+        nrows: R,
+        ncols: C,
+        iter: I,
+    ) -> Self::Buffer {
+        InlineStorage::from_iterator(nrows, ncols, iter)
+    }
+}
+
+/*
+ *
+ * Reallocator.
+ *
+ */
+impl<T: Scalar, RFrom, CFrom, RTo, CTo, const N: usize> Reallocator<T, RFrom, CFrom, RTo, CTo>
+    for InlineAllocator<N>
+where
+    RFrom: Dim,
+    CFrom: Dim,
+    RTo: Dim,
+    CTo: Dim,
+{
+    #[inline]
+    unsafe fn reallocate_copy(
+        rto: RTo,
+        cto: CTo,
+        buf: InlineStorage<T, RFrom, CFrom, N>,
+    ) -> InlineStorage<MaybeUninit<T>, RTo, CTo, N> {
+        let mut res = <Self as Allocator<T, RTo, CTo>>::allocate_uninit(rto, cto);
+
+        let (rfrom, cfrom) = buf.shape();
+        let len_from = rfrom.value() * cfrom.value();
+        let len_to = rto.value() * cto.value();
+
+        // The removed components (if any) are not dropped, as required by the `Reallocator`
+        // trait: they are simply left behind in the forgotten buffer.
+        let (data, _, _) = buf.into_raw_parts();
+        ptr::copy_nonoverlapping(
+            data.as_ptr() as *const T,
+            res.ptr_mut() as *mut T,
+            len_from.min(len_to),
+        );
+
+        res
+    }
+}
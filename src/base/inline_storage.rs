@@ -0,0 +1,546 @@
+//! Fixed-capacity matrix data storage that does not require any heap allocation.
+
+use std::fmt::{self, Debug, Formatter};
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::ptr;
+
+use crate::base::allocator::Allocator;
+use crate::base::default_allocator::DefaultAllocator;
+use crate::base::dimension::{Dim, Dynamic, U1};
+use crate::base::storage::{IsContiguous, Owned, RawStorage, RawStorageMut, Storage};
+use crate::base::{Matrix, Scalar};
+
+/*
+ *
+ * RawStorage.
+ *
+ */
+/// An array-based matrix data storage with a fixed capacity of `N` components.
+///
+/// Unlike `ArrayStorage`, its dimensions may be only known at run-time, like with `VecStorage`,
+/// but the components are stored inline instead of on the heap. This makes it possible to work
+/// with dynamically-sized matrices, as long as they never hold more than `N` components, in
+/// environments without any allocator. The components are stored in column-major order.
+#[repr(C)]
+pub struct InlineStorage<T, R: Dim, C: Dim, const N: usize> {
+    // Invariant: the first `nrows * ncols` elements are initialized.
+    data: [MaybeUninit<T>; N],
+    nrows: R,
+    ncols: C,
+}
+
+impl<T, R: Dim, C: Dim, const N: usize> InlineStorage<T, R, C, N> {
+    /// Creates a new inline matrix data storage by moving the first `nrows * ncols` elements
+    /// of the given iterator, in column-major order.
+    ///
+    /// Panics if `nrows * ncols` exceeds the capacity `N`, or if the iterator does not yield
+    /// enough elements.
+    #[inline]
+    pub fn from_iterator<I: IntoIterator<Item = T>>(nrows: R, ncols: C, iter: I) -> Self {
+        let len = nrows.value() * ncols.value();
+        assert_capacity::<N>(len);
+
+        // SAFETY: An uninitialized `[MaybeUninit<_>; _]` is valid.
+        let mut data: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
+        let mut count = 0;
+
+        for (e, val) in data[..len].iter_mut().zip(iter) {
+            *e = MaybeUninit::new(val);
+            count += 1;
+        }
+
+        if count != len {
+            // Drop the elements we already moved before panicking.
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                    data.as_mut_ptr() as *mut T,
+                    count,
+                ))
+            };
+            panic!("Matrix init. from iterator: iterator not long enough.");
+        }
+
+        Self { data, nrows, ncols }
+    }
+
+    /// Creates a new inline matrix data storage with all its components set to `elem`.
+    ///
+    /// Panics if `nrows * ncols` exceeds the capacity `N`.
+    #[inline]
+    pub fn from_element(nrows: R, ncols: C, elem: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(nrows, ncols, |_, _| elem.clone())
+    }
+
+    /// Creates a new inline matrix data storage with its components filled by the function `f`,
+    /// called with the row and column index of each component.
+    ///
+    /// Panics if `nrows * ncols` exceeds the capacity `N`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(nrows: R, ncols: C, mut f: F) -> Self {
+        let (nrows_val, ncols_val) = (nrows.value(), ncols.value());
+        let iter = (0..ncols_val).flat_map(|j| (0..nrows_val).map(move |i| (i, j)));
+        Self::from_iterator(nrows, ncols, iter.map(|(i, j)| f(i, j)))
+    }
+
+    /// Creates a new inline matrix data storage from a slice of components in column-major
+    /// order.
+    ///
+    /// Panics if `nrows * ncols` exceeds the capacity `N`, or if the slice is too short.
+    #[inline]
+    pub fn from_column_slice(nrows: R, ncols: C, slice: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_iterator(nrows, ncols, slice.iter().cloned())
+    }
+
+    /// Creates a new inline matrix data storage from a slice of components in row-major order.
+    ///
+    /// Panics if `nrows * ncols` exceeds the capacity `N`, or if the slice is too short.
+    #[inline]
+    pub fn from_row_slice(nrows: R, ncols: C, slice: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        let ncols_val = ncols.value();
+        assert!(
+            slice.len() >= nrows.value() * ncols_val,
+            "Matrix init. from row slice: slice not long enough."
+        );
+        Self::from_fn(nrows, ncols, |i, j| slice[i * ncols_val + j].clone())
+    }
+
+    /// The maximum number of components this storage can hold.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// The number of components currently stored.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nrows.value() * self.ncols.value()
+    }
+
+    /// Returns true if this storage contains no components.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// A slice containing all the components stored in this storage in column-major order.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `self.len()` elements are initialized.
+        unsafe { std::slice::from_raw_parts(self.data.as_ptr() as *const T, self.len()) }
+    }
+
+    /// A mutable slice containing all the components stored in this storage in column-major order.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first `self.len()` elements are initialized.
+        unsafe { std::slice::from_raw_parts_mut(self.data.as_mut_ptr() as *mut T, self.len()) }
+    }
+
+    /// Creates a storage from its raw buffer and shape.
+    ///
+    /// # Safety
+    /// `nrows * ncols` must not exceed `N`, and the first `nrows * ncols` elements of `data` must
+    /// be initialized.
+    #[inline]
+    pub(crate) unsafe fn from_raw_parts(data: [MaybeUninit<T>; N], nrows: R, ncols: C) -> Self {
+        debug_assert!(nrows.value() * ncols.value() <= N);
+        Self { data, nrows, ncols }
+    }
+
+    /// Splits this storage into its raw buffer and shape, without dropping any component.
+    #[inline]
+    pub(crate) fn into_raw_parts(self) -> ([MaybeUninit<T>; N], R, C) {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `this` is never dropped, so the buffer is moved out only once.
+        (unsafe { ptr::read(&this.data) }, this.nrows, this.ncols)
+    }
+}
+
+impl<T, R: Dim, C: Dim, const N: usize> Drop for InlineStorage<T, R, C, N> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: the first `self.len()` elements are initialized and dropped only once here.
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+impl<T: Clone, R: Dim, C: Dim, const N: usize> Clone for InlineStorage<T, R, C, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::from_iterator(self.nrows, self.ncols, self.as_slice().iter().cloned())
+    }
+}
+
+impl<T: Debug, R: Dim, C: Dim, const N: usize> Debug for InlineStorage<T, R, C, N> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("InlineStorage")
+            .field("data", &self.as_slice())
+            .field("nrows", &self.nrows)
+            .field("ncols", &self.ncols)
+            .finish()
+    }
+}
+
+impl<T: PartialEq, R: Dim, C: Dim, const N: usize> PartialEq for InlineStorage<T, R, C, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.nrows == other.nrows
+            && self.ncols == other.ncols
+            && self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, R: Dim, C: Dim, const N: usize> Eq for InlineStorage<T, R, C, N> {}
+
+unsafe impl<T, R: Dim, C: Dim, const N: usize> RawStorage<T, R, C> for InlineStorage<T, R, C, N> {
+    type RStride = U1;
+    type CStride = R;
+
+    #[inline]
+    fn ptr(&self) -> *const T {
+        self.data.as_ptr() as *const T
+    }
+
+    #[inline]
+    fn shape(&self) -> (R, C) {
+        (self.nrows, self.ncols)
+    }
+
+    #[inline]
+    fn strides(&self) -> (Self::RStride, Self::CStride) {
+        (U1::from_usize(1), self.nrows)
+    }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    unsafe fn as_slice_unchecked(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+unsafe impl<T: Scalar, R: Dim, C: Dim, const N: usize> Storage<T, R, C>
+    for InlineStorage<T, R, C, N>
+{
+    #[inline]
+    fn into_owned(self) -> Owned<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        self.clone_owned()
+    }
+
+    #[inline]
+    fn clone_owned(&self) -> Owned<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        DefaultAllocator::allocate_from_iterator(
+            self.nrows,
+            self.ncols,
+            self.as_slice().iter().cloned(),
+        )
+    }
+}
+
+unsafe impl<T, R: Dim, C: Dim, const N: usize> RawStorageMut<T, R, C>
+    for InlineStorage<T, R, C, N>
+{
+    #[inline]
+    fn ptr_mut(&mut self) -> *mut T {
+        self.data.as_mut_ptr() as *mut T
+    }
+
+    #[inline]
+    unsafe fn as_mut_slice_unchecked(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+unsafe impl<T, R: Dim, C: Dim, const N: usize> IsContiguous for InlineStorage<T, R, C, N> {}
+
+/*
+ *
+ * In-place edition.
+ *
+ */
+#[inline]
+pub(crate) fn assert_capacity<const N: usize>(len: usize) {
+    assert!(
+        len <= N,
+        "Inline storage capacity exceeded: {} components requested, capacity is {}.",
+        len,
+        N
+    );
+}
+
+impl<T, C: Dim, const N: usize> InlineStorage<T, Dynamic, C, N> {
+    fn insert_rows(&mut self, i: usize, n: usize, val: T)
+    where
+        T: Clone,
+    {
+        let (nrows, ncols) = (self.nrows.value(), self.ncols.value());
+        assert!(i <= nrows, "Row insertion index out of range.");
+        assert_capacity::<N>((nrows + n) * ncols);
+
+        // Until the end of the operation, the storage is marked as empty so that a panicking
+        // `clone` leaks the components instead of dropping moved or uninitialized ones.
+        self.nrows = Dynamic::new(0);
+        let ptr = self.data.as_mut_ptr() as *mut T;
+
+        // SAFETY: the columns are moved from the last one to the first one, and the new column
+        //         positions are never before the old ones, so no component is overwritten
+        //         before being moved. The capacity check ensures every access is in-bounds.
+        unsafe {
+            for j in (0..ncols).rev() {
+                let (old, new) = (j * nrows, j * (nrows + n));
+                ptr::copy(ptr.add(old + i), ptr.add(new + i + n), nrows - i);
+                ptr::copy(ptr.add(old), ptr.add(new), i);
+            }
+
+            for j in 0..ncols {
+                let start = j * (nrows + n) + i;
+                for k in 0..n {
+                    ptr::write(ptr.add(start + k), val.clone());
+                }
+            }
+        }
+
+        self.nrows = Dynamic::new(nrows + n);
+    }
+
+    fn remove_rows(&mut self, i: usize, n: usize) {
+        let (nrows, ncols) = (self.nrows.value(), self.ncols.value());
+        assert!(i + n <= nrows, "Row removal indices out of range.");
+
+        self.nrows = Dynamic::new(0);
+        let ptr = self.data.as_mut_ptr() as *mut T;
+
+        // SAFETY: the removed components are dropped once, then the remaining ones are moved
+        //         from the first column to the last one, towards lower positions only.
+        unsafe {
+            for j in 0..ncols {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr.add(j * nrows + i), n));
+            }
+
+            for j in 0..ncols {
+                let (old, new) = (j * nrows, j * (nrows - n));
+                ptr::copy(ptr.add(old), ptr.add(new), i);
+                ptr::copy(ptr.add(old + i + n), ptr.add(new + i), nrows - i - n);
+            }
+        }
+
+        self.nrows = Dynamic::new(nrows - n);
+    }
+}
+
+impl<T, R: Dim, const N: usize> InlineStorage<T, R, Dynamic, N> {
+    fn insert_columns(&mut self, i: usize, n: usize, val: T)
+    where
+        T: Clone,
+    {
+        let (nrows, ncols) = (self.nrows.value(), self.ncols.value());
+        assert!(i <= ncols, "Column insertion index out of range.");
+        assert_capacity::<N>(nrows * (ncols + n));
+
+        self.ncols = Dynamic::new(0);
+        let ptr = self.data.as_mut_ptr() as *mut T;
+
+        // SAFETY: the trailing columns are moved before being overwritten by the new ones.
+        unsafe {
+            ptr::copy(
+                ptr.add(i * nrows),
+                ptr.add((i + n) * nrows),
+                (ncols - i) * nrows,
+            );
+
+            for k in i * nrows..(i + n) * nrows {
+                ptr::write(ptr.add(k), val.clone());
+            }
+        }
+
+        self.ncols = Dynamic::new(ncols + n);
+    }
+
+    fn remove_columns(&mut self, i: usize, n: usize) {
+        let (nrows, ncols) = (self.nrows.value(), self.ncols.value());
+        assert!(i + n <= ncols, "Column removal indices out of range.");
+
+        self.ncols = Dynamic::new(0);
+        let ptr = self.data.as_mut_ptr() as *mut T;
+
+        // SAFETY: the removed components are dropped once before being overwritten by the
+        //         trailing columns.
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr.add(i * nrows), n * nrows));
+            ptr::copy(
+                ptr.add((i + n) * nrows),
+                ptr.add(i * nrows),
+                (ncols - i - n) * nrows,
+            );
+        }
+
+        self.ncols = Dynamic::new(ncols - n);
+    }
+}
+
+/// # In-place edition of inline matrices
+///
+/// Those methods mirror `insert_rows`, `remove_rows`, `resize_vertically`, etc. but modify the
+/// matrix in-place, within the fixed capacity of its storage, instead of allocating a new one.
+impl<T: Scalar, C: Dim, const N: usize> Matrix<T, Dynamic, C, InlineStorage<T, Dynamic, C, N>> {
+    /// Inserts `n` rows filled with `val` starting at the `i-th` position.
+    ///
+    /// Panics if the resulting matrix does not fit in the storage capacity.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Dynamic, InlineDMatrix, InlineStorage, Matrix};
+    /// let mut m: InlineDMatrix<i32, 8> = Matrix::from_data(InlineStorage::from_row_slice(
+    ///     Dynamic::new(2),
+    ///     Dynamic::new(2),
+    ///     &[1, 2,
+    ///       3, 4],
+    /// ));
+    /// m.insert_rows_in_place(1, 2, 0);
+    /// assert_eq!(m.data.as_slice(), &[1, 0, 0, 3, 2, 0, 0, 4]);
+    /// ```
+    #[inline]
+    pub fn insert_rows_in_place(&mut self, i: usize, n: usize, val: T) {
+        self.data.insert_rows(i, n, val)
+    }
+
+    /// Inserts a row filled with `val` at the `i-th` position.
+    ///
+    /// Panics if the resulting matrix does not fit in the storage capacity.
+    #[inline]
+    pub fn insert_row_in_place(&mut self, i: usize, val: T) {
+        self.insert_rows_in_place(i, 1, val)
+    }
+
+    /// Removes `n` consecutive rows starting at the `i-th` position.
+    #[inline]
+    pub fn remove_rows_in_place(&mut self, i: usize, n: usize) {
+        self.data.remove_rows(i, n)
+    }
+
+    /// Removes the `i-th` row.
+    #[inline]
+    pub fn remove_row_in_place(&mut self, i: usize) {
+        self.remove_rows_in_place(i, 1)
+    }
+
+    /// Resizes this matrix vertically so that it contains `new_nrows` rows.
+    ///
+    /// The values are copied such that `self[(i, j)] == result[(i, j)]`. If the result has more
+    /// rows than `self`, then the extra rows are filled with `val`.
+    ///
+    /// Panics if the resulting matrix does not fit in the storage capacity.
+    #[inline]
+    pub fn resize_vertically_in_place(&mut self, new_nrows: usize, val: T) {
+        let nrows = self.nrows();
+
+        if new_nrows < nrows {
+            self.remove_rows_in_place(new_nrows, nrows - new_nrows);
+        } else {
+            self.insert_rows_in_place(nrows, new_nrows - nrows, val);
+        }
+    }
+}
+
+impl<T: Scalar, R: Dim, const N: usize> Matrix<T, R, Dynamic, InlineStorage<T, R, Dynamic, N>> {
+    /// Inserts `n` columns filled with `val` starting at the `i-th` position.
+    ///
+    /// Panics if the resulting matrix does not fit in the storage capacity.
+    #[inline]
+    pub fn insert_columns_in_place(&mut self, i: usize, n: usize, val: T) {
+        self.data.insert_columns(i, n, val)
+    }
+
+    /// Inserts a column filled with `val` at the `i-th` position.
+    ///
+    /// Panics if the resulting matrix does not fit in the storage capacity.
+    #[inline]
+    pub fn insert_column_in_place(&mut self, i: usize, val: T) {
+        self.insert_columns_in_place(i, 1, val)
+    }
+
+    /// Removes `n` consecutive columns starting at the `i-th` position.
+    #[inline]
+    pub fn remove_columns_in_place(&mut self, i: usize, n: usize) {
+        self.data.remove_columns(i, n)
+    }
+
+    /// Removes the `i-th` column.
+    #[inline]
+    pub fn remove_column_in_place(&mut self, i: usize) {
+        self.remove_columns_in_place(i, 1)
+    }
+
+    /// Resizes this matrix horizontally so that it contains `new_ncols` columns.
+    ///
+    /// The values are copied such that `self[(i, j)] == result[(i, j)]`. If the result has more
+    /// columns than `self`, then the extra columns are filled with `val`.
+    ///
+    /// Panics if the resulting matrix does not fit in the storage capacity.
+    #[inline]
+    pub fn resize_horizontally_in_place(&mut self, new_ncols: usize, val: T) {
+        let ncols = self.ncols();
+
+        if new_ncols < ncols {
+            self.remove_columns_in_place(new_ncols, ncols - new_ncols);
+        } else {
+            self.insert_columns_in_place(ncols, new_ncols - ncols, val);
+        }
+    }
+}
+
+impl<T: Scalar, const N: usize> Matrix<T, Dynamic, Dynamic, InlineStorage<T, Dynamic, Dynamic, N>> {
+    /// Resizes this matrix so that it contains `new_nrows` rows and `new_ncols` columns.
+    ///
+    /// The values are copied such that `self[(i, j)] == result[(i, j)]`. If the result has more
+    /// rows and/or columns than `self`, then the extra rows or columns are filled with `val`.
+    ///
+    /// Panics if the resulting matrix does not fit in the storage capacity.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Dynamic, InlineDMatrix, InlineStorage, Matrix};
+    /// let mut m: InlineDMatrix<f32, 6> = Matrix::from_data(InlineStorage::from_element(
+    ///     Dynamic::new(1),
+    ///     Dynamic::new(6),
+    ///     1.0,
+    /// ));
+    /// m.resize_in_place(3, 2, 0.0);
+    /// assert_eq!(m.shape(), (3, 2));
+    /// assert_eq!(m.data.as_slice(), &[1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+    /// ```
+    #[inline]
+    pub fn resize_in_place(&mut self, new_nrows: usize, new_ncols: usize, val: T) {
+        // Shrink first so that the intermediate shapes never exceed the capacity.
+        if new_nrows < self.nrows() {
+            self.resize_vertically_in_place(new_nrows, val.clone());
+        }
+        if new_ncols < self.ncols() {
+            self.resize_horizontally_in_place(new_ncols, val.clone());
+        }
+
+        self.resize_vertically_in_place(new_nrows, val.clone());
+        self.resize_horizontally_in_place(new_ncols, val);
+    }
+}
@@ -3,6 +3,7 @@
 use std::iter::FusedIterator;
 use std::marker::PhantomData;
 use std::mem;
+use std::ops::Range;
 
 use crate::base::dimension::{Dim, U1};
 use crate::base::storage::{RawStorage, RawStorageMut};
@@ -13,9 +14,11 @@ macro_rules! iterator {
         /// An iterator through a dense matrix with arbitrary strides matrix.
         #[derive(Debug)]
         pub struct $Name<'a, T, R: Dim, C: Dim, S: 'a + $Storage<T, R, C>> {
+            // Pointer to the start of the current outer dimension.
             ptr: $Ptr,
-            inner_ptr: $Ptr,
-            inner_end: $Ptr,
+            // Index of the next element along the current outer dimension.
+            inner_pos: usize,
+            inner_size: usize,
             size: usize, // We can't use an end pointer here because a stride might be zero.
             strides: (S::RStride, S::CStride),
             _phantoms: PhantomData<($Ref, R, C, S)>,
@@ -28,31 +31,11 @@ macro_rules! iterator {
             pub fn new(storage: $SRef) -> $Name<'a, T, R, C, S> {
                 let shape = storage.shape();
                 let strides = storage.strides();
-                let inner_offset = shape.0.value() * strides.0.value();
-                let size = shape.0.value() * shape.1.value();
-                let ptr = storage.$ptr();
-
-                // If we have a size of 0, 'ptr' must be
-                // dangling. Howver, 'inner_offset' might
-                // not be zero if only one dimension is zero, so
-                // we don't want to call 'offset'.
-                // This pointer will never actually get used
-                // if our size is '0', so it's fine to use
-                // 'ptr' for both the start and end.
-                let inner_end = if size == 0 {
-                    ptr
-                } else {
-                    // Safety:
-                    // If 'size' is non-zero, we know that 'ptr'
-                    // is not dangling, and 'inner_offset' must lie
-                    // within the allocation
-                    unsafe { ptr.add(inner_offset) }
-                };
 
                 $Name {
-                    ptr,
-                    inner_ptr: ptr,
-                    inner_end,
+                    ptr: storage.$ptr(),
+                    inner_pos: 0,
+                    inner_size: shape.0.value(),
                     size: shape.0.value() * shape.1.value(),
                     strides,
                     _phantoms: PhantomData,
@@ -72,34 +55,22 @@ macro_rules! iterator {
                         self.size -= 1;
 
                         // Jump to the next outer dimension if needed.
-                        if self.ptr == self.inner_end {
-                            let stride = self.strides.1.value() as isize;
-                            // This might go past the end of the allocation,
-                            // depending on the value of 'size'. We use
-                            // `wrapping_offset` to avoid UB
-                            self.inner_end = self.ptr.wrapping_offset(stride);
-                            // This will always be in bounds, since
-                            // we're going to dereference it
-                            self.ptr = self.inner_ptr.offset(stride);
-                            self.inner_ptr = self.ptr;
+                        // Safety: there is at least one element left, so the start of the next
+                        // outer dimension is within the allocation. We never compute pointers
+                        // past the last element because they might be out of bounds, e.g., for
+                        // row-major storages where the inner stride is not 1.
+                        if self.inner_pos == self.inner_size {
+                            self.ptr = self.ptr.add(self.strides.1.value());
+                            self.inner_pos = 0;
                         }
 
-                        // Go to the next element.
-                        let old = self.ptr;
+                        let curr = self.ptr.add(self.inner_pos * self.strides.0.value());
+                        self.inner_pos += 1;
 
-                        // Don't offset `self.ptr` for the last element,
-                        // as this will be out of bounds. Iteration is done
-                        // at this point (the next call to `next` will return `None`)
-                        // so this is not observable.
-                        if self.size != 0 {
-                            let stride = self.strides.0.value();
-                            self.ptr = self.ptr.add(stride);
-                        }
-
-                        // We want either `& *last` or `&mut *last` here, depending
+                        // We want either `& *curr` or `&mut *curr` here, depending
                         // on the mutability of `$Ref`.
                         #[allow(clippy::transmute_ptr_to_ref)]
-                        Some(mem::transmute(old))
+                        Some(mem::transmute(curr))
                     }
                 }
             }
@@ -132,19 +103,16 @@ macro_rules! iterator {
                         let inner_stride = self.strides.0.value();
                         let outer_stride = self.strides.1.value();
 
-                        // Compute number of rows
-                        // Division should be exact
-                        let inner_raw_size = self.inner_end.offset_from(self.inner_ptr) as usize;
-                        let inner_size = inner_raw_size / inner_stride;
-
-                        // Compute rows and cols remaining
-                        let outer_remaining = self.size / inner_size;
-                        let inner_remaining = self.size % inner_size;
+                        // Compute the position of the last element relative to the start of the
+                        // current outer dimension.
+                        let pos = self.inner_pos + self.size;
+                        let outer_remaining = pos / self.inner_size;
+                        let inner_remaining = pos % self.inner_size;
 
                         // Compute pointer to last element
                         let last = self
                             .ptr
-                            .add((outer_remaining * outer_stride + inner_remaining * inner_stride));
+                            .add(outer_remaining * outer_stride + inner_remaining * inner_stride);
 
                         // We want either `& *last` or `&mut *last` here, depending
                         // on the mutability of `$Ref`.
@@ -296,12 +264,30 @@ impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> ExactSizeIte
 /// An iterator through the columns of a matrix.
 pub struct ColumnIter<'a, T, R: Dim, C: Dim, S: RawStorage<T, R, C>> {
     mat: &'a Matrix<T, R, C, S>,
-    curr: usize,
+    range: Range<usize>,
 }
 
 impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> ColumnIter<'a, T, R, C, S> {
     pub(crate) fn new(mat: &'a Matrix<T, R, C, S>) -> Self {
-        ColumnIter { mat, curr: 0 }
+        let range = 0..mat.ncols();
+        ColumnIter { mat, range }
+    }
+
+    /// Splits this iterator into two iterators, the first one yielding the `index` next columns
+    /// and the second one yielding the remaining columns.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        // The clamping makes sure both ranges stay within the range of this iterator.
+        let split_pos = (self.range.start + index).min(self.range.end);
+        let left = ColumnIter {
+            mat: self.mat,
+            range: self.range.start..split_pos,
+        };
+        let right = ColumnIter {
+            mat: self.mat,
+            range: split_pos..self.range.end,
+        };
+        (left, right)
     }
 }
 
@@ -310,60 +296,91 @@ impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> Iterator for ColumnIter
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.curr < self.mat.ncols() {
-            let res = self.mat.column(self.curr);
-            self.curr += 1;
-            Some(res)
-        } else {
-            None
-        }
+        let j = self.range.next()?;
+        Some(self.mat.column(j))
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (
-            self.mat.ncols() - self.curr,
-            Some(self.mat.ncols() - self.curr),
-        )
+        self.range.size_hint()
     }
 
     #[inline]
     fn count(self) -> usize {
-        self.mat.ncols() - self.curr
+        self.range.len()
     }
 }
 
-impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> ExactSizeIterator
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> DoubleEndedIterator
+    for ColumnIter<'a, T, R, C, S>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let j = self.range.next_back()?;
+        Some(self.mat.column(j))
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> ExactSizeIterator
     for ColumnIter<'a, T, R, C, S>
 {
     #[inline]
     fn len(&self) -> usize {
-        self.mat.ncols() - self.curr
+        self.range.len()
     }
 }
 
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorage<T, R, C>> FusedIterator
+    for ColumnIter<'a, T, R, C, S>
+{
+}
+
 /// An iterator through the mutable columns of a matrix.
 #[derive(Debug)]
 pub struct ColumnIterMut<'a, T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> {
     mat: *mut Matrix<T, R, C, S>,
-    curr: usize,
+    range: Range<usize>,
     phantom: PhantomData<&'a mut Matrix<T, R, C, S>>,
 }
 
 impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> ColumnIterMut<'a, T, R, C, S> {
     pub(crate) fn new(mat: &'a mut Matrix<T, R, C, S>) -> Self {
+        let range = 0..mat.ncols();
         ColumnIterMut {
             mat,
-            curr: 0,
+            range,
             phantom: PhantomData,
         }
     }
 
-    fn ncols(&self) -> usize {
-        unsafe { (*self.mat).ncols() }
+    /// Splits this iterator into two iterators, the first one yielding the `index` next columns
+    /// and the second one yielding the remaining columns.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn split_at(self, index: usize) -> (Self, Self) {
+        // The clamping makes sure both ranges are disjoint and stay within the range of this
+        // iterator, so that no column can be borrowed mutably twice.
+        let split_pos = (self.range.start + index).min(self.range.end);
+        let left = ColumnIterMut {
+            mat: self.mat,
+            range: self.range.start..split_pos,
+            phantom: PhantomData,
+        };
+        let right = ColumnIterMut {
+            mat: self.mat,
+            range: split_pos..self.range.end,
+            phantom: PhantomData,
+        };
+        (left, right)
     }
 }
 
+// SAFETY: the iterator only hands out columns within its own range, and the ranges of split
+// iterators are disjoint, so it behaves like a `&'a mut Matrix<T, R, C, S>`.
+unsafe impl<'a, T: Send, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C> + Send> Send
+    for ColumnIterMut<'a, T, R, C, S>
+{
+}
+
 impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> Iterator
     for ColumnIterMut<'a, T, R, C, S>
 {
@@ -371,31 +388,43 @@ impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> Iterator
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.curr < self.ncols() {
-            let res = unsafe { (*self.mat).column_mut(self.curr) };
-            self.curr += 1;
-            Some(res)
-        } else {
-            None
-        }
+        let j = self.range.next()?;
+        // SAFETY: each column index is yielded at most once.
+        Some(unsafe { (*self.mat).column_mut(j) })
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.ncols() - self.curr, Some(self.ncols() - self.curr))
+        self.range.size_hint()
     }
 
     #[inline]
     fn count(self) -> usize {
-        self.ncols() - self.curr
+        self.range.len()
     }
 }
 
-impl<'a, T: Scalar, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> ExactSizeIterator
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> DoubleEndedIterator
+    for ColumnIterMut<'a, T, R, C, S>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let j = self.range.next_back()?;
+        // SAFETY: each column index is yielded at most once.
+        Some(unsafe { (*self.mat).column_mut(j) })
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> ExactSizeIterator
     for ColumnIterMut<'a, T, R, C, S>
 {
     #[inline]
     fn len(&self) -> usize {
-        self.ncols() - self.curr
+        self.range.len()
     }
 }
+
+impl<'a, T, R: Dim, C: Dim, S: 'a + RawStorageMut<T, R, C>> FusedIterator
+    for ColumnIterMut<'a, T, R, C, S>
+{
+}
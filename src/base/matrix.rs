@@ -594,6 +594,51 @@ impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
         Matrix::from_data(self.data.clone_owned())
     }
 
+    /// Moves this matrix into one that owns its data, allocated by `A` instead of the
+    /// `DefaultAllocator`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, RowMajorAllocator};
+    /// let m = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    /// let r = m.into_owned_in::<RowMajorAllocator>();
+    /// assert_eq!(r.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[inline]
+    pub fn into_owned_in<A>(self) -> Matrix<T, R, C, A::Buffer>
+    where
+        T: Scalar,
+        A: Allocator<T, R, C>,
+    {
+        self.clone_owned_in::<A>()
+    }
+
+    /// Clones this matrix to one that owns its data, allocated by `A` instead of the
+    /// `DefaultAllocator`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, RowMajorAllocator};
+    /// let m = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    /// let r = m.clone_owned_in::<RowMajorAllocator>();
+    /// assert_eq!(r, m);
+    /// assert_eq!(r.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn clone_owned_in<A>(&self) -> Matrix<T, R, C, A::Buffer>
+    where
+        T: Scalar,
+        A: Allocator<T, R, C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        Matrix::from_data(A::allocate_from_iterator(
+            nrows,
+            ncols,
+            self.iter().cloned(),
+        ))
+    }
+
     /// Clones this matrix into one that owns its data. The actual type of the result depends on
     /// matrix storage combination rules for addition.
     #[inline]
@@ -689,6 +734,31 @@ impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
         // Safety: res is now fully initialized.
         unsafe { res.assume_init() }
     }
+
+    /// Transposes `self` into a matrix allocated by `A` instead of the `DefaultAllocator`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Matrix3x2, RowMajorAllocator};
+    /// let m = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    /// let t = m.transpose_in::<RowMajorAllocator>();
+    /// assert_eq!(t, Matrix3x2::new(1, 4, 2, 5, 3, 6));
+    /// assert_eq!(t.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    /// ```
+    #[inline]
+    #[must_use = "Did you mean to use transpose_mut()?"]
+    pub fn transpose_in<A>(&self) -> Matrix<T, C, R, A::Buffer>
+    where
+        T: Scalar,
+        A: Allocator<T, C, R>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+
+        let mut res = Matrix::from_data(A::allocate_uninit(ncols, nrows));
+        self.transpose_to_uninit(Uninit, &mut res);
+        // Safety: res is now fully initialized.
+        Matrix::from_data(unsafe { A::assume_init(res.data) })
+    }
 }
 
 /// # Elementwise mapping and folding
@@ -718,6 +788,32 @@ impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
         unsafe { res.assume_init() }
     }
 
+    /// Returns a matrix allocated by `A` containing the result of `f` applied to each of its
+    /// entries.
+    #[inline]
+    #[must_use]
+    pub fn map_in<A, T2: Scalar, F: FnMut(T) -> T2>(&self, mut f: F) -> Matrix<T2, R, C, A::Buffer>
+    where
+        T: Scalar,
+        A: Allocator<T2, R, C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let mut res = Matrix::from_data(A::allocate_uninit(nrows, ncols));
+
+        for j in 0..ncols.value() {
+            for i in 0..nrows.value() {
+                // Safety: all indices are in range.
+                unsafe {
+                    let a = self.data.get_unchecked(i, j).clone();
+                    *res.data.get_unchecked_mut(i, j) = MaybeUninit::new(f(a));
+                }
+            }
+        }
+
+        // Safety: res is now fully initialized.
+        Matrix::from_data(unsafe { A::assume_init(res.data) })
+    }
+
     /// Cast the components of `self` to another type.
     ///
     /// # Example
@@ -1286,7 +1382,9 @@ impl<T, D: Dim, S: RawStorageMut<T, D>> Vector<T, D, S> {
 }
 
 impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C> + IsContiguous> Matrix<T, R, C, S> {
-    /// Extracts a slice containing the entire matrix entries ordered column-by-columns.
+    /// Extracts a slice containing the entire matrix entries, in the order they are stored.
+    ///
+    /// This is column-by-column for the default storages, and row-by-row for row-major ones.
     #[inline]
     #[must_use]
     pub fn as_slice(&self) -> &[T] {
@@ -1296,7 +1394,9 @@ impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C> + IsContiguous> Matrix<T, R, C, S
 }
 
 impl<T, R: Dim, C: Dim, S: RawStorageMut<T, R, C> + IsContiguous> Matrix<T, R, C, S> {
-    /// Extracts a mutable slice containing the entire matrix entries ordered column-by-columns.
+    /// Extracts a mutable slice containing the entire matrix entries, in the order they are stored.
+    ///
+    /// This is column-by-column for the default storages, and row-by-row for row-major ones.
     #[inline]
     #[must_use]
     pub fn as_mut_slice(&mut self) -> &mut [T] {
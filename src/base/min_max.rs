@@ -1,5 +1,9 @@
+use crate::allocator::Allocator;
 use crate::storage::RawStorage;
-use crate::{ComplexField, Dim, Matrix, Scalar, SimdComplexField, SimdPartialOrd, Vector};
+use crate::{
+    ComplexField, Const, DefaultAllocator, Dim, Matrix, OVector, RowOVector, Scalar,
+    SimdComplexField, SimdPartialOrd, Vector, VectorSlice, U1,
+};
 use num::{Signed, Zero};
 use simba::simd::SimdSigned;
 
@@ -403,3 +407,264 @@ impl<T: Scalar, D: Dim, S: RawStorage<T, D>> Vector<T, D, S> {
         the_i
     }
 }
+
+/// # Find the min and max components along rows and columns
+impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Returns a row vector containing the largest component of each column of this matrix.
+    ///
+    /// Empty columns yield zero, as with `.max()`.
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, RowVector3};
+    /// let m = Matrix2x3::new(1, 5, 3,
+    ///                        4, 2, 6);
+    /// assert_eq!(m.row_max(), RowVector3::new(4, 5, 6));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_max(&self) -> RowOVector<T, C>
+    where
+        T: SimdPartialOrd + Zero,
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        self.compress_rows(|col| col.max())
+    }
+
+    /// Returns a row vector containing the smallest component of each column of this matrix.
+    ///
+    /// Empty columns yield zero, as with `.min()`.
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, RowVector3};
+    /// let m = Matrix2x3::new(1, 5, 3,
+    ///                        4, 2, 6);
+    /// assert_eq!(m.row_min(), RowVector3::new(1, 2, 3));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_min(&self) -> RowOVector<T, C>
+    where
+        T: SimdPartialOrd + Zero,
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        self.compress_rows(|col| col.min())
+    }
+
+    /// Returns a column vector containing the largest component of each row of this matrix.
+    ///
+    /// Empty rows yield zero, as with `.max()`.
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    /// let m = Matrix2x3::new(1, 5, 3,
+    ///                        4, 2, 6);
+    /// assert_eq!(m.column_max(), Vector2::new(5, 6));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_max(&self) -> OVector<T, R>
+    where
+        T: SimdPartialOrd + Zero,
+        DefaultAllocator: Allocator<T, R>,
+    {
+        self.column_extremum(|a, b| a.simd_max(b))
+    }
+
+    /// Returns a column vector containing the smallest component of each row of this matrix.
+    ///
+    /// Empty rows yield zero, as with `.min()`.
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    /// let m = Matrix2x3::new(1, 5, 3,
+    ///                        4, 2, 6);
+    /// assert_eq!(m.column_min(), Vector2::new(1, 2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_min(&self) -> OVector<T, R>
+    where
+        T: SimdPartialOrd + Zero,
+        DefaultAllocator: Allocator<T, R>,
+    {
+        self.column_extremum(|a, b| a.simd_min(b))
+    }
+
+    /// Combines all the columns of this matrix component-wise with `f`, or returns zero if this
+    /// matrix has no columns.
+    fn column_extremum(&self, f: impl Fn(T, T) -> T) -> OVector<T, R>
+    where
+        T: Zero,
+        DefaultAllocator: Allocator<T, R>,
+    {
+        if self.ncols() == 0 {
+            return OVector::zeros_generic(self.shape_generic().0, Const::<1>);
+        }
+
+        self.compress_columns(self.column(0).clone_owned(), |out, col| {
+            out.zip_apply(&col, |a, b| *a = f(a.clone(), b))
+        })
+    }
+
+    /// Computes the index and value of the largest component of each column of this matrix.
+    ///
+    /// The first returned row vector contains the row index of each maximum and the second one
+    /// contains their values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this matrix has columns but no rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, RowVector3};
+    /// let m = Matrix2x3::new(1, 5, 3,
+    ///                        4, 2, 6);
+    /// let (indices, values) = m.row_argmax();
+    /// assert_eq!(indices, RowVector3::new(1, 0, 1));
+    /// assert_eq!(values, RowVector3::new(4, 5, 6));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_argmax(&self) -> (RowOVector<usize, C>, RowOVector<T, C>)
+    where
+        T: PartialOrd,
+        DefaultAllocator: Allocator<usize, U1, C> + Allocator<T, U1, C>,
+    {
+        self.row_argext(|col| col.argmax())
+    }
+
+    /// Computes the index and value of the smallest component of each column of this matrix.
+    ///
+    /// The first returned row vector contains the row index of each minimum and the second one
+    /// contains their values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this matrix has columns but no rows.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, RowVector3};
+    /// let m = Matrix2x3::new(1, 5, 3,
+    ///                        4, 2, 6);
+    /// let (indices, values) = m.row_argmin();
+    /// assert_eq!(indices, RowVector3::new(0, 1, 0));
+    /// assert_eq!(values, RowVector3::new(1, 2, 3));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_argmin(&self) -> (RowOVector<usize, C>, RowOVector<T, C>)
+    where
+        T: PartialOrd,
+        DefaultAllocator: Allocator<usize, U1, C> + Allocator<T, U1, C>,
+    {
+        self.row_argext(|col| col.argmin())
+    }
+
+    fn row_argext(
+        &self,
+        argext: impl Fn(VectorSlice<'_, T, R, S::RStride, S::CStride>) -> (usize, T),
+    ) -> (RowOVector<usize, C>, RowOVector<T, C>)
+    where
+        DefaultAllocator: Allocator<usize, U1, C> + Allocator<T, U1, C>,
+    {
+        let ncols = self.shape_generic().1;
+        let indices =
+            RowOVector::from_fn_generic(Const::<1>, ncols, |_, j| argext(self.column(j)).0);
+        let values =
+            RowOVector::from_fn_generic(Const::<1>, ncols, |_, j| self[(indices[j], j)].clone());
+
+        (indices, values)
+    }
+
+    /// Computes the index and value of the largest component of each row of this matrix.
+    ///
+    /// The first returned vector contains the column index of each maximum and the second one
+    /// contains their values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this matrix has rows but no columns.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    /// let m = Matrix2x3::new(1, 5, 3,
+    ///                        4, 2, 6);
+    /// let (indices, values) = m.column_argmax();
+    /// assert_eq!(indices, Vector2::new(1, 2));
+    /// assert_eq!(values, Vector2::new(5, 6));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_argmax(&self) -> (OVector<usize, R>, OVector<T, R>)
+    where
+        T: PartialOrd,
+        DefaultAllocator: Allocator<usize, R> + Allocator<T, R>,
+    {
+        self.column_argext(|val, best| val > best)
+    }
+
+    /// Computes the index and value of the smallest component of each row of this matrix.
+    ///
+    /// The first returned vector contains the column index of each minimum and the second one
+    /// contains their values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this matrix has rows but no columns.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    /// let m = Matrix2x3::new(1, 5, 3,
+    ///                        4, 2, 6);
+    /// let (indices, values) = m.column_argmin();
+    /// assert_eq!(indices, Vector2::new(0, 1));
+    /// assert_eq!(values, Vector2::new(1, 2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_argmin(&self) -> (OVector<usize, R>, OVector<T, R>)
+    where
+        T: PartialOrd,
+        DefaultAllocator: Allocator<usize, R> + Allocator<T, R>,
+    {
+        self.column_argext(|val, best| val < best)
+    }
+
+    fn column_argext(
+        &self,
+        is_better: impl Fn(&T, &T) -> bool,
+    ) -> (OVector<usize, R>, OVector<T, R>)
+    where
+        DefaultAllocator: Allocator<usize, R> + Allocator<T, R>,
+    {
+        let nrows = self.shape_generic().0;
+        let mut indices: OVector<usize, R> = OVector::zeros_generic(nrows, Const::<1>);
+
+        assert!(
+            nrows.value() == 0 || self.ncols() != 0,
+            "The input matrix must not have empty rows."
+        );
+
+        for j in 1..self.ncols() {
+            for i in 0..nrows.value() {
+                // Safety: all indices are in range.
+                unsafe {
+                    let best = indices.vget_unchecked_mut(i);
+
+                    if is_better(self.get_unchecked((i, j)), self.get_unchecked((i, *best))) {
+                        *best = j;
+                    }
+                }
+            }
+        }
+
+        let values =
+            OVector::from_fn_generic(nrows, Const::<1>, |i, _| self[(i, indices[i])].clone());
+
+        (indices, values)
+    }
+}
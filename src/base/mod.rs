@@ -6,29 +6,48 @@ pub mod constraint;
 pub mod coordinates;
 pub mod default_allocator;
 pub mod dimension;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod display;
+pub mod inline_allocator;
 pub mod iter;
 mod ops;
+#[cfg(feature = "rayon")]
+pub mod par_iter;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod parse;
+pub mod row_major_allocator;
 pub mod storage;
 
 mod alias;
 mod alias_slice;
 mod array_storage;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod band_matrix;
 mod cg;
 mod componentwise;
 #[macro_use]
 mod construction;
 mod construction_slice;
 mod conversion;
+mod diagonal_matrix;
 mod edition;
 pub mod indexing;
+mod inline_storage;
 mod matrix;
 mod matrix_simba;
 mod matrix_slice;
 mod norm;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod packed;
+mod permutation_matrix;
 mod properties;
+mod row_major_storage;
 mod scalar;
+mod shape_mismatch;
+mod stack;
 mod statistics;
 mod swizzle;
+mod triangular;
 mod unit;
 #[cfg(any(feature = "std", feature = "alloc"))]
 mod vec_storage;
@@ -41,18 +60,33 @@ mod min_max;
 /// Mechanisms for working with values that may not be initialized.
 pub mod uninit;
 
+pub use self::blas::{Diag, Side, Transposition, Uplo};
 pub use self::matrix::*;
 pub use self::norm::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::packed::*;
 pub use self::scalar::*;
+pub use self::shape_mismatch::*;
+pub use self::stack::*;
+pub use self::statistics::{Normalization, Welford};
+pub use self::triangular::*;
 pub use self::unit::*;
 
 pub use self::default_allocator::*;
 pub use self::dimension::*;
+pub use self::inline_allocator::*;
+pub use self::row_major_allocator::*;
 
 pub use self::alias::*;
 pub use self::alias_slice::*;
 pub use self::array_storage::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::band_matrix::*;
+pub use self::diagonal_matrix::*;
+pub use self::inline_storage::*;
 pub use self::matrix_slice::*;
+pub use self::permutation_matrix::*;
+pub use self::row_major_storage::*;
 pub use self::storage::*;
 #[cfg(any(feature = "std", feature = "alloc"))]
 pub use self::vec_storage::*;
@@ -14,7 +14,9 @@ use crate::base::constraint::{
 use crate::base::dimension::{Dim, DimMul, DimName, DimProd, Dynamic};
 use crate::base::storage::{Storage, StorageMut};
 use crate::base::uninit::Uninit;
-use crate::base::{DefaultAllocator, Matrix, MatrixSum, OMatrix, Scalar, VectorSlice};
+use crate::base::{
+    DefaultAllocator, Matrix, MatrixSum, OMatrix, Scalar, ShapeMismatch, VectorSlice,
+};
 use crate::storage::IsContiguous;
 use crate::uninit::{Init, InitStatus};
 use crate::{RawStorage, RawStorageMut, SimdComplexField};
@@ -127,11 +129,27 @@ where
  *
  */
 
+/// Whether two matrices with the same shape and contiguous storages store their components in
+/// the same order, e.g., because they are both column-major.
+#[inline]
+fn same_linear_order<T1, T2, R1: Dim, C1: Dim, R2: Dim, C2: Dim, S1, S2>(
+    a: &Matrix<T1, R1, C1, S1>,
+    b: &Matrix<T2, R2, C2, S2>,
+) -> bool
+where
+    S1: RawStorage<T1, R1, C1>,
+    S2: RawStorage<T2, R2, C2>,
+{
+    let (nrows, ncols) = a.shape();
+    nrows <= 1 || ncols <= 1 || a.strides() == b.strides()
+}
+
 macro_rules! componentwise_binop_impl(
     ($Trait: ident, $method: ident, $bound: ident;
      $TraitAssign: ident, $method_assign: ident, $method_assign_statically_unchecked: ident,
      $method_assign_statically_unchecked_rhs: ident;
-     $method_to: ident, $method_to_statically_unchecked_uninit: ident) => {
+     $method_to: ident, $method_to_statically_unchecked_uninit: ident;
+     $method_in: ident, $doc_in: expr) => {
 
         impl<T, R1: Dim, C1: Dim, SA: Storage<T, R1, C1>> Matrix<T, R1, C1, SA>
             where T: Scalar + $bound {
@@ -158,7 +176,8 @@ macro_rules! componentwise_binop_impl(
                 // This is the most common case and should be deduced at compile-time.
                 // TODO: use specialization instead?
                 unsafe {
-                    if self.data.is_contiguous() && rhs.data.is_contiguous() && out.data.is_contiguous() {
+                    if self.data.is_contiguous() && rhs.data.is_contiguous() && out.data.is_contiguous()
+                        && same_linear_order(self, rhs) && same_linear_order(self, out) {
                         let arr1 = self.data.as_slice_unchecked();
                         let arr2 = rhs.data.as_slice_unchecked();
                         let out  = out.data.as_mut_slice_unchecked();
@@ -188,7 +207,7 @@ macro_rules! componentwise_binop_impl(
                 // This is the most common case and should be deduced at compile-time.
                 // TODO: use specialization instead?
                 unsafe {
-                    if self.data.is_contiguous() && rhs.data.is_contiguous() {
+                    if self.data.is_contiguous() && rhs.data.is_contiguous() && same_linear_order(self, rhs) {
                         let arr1 = self.data.as_mut_slice_unchecked();
                         let arr2 = rhs.data.as_slice_unchecked();
 
@@ -216,7 +235,7 @@ macro_rules! componentwise_binop_impl(
                 // This is the most common case and should be deduced at compile-time.
                 // TODO: use specialization instead?
                 unsafe {
-                    if self.data.is_contiguous() && rhs.data.is_contiguous() {
+                    if self.data.is_contiguous() && rhs.data.is_contiguous() && same_linear_order(self, rhs) {
                         let arr1 = self.data.as_slice_unchecked();
                         let arr2 = rhs.data.as_mut_slice_unchecked();
 
@@ -255,6 +274,21 @@ macro_rules! componentwise_binop_impl(
                                        SameNumberOfRows<R1, R3> + SameNumberOfColumns<C1, C3> {
                 self.$method_to_statically_unchecked_uninit(Init, rhs, out)
             }
+
+            #[doc = $doc_in]
+            #[inline]
+            #[must_use]
+            pub fn $method_in<A, R2: Dim, C2: Dim, SB>(&self, rhs: &Matrix<T, R2, C2, SB>)
+                -> Matrix<T, R1, C1, A::Buffer>
+                where A: Allocator<T, R1, C1>,
+                      SB: Storage<T, R2, C2>,
+                      ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2> {
+                let (nrows, ncols) = self.shape_generic();
+                let mut res = Matrix::from_data(A::allocate_uninit(nrows, ncols));
+                self.$method_to_statically_unchecked_uninit(Uninit, rhs, &mut res);
+                // SAFETY: the output has been initialized above.
+                Matrix::from_data(unsafe { A::assume_init(res.data) })
+            }
         }
 
         impl<'b, T, R1, C1, R2, C2, SA, SB> $Trait<&'b Matrix<T, R2, C2, SB>> for Matrix<T, R1, C1, SA>
@@ -359,10 +393,12 @@ macro_rules! componentwise_binop_impl(
 
 componentwise_binop_impl!(Add, add, ClosedAdd;
                           AddAssign, add_assign, add_assign_statically_unchecked, add_assign_statically_unchecked_mut;
-                          add_to, add_to_statically_unchecked_uninit);
+                          add_to, add_to_statically_unchecked_uninit;
+                          add_in, "Equivalent to `self + rhs` but allocates the result with `A` instead of the `DefaultAllocator`.");
 componentwise_binop_impl!(Sub, sub, ClosedSub;
                           SubAssign, sub_assign, sub_assign_statically_unchecked, sub_assign_statically_unchecked_mut;
-                          sub_to, sub_to_statically_unchecked_uninit);
+                          sub_to, sub_to_statically_unchecked_uninit;
+                          sub_in, "Equivalent to `self - rhs` but allocates the result with `A` instead of the `DefaultAllocator`.");
 
 impl<T, R: DimName, C: DimName> iter::Sum for OMatrix<T, R, C>
 where
@@ -659,6 +695,117 @@ where
     }
 }
 
+/// # Fallible arithmetic
+impl<T: Scalar, R1: Dim, C1: Dim, SA: Storage<T, R1, C1>> Matrix<T, R1, C1, SA> {
+    /// Computes `self + rhs`, or returns an error if the two matrices do not have the same
+    /// shape.
+    ///
+    /// Unlike the `+` operator, this does not panic if the shapes of dynamically-sized matrices
+    /// mismatch.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, ShapeMismatch};
+    /// let a = DMatrix::from_element(2, 3, 1.0);
+    /// let b = DMatrix::from_element(2, 3, 2.0);
+    /// assert_eq!(a.try_add(&b), Ok(DMatrix::from_element(2, 3, 3.0)));
+    ///
+    /// let c = DMatrix::from_element(3, 2, 2.0);
+    /// assert_eq!(a.try_add(&c), Err(ShapeMismatch::new((2, 3), (3, 2))));
+    /// ```
+    #[inline]
+    pub fn try_add<R2, C2, SB>(
+        &self,
+        rhs: &Matrix<T, R2, C2, SB>,
+    ) -> Result<MatrixSum<T, R1, C1, R2, C2>, ShapeMismatch>
+    where
+        T: ClosedAdd,
+        R2: Dim,
+        C2: Dim,
+        SB: Storage<T, R2, C2>,
+        DefaultAllocator: SameShapeAllocator<T, R1, C1, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        if self.shape() != rhs.shape() {
+            return Err(ShapeMismatch::new(self.shape(), rhs.shape()));
+        }
+
+        Ok(self + rhs)
+    }
+
+    /// Computes `self - rhs`, or returns an error if the two matrices do not have the same
+    /// shape.
+    ///
+    /// Unlike the `-` operator, this does not panic if the shapes of dynamically-sized matrices
+    /// mismatch.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, ShapeMismatch};
+    /// let a = DMatrix::from_element(2, 3, 1.0);
+    /// let b = DMatrix::from_element(2, 3, 2.0);
+    /// assert_eq!(a.try_sub(&b), Ok(DMatrix::from_element(2, 3, -1.0)));
+    ///
+    /// let c = DMatrix::from_element(2, 2, 2.0);
+    /// assert_eq!(a.try_sub(&c), Err(ShapeMismatch::new((2, 3), (2, 2))));
+    /// ```
+    #[inline]
+    pub fn try_sub<R2, C2, SB>(
+        &self,
+        rhs: &Matrix<T, R2, C2, SB>,
+    ) -> Result<MatrixSum<T, R1, C1, R2, C2>, ShapeMismatch>
+    where
+        T: ClosedSub,
+        R2: Dim,
+        C2: Dim,
+        SB: Storage<T, R2, C2>,
+        DefaultAllocator: SameShapeAllocator<T, R1, C1, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R1, R2> + SameNumberOfColumns<C1, C2>,
+    {
+        if self.shape() != rhs.shape() {
+            return Err(ShapeMismatch::new(self.shape(), rhs.shape()));
+        }
+
+        Ok(self - rhs)
+    }
+
+    /// Computes `self * rhs`, or returns an error if the number of columns of `self` differs
+    /// from the number of rows of `rhs`.
+    ///
+    /// Unlike the `*` operator, this does not panic if the shapes of dynamically-sized matrices
+    /// mismatch.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, ShapeMismatch};
+    /// let a = DMatrix::from_element(2, 3, 1.0);
+    /// let b = DMatrix::from_element(3, 4, 2.0);
+    /// assert_eq!(a.try_mul(&b), Ok(DMatrix::from_element(2, 4, 6.0)));
+    ///
+    /// let c = DMatrix::from_element(2, 4, 2.0);
+    /// assert_eq!(a.try_mul(&c), Err(ShapeMismatch::new((2, 3), (2, 4))));
+    /// ```
+    #[inline]
+    pub fn try_mul<R2, C2, SB>(
+        &self,
+        rhs: &Matrix<T, R2, C2, SB>,
+    ) -> Result<OMatrix<T, R1, C2>, ShapeMismatch>
+    where
+        T: Zero + One + ClosedAdd + ClosedMul,
+        R2: Dim,
+        C2: Dim,
+        SB: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R1, C2>,
+        ShapeConstraint: AreMultipliable<R1, C1, R2, C2>,
+    {
+        if self.ncols() != rhs.nrows() {
+            return Err(ShapeMismatch::new(self.shape(), rhs.shape()));
+        }
+
+        Ok(self * rhs)
+    }
+}
+
 /// # Special multiplications.
 impl<T, R1: Dim, C1: Dim, SA> Matrix<T, R1, C1, SA>
 where
@@ -791,6 +938,40 @@ where
         out.gemm(T::one(), self, rhs, T::zero());
     }
 
+    /// Equivalent to `self * rhs` but allocates the result with `A` instead of the
+    /// `DefaultAllocator`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2, Matrix2x3, Matrix3x2, RowMajorAllocator};
+    /// let a = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    /// let b = Matrix3x2::new(1, 0, 0, 1, 1, 1);
+    /// let c = a.mul_in::<RowMajorAllocator, _, _, _>(&b);
+    /// assert_eq!(c, Matrix2::new(4, 5, 10, 11));
+    /// assert_eq!(c.as_slice(), &[4, 5, 10, 11]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn mul_in<A, R2: Dim, C2: Dim, SB>(
+        &self,
+        rhs: &Matrix<T, R2, C2, SB>,
+    ) -> Matrix<T, R1, C2, A::Buffer>
+    where
+        A: Allocator<T, R1, C2>,
+        SB: Storage<T, R2, C2>,
+        ShapeConstraint: AreMultipliable<R1, C1, R2, C2>,
+    {
+        let mut res = Matrix::from_data(A::allocate_uninit(
+            self.shape_generic().0,
+            rhs.shape_generic().1,
+        ));
+        unsafe {
+            // SAFETY: this is OK because status = Uninit && beta == 0
+            gemm_uninit(Uninit, &mut res, T::one(), self, rhs, T::zero());
+            Matrix::from_data(A::assume_init(res.data))
+        }
+    }
+
     /// The kronecker product of two matrices (aka. tensor product of the corresponding linear
     /// maps).
     #[must_use]
@@ -0,0 +1,468 @@
+//! Packed storage of symmetric and hermitian matrices.
+//!
+//! Only one triangle of the matrix is stored, column by column, following the LAPACK `sp` and
+//! `hp` layouts. This takes `n * (n + 1) / 2` components instead of `n * n`.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+use std::ops::Mul;
+
+use num::{One, Zero};
+use simba::scalar::{ClosedAdd, ClosedMul, ComplexField, RealField};
+use simba::simd::SimdComplexField;
+
+use crate::base::allocator::Allocator;
+use crate::base::blas::Uplo;
+use crate::base::constraint::{AreMultipliable, DimEq, ShapeConstraint};
+use crate::base::dimension::{Dim, DimDiff, DimSub, U1};
+use crate::base::storage::{Storage, StorageMut};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, Scalar, SquareMatrix, Vector};
+use crate::linalg::{Cholesky, SymmetricEigen};
+
+/// The index of the component `(i, j)`, with `i <= j`, in the upper-triangular packed layout.
+#[inline(always)]
+fn upper_index(i: usize, j: usize) -> usize {
+    i + j * (j + 1) / 2
+}
+
+/// The index of the component `(i, j)`, with `i >= j`, in the lower-triangular packed layout.
+#[inline(always)]
+fn lower_index(dim: usize, i: usize, j: usize) -> usize {
+    i + j * (2 * dim - j - 1) / 2
+}
+
+macro_rules! packed_impl(
+    ($Packed: ident, $mirror: expr, [$($Bounds: tt)*], $doc: literal) => {
+        #[doc = $doc]
+        ///
+        /// The stored triangle, selected by `Uplo`, is laid out column by column: the upper
+        /// triangle stores `(0, 0), (0, 1), (1, 1), (0, 2), ...` and the lower triangle stores
+        /// `(0, 0), (1, 0), ..., (n - 1, 0), (1, 1), ...`.
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $Packed<T, D: Dim> {
+            data: Vec<T>,
+            dim: D,
+            uplo: Uplo,
+        }
+
+        impl<T: Scalar, D: Dim> $Packed<T, D>
+        where
+            T: $($Bounds)*,
+        {
+            /// Creates a packed matrix from its dimension, stored triangle, and packed components.
+            ///
+            /// Panics if `data` does not contain exactly `n * (n + 1) / 2` components.
+            #[inline]
+            pub fn from_vec(dim: D, uplo: Uplo, data: Vec<T>) -> Self {
+                let n = dim.value();
+                assert_eq!(
+                    data.len(),
+                    n * (n + 1) / 2,
+                    "Packed matrix: the number of components does not match the dimension."
+                );
+                Self { data, dim, uplo }
+            }
+
+            /// Packs the triangle of the square matrix `m` selected by `uplo`.
+            ///
+            /// The other triangle of `m` is never read.
+            #[inline]
+            pub fn from_matrix<S: Storage<T, D, D>>(m: &SquareMatrix<T, D, S>, uplo: Uplo) -> Self {
+                assert!(m.is_square(), "Packed matrix: the matrix must be square.");
+                let dim = m.shape_generic().0;
+                let n = dim.value();
+                let mut data = Vec::with_capacity(n * (n + 1) / 2);
+
+                for j in 0..n {
+                    let rows = match uplo {
+                        Uplo::Upper => 0..j + 1,
+                        Uplo::Lower => j..n,
+                    };
+                    data.extend(rows.map(|i| m[(i, j)].clone()));
+                }
+
+                Self { data, dim, uplo }
+            }
+
+            /// The number of rows (and columns) of this matrix.
+            #[inline]
+            #[must_use]
+            pub fn dim(&self) -> usize {
+                self.dim.value()
+            }
+
+            /// The triangle actually stored by this matrix.
+            #[inline]
+            #[must_use]
+            pub fn uplo(&self) -> Uplo {
+                self.uplo
+            }
+
+            /// The packed components of this matrix.
+            #[inline]
+            #[must_use]
+            pub fn as_slice(&self) -> &[T] {
+                &self.data
+            }
+
+            /// The packed components of this matrix, mutably.
+            #[inline]
+            #[must_use]
+            pub fn as_mut_slice(&mut self) -> &mut [T] {
+                &mut self.data
+            }
+
+            /// The index of the component `(i, j)` in the packed storage, and whether the
+            /// component is actually stored at the mirrored position `(j, i)`.
+            #[inline]
+            fn packed_index(&self, i: usize, j: usize) -> (usize, bool) {
+                let n = self.dim.value();
+                assert!(i < n && j < n, "Packed matrix index out of bounds.");
+
+                match self.uplo {
+                    Uplo::Upper if i <= j => (upper_index(i, j), false),
+                    Uplo::Upper => (upper_index(j, i), true),
+                    Uplo::Lower if i >= j => (lower_index(n, i, j), false),
+                    Uplo::Lower => (lower_index(n, j, i), true),
+                }
+            }
+
+            /// The component `(i, j)` of this matrix.
+            #[inline]
+            #[must_use]
+            pub fn get(&self, i: usize, j: usize) -> T {
+                let (id, mirrored) = self.packed_index(i, j);
+                let val = self.data[id].clone();
+
+                if mirrored {
+                    $mirror(val)
+                } else {
+                    val
+                }
+            }
+
+            /// Sets the component `(i, j)`, and thus its mirrored component `(j, i)`, of this
+            /// matrix.
+            #[inline]
+            pub fn set(&mut self, i: usize, j: usize, val: T) {
+                let (id, mirrored) = self.packed_index(i, j);
+                self.data[id] = if mirrored { $mirror(val) } else { val };
+            }
+
+            /// Unpacks this matrix into a regular matrix with both triangles filled.
+            #[inline]
+            #[must_use]
+            pub fn to_matrix(&self) -> OMatrix<T, D, D>
+            where
+                DefaultAllocator: Allocator<T, D, D>,
+            {
+                OMatrix::from_fn_generic(self.dim, self.dim, |i, j| self.get(i, j))
+            }
+
+            /// Computes `y = alpha * self * x + beta * y`.
+            ///
+            /// If `beta` is zero, `y` is never read.
+            #[inline]
+            fn gemv_to<D2: Dim, S2, D3: Dim, S3>(
+                &self,
+                y: &mut Vector<T, D2, S2>,
+                alpha: T,
+                x: &Vector<T, D3, S3>,
+                beta: T,
+            ) where
+                S2: StorageMut<T, D2>,
+                S3: Storage<T, D3>,
+            {
+                let n = self.dim.value();
+                assert!(
+                    y.nrows() == n && x.nrows() == n,
+                    "Packed gemv: dimensions mismatch."
+                );
+
+                if beta.is_zero() {
+                    y.fill(T::zero());
+                } else {
+                    *y *= beta;
+                }
+
+                let mut id = 0;
+                for j in 0..n {
+                    let rows = match self.uplo {
+                        Uplo::Upper => 0..j + 1,
+                        Uplo::Lower => j..n,
+                    };
+
+                    let ax_j = alpha.clone() * x[j].clone();
+                    let mut acc = T::zero();
+
+                    for i in rows {
+                        let a = self.data[id].clone();
+                        id += 1;
+
+                        y[i] += a.clone() * ax_j.clone();
+                        if i != j {
+                            acc += $mirror(a) * x[i].clone();
+                        }
+                    }
+
+                    y[j] += alpha.clone() * acc;
+                }
+            }
+
+            /// Computes `self = alpha * x * conj(y)^T + beta * self` on the stored triangle.
+            #[inline]
+            fn ger_xx<D2: Dim, S2, D3: Dim, S3>(
+                &mut self,
+                alpha: T,
+                x: &Vector<T, D2, S2>,
+                y: &Vector<T, D3, S3>,
+                beta: T,
+            ) where
+                S2: Storage<T, D2>,
+                S3: Storage<T, D3>,
+            {
+                let n = self.dim.value();
+                assert!(
+                    x.nrows() == n && y.nrows() == n,
+                    "Packed ger: dimensions mismatch."
+                );
+
+                let mut id = 0;
+                for j in 0..n {
+                    let rows = match self.uplo {
+                        Uplo::Upper => 0..j + 1,
+                        Uplo::Lower => j..n,
+                    };
+                    let ay_j = alpha.clone() * $mirror(y[j].clone());
+
+                    for i in rows {
+                        let update = x[i].clone() * ay_j.clone();
+                        let e = &mut self.data[id];
+                        id += 1;
+
+                        *e = if beta.is_zero() {
+                            update
+                        } else {
+                            e.clone() * beta.clone() + update
+                        };
+                    }
+                }
+            }
+        }
+
+        impl<T: Scalar, D: Dim> From<$Packed<T, D>> for OMatrix<T, D, D>
+        where
+            T: $($Bounds)*,
+            DefaultAllocator: Allocator<T, D, D>,
+        {
+            #[inline]
+            fn from(packed: $Packed<T, D>) -> Self {
+                packed.to_matrix()
+            }
+        }
+
+        impl<T, D: Dim> From<$Packed<T, D>> for Vec<T> {
+            #[inline]
+            fn from(packed: $Packed<T, D>) -> Self {
+                packed.data
+            }
+        }
+
+        impl<'a, 'b, T: Scalar, D: Dim, R2: Dim, C2: Dim, S2> Mul<&'b Matrix<T, R2, C2, S2>>
+            for &'a $Packed<T, D>
+        where
+            T: $($Bounds)*,
+            S2: Storage<T, R2, C2>,
+            DefaultAllocator: Allocator<T, D, C2>,
+            ShapeConstraint: AreMultipliable<D, D, R2, C2>,
+        {
+            type Output = OMatrix<T, D, C2>;
+
+            #[inline]
+            fn mul(self, rhs: &'b Matrix<T, R2, C2, S2>) -> Self::Output {
+                let ncols = rhs.shape_generic().1;
+                let mut res = OMatrix::zeros_generic(self.dim, ncols);
+
+                for j in 0..rhs.ncols() {
+                    self.gemv_to(&mut res.column_mut(j), T::one(), &rhs.column(j), T::zero());
+                }
+
+                res
+            }
+        }
+    }
+);
+
+packed_impl!(
+    PackedSymmetricMatrix,
+    |e| e,
+    [Zero + One + ClosedAdd + ClosedMul],
+    "A **symmetric** square matrix stored in packed form."
+);
+packed_impl!(
+    PackedHermitianMatrix,
+    SimdComplexField::simd_conjugate,
+    [SimdComplexField],
+    "An **hermitian** square matrix stored in packed form."
+);
+
+/// # Decompositions
+impl<T: RealField, D: Dim> PackedSymmetricMatrix<T, D> {
+    /// Computes the Cholesky decomposition of this symmetric matrix.
+    ///
+    /// Returns `None` if this matrix is not positive-definite.
+    #[inline]
+    pub fn cholesky(&self) -> Option<Cholesky<T, D>>
+    where
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        Cholesky::new(self.to_matrix())
+    }
+
+    /// Computes the eigenvalues and eigenvectors of this symmetric matrix.
+    #[inline]
+    pub fn symmetric_eigen(&self) -> SymmetricEigen<T, D>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, D, D>
+            + Allocator<T, DimDiff<D, U1>>
+            + Allocator<T, D>
+            + Allocator<T::RealField, D>
+            + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        SymmetricEigen::new(self.to_matrix())
+    }
+}
+
+/// # Decompositions
+impl<T: ComplexField, D: Dim> PackedHermitianMatrix<T, D> {
+    /// Computes the Cholesky decomposition of this hermitian matrix.
+    ///
+    /// Returns `None` if this matrix is not positive-definite.
+    #[inline]
+    pub fn cholesky(&self) -> Option<Cholesky<T, D>>
+    where
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        Cholesky::new(self.to_matrix())
+    }
+
+    /// Computes the eigenvalues and eigenvectors of this hermitian matrix.
+    #[inline]
+    pub fn symmetric_eigen(&self) -> SymmetricEigen<T, D>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, D, D>
+            + Allocator<T, DimDiff<D, U1>>
+            + Allocator<T, D>
+            + Allocator<T::RealField, D>
+            + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        SymmetricEigen::new(self.to_matrix())
+    }
+}
+
+/// # Packed BLAS
+impl<T: Scalar + Zero + One + ClosedAdd + ClosedMul, D: Dim> PackedSymmetricMatrix<T, D> {
+    /// Computes `self = alpha * x * y.transpose() + beta * self` on the stored triangle.
+    ///
+    /// This is the packed counterpart of `Matrix::syger`. If `beta` is zero, `self` is never
+    /// read.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2, PackedSymmetricMatrix, Uplo, Vector2};
+    /// let mut mat = PackedSymmetricMatrix::from_matrix(&Matrix2::identity(), Uplo::Upper);
+    /// let vec = Vector2::new(1.0, 2.0);
+    ///
+    /// mat.syger(10.0, &vec, &vec, 5.0);
+    /// assert_eq!(mat.to_matrix(), vec * vec.transpose() * 10.0 + Matrix2::identity() * 5.0);
+    /// ```
+    #[inline]
+    pub fn syger<D2: Dim, S2, D3: Dim, S3>(
+        &mut self,
+        alpha: T,
+        x: &Vector<T, D2, S2>,
+        y: &Vector<T, D3, S3>,
+        beta: T,
+    ) where
+        S2: Storage<T, D2>,
+        S3: Storage<T, D3>,
+        ShapeConstraint: DimEq<D, D2> + DimEq<D, D3>,
+    {
+        self.ger_xx(alpha, x, y, beta)
+    }
+}
+
+impl<T: SimdComplexField, D: Dim> PackedHermitianMatrix<T, D> {
+    /// Computes `self = alpha * x * y.adjoint() + beta * self` on the stored triangle.
+    ///
+    /// This is the packed counterpart of `Matrix::hegerc`. If `beta` is zero, `self` is never
+    /// read.
+    #[inline]
+    pub fn hegerc<D2: Dim, S2, D3: Dim, S3>(
+        &mut self,
+        alpha: T,
+        x: &Vector<T, D2, S2>,
+        y: &Vector<T, D3, S3>,
+        beta: T,
+    ) where
+        S2: Storage<T, D2>,
+        S3: Storage<T, D3>,
+        ShapeConstraint: DimEq<D, D2> + DimEq<D, D3>,
+    {
+        self.ger_xx(alpha, x, y, beta)
+    }
+}
+
+/// # Packed BLAS
+impl<T: Scalar, D: Dim, S: StorageMut<T, D>> Vector<T, D, S> {
+    /// Computes `self = alpha * a * x + beta * self`, where `a` is a **symmetric** matrix stored
+    /// in packed form.
+    ///
+    /// This is the packed counterpart of `sygemv`. If `beta` is zero, `self` is never read.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2, PackedSymmetricMatrix, Uplo, Vector2};
+    /// let mat = PackedSymmetricMatrix::from_matrix(&Matrix2::new(1.0, 2.0,
+    ///                                                            2.0, 4.0), Uplo::Lower);
+    /// let mut vec1 = Vector2::new(1.0, 2.0);
+    /// let vec2 = Vector2::new(0.1, 0.2);
+    /// vec1.sygemv_packed(10.0, &mat, &vec2, 5.0);
+    /// assert_eq!(vec1, Vector2::new(10.0, 20.0));
+    /// ```
+    #[inline]
+    pub fn sygemv_packed<D2: Dim, D3: Dim, S3>(
+        &mut self,
+        alpha: T,
+        a: &PackedSymmetricMatrix<T, D2>,
+        x: &Vector<T, D3, S3>,
+        beta: T,
+    ) where
+        T: Zero + One + ClosedAdd + ClosedMul,
+        S3: Storage<T, D3>,
+        ShapeConstraint: DimEq<D, D2> + AreMultipliable<D2, D2, D3, U1>,
+    {
+        a.gemv_to(self, alpha, x, beta)
+    }
+
+    /// Computes `self = alpha * a * x + beta * self`, where `a` is an **hermitian** matrix stored
+    /// in packed form.
+    ///
+    /// This is the packed counterpart of `hegemv`. If `beta` is zero, `self` is never read.
+    #[inline]
+    pub fn hegemv_packed<D2: Dim, D3: Dim, S3>(
+        &mut self,
+        alpha: T,
+        a: &PackedHermitianMatrix<T, D2>,
+        x: &Vector<T, D3, S3>,
+        beta: T,
+    ) where
+        T: SimdComplexField,
+        S3: Storage<T, D3>,
+        ShapeConstraint: DimEq<D, D2> + AreMultipliable<D2, D2, D3, U1>,
+    {
+        a.gemv_to(self, alpha, x, beta)
+    }
+}
@@ -0,0 +1,500 @@
+//! Parallel iterators for matrices compatible with rayon.
+
+use std::mem::MaybeUninit;
+
+use num::Zero;
+use simba::scalar::ClosedAdd;
+use simba::simd::SimdComplexField;
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{Const, Dim, U1};
+use crate::base::iter::{ColumnIter, ColumnIterMut};
+use crate::base::storage::{RawStorage, RawStorageMut};
+use crate::base::{
+    DefaultAllocator, Matrix, MatrixSlice, MatrixSliceMut, OMatrix, OVector, RowOVector, Scalar,
+};
+use rayon::iter::plumbing::{bridge, Producer};
+use rayon::prelude::*;
+
+/// A rayon parallel iterator over the columns of a matrix. It is created
+/// using the [`par_column_iter`] method of [`Matrix`].
+///
+/// *Only available if compiled with the feature `rayon`.*
+/// [`par_column_iter`]: crate::Matrix::par_column_iter
+/// [`Matrix`]: crate::Matrix
+#[derive(Debug)]
+pub struct ParColumnIter<'a, T, R: Dim, C: Dim, S: RawStorage<T, R, C>> {
+    mat: &'a Matrix<T, R, C, S>,
+}
+
+impl<'a, T, R: Dim, C: Dim, S: RawStorage<T, R, C>> ParColumnIter<'a, T, R, C, S> {
+    /// Create a new parallel iterator for the given matrix.
+    fn new(matrix: &'a Matrix<T, R, C, S>) -> Self {
+        Self { mat: matrix }
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S> ParallelIterator for ParColumnIter<'a, T, R, C, S>
+where
+    T: Send + Sync,
+    S: Sync + RawStorage<T, R, C>,
+{
+    type Item = MatrixSlice<'a, T, R, U1, S::RStride, S::CStride>;
+
+    fn drive_unindexed<Consumer>(self, consumer: Consumer) -> Consumer::Result
+    where
+        Consumer: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.mat.ncols())
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S> IndexedParallelIterator for ParColumnIter<'a, T, R, C, S>
+where
+    T: Send + Sync,
+    S: Sync + RawStorage<T, R, C>,
+{
+    fn len(&self) -> usize {
+        self.mat.ncols()
+    }
+
+    fn drive<Consumer: rayon::iter::plumbing::Consumer<Self::Item>>(
+        self,
+        consumer: Consumer,
+    ) -> Consumer::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<Callback: rayon::iter::plumbing::ProducerCallback<Self::Item>>(
+        self,
+        callback: Callback,
+    ) -> Callback::Output {
+        let producer = ColumnIter::new(self.mat);
+        callback.callback(producer)
+    }
+}
+
+/// A rayon parallel iterator through the mutable columns of a matrix. It is created
+/// using the [`par_column_iter_mut`] method of [`Matrix`].
+///
+/// *Only available if compiled with the feature `rayon`.*
+/// [`par_column_iter_mut`]: crate::Matrix::par_column_iter_mut
+/// [`Matrix`]: crate::Matrix
+#[derive(Debug)]
+pub struct ParColumnIterMut<'a, T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> {
+    mat: &'a mut Matrix<T, R, C, S>,
+}
+
+impl<'a, T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> ParColumnIterMut<'a, T, R, C, S> {
+    /// Create a new parallel iterator for the given matrix.
+    fn new(mat: &'a mut Matrix<T, R, C, S>) -> Self {
+        Self { mat }
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S> ParallelIterator for ParColumnIterMut<'a, T, R, C, S>
+where
+    T: Send + Sync,
+    S: Send + Sync + RawStorageMut<T, R, C>,
+{
+    type Item = MatrixSliceMut<'a, T, R, U1, S::RStride, S::CStride>;
+
+    fn drive_unindexed<Consumer>(self, consumer: Consumer) -> Consumer::Result
+    where
+        Consumer: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.mat.ncols())
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S> IndexedParallelIterator for ParColumnIterMut<'a, T, R, C, S>
+where
+    T: Send + Sync,
+    S: Send + Sync + RawStorageMut<T, R, C>,
+{
+    fn drive<Consumer: rayon::iter::plumbing::Consumer<Self::Item>>(
+        self,
+        consumer: Consumer,
+    ) -> Consumer::Result {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.mat.ncols()
+    }
+
+    fn with_producer<Callback: rayon::iter::plumbing::ProducerCallback<Self::Item>>(
+        self,
+        callback: Callback,
+    ) -> Callback::Output {
+        let producer = ColumnIterMut::new(self.mat);
+        callback.callback(producer)
+    }
+}
+
+/// # Parallel iterators using `rayon`
+/// *Only available if compiled with the feature `rayon`*
+impl<T, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S>
+where
+    T: Send + Sync,
+    S: Sync,
+{
+    /// Iterate through the columns of the matrix in parallel using rayon.
+    ///
+    /// This iterates over *immutable* references to the columns of the matrix;
+    /// if mutable access to the columns is required, use [`par_column_iter_mut`]
+    /// instead.
+    ///
+    /// # Example
+    /// Using parallel column iterators to calculate the sum of the maximum
+    /// elements in each column:
+    /// ```
+    /// use nalgebra::{dmatrix, DMatrix};
+    /// # extern crate rayon_package as rayon;
+    /// use rayon::prelude::*;
+    ///
+    /// let matrix: DMatrix<f64> = dmatrix![1.0, 0.0, 5.0;
+    ///                                     2.0, 4.0, 1.0;
+    ///                                     3.0, 2.0, 2.0;
+    /// ];
+    /// let sum_of_max: f64 = matrix
+    ///     .par_column_iter()
+    ///     .map(|col| col.max())
+    ///     .sum();
+    ///
+    /// assert_eq!(sum_of_max, 3.0 + 4.0 + 5.0);
+    /// ```
+    ///
+    /// [`par_column_iter_mut`]: crate::Matrix::par_column_iter_mut
+    pub fn par_column_iter(&self) -> ParColumnIter<'_, T, R, C, S> {
+        ParColumnIter::new(self)
+    }
+
+    /// Returns a matrix containing the result of `f` applied to each of its entries, with the
+    /// columns processed in parallel using rayon.
+    ///
+    /// This is the parallel counterpart of [`map`](crate::Matrix::map), so `f` must be `Sync`
+    /// since it may be called from several threads at once.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::DMatrix;
+    ///
+    /// let m = DMatrix::from_fn(100, 50, |i, j| (i * 50 + j) as f64);
+    /// assert_eq!(m.par_map(|e| e.sqrt()), m.map(|e| e.sqrt()));
+    /// ```
+    pub fn par_map<T2, F>(&self, f: F) -> OMatrix<T2, R, C>
+    where
+        T: Scalar,
+        T2: Scalar + Send,
+        F: Fn(T) -> T2 + Sync,
+        DefaultAllocator: Allocator<T2, R, C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        let mut res = Matrix::uninit(nrows, ncols);
+        let out = OutputPtr::new(&mut res);
+
+        self.par_column_iter().enumerate().for_each(|(j, col)| {
+            for (i, e) in col.iter().enumerate() {
+                // Safety: each column of the output is written by exactly one thread.
+                unsafe { out.write(i, j, f(e.clone())) }
+            }
+        });
+
+        // Safety: res is now fully initialized.
+        unsafe { res.assume_init() }
+    }
+
+    /// Returns a matrix containing the result of `f` applied to each entries of `self` and
+    /// `rhs`, with the columns processed in parallel using rayon.
+    ///
+    /// This is the parallel counterpart of [`zip_map`](crate::Matrix::zip_map).
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::DMatrix;
+    ///
+    /// let a = DMatrix::from_fn(100, 50, |i, j| (i + j) as f64);
+    /// let b = DMatrix::from_fn(100, 50, |i, j| (i * j) as f64);
+    /// assert_eq!(a.par_zip_map(&b, |x, y| x * y + 1.0), a.zip_map(&b, |x, y| x * y + 1.0));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `self` and `rhs` do not have the same shape.
+    pub fn par_zip_map<T2, N3, S2, F>(&self, rhs: &Matrix<T2, R, C, S2>, f: F) -> OMatrix<N3, R, C>
+    where
+        T: Scalar,
+        T2: Scalar + Send + Sync,
+        N3: Scalar + Send,
+        S2: RawStorage<T2, R, C> + Sync,
+        F: Fn(T, T2) -> N3 + Sync,
+        DefaultAllocator: Allocator<N3, R, C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+
+        assert_eq!(
+            (nrows.value(), ncols.value()),
+            rhs.shape(),
+            "Matrix simultaneous traversal error: dimension mismatch."
+        );
+
+        let mut res = Matrix::uninit(nrows, ncols);
+        let out = OutputPtr::new(&mut res);
+
+        self.par_column_iter()
+            .zip(rhs.par_column_iter())
+            .enumerate()
+            .for_each(|(j, (col1, col2))| {
+                for (i, (a, b)) in col1.iter().zip(col2.iter()).enumerate() {
+                    // Safety: each column of the output is written by exactly one thread.
+                    unsafe { out.write(i, j, f(a.clone(), b.clone())) }
+                }
+            });
+
+        // Safety: res is now fully initialized.
+        unsafe { res.assume_init() }
+    }
+
+    /// The sum of all the elements of this matrix, with the columns summed in parallel using
+    /// rayon.
+    ///
+    /// This is the parallel counterpart of [`sum`](crate::Matrix::sum). The elements may be
+    /// added in a different order, so the result may differ slightly for floating-point numbers.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::DMatrix;
+    ///
+    /// let m = DMatrix::from_fn(100, 50, |i, j| (i * 50 + j) as u64);
+    /// assert_eq!(m.par_sum(), m.sum());
+    /// ```
+    #[must_use]
+    pub fn par_sum(&self) -> T
+    where
+        T: Scalar + ClosedAdd + Zero,
+    {
+        self.par_column_iter()
+            .map(|col| col.sum())
+            .reduce(T::zero, |a, b| a + b)
+    }
+
+    /// The sum of all the rows of this matrix, i.e., the sums of each column computed in
+    /// parallel using rayon.
+    ///
+    /// This is the parallel counterpart of [`row_sum`](crate::Matrix::row_sum).
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::DMatrix;
+    ///
+    /// let m = DMatrix::from_fn(100, 50, |i, j| (i * 50 + j) as f64);
+    /// assert_eq!(m.par_row_sum(), m.row_sum());
+    /// ```
+    #[must_use]
+    pub fn par_row_sum(&self) -> RowOVector<T, C>
+    where
+        T: Scalar + ClosedAdd + Zero,
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        let ncols = self.shape_generic().1;
+        let mut res = Matrix::uninit(Const::<1>, ncols);
+        let out = OutputPtr::new(&mut res);
+
+        self.par_column_iter().enumerate().for_each(|(j, col)| {
+            // Safety: each column of the output is written by exactly one thread.
+            unsafe { out.write(0, j, col.sum()) }
+        });
+
+        // Safety: res is now fully initialized.
+        unsafe { res.assume_init() }
+    }
+
+    /// The sum of all the columns of this matrix, with groups of columns summed in parallel
+    /// using rayon.
+    ///
+    /// This is the parallel counterpart of [`column_sum`](crate::Matrix::column_sum). The
+    /// columns may be added in a different order, so the result may differ slightly for
+    /// floating-point numbers.
+    ///
+    /// # Example
+    /// ```
+    /// use nalgebra::DMatrix;
+    ///
+    /// let m = DMatrix::from_fn(100, 50, |i, j| (i * 50 + j) as i64);
+    /// assert_eq!(m.par_column_sum(), m.column_sum());
+    /// ```
+    #[must_use]
+    pub fn par_column_sum(&self) -> OVector<T, R>
+    where
+        T: Scalar + ClosedAdd + Zero,
+        DefaultAllocator: Allocator<T, R>,
+        OVector<T, R>: Send,
+    {
+        let nrows = self.shape_generic().0;
+        let zeros = || OVector::zeros_generic(nrows, Const::<1>);
+
+        self.par_column_iter()
+            .fold(zeros, |mut acc, col| {
+                acc += col;
+                acc
+            })
+            .reduce(zeros, |a, b| a + b)
+    }
+
+    /// The squared L2 norm of this matrix, with the columns processed in parallel using rayon.
+    ///
+    /// This is the parallel counterpart of [`norm_squared`](crate::Matrix::norm_squared).
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// use nalgebra::DMatrix;
+    ///
+    /// let m = DMatrix::from_fn(100, 50, |i, j| (i as f64 - j as f64) / 7.0);
+    /// assert_relative_eq!(m.par_norm_squared(), m.norm_squared(), epsilon = 1.0e-8);
+    /// ```
+    #[must_use]
+    pub fn par_norm_squared(&self) -> T::SimdRealField
+    where
+        T: SimdComplexField,
+        T::SimdRealField: Send,
+    {
+        self.par_column_iter()
+            .map(|col| col.norm_squared())
+            .reduce(T::SimdRealField::zero, |a, b| a + b)
+    }
+
+    /// The L2 norm of this matrix, with the columns processed in parallel using rayon.
+    ///
+    /// This is the parallel counterpart of [`norm`](crate::Matrix::norm).
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// use nalgebra::DMatrix;
+    ///
+    /// let m = DMatrix::from_fn(100, 50, |i, j| (i as f64 - j as f64) / 7.0);
+    /// assert_relative_eq!(m.par_norm(), m.norm(), epsilon = 1.0e-8);
+    /// ```
+    #[must_use]
+    pub fn par_norm(&self) -> T::SimdRealField
+    where
+        T: SimdComplexField,
+        T::SimdRealField: Send,
+    {
+        self.par_norm_squared().simd_sqrt()
+    }
+}
+
+impl<T, R: Dim, C: Dim, S: RawStorageMut<T, R, C>> Matrix<T, R, C, S>
+where
+    T: Send + Sync,
+    S: Send + Sync,
+{
+    /// Mutably iterate through the columns of this matrix in parallel using rayon.
+    /// Allows mutable access to the columns in parallel using mutable references.
+    /// If mutable access to the columns is not required rather use [`par_column_iter`]
+    /// instead.
+    ///
+    /// # Example
+    /// Normalize each column of a matrix with respect to its own maximum value.
+    ///
+    /// ```
+    /// use nalgebra::{dmatrix, DMatrix};
+    /// # extern crate rayon_package as rayon;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut matrix: DMatrix<f64> = dmatrix![2.0, 4.0, 6.0;
+    ///                                         1.0, 2.0, 3.0];
+    /// matrix.par_column_iter_mut().for_each(|mut col| {
+    ///     let max = col.max();
+    ///     col /= max;
+    /// });
+    ///
+    /// assert_eq!(matrix, dmatrix![1.0, 1.0, 1.0; 0.5, 0.5, 0.5]);
+    /// ```
+    ///
+    /// [`par_column_iter`]: crate::Matrix::par_column_iter
+    pub fn par_column_iter_mut(&mut self) -> ParColumnIterMut<'_, T, R, C, S> {
+        ParColumnIterMut::new(self)
+    }
+}
+
+/// A pointer to the (uninitialized) output of `par_map`, `par_zip_map` and `par_row_sum`, shared
+/// between threads.
+struct OutputPtr<T> {
+    ptr: *mut MaybeUninit<T>,
+    strides: (usize, usize),
+}
+
+// Safety: the output is only ever written through `OutputPtr::write`, at disjoint locations
+// from different threads.
+unsafe impl<T: Send> Send for OutputPtr<T> {}
+unsafe impl<T: Send> Sync for OutputPtr<T> {}
+
+impl<T> OutputPtr<T> {
+    fn new<R: Dim, C: Dim, S: RawStorageMut<MaybeUninit<T>, R, C>>(
+        out: &mut Matrix<MaybeUninit<T>, R, C, S>,
+    ) -> Self {
+        let (rstride, cstride) = out.strides();
+        Self {
+            ptr: out.as_mut_ptr(),
+            strides: (rstride, cstride),
+        }
+    }
+
+    /// Initializes the output component at row `i` and column `j`.
+    ///
+    /// # Safety
+    /// `(i, j)` must be within the bounds of the output, and must not be written concurrently by
+    /// another thread.
+    unsafe fn write(&self, i: usize, j: usize, val: T) {
+        *self.ptr.add(i * self.strides.0 + j * self.strides.1) = MaybeUninit::new(val);
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S> Producer for ColumnIter<'a, T, R, C, S>
+where
+    T: Send + Sync,
+    S: Sync + RawStorage<T, R, C>,
+{
+    type Item = MatrixSlice<'a, T, R, U1, S::RStride, S::CStride>;
+    type IntoIter = ColumnIter<'a, T, R, C, S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    #[inline]
+    fn split_at(self, index: usize) -> (Self, Self) {
+        self.split_at(index)
+    }
+}
+
+impl<'a, T, R: Dim, C: Dim, S> Producer for ColumnIterMut<'a, T, R, C, S>
+where
+    T: Send + Sync,
+    S: Send + Sync + RawStorageMut<T, R, C>,
+{
+    type Item = MatrixSliceMut<'a, T, R, U1, S::RStride, S::CStride>;
+    type IntoIter = ColumnIterMut<'a, T, R, C, S>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self
+    }
+
+    #[inline]
+    fn split_at(self, index: usize) -> (Self, Self) {
+        self.split_at(index)
+    }
+}
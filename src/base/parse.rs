@@ -0,0 +1,441 @@
+//! Parsing of matrices from their textual representation.
+//!
+//! Matrices can be written in the MATLAB syntax `[1 2; 3 4]`, as nested arrays `[[1, 2], [3, 4]]`
+//! like NumPy or Rust, or as whitespace-separated components with one row per line. Components
+//! may be separated by commas or whitespaces, and the whole matrix may be prefixed by a macro name
+//! like `matrix!` or wrapped into a call like `np.array(...)`.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::Dim;
+use crate::base::{DefaultAllocator, OMatrix, OVector, Scalar, ShapeMismatch};
+use crate::geometry::OPoint;
+use crate::DimName;
+
+/// The kind of an error returned when parsing a matrix.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseMatrixErrorKind<E> {
+    /// A character was found where it is not allowed, e.g., a `;` between nested rows.
+    UnexpectedChar(char),
+    /// The input ended before the matrix was complete, e.g., because of a missing `]`.
+    UnexpectedEnd,
+    /// A component could not be parsed.
+    InvalidComponent(E),
+    /// A row does not have the same number of components as the first row.
+    RaggedRow {
+        /// The number of components of the first row.
+        expected: usize,
+        /// The number of components of the offending row.
+        found: usize,
+    },
+    /// The shape of the parsed matrix does not match the statically-known shape of the target.
+    ShapeMismatch(ShapeMismatch),
+}
+
+/// The error returned when parsing a matrix, with the position of its cause.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseMatrixError<E> {
+    /// The kind of this error.
+    pub kind: ParseMatrixErrorKind<E>,
+    /// The line, starting at 1, where this error occurred.
+    pub line: usize,
+    /// The column, in characters and starting at 1, where this error occurred.
+    pub column: usize,
+}
+
+impl<E: fmt::Display> fmt::Display for ParseMatrixErrorKind<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedChar(c) => write!(f, "unexpected character `{}`", c),
+            Self::UnexpectedEnd => write!(f, "unexpected end of input"),
+            Self::InvalidComponent(e) => write!(f, "invalid component: {}", e),
+            Self::RaggedRow { expected, found } => {
+                write!(f, "row with {} components instead of {}", found, expected)
+            }
+            Self::ShapeMismatch(e) => write!(
+                f,
+                "parsed a {}x{} matrix instead of a {}x{} matrix",
+                e.lhs.0, e.lhs.1, e.rhs.0, e.rhs.1
+            ),
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ParseMatrixError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.kind, self.line, self.column
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for ParseMatrixError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ParseMatrixErrorKind::InvalidComponent(e) => Some(e),
+            ParseMatrixErrorKind::ShapeMismatch(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// The characters delimiting components, besides whitespaces.
+const DELIMITERS: &[char] = &['[', ']', '(', ')', ',', ';'];
+
+#[derive(Copy, Clone)]
+struct Cursor<'a> {
+    input: &'a str,
+    pos: usize,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+            if c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+    }
+
+    /// Skips whitespaces, including new lines if `newlines` is `true`.
+    fn skip_whitespaces(&mut self, newlines: bool) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace() && (newlines || c != '\n')) {
+            self.bump();
+        }
+    }
+
+    fn error<E>(&self, kind: ParseMatrixErrorKind<E>) -> ParseMatrixError<E> {
+        ParseMatrixError {
+            kind,
+            line: self.line,
+            column: self.column,
+        }
+    }
+
+    fn unexpected<E>(&self) -> ParseMatrixError<E> {
+        match self.peek() {
+            Some(c) => self.error(ParseMatrixErrorKind::UnexpectedChar(c)),
+            None => self.error(ParseMatrixErrorKind::UnexpectedEnd),
+        }
+    }
+}
+
+struct Parser<'a, T: FromStr> {
+    cursor: Cursor<'a>,
+    data: Vec<T>,
+    nrows: usize,
+    ncols: Option<usize>,
+    row_len: usize,
+    row_start: Cursor<'a>,
+}
+
+impl<'a, T: FromStr> Parser<'a, T> {
+    fn component(&mut self) -> Result<(), ParseMatrixError<T::Err>> {
+        let start = self.cursor;
+        while matches!(self.cursor.peek(), Some(c) if !c.is_whitespace() && !DELIMITERS.contains(&c))
+        {
+            self.cursor.bump();
+        }
+
+        let value = T::from_str(&self.cursor.input[start.pos..self.cursor.pos])
+            .map_err(|e| start.error(ParseMatrixErrorKind::InvalidComponent(e)))?;
+
+        self.data.push(value);
+        self.row_len += 1;
+        Ok(())
+    }
+
+    /// Ends the current row. Empty rows are ignored unless they are `explicit`, e.g., `[]`.
+    fn end_row(&mut self, explicit: bool) -> Result<(), ParseMatrixError<T::Err>> {
+        if self.row_len == 0 && !explicit {
+            return Ok(());
+        }
+
+        match self.ncols {
+            Some(ncols) if ncols != self.row_len => {
+                return Err(self.row_start.error(ParseMatrixErrorKind::RaggedRow {
+                    expected: ncols,
+                    found: self.row_len,
+                }))
+            }
+            _ => self.ncols = Some(self.row_len),
+        }
+
+        self.nrows += 1;
+        self.row_len = 0;
+        Ok(())
+    }
+
+    /// Parses rows separated by new lines or semicolons, until `close` or the end of the input.
+    fn flat_rows(&mut self, close: Option<char>) -> Result<(), ParseMatrixError<T::Err>> {
+        let mut after_component = false;
+
+        loop {
+            self.cursor.skip_whitespaces(false);
+            match self.cursor.peek() {
+                None if close.is_none() => return self.end_row(false),
+                c if c.is_some() && c == close => {
+                    self.cursor.bump();
+                    return self.end_row(false);
+                }
+                Some('\n') | Some(';') => {
+                    self.cursor.bump();
+                    self.end_row(false)?;
+                    after_component = false;
+                }
+                Some(',') if after_component => {
+                    self.cursor.bump();
+                    after_component = false;
+                }
+                Some(c) if !DELIMITERS.contains(&c) => {
+                    if self.row_len == 0 {
+                        self.row_start = self.cursor;
+                    }
+                    self.component()?;
+                    after_component = true;
+                }
+                _ => return Err(self.cursor.unexpected()),
+            }
+        }
+    }
+
+    /// Parses rows written as nested arrays, until the closing `]` of the outer array.
+    fn nested_rows(&mut self) -> Result<(), ParseMatrixError<T::Err>> {
+        let mut after_row = false;
+
+        loop {
+            self.cursor.skip_whitespaces(true);
+            match self.cursor.peek() {
+                Some('[') if !after_row => {
+                    self.row_start = self.cursor;
+                    self.cursor.bump();
+                    self.nested_row()?;
+                    after_row = true;
+                }
+                Some(',') if after_row => {
+                    self.cursor.bump();
+                    after_row = false;
+                }
+                Some(']') => {
+                    self.cursor.bump();
+                    return Ok(());
+                }
+                _ => return Err(self.cursor.unexpected()),
+            }
+        }
+    }
+
+    /// Parses the components of a row written as an array, after its opening `[`.
+    fn nested_row(&mut self) -> Result<(), ParseMatrixError<T::Err>> {
+        let mut after_component = false;
+
+        loop {
+            self.cursor.skip_whitespaces(true);
+            match self.cursor.peek() {
+                Some(']') => {
+                    self.cursor.bump();
+                    return self.end_row(true);
+                }
+                Some(',') if after_component => {
+                    self.cursor.bump();
+                    after_component = false;
+                }
+                Some(c) if !DELIMITERS.contains(&c) => {
+                    self.component()?;
+                    after_component = true;
+                }
+                _ => return Err(self.cursor.unexpected()),
+            }
+        }
+    }
+
+    /// Skips a prefix like `matrix!` or `np.array(`, and returns `true` if it must be followed by
+    /// a closing parenthesis.
+    fn prefix(&mut self) -> Result<bool, ParseMatrixError<T::Err>> {
+        let mut lookahead = self.cursor;
+        while matches!(lookahead.peek(), Some(c) if c.is_alphanumeric() || c == '_' || c == '.') {
+            lookahead.bump();
+        }
+
+        if lookahead.pos == self.cursor.pos
+            || !matches!(self.cursor.peek(), Some(c) if c.is_alphabetic() || c == '_')
+        {
+            return Ok(false);
+        }
+
+        lookahead.skip_whitespaces(true);
+        match lookahead.peek() {
+            Some('!') => {
+                lookahead.bump();
+                lookahead.skip_whitespaces(true);
+                if lookahead.peek() != Some('[') {
+                    return Err(lookahead.unexpected());
+                }
+                self.cursor = lookahead;
+                Ok(false)
+            }
+            Some('(') => {
+                lookahead.bump();
+                self.cursor = lookahead;
+                Ok(true)
+            }
+            // This is not a prefix, but a component like `inf`.
+            _ => Ok(false),
+        }
+    }
+
+    fn matrix(&mut self) -> Result<(), ParseMatrixError<T::Err>> {
+        self.cursor.skip_whitespaces(true);
+        let parenthesized = self.prefix()?;
+        self.cursor.skip_whitespaces(true);
+
+        if self.cursor.peek() == Some('[') {
+            self.cursor.bump();
+            self.cursor.skip_whitespaces(true);
+            if self.cursor.peek() == Some('[') {
+                self.nested_rows()?;
+            } else {
+                self.flat_rows(Some(']'))?;
+            }
+        } else if parenthesized {
+            return Err(self.cursor.unexpected());
+        } else {
+            self.flat_rows(None)?;
+        }
+
+        self.cursor.skip_whitespaces(true);
+        if parenthesized {
+            if self.cursor.peek() != Some(')') {
+                return Err(self.cursor.unexpected());
+            }
+            self.cursor.bump();
+            self.cursor.skip_whitespaces(true);
+        }
+
+        if self.cursor.peek().is_some() {
+            return Err(self.cursor.unexpected());
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a matrix and returns its number of rows, its number of columns, and its components in
+/// row-major order.
+///
+/// See the [module-level documentation](self) for the accepted syntaxes.
+///
+/// # Example
+/// ```
+/// # use nalgebra::parse::{parse_row_major, ParseMatrixErrorKind};
+/// let expected = (2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+/// assert_eq!(parse_row_major::<f64>("[1 2 3; 4 5 6]"), Ok(expected.clone()));
+/// assert_eq!(parse_row_major::<f64>("[[1, 2, 3], [4, 5, 6]]"), Ok(expected.clone()));
+/// assert_eq!(parse_row_major::<f64>("1 2 3\n4 5 6\n"), Ok(expected));
+///
+/// let err = parse_row_major::<f64>("[1 2 3;\n 4 x 6]").unwrap_err();
+/// assert!(matches!(err.kind, ParseMatrixErrorKind::InvalidComponent(_)));
+/// assert_eq!((err.line, err.column), (2, 4));
+/// ```
+pub fn parse_row_major<T: FromStr>(
+    input: &str,
+) -> Result<(usize, usize, Vec<T>), ParseMatrixError<T::Err>> {
+    let cursor = Cursor {
+        input,
+        pos: 0,
+        line: 1,
+        column: 1,
+    };
+    let mut parser = Parser {
+        cursor,
+        data: Vec::new(),
+        nrows: 0,
+        ncols: None,
+        row_len: 0,
+        row_start: cursor,
+    };
+
+    parser.matrix()?;
+    Ok((parser.nrows, parser.ncols.unwrap_or(0), parser.data))
+}
+
+impl<T, R: Dim, C: Dim> FromStr for OMatrix<T, R, C>
+where
+    T: Scalar + FromStr,
+    DefaultAllocator: Allocator<T, R, C>,
+{
+    type Err = ParseMatrixError<T::Err>;
+
+    /// Parses a matrix written in any of the syntaxes accepted by
+    /// [`parse_row_major`](crate::parse::parse_row_major).
+    ///
+    /// A single row is accepted for a matrix with a single column, e.g., when parsing a vector
+    /// written as `[1, 2, 3]`. An error is returned if the shape of the parsed matrix does not
+    /// match the statically-known dimensions of `R` or `C`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mut nrows, mut ncols, data) = parse_row_major(s)?;
+
+        if C::try_to_usize() == Some(1) && nrows == 1 && R::try_to_usize().unwrap_or(ncols) == ncols
+        {
+            std::mem::swap(&mut nrows, &mut ncols);
+        }
+
+        let expected = (
+            R::try_to_usize().unwrap_or(nrows),
+            C::try_to_usize().unwrap_or(ncols),
+        );
+        if expected != (nrows, ncols) {
+            let start = s.len() - s.trim_start().len();
+            let line = 1 + s[..start].matches('\n').count();
+            let column = 1 + s[..start]
+                .rsplit('\n')
+                .next()
+                .map_or(0, |l| l.chars().count());
+
+            return Err(ParseMatrixError {
+                kind: ParseMatrixErrorKind::ShapeMismatch(ShapeMismatch::new(
+                    (nrows, ncols),
+                    expected,
+                )),
+                line,
+                column,
+            });
+        }
+
+        Ok(Self::from_row_slice_generic(
+            R::from_usize(nrows),
+            C::from_usize(ncols),
+            &data,
+        ))
+    }
+}
+
+impl<T, D: DimName> FromStr for OPoint<T, D>
+where
+    T: Scalar + FromStr,
+    DefaultAllocator: Allocator<T, D>,
+{
+    type Err = ParseMatrixError<T::Err>;
+
+    /// Parses the coordinates of a point, e.g., `[1, 2, 3]` or `point![1.0, 2.0, 3.0]`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        OVector::<T, D>::from_str(s).map(Self::from)
+    }
+}
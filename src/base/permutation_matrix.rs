@@ -0,0 +1,285 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use std::ops::Mul;
+
+use num::{One, Zero};
+use simba::scalar::ClosedNeg;
+
+use crate::base::allocator::Allocator;
+use crate::base::constraint::{DimEq, SameNumberOfRows, ShapeConstraint};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::dimension::Dynamic;
+use crate::base::dimension::{Dim, DimName, U1};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector, Scalar};
+
+/// A square matrix with exactly one component equal to one on each row and each column, all the
+/// other components being zero.
+///
+/// Only the column index of the nonzero component of each row is stored: multiplying this matrix
+/// by `m` yields the matrix whose `i`-th row is the `self.indices()[i]`-th row of `m`. Products
+/// with other matrices thus only move components around and take `O(nrows * ncols)` operations.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<usize, D>,
+         OVector<usize, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<usize, D>,
+         OVector<usize, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PermutationMatrix<D: Dim>
+where
+    DefaultAllocator: Allocator<usize, D>,
+{
+    perm: OVector<usize, D>,
+}
+
+impl<D: Dim> Copy for PermutationMatrix<D>
+where
+    DefaultAllocator: Allocator<usize, D>,
+    OVector<usize, D>: Copy,
+{
+}
+
+impl<D: DimName> PermutationMatrix<D>
+where
+    DefaultAllocator: Allocator<usize, D>,
+{
+    /// Creates a new statically-sized identity permutation matrix.
+    #[inline]
+    pub fn identity() -> Self {
+        Self::identity_generic(D::name())
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl PermutationMatrix<Dynamic>
+where
+    DefaultAllocator: Allocator<usize, Dynamic>,
+{
+    /// Creates a new dynamically-sized identity permutation matrix with `n` rows and columns.
+    #[inline]
+    pub fn identity(n: usize) -> Self {
+        Self::identity_generic(Dynamic::new(n))
+    }
+}
+
+/// Sorts `perm` in-place with cyclic swaps, and returns the number of swaps performed, or `None`
+/// if `perm` is not a permutation of `0..perm.len()`.
+fn cycle_sort<D: Dim>(perm: &mut OVector<usize, D>) -> Option<usize>
+where
+    DefaultAllocator: Allocator<usize, D>,
+{
+    let n = perm.len();
+    let mut nswaps = 0;
+
+    for i in 0..n {
+        while perm[i] != i {
+            let j = perm[i];
+            if j >= n || perm[j] == j {
+                return None;
+            }
+            perm.swap_rows(i, j);
+            nswaps += 1;
+        }
+    }
+
+    Some(nswaps)
+}
+
+impl<D: Dim> PermutationMatrix<D>
+where
+    DefaultAllocator: Allocator<usize, D>,
+{
+    /// Creates a permutation matrix such that the `i`-th row of `self * m` is the
+    /// `indices[i]`-th row of `m`.
+    ///
+    /// Returns `None` if `indices` is not a permutation of `0..indices.len()`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix3x2, PermutationMatrix, Vector3};
+    /// let p = PermutationMatrix::new(Vector3::new(2, 0, 1)).unwrap();
+    /// let m = Matrix3x2::new(1.0, 2.0,
+    ///                        3.0, 4.0,
+    ///                        5.0, 6.0);
+    /// assert_eq!(&p * &m, Matrix3x2::new(5.0, 6.0,
+    ///                                    1.0, 2.0,
+    ///                                    3.0, 4.0));
+    /// assert_eq!(&p * &m, p.to_dense() * m);
+    /// assert!(PermutationMatrix::new(Vector3::new(2, 0, 2)).is_none());
+    /// ```
+    #[inline]
+    pub fn new(indices: OVector<usize, D>) -> Option<Self> {
+        if cycle_sort(&mut indices.clone()).is_some() {
+            Some(Self { perm: indices })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a permutation matrix without checking that `indices` is a permutation of
+    /// `0..indices.len()`.
+    ///
+    /// The operations of the resulting matrix may panic or produce meaningless results if
+    /// `indices` is not a permutation.
+    #[inline]
+    pub fn new_unchecked(indices: OVector<usize, D>) -> Self {
+        Self { perm: indices }
+    }
+
+    /// Creates a new identity permutation matrix with the given dimension.
+    #[inline]
+    pub fn identity_generic(dim: D) -> Self {
+        Self {
+            perm: OVector::from_fn_generic(dim, U1::name(), |i, _| i),
+        }
+    }
+
+    /// The number of rows (and columns) of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn dim(&self) -> usize {
+        self.perm.len()
+    }
+
+    /// The column index of the nonzero component of each row of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn indices(&self) -> &OVector<usize, D> {
+        &self.perm
+    }
+
+    /// Retrieves the column index of the nonzero component of each row of this matrix.
+    #[inline]
+    pub fn into_inner(self) -> OVector<usize, D> {
+        self.perm
+    }
+
+    /// The inverse of this matrix, which is also its transpose.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let mut inv = self.perm.clone();
+        for (i, p) in self.perm.iter().enumerate() {
+            inv[*p] = i;
+        }
+        Self { perm: inv }
+    }
+
+    /// The transpose of this matrix, which is also its inverse.
+    #[inline]
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        self.inverse()
+    }
+
+    /// The determinant of this matrix, i.e., the signature of the permutation.
+    #[inline]
+    #[must_use]
+    pub fn determinant<T: One + ClosedNeg>(&self) -> T {
+        let nswaps = cycle_sort(&mut self.perm.clone())
+            .expect("Permutation matrix: the indices are not a permutation.");
+
+        if nswaps % 2 == 0 {
+            T::one()
+        } else {
+            -T::one()
+        }
+    }
+
+    /// Converts this permutation matrix into a regular dense matrix.
+    #[inline]
+    #[must_use]
+    pub fn to_dense<T: Scalar + Zero + One>(&self) -> OMatrix<T, D, D>
+    where
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        let dim = self.perm.shape_generic().0;
+        let mut res = OMatrix::zeros_generic(dim, dim);
+        for (i, p) in self.perm.iter().enumerate() {
+            res[(i, *p)] = T::one();
+        }
+        res
+    }
+}
+
+impl<'b, T, D: Dim, R2: Dim, C2: Dim, S2> Mul<&'b Matrix<T, R2, C2, S2>> for &PermutationMatrix<D>
+where
+    T: Scalar,
+    S2: Storage<T, R2, C2>,
+    DefaultAllocator: Allocator<usize, D> + Allocator<T, R2, C2>,
+    ShapeConstraint: SameNumberOfRows<R2, D>,
+{
+    type Output = OMatrix<T, R2, C2>;
+
+    #[inline]
+    fn mul(self, rhs: &'b Matrix<T, R2, C2, S2>) -> Self::Output {
+        assert_eq!(
+            rhs.nrows(),
+            self.dim(),
+            "Permutation matrix multiplication: dimensions mismatch."
+        );
+
+        let (nrows, ncols) = rhs.shape_generic();
+        OMatrix::from_fn_generic(nrows, ncols, |i, j| rhs[(self.perm[i], j)].clone())
+    }
+}
+
+impl<'b, T, D: Dim, R1: Dim, C1: Dim, S1> Mul<&'b PermutationMatrix<D>> for &Matrix<T, R1, C1, S1>
+where
+    T: Scalar,
+    S1: Storage<T, R1, C1>,
+    DefaultAllocator: Allocator<usize, D> + Allocator<T, R1, C1>,
+    ShapeConstraint: DimEq<C1, D>,
+{
+    type Output = OMatrix<T, R1, C1>;
+
+    #[inline]
+    fn mul(self, rhs: &'b PermutationMatrix<D>) -> Self::Output {
+        assert_eq!(
+            self.ncols(),
+            rhs.dim(),
+            "Permutation matrix multiplication: dimensions mismatch."
+        );
+
+        let inv = rhs.inverse();
+        let (nrows, ncols) = self.shape_generic();
+        OMatrix::from_fn_generic(nrows, ncols, |i, j| self[(i, inv.perm[j])].clone())
+    }
+}
+
+impl<'b, D: Dim> Mul<&'b PermutationMatrix<D>> for &PermutationMatrix<D>
+where
+    DefaultAllocator: Allocator<usize, D>,
+{
+    type Output = PermutationMatrix<D>;
+
+    #[inline]
+    fn mul(self, rhs: &'b PermutationMatrix<D>) -> Self::Output {
+        assert_eq!(
+            self.dim(),
+            rhs.dim(),
+            "Permutation matrix multiplication: dimensions mismatch."
+        );
+
+        PermutationMatrix {
+            perm: self.perm.map(|i| rhs.perm[i]),
+        }
+    }
+}
+
+impl<T: Scalar + Zero + One, D: Dim> From<PermutationMatrix<D>> for OMatrix<T, D, D>
+where
+    DefaultAllocator: Allocator<usize, D> + Allocator<T, D, D>,
+{
+    #[inline]
+    fn from(perm: PermutationMatrix<D>) -> Self {
+        perm.to_dense()
+    }
+}
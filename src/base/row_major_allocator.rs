@@ -0,0 +1,228 @@
+//! A matrix data storage allocator producing row-major buffers.
+//!
+//! This will use stack-allocated buffers for matrices with dimensions known at compile-time, and
+//! heap-allocated buffers for matrices with at least one dimension unknown at compile-time.
+//!
+//! The arithmetic operators and methods like `map`, `transpose` or `clone_owned` allocate their
+//! result with the `DefaultAllocator`. Their `_in` counterparts (`add_in`, `sub_in`, `mul_in`,
+//! `map_in`, `transpose_in`, `clone_owned_in`, `into_owned_in`) take the allocator as a type
+//! parameter, so `a.mul_in::<RowMajorAllocator, _, _, _>(&b)` returns a row-major matrix.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use crate::base::allocator::Allocator;
+#[cfg(any(feature = "alloc", feature = "std"))]
+use crate::base::dimension::DimName;
+#[cfg(any(feature = "alloc", feature = "std"))]
+use crate::base::dimension::Dynamic;
+use crate::base::dimension::{Const, Dim};
+use crate::base::row_major_storage::RowMajorArrayStorage;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::row_major_storage::{column_major_to_row_major, RowMajorVecStorage};
+use crate::base::storage::{RawStorage, RawStorageMut};
+use crate::base::{Matrix, RowMajorOMatrix, Scalar};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use std::mem::ManuallyDrop;
+use std::mem::MaybeUninit;
+
+/*
+ *
+ * Allocator.
+ *
+ */
+/// An allocator based on `RowMajorArrayStorage` and `RowMajorVecStorage` for statically-sized and
+/// dynamically-sized matrices respectively.
+///
+/// The iterator given to `allocate_from_iterator` is still read in column-major order, like for
+/// the `DefaultAllocator`: only the memory layout of the resulting buffer differs.
+///
+/// Pass it to the `_in` methods, e.g., `transpose_in::<RowMajorAllocator>()`, to get row-major
+/// results.
+#[derive(Copy, Clone, Debug)]
+pub struct RowMajorAllocator;
+
+// Static - Static
+impl<T: Scalar, const R: usize, const C: usize> Allocator<T, Const<R>, Const<C>>
+    for RowMajorAllocator
+{
+    type Buffer = RowMajorArrayStorage<T, R, C>;
+    type BufferUninit = RowMajorArrayStorage<MaybeUninit<T>, R, C>;
+
+    #[inline(always)]
+    fn allocate_uninit(_: Const<R>, _: Const<C>) -> RowMajorArrayStorage<MaybeUninit<T>, R, C> {
+        // SAFETY: An uninitialized `[MaybeUninit<_>; _]` is valid.
+        let array: [[MaybeUninit<T>; C]; R] = unsafe { MaybeUninit::uninit().assume_init() };
+        RowMajorArrayStorage(array)
+    }
+
+    #[inline(always)]
+    unsafe fn assume_init(
+        uninit: RowMajorArrayStorage<MaybeUninit<T>, R, C>,
+    ) -> RowMajorArrayStorage<T, R, C> {
+        // Safety:
+        // * The caller guarantees that all elements of the array are initialized
+        // * `MaybeUninit<T>` and T are guaranteed to have the same layout
+        // * `MaybeUninit` does not drop, so there are no double-frees
+        // And thus the conversion is safe
+        RowMajorArrayStorage((&uninit as *const _ as *const [_; R]).read())
+    }
+
+    #[inline]
+    fn allocate_from_iterator<I: IntoIterator<Item = T>>(
+        nrows: Const<R>,
+        ncols: Const<C>,
+        iter: I,
+    ) -> Self::Buffer {
+        let mut res = Self::allocate_uninit(nrows, ncols);
+        let mut count = 0;
+
+        // Safety: conversion to a slice is OK because the Buffer is known to be contiguous.
+        let res_slice = unsafe { res.as_mut_slice_unchecked() };
+        for (k, e) in iter.into_iter().take(R * C).enumerate() {
+            // The iterator yields the components in column-major order.
+            res_slice[(k % R) * C + k / R] = MaybeUninit::new(e);
+            count += 1;
+        }
+
+        assert!(
+            count == nrows.value() * ncols.value(),
+            "Matrix init. from iterator: iterator not long enough."
+        );
+
+        // Safety: the assertion above made sure that the iterator
+        //         yielded enough elements to initialize our matrix.
+        unsafe { <Self as Allocator<T, Const<R>, Const<C>>>::assume_init(res) }
+    }
+
+    #[inline]
+    fn allocate_from_row_iterator<I: IntoIterator<Item = T>>(
+        nrows: Const<R>,
+        ncols: Const<C>,
+        iter: I,
+    ) -> Self::Buffer {
+        let mut res = Self::allocate_uninit(nrows, ncols);
+        let mut count = 0;
+
+        // Safety: conversion to a slice is OK because the Buffer is known to be contiguous.
+        let res_slice = unsafe { res.as_mut_slice_unchecked() };
+        for (res, e) in res_slice.iter_mut().zip(iter) {
+            *res = MaybeUninit::new(e);
+            count += 1;
+        }
+
+        assert!(
+            count == nrows.value() * ncols.value(),
+            "Matrix init. from row iterator: iterator not long enough."
+        );
+
+        // Safety: the assertion above made sure that the iterator
+        //         yielded enough elements to initialize our matrix.
+        unsafe { <Self as Allocator<T, Const<R>, Const<C>>>::assume_init(res) }
+    }
+}
+
+macro_rules! row_major_vec_allocator_impl(
+    ($($D: ident: $Bound: ident => ($R: ty, $C: ty));* $(;)*) => {$(
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        impl<T: Scalar, $D: $Bound> Allocator<T, $R, $C> for RowMajorAllocator {
+            type Buffer = RowMajorVecStorage<T, $R, $C>;
+            type BufferUninit = RowMajorVecStorage<MaybeUninit<T>, $R, $C>;
+
+            #[inline]
+            fn allocate_uninit(nrows: $R, ncols: $C) -> RowMajorVecStorage<MaybeUninit<T>, $R, $C> {
+                let mut data = Vec::new();
+                let length = nrows.value() * ncols.value();
+                data.reserve_exact(length);
+                data.resize_with(length, MaybeUninit::uninit);
+
+                RowMajorVecStorage::new(nrows, ncols, data)
+            }
+
+            #[inline]
+            unsafe fn assume_init(
+                uninit: RowMajorVecStorage<MaybeUninit<T>, $R, $C>,
+            ) -> RowMajorVecStorage<T, $R, $C> {
+                // Avoids a double-drop.
+                let (nrows, ncols) = uninit.shape();
+                let vec: Vec<_> = uninit.into();
+                let mut md = ManuallyDrop::new(vec);
+
+                // Safety:
+                // - MaybeUninit<T> has the same alignment and layout as T.
+                // - The length and capacity come from a valid vector.
+                let new_data =
+                    Vec::from_raw_parts(md.as_mut_ptr() as *mut _, md.len(), md.capacity());
+
+                RowMajorVecStorage::new(nrows, ncols, new_data)
+            }
+
+            #[inline]
+            fn allocate_from_iterator<I: IntoIterator<Item = T>>(
+                nrows: $R,
+                ncols: $C,
+                iter: I,
+            ) -> Self::Buffer {
+                let mut res: Vec<T> = iter.into_iter().collect();
+                assert!(res.len() == nrows.value() * ncols.value(),
+                        "Allocation from iterator error: the iterator did not yield the correct number of elements.");
+
+                // The iterator yields the components in column-major order.
+                column_major_to_row_major(&mut res, nrows.value(), ncols.value());
+                RowMajorVecStorage::new(nrows, ncols, res)
+            }
+
+            #[inline]
+            fn allocate_from_row_iterator<I: IntoIterator<Item = T>>(
+                nrows: $R,
+                ncols: $C,
+                iter: I,
+            ) -> Self::Buffer {
+                let res: Vec<T> = iter.into_iter().collect();
+                assert!(res.len() == nrows.value() * ncols.value(),
+                        "Allocation from iterator error: the iterator did not yield the correct number of elements.");
+
+                RowMajorVecStorage::new(nrows, ncols, res)
+            }
+        }
+    )*}
+);
+
+row_major_vec_allocator_impl!(
+    // Dynamic - Static
+    // Dynamic - Dynamic
+    C: Dim => (Dynamic, C);
+    // Static - Dynamic
+    R: DimName => (R, Dynamic);
+);
+
+/// # Row-major conversion
+impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
+    /// Clones this matrix into an owned matrix with a row-major layout.
+    ///
+    /// The result is stack-allocated if both dimensions are known at compile-time, and
+    /// heap-allocated otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    /// let rm = m.to_row_major();
+    /// assert_eq!(rm.data.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(rm, m);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_row_major(&self) -> RowMajorOMatrix<T, R, C>
+    where
+        RowMajorAllocator: Allocator<T, R, C>,
+    {
+        let (nrows, ncols) = self.shape_generic();
+        Matrix::from_data(RowMajorAllocator::allocate_from_iterator(
+            nrows,
+            ncols,
+            self.iter().cloned(),
+        ))
+    }
+}
@@ -0,0 +1,434 @@
+//! Row-major matrix data storages.
+//!
+//! These storages lay out their components row-by-row, which matches the memory layout used by
+//! most C APIs, image buffers and NumPy arrays. Matrices backed by them are regular `Matrix`
+//! values: indexing, slicing, iteration and arithmetic all go through the strides of the storage.
+
+use std::fmt::{self, Debug, Formatter};
+use std::mem::{ManuallyDrop, MaybeUninit};
+use std::ptr;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
+
+use crate::base::allocator::Allocator;
+use crate::base::default_allocator::DefaultAllocator;
+use crate::base::dimension::{Const, U1};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::dimension::{Dim, DimName};
+use crate::base::storage::{IsContiguous, Owned, RawStorage, RawStorageMut, Storage};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::vec_storage::VecStorage;
+use crate::base::{ArrayStorage, Matrix, Scalar};
+
+/*
+ *
+ * Static row-major storage.
+ *
+ */
+/// A array-based statically sized matrix data storage with a row-major layout.
+///
+/// Each inner array is one row of the matrix, so `[[1, 2, 3], [4, 5, 6]]` is the 2x3 matrix whose
+/// first row is `1, 2, 3`.
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RowMajorArrayStorage<T, const R: usize, const C: usize>(pub [[T; C]; R]);
+
+impl<T, const R: usize, const C: usize> RowMajorArrayStorage<T, R, C> {
+    /// Converts this array storage to a slice, in row-major order.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: this is OK because RowMajorArrayStorage is contiguous.
+        unsafe { self.as_slice_unchecked() }
+    }
+
+    /// Converts this array storage to a mutable slice, in row-major order.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: this is OK because RowMajorArrayStorage is contiguous.
+        unsafe { self.as_mut_slice_unchecked() }
+    }
+}
+
+impl<T: Debug, const R: usize, const C: usize> Debug for RowMajorArrayStorage<T, R, C> {
+    #[inline]
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        self.0.fmt(fmt)
+    }
+}
+
+unsafe impl<T, const R: usize, const C: usize> RawStorage<T, Const<R>, Const<C>>
+    for RowMajorArrayStorage<T, R, C>
+{
+    type RStride = Const<C>;
+    type CStride = U1;
+
+    #[inline]
+    fn ptr(&self) -> *const T {
+        self.0.as_ptr() as *const T
+    }
+
+    #[inline]
+    fn shape(&self) -> (Const<R>, Const<C>) {
+        (Const, Const)
+    }
+
+    #[inline]
+    fn strides(&self) -> (Self::RStride, Self::CStride) {
+        (Const, Const)
+    }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    unsafe fn as_slice_unchecked(&self) -> &[T] {
+        std::slice::from_raw_parts(self.ptr(), R * C)
+    }
+}
+
+unsafe impl<T: Scalar, const R: usize, const C: usize> Storage<T, Const<R>, Const<C>>
+    for RowMajorArrayStorage<T, R, C>
+{
+    #[inline]
+    fn into_owned(self) -> Owned<T, Const<R>, Const<C>>
+    where
+        DefaultAllocator: Allocator<T, Const<R>, Const<C>>,
+    {
+        DefaultAllocator::allocate_from_row_iterator(
+            Const,
+            Const,
+            IntoIterator::into_iter(self.0).flat_map(IntoIterator::into_iter),
+        )
+    }
+
+    #[inline]
+    fn clone_owned(&self) -> Owned<T, Const<R>, Const<C>>
+    where
+        DefaultAllocator: Allocator<T, Const<R>, Const<C>>,
+    {
+        DefaultAllocator::allocate_from_row_iterator(Const, Const, self.as_slice().iter().cloned())
+    }
+}
+
+unsafe impl<T, const R: usize, const C: usize> RawStorageMut<T, Const<R>, Const<C>>
+    for RowMajorArrayStorage<T, R, C>
+{
+    #[inline]
+    fn ptr_mut(&mut self) -> *mut T {
+        self.0.as_mut_ptr() as *mut T
+    }
+
+    #[inline]
+    unsafe fn as_mut_slice_unchecked(&mut self) -> &mut [T] {
+        std::slice::from_raw_parts_mut(self.ptr_mut(), R * C)
+    }
+}
+
+unsafe impl<T, const R: usize, const C: usize> IsContiguous for RowMajorArrayStorage<T, R, C> {}
+
+/*
+ *
+ * Dynamic row-major storage.
+ *
+ */
+/// A Vec-based matrix data storage with a row-major layout. It may be dynamically-sized.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[repr(C)]
+#[derive(Eq, Debug, Clone, PartialEq)]
+pub struct RowMajorVecStorage<T, R: Dim, C: Dim> {
+    data: Vec<T>,
+    nrows: R,
+    ncols: C,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T, R: Dim, C: Dim> RowMajorVecStorage<T, R, C> {
+    /// Creates a new row-major matrix data storage from the given vector and shape.
+    ///
+    /// The vector must contain the matrix components row-by-row.
+    #[inline]
+    pub fn new(nrows: R, ncols: C, data: Vec<T>) -> Self {
+        assert!(
+            nrows.value() * ncols.value() == data.len(),
+            "Data storage buffer dimension mismatch."
+        );
+        Self { data, nrows, ncols }
+    }
+
+    /// The underlying data storage.
+    #[inline]
+    #[must_use]
+    pub fn as_vec(&self) -> &Vec<T> {
+        &self.data
+    }
+
+    /// The underlying mutable data storage.
+    ///
+    /// # Safety
+    /// This is unsafe because this may cause UB if the size of the vector is changed
+    /// by the user.
+    #[inline]
+    pub unsafe fn as_vec_mut(&mut self) -> &mut Vec<T> {
+        &mut self.data
+    }
+
+    /// The number of elements on the underlying vector.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if the underlying vector contains no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// A slice containing all the components stored in this storage in row-major order.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data[..]
+    }
+
+    /// A mutable slice containing all the components stored in this storage in row-major order.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data[..]
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T, R: Dim, C: Dim> From<RowMajorVecStorage<T, R, C>> for Vec<T> {
+    fn from(vec: RowMajorVecStorage<T, R, C>) -> Self {
+        vec.data
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+unsafe impl<T, R: Dim, C: Dim> RawStorage<T, R, C> for RowMajorVecStorage<T, R, C> {
+    type RStride = C;
+    type CStride = U1;
+
+    #[inline]
+    fn ptr(&self) -> *const T {
+        self.data.as_ptr()
+    }
+
+    #[inline]
+    fn shape(&self) -> (R, C) {
+        (self.nrows, self.ncols)
+    }
+
+    #[inline]
+    fn strides(&self) -> (Self::RStride, Self::CStride) {
+        (self.ncols, U1::name())
+    }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    unsafe fn as_slice_unchecked(&self) -> &[T] {
+        &self.data
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+unsafe impl<T: Scalar, R: Dim, C: Dim> Storage<T, R, C> for RowMajorVecStorage<T, R, C> {
+    #[inline]
+    fn into_owned(self) -> Owned<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        DefaultAllocator::allocate_from_row_iterator(self.nrows, self.ncols, self.data)
+    }
+
+    #[inline]
+    fn clone_owned(&self) -> Owned<T, R, C>
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        DefaultAllocator::allocate_from_row_iterator(
+            self.nrows,
+            self.ncols,
+            self.data.iter().cloned(),
+        )
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+unsafe impl<T, R: Dim, C: Dim> RawStorageMut<T, R, C> for RowMajorVecStorage<T, R, C> {
+    #[inline]
+    fn ptr_mut(&mut self) -> *mut T {
+        self.data.as_mut_ptr()
+    }
+
+    #[inline]
+    unsafe fn as_mut_slice_unchecked(&mut self) -> &mut [T] {
+        &mut self.data[..]
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+unsafe impl<T, R: Dim, C: Dim> IsContiguous for RowMajorVecStorage<T, R, C> {}
+
+/// Reorders in-place the components of a `nrows x ncols` matrix stored in column-major order so
+/// that they end up in row-major order.
+///
+/// Calling this with `nrows` and `ncols` swapped performs the opposite conversion.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub(crate) fn column_major_to_row_major<T>(data: &mut [T], nrows: usize, ncols: usize) {
+    assert_eq!(data.len(), nrows * ncols);
+    let mut visited = vec![false; data.len()];
+
+    // The component at the column-major index `i + j * nrows` moves to the row-major index
+    // `i * ncols + j`. Follow each permutation cycle once, using its first slot as a buffer.
+    for start in 0..data.len() {
+        if visited[start] {
+            continue;
+        }
+
+        let mut k = start;
+        loop {
+            visited[k] = true;
+            let dest = (k % nrows) * ncols + k / nrows;
+
+            if dest == start {
+                break;
+            }
+
+            data.swap(start, dest);
+            k = dest;
+        }
+    }
+}
+
+/*
+ *
+ * Layout conversion.
+ *
+ */
+/// # Memory layout conversion
+impl<T, const R: usize, const C: usize> Matrix<T, Const<R>, Const<C>, ArrayStorage<T, R, C>> {
+    /// Moves the components of this column-major matrix into a row-major one, without cloning
+    /// them.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2x3, RowMajorArrayStorage};
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    /// let rm = m.into_row_major();
+    /// assert_eq!(rm.data, RowMajorArrayStorage([[1, 2, 3], [4, 5, 6]]));
+    /// assert_eq!(rm, m);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_row_major(self) -> Matrix<T, Const<R>, Const<C>, RowMajorArrayStorage<T, R, C>> {
+        // SAFETY: an uninitialized `[MaybeUninit<_>; _]` is valid.
+        let mut res: [[MaybeUninit<T>; C]; R] = unsafe { MaybeUninit::uninit().assume_init() };
+        let src = ManuallyDrop::new(self.data.0);
+
+        for j in 0..C {
+            for i in 0..R {
+                // SAFETY: each component of `src` is read exactly once and `src` is never dropped.
+                res[i][j] = MaybeUninit::new(unsafe { ptr::read(&src[j][i]) });
+            }
+        }
+
+        // SAFETY: every component of `res` has been initialized by the loop above.
+        let data = unsafe { (&res as *const _ as *const [[T; C]; R]).read() };
+        Matrix::from_data(RowMajorArrayStorage(data))
+    }
+}
+
+impl<T, const R: usize, const C: usize>
+    Matrix<T, Const<R>, Const<C>, RowMajorArrayStorage<T, R, C>>
+{
+    /// Moves the components of this row-major matrix into a column-major one, without cloning
+    /// them.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix, Matrix2x3, RowMajorArrayStorage};
+    /// let rm = Matrix::from_data(RowMajorArrayStorage([[1, 2, 3], [4, 5, 6]]));
+    /// assert_eq!(rm.into_column_major(), Matrix2x3::new(1, 2, 3, 4, 5, 6));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_column_major(self) -> Matrix<T, Const<R>, Const<C>, ArrayStorage<T, R, C>> {
+        // SAFETY: an uninitialized `[MaybeUninit<_>; _]` is valid.
+        let mut res: [[MaybeUninit<T>; R]; C] = unsafe { MaybeUninit::uninit().assume_init() };
+        let src = ManuallyDrop::new(self.data.0);
+
+        for i in 0..R {
+            for j in 0..C {
+                // SAFETY: each component of `src` is read exactly once and `src` is never dropped.
+                res[j][i] = MaybeUninit::new(unsafe { ptr::read(&src[i][j]) });
+            }
+        }
+
+        // SAFETY: every component of `res` has been initialized by the loop above.
+        let data = unsafe { (&res as *const _ as *const [[T; R]; C]).read() };
+        Matrix::from_data(ArrayStorage(data))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T, R: Dim, C: Dim> Matrix<T, R, C, VecStorage<T, R, C>>
+where
+    VecStorage<T, R, C>: RawStorage<T, R, C>,
+{
+    /// Converts this column-major matrix into a row-major one by reordering its components
+    /// in-place, reusing the same heap allocation.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]);
+    /// let rm = m.clone().into_row_major();
+    /// assert_eq!(rm.data.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(rm, m);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_row_major(self) -> Matrix<T, R, C, RowMajorVecStorage<T, R, C>> {
+        let (nrows, ncols) = self.shape_generic();
+        let mut data: Vec<T> = self.data.into();
+        column_major_to_row_major(&mut data, nrows.value(), ncols.value());
+        Matrix::from_data(RowMajorVecStorage::new(nrows, ncols, data))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T, R: Dim, C: Dim> Matrix<T, R, C, RowMajorVecStorage<T, R, C>>
+where
+    VecStorage<T, R, C>: RawStorage<T, R, C>,
+{
+    /// Converts this row-major matrix into a column-major one by reordering its components
+    /// in-place, reusing the same heap allocation.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Dynamic, Matrix, RowMajorVecStorage};
+    /// let data = RowMajorVecStorage::new(Dynamic::new(2), Dynamic::new(3), vec![1, 2, 3, 4, 5, 6]);
+    /// let rm = Matrix::from_data(data);
+    /// let m = rm.into_column_major();
+    /// assert_eq!(m, DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_column_major(self) -> Matrix<T, R, C, VecStorage<T, R, C>> {
+        let (nrows, ncols) = self.shape_generic();
+        let mut data: Vec<T> = self.data.into();
+        column_major_to_row_major(&mut data, ncols.value(), nrows.value());
+        Matrix::from_data(VecStorage::new(nrows, ncols, data))
+    }
+}
@@ -0,0 +1,34 @@
+use std::fmt;
+
+/// The error returned by fallible operations when the shapes of their operands are incompatible.
+///
+/// This is returned, e.g., by `Matrix::try_mul` or by the conversion of a `DMatrix` into a
+/// statically-sized matrix, instead of panicking like their infallible counterparts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ShapeMismatch {
+    /// The shape `(nrows, ncols)` of the left operand, or of the converted matrix.
+    pub lhs: (usize, usize),
+    /// The shape `(nrows, ncols)` of the right operand, or the shape expected by the conversion.
+    pub rhs: (usize, usize),
+}
+
+impl ShapeMismatch {
+    /// Creates an error reporting that the shapes `lhs` and `rhs` are incompatible.
+    #[inline]
+    pub fn new(lhs: (usize, usize), rhs: (usize, usize)) -> Self {
+        Self { lhs, rhs }
+    }
+}
+
+impl fmt::Display for ShapeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "mismatched matrix shapes: {}x{} and {}x{}",
+            self.lhs.0, self.lhs.1, self.rhs.0, self.rhs.1
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ShapeMismatch {}
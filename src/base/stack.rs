@@ -0,0 +1,469 @@
+//! Assembly of matrices from blocks: horizontal and vertical stacking, and block-diagonal
+//! matrices.
+
+use num::Zero;
+use std::mem::MaybeUninit;
+
+use crate::base::allocator::Allocator;
+use crate::base::constraint::{SameNumberOfColumns, SameNumberOfRows, ShapeConstraint};
+use crate::base::dimension::{Dim, DimAdd, DimSum};
+use crate::base::storage::{RawStorage, RawStorageMut};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, Scalar};
+
+/// A matrix that can be used as a block of the matrices assembled by [`hstack`], [`vstack`] or
+/// [`block_diagonal`].
+///
+/// This is implemented by all matrices and matrix slices, as well as references to them.
+pub trait Block<T> {
+    /// The type of the number of rows of this block.
+    type Rows: Dim;
+    /// The type of the number of columns of this block.
+    type Cols: Dim;
+
+    /// The number of rows and columns of this block.
+    fn shape_generic(&self) -> (Self::Rows, Self::Cols);
+
+    /// Initializes the components of `out` covered by this block when its top-left corner is
+    /// placed at `start`.
+    fn write_to<R2: Dim, C2: Dim, S2: RawStorageMut<MaybeUninit<T>, R2, C2>>(
+        &self,
+        out: &mut Matrix<MaybeUninit<T>, R2, C2, S2>,
+        start: (usize, usize),
+    );
+}
+
+impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Block<T> for Matrix<T, R, C, S> {
+    type Rows = R;
+    type Cols = C;
+
+    #[inline]
+    fn shape_generic(&self) -> (R, C) {
+        Matrix::shape_generic(self)
+    }
+
+    fn write_to<R2: Dim, C2: Dim, S2: RawStorageMut<MaybeUninit<T>, R2, C2>>(
+        &self,
+        out: &mut Matrix<MaybeUninit<T>, R2, C2, S2>,
+        start: (usize, usize),
+    ) {
+        let mut out = out.slice_mut(start, self.shape());
+
+        for (o, e) in out.iter_mut().zip(self.iter()) {
+            *o = MaybeUninit::new(e.clone());
+        }
+    }
+}
+
+impl<T, B: Block<T>> Block<T> for &B {
+    type Rows = B::Rows;
+    type Cols = B::Cols;
+
+    #[inline]
+    fn shape_generic(&self) -> (Self::Rows, Self::Cols) {
+        (*self).shape_generic()
+    }
+
+    #[inline]
+    fn write_to<R2: Dim, C2: Dim, S2: RawStorageMut<MaybeUninit<T>, R2, C2>>(
+        &self,
+        out: &mut Matrix<MaybeUninit<T>, R2, C2, S2>,
+        start: (usize, usize),
+    ) {
+        (*self).write_to(out, start)
+    }
+}
+
+/// A tuple of blocks that can be stacked horizontally with [`hstack`].
+pub trait HStack<T> {
+    /// The type of the number of rows of the stacked matrix.
+    type Rows: Dim;
+    /// The type of the number of columns of the stacked matrix.
+    type Cols: Dim;
+
+    /// The number of rows and columns of the stacked matrix.
+    ///
+    /// Panics if the blocks do not all have the same number of rows.
+    fn shape_generic(&self) -> (Self::Rows, Self::Cols);
+
+    /// Initializes the components of `out` covered by the stacked matrix when its top-left
+    /// corner is placed at `start`.
+    fn write_to<R2: Dim, C2: Dim, S2: RawStorageMut<MaybeUninit<T>, R2, C2>>(
+        &self,
+        out: &mut Matrix<MaybeUninit<T>, R2, C2, S2>,
+        start: (usize, usize),
+    );
+}
+
+/// A tuple of blocks that can be stacked vertically with [`vstack`].
+pub trait VStack<T> {
+    /// The type of the number of rows of the stacked matrix.
+    type Rows: Dim;
+    /// The type of the number of columns of the stacked matrix.
+    type Cols: Dim;
+
+    /// The number of rows and columns of the stacked matrix.
+    ///
+    /// Panics if the blocks do not all have the same number of columns.
+    fn shape_generic(&self) -> (Self::Rows, Self::Cols);
+
+    /// Initializes the components of `out` covered by the stacked matrix when its top-left
+    /// corner is placed at `start`.
+    fn write_to<R2: Dim, C2: Dim, S2: RawStorageMut<MaybeUninit<T>, R2, C2>>(
+        &self,
+        out: &mut Matrix<MaybeUninit<T>, R2, C2, S2>,
+        start: (usize, usize),
+    );
+}
+
+/// A tuple of blocks that can be placed along the diagonal of a matrix with [`block_diagonal`].
+pub trait BlockDiagonal<T> {
+    /// The type of the number of rows of the block-diagonal matrix.
+    type Rows: Dim;
+    /// The type of the number of columns of the block-diagonal matrix.
+    type Cols: Dim;
+
+    /// The number of rows and columns of the block-diagonal matrix.
+    fn shape_generic(&self) -> (Self::Rows, Self::Cols);
+
+    /// Initializes the components of `out` covered by the diagonal blocks when the top-left
+    /// corner of the block-diagonal matrix is placed at `start`.
+    fn write_to<R2: Dim, C2: Dim, S2: RawStorageMut<MaybeUninit<T>, R2, C2>>(
+        &self,
+        out: &mut Matrix<MaybeUninit<T>, R2, C2, S2>,
+        start: (usize, usize),
+    );
+}
+
+impl<T, X0: Block<T>> HStack<T> for (X0,) {
+    type Rows = X0::Rows;
+    type Cols = X0::Cols;
+
+    #[inline]
+    fn shape_generic(&self) -> (Self::Rows, Self::Cols) {
+        self.0.shape_generic()
+    }
+
+    #[inline]
+    fn write_to<R2: Dim, C2: Dim, S2: RawStorageMut<MaybeUninit<T>, R2, C2>>(
+        &self,
+        out: &mut Matrix<MaybeUninit<T>, R2, C2, S2>,
+        start: (usize, usize),
+    ) {
+        self.0.write_to(out, start)
+    }
+}
+
+impl<T, X0: Block<T>> VStack<T> for (X0,) {
+    type Rows = X0::Rows;
+    type Cols = X0::Cols;
+
+    #[inline]
+    fn shape_generic(&self) -> (Self::Rows, Self::Cols) {
+        self.0.shape_generic()
+    }
+
+    #[inline]
+    fn write_to<R2: Dim, C2: Dim, S2: RawStorageMut<MaybeUninit<T>, R2, C2>>(
+        &self,
+        out: &mut Matrix<MaybeUninit<T>, R2, C2, S2>,
+        start: (usize, usize),
+    ) {
+        self.0.write_to(out, start)
+    }
+}
+
+impl<T, X0: Block<T>> BlockDiagonal<T> for (X0,) {
+    type Rows = X0::Rows;
+    type Cols = X0::Cols;
+
+    #[inline]
+    fn shape_generic(&self) -> (Self::Rows, Self::Cols) {
+        self.0.shape_generic()
+    }
+
+    #[inline]
+    fn write_to<R2: Dim, C2: Dim, S2: RawStorageMut<MaybeUninit<T>, R2, C2>>(
+        &self,
+        out: &mut Matrix<MaybeUninit<T>, R2, C2, S2>,
+        start: (usize, usize),
+    ) {
+        self.0.write_to(out, start)
+    }
+}
+
+/*
+ *
+ * The dimensions of the stacked matrices are computed recursively: the dimensions of the tuple
+ * `(X0, X1, …, Xn)` are obtained by combining the dimensions of `X0` with those of the tuple
+ * `(X1, …, Xn)`.
+ *
+ */
+macro_rules! impl_stack_tuple(
+    ($X0: ident . $i0: tt $(, $X: ident . $i: tt)+) => {
+        impl<T, $X0: Block<T>, $($X: Block<T>),+> HStack<T> for ($X0, $($X),+)
+        where
+            ($($X,)+): HStack<T>,
+            ShapeConstraint: SameNumberOfRows<$X0::Rows, <($($X,)+) as HStack<T>>::Rows>,
+            $X0::Cols: DimAdd<<($($X,)+) as HStack<T>>::Cols>,
+        {
+            type Rows = <ShapeConstraint as SameNumberOfRows<
+                $X0::Rows,
+                <($($X,)+) as HStack<T>>::Rows,
+            >>::Representative;
+            type Cols = DimSum<$X0::Cols, <($($X,)+) as HStack<T>>::Cols>;
+
+            fn shape_generic(&self) -> (Self::Rows, Self::Cols) {
+                let (nrows, ncols) = self.$i0.shape_generic();
+                let (nrows, mut ncols) = (nrows.value(), ncols.value());
+
+                $(
+                    let shape = self.$i.shape_generic();
+                    assert_eq!(
+                        shape.0.value(),
+                        nrows,
+                        "hstack: all the blocks must have the same number of rows."
+                    );
+                    ncols += shape.1.value();
+                )+
+
+                (Self::Rows::from_usize(nrows), Self::Cols::from_usize(ncols))
+            }
+
+            fn write_to<R2: Dim, C2: Dim, S2: RawStorageMut<MaybeUninit<T>, R2, C2>>(
+                &self,
+                out: &mut Matrix<MaybeUninit<T>, R2, C2, S2>,
+                (i, mut j): (usize, usize),
+            ) {
+                self.$i0.write_to(out, (i, j));
+                j += self.$i0.shape_generic().1.value();
+
+                $(
+                    self.$i.write_to(out, (i, j));
+                    j += self.$i.shape_generic().1.value();
+                )+
+
+                let _ = j;
+            }
+        }
+
+        impl<T, $X0: Block<T>, $($X: Block<T>),+> VStack<T> for ($X0, $($X),+)
+        where
+            ($($X,)+): VStack<T>,
+            $X0::Rows: DimAdd<<($($X,)+) as VStack<T>>::Rows>,
+            ShapeConstraint: SameNumberOfColumns<$X0::Cols, <($($X,)+) as VStack<T>>::Cols>,
+        {
+            type Rows = DimSum<$X0::Rows, <($($X,)+) as VStack<T>>::Rows>;
+            type Cols = <ShapeConstraint as SameNumberOfColumns<
+                $X0::Cols,
+                <($($X,)+) as VStack<T>>::Cols,
+            >>::Representative;
+
+            fn shape_generic(&self) -> (Self::Rows, Self::Cols) {
+                let (nrows, ncols) = self.$i0.shape_generic();
+                let (mut nrows, ncols) = (nrows.value(), ncols.value());
+
+                $(
+                    let shape = self.$i.shape_generic();
+                    assert_eq!(
+                        shape.1.value(),
+                        ncols,
+                        "vstack: all the blocks must have the same number of columns."
+                    );
+                    nrows += shape.0.value();
+                )+
+
+                (Self::Rows::from_usize(nrows), Self::Cols::from_usize(ncols))
+            }
+
+            fn write_to<R2: Dim, C2: Dim, S2: RawStorageMut<MaybeUninit<T>, R2, C2>>(
+                &self,
+                out: &mut Matrix<MaybeUninit<T>, R2, C2, S2>,
+                (mut i, j): (usize, usize),
+            ) {
+                self.$i0.write_to(out, (i, j));
+                i += self.$i0.shape_generic().0.value();
+
+                $(
+                    self.$i.write_to(out, (i, j));
+                    i += self.$i.shape_generic().0.value();
+                )+
+
+                let _ = i;
+            }
+        }
+
+        impl<T, $X0: Block<T>, $($X: Block<T>),+> BlockDiagonal<T> for ($X0, $($X),+)
+        where
+            ($($X,)+): BlockDiagonal<T>,
+            $X0::Rows: DimAdd<<($($X,)+) as BlockDiagonal<T>>::Rows>,
+            $X0::Cols: DimAdd<<($($X,)+) as BlockDiagonal<T>>::Cols>,
+        {
+            type Rows = DimSum<$X0::Rows, <($($X,)+) as BlockDiagonal<T>>::Rows>;
+            type Cols = DimSum<$X0::Cols, <($($X,)+) as BlockDiagonal<T>>::Cols>;
+
+            fn shape_generic(&self) -> (Self::Rows, Self::Cols) {
+                let (nrows, ncols) = self.$i0.shape_generic();
+                let (mut nrows, mut ncols) = (nrows.value(), ncols.value());
+
+                $(
+                    let shape = self.$i.shape_generic();
+                    nrows += shape.0.value();
+                    ncols += shape.1.value();
+                )+
+
+                (Self::Rows::from_usize(nrows), Self::Cols::from_usize(ncols))
+            }
+
+            fn write_to<R2: Dim, C2: Dim, S2: RawStorageMut<MaybeUninit<T>, R2, C2>>(
+                &self,
+                out: &mut Matrix<MaybeUninit<T>, R2, C2, S2>,
+                (mut i, mut j): (usize, usize),
+            ) {
+                self.$i0.write_to(out, (i, j));
+                let shape = self.$i0.shape_generic();
+                i += shape.0.value();
+                j += shape.1.value();
+
+                $(
+                    self.$i.write_to(out, (i, j));
+                    let shape = self.$i.shape_generic();
+                    i += shape.0.value();
+                    j += shape.1.value();
+                )+
+
+                let _ = (i, j);
+            }
+        }
+    }
+);
+
+impl_stack_tuple!(X0.0, X1.1);
+impl_stack_tuple!(X0.0, X1.1, X2.2);
+impl_stack_tuple!(X0.0, X1.1, X2.2, X3.3);
+impl_stack_tuple!(X0.0, X1.1, X2.2, X3.3, X4.4);
+impl_stack_tuple!(X0.0, X1.1, X2.2, X3.3, X4.4, X5.5);
+impl_stack_tuple!(X0.0, X1.1, X2.2, X3.3, X4.4, X5.5, X6.6);
+impl_stack_tuple!(X0.0, X1.1, X2.2, X3.3, X4.4, X5.5, X6.6, X7.7);
+
+/// Stacks a tuple of matrices horizontally, i.e., concatenates their columns.
+///
+/// The blocks may be any mix of statically-sized and dynamically-sized matrices or matrix
+/// slices. The number of rows of the result is a type-level constant if at least one of the
+/// blocks has a statically-known number of rows, and the number of columns is a type-level
+/// constant if all the blocks have a statically-known number of columns. Up to eight blocks
+/// are supported.
+///
+/// # Panics
+/// Panics if the blocks do not all have the same number of rows.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{hstack, DMatrix, Matrix2, Matrix2x3, Vector2};
+/// let a = Matrix2::new(1, 2,
+///                      3, 4);
+/// let b = Vector2::new(5, 6);
+/// let c = DMatrix::from_row_slice(2, 1, &[7, 8]);
+///
+/// // The number of rows is known at compile-time thanks to `a` and `b`.
+/// let m = hstack((&a, &b, &c));
+/// assert_eq!(m.shape(), (2, 4));
+/// assert_eq!(m.column(3), c.column(0));
+///
+/// let m: Matrix2x3<i32> = hstack((&a, &b));
+/// assert_eq!(m, Matrix2x3::new(1, 2, 5,
+///                              3, 4, 6));
+/// ```
+#[inline]
+pub fn hstack<T: Scalar, X: HStack<T>>(blocks: X) -> OMatrix<T, X::Rows, X::Cols>
+where
+    DefaultAllocator: Allocator<T, X::Rows, X::Cols>,
+{
+    let (nrows, ncols) = blocks.shape_generic();
+    let mut res = Matrix::uninit(nrows, ncols);
+    blocks.write_to(&mut res, (0, 0));
+    // Safety: the blocks cover all the components of `res`.
+    unsafe { res.assume_init() }
+}
+
+/// Stacks a tuple of matrices vertically, i.e., concatenates their rows.
+///
+/// The blocks may be any mix of statically-sized and dynamically-sized matrices or matrix
+/// slices. The number of columns of the result is a type-level constant if at least one of the
+/// blocks has a statically-known number of columns, and the number of rows is a type-level
+/// constant if all the blocks have a statically-known number of rows. Up to eight blocks are
+/// supported.
+///
+/// # Panics
+/// Panics if the blocks do not all have the same number of columns.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{vstack, DMatrix, Dynamic, Matrix2, Matrix3x2, OMatrix, RowVector2, U2};
+/// let a = RowVector2::new(1, 2);
+/// let b = Matrix2::new(3, 4,
+///                      5, 6);
+/// let expected = Matrix3x2::new(1, 2,
+///                               3, 4,
+///                               5, 6);
+///
+/// // All the blocks are statically-sized, and so is the result.
+/// let m: Matrix3x2<i32> = vstack((&a, &b));
+/// assert_eq!(m, expected);
+///
+/// // Only the number of columns is known at compile-time.
+/// let c = DMatrix::from_row_slice(2, 2, &[3, 4, 5, 6]);
+/// let m: OMatrix<i32, Dynamic, U2> = vstack((&a, &c));
+/// assert_eq!(m, expected);
+/// ```
+#[inline]
+pub fn vstack<T: Scalar, X: VStack<T>>(blocks: X) -> OMatrix<T, X::Rows, X::Cols>
+where
+    DefaultAllocator: Allocator<T, X::Rows, X::Cols>,
+{
+    let (nrows, ncols) = blocks.shape_generic();
+    let mut res = Matrix::uninit(nrows, ncols);
+    blocks.write_to(&mut res, (0, 0));
+    // Safety: the blocks cover all the components of `res`.
+    unsafe { res.assume_init() }
+}
+
+/// Builds a block-diagonal matrix from a tuple of matrices, i.e., a matrix with the given
+/// blocks along its diagonal and zeros everywhere else.
+///
+/// The blocks may be any mix of statically-sized and dynamically-sized matrices or matrix
+/// slices, and do not need to be square. Each dimension of the result is a type-level
+/// constant if the corresponding dimensions of all the blocks are statically known. Up to
+/// eight blocks are supported.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{block_diagonal, Matrix2, Matrix4x5, RowVector2, Vector1};
+/// let a = Matrix2::new(1, 2,
+///                      3, 4);
+/// let b = Vector1::new(5);
+/// let c = RowVector2::new(6, 7);
+///
+/// let m: Matrix4x5<i32> = block_diagonal((&a, &b, &c));
+/// assert_eq!(m, Matrix4x5::new(1, 2, 0, 0, 0,
+///                              3, 4, 0, 0, 0,
+///                              0, 0, 5, 0, 0,
+///                              0, 0, 0, 6, 7));
+/// ```
+#[inline]
+pub fn block_diagonal<T: Scalar + Zero, X: BlockDiagonal<T>>(
+    blocks: X,
+) -> OMatrix<T, X::Rows, X::Cols>
+where
+    DefaultAllocator: Allocator<T, X::Rows, X::Cols>,
+{
+    let (nrows, ncols) = blocks.shape_generic();
+    let mut res = Matrix::uninit(nrows, ncols);
+
+    for e in res.iter_mut() {
+        *e = MaybeUninit::new(T::zero());
+    }
+
+    blocks.write_to(&mut res, (0, 0));
+    // Safety: all the components of `res` have been initialized with zeros.
+    unsafe { res.assume_init() }
+}
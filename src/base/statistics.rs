@@ -1,6 +1,10 @@
 use crate::allocator::Allocator;
-use crate::storage::RawStorage;
-use crate::{Const, DefaultAllocator, Dim, Matrix, OVector, RowOVector, Scalar, VectorSlice, U1};
+use crate::constraint::{DimEq, ShapeConstraint};
+use crate::storage::{RawStorage, Storage};
+use crate::{
+    ComplexField, Const, DefaultAllocator, Dim, DimName, Matrix, OMatrix, OVector, RealField,
+    RowOVector, Scalar, Vector, VectorSlice, U1,
+};
 use num::{One, Zero};
 use simba::scalar::{ClosedAdd, ClosedMul, Field, SupersetOf};
 use std::mem::MaybeUninit;
@@ -80,6 +84,79 @@ impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
 
         res
     }
+
+    /// Folds the elements of each column of this matrix, from the first row to the last.
+    ///
+    /// The `j`-th element of the returned row vector is the result of the application of
+    /// `f(accumulator, element)` on all the elements of the `j`-th column, starting with
+    /// `init` as the accumulator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, RowVector3};
+    ///
+    /// let m = Matrix2x3::new(1, -2, 3,
+    ///                        -4, 5, 6);
+    /// // Count the negative elements of each column.
+    /// let negatives = m.fold_rows(0usize, |acc, e| acc + (*e < 0) as usize);
+    /// assert_eq!(negatives, RowVector3::new(1, 1, 0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn fold_rows<A: Scalar>(&self, init: A, mut f: impl FnMut(A, &T) -> A) -> RowOVector<A, C>
+    where
+        DefaultAllocator: Allocator<A, U1, C>,
+    {
+        let ncols = self.shape_generic().1;
+        let mut res = Matrix::uninit(Const::<1>, ncols);
+
+        for j in 0..ncols.value() {
+            let folded = self.column(j).iter().fold(init.clone(), &mut f);
+            // Safety: all indices are in range.
+            unsafe {
+                *res.get_unchecked_mut((0, j)) = MaybeUninit::new(folded);
+            }
+        }
+
+        // Safety: res is now fully initialized.
+        unsafe { res.assume_init() }
+    }
+
+    /// Folds the elements of each row of this matrix, from the first column to the last.
+    ///
+    /// The `i`-th element of the returned vector is the result of the application of
+    /// `f(accumulator, element)` on all the elements of the `i`-th row, starting with `init`
+    /// as the accumulator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    ///
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    /// // Read each row as the digits of a number.
+    /// let numbers = m.fold_columns(0, |acc, e| acc * 10 + e);
+    /// assert_eq!(numbers, Vector2::new(123, 456));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn fold_columns<A: Scalar>(&self, init: A, mut f: impl FnMut(A, &T) -> A) -> OVector<A, R>
+    where
+        DefaultAllocator: Allocator<A, R>,
+    {
+        let nrows = self.shape_generic().0;
+        let mut res = OVector::from_element_generic(nrows, Const::<1>, init);
+
+        for column in self.column_iter() {
+            for (acc, e) in res.iter_mut().zip(column.iter()) {
+                *acc = f(acc.clone(), e);
+            }
+        }
+
+        res
+    }
 }
 
 /// # Common statistics operations
@@ -422,6 +499,110 @@ impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
         })
     }
 
+    /// The sample variance of all the elements of this matrix.
+    ///
+    /// Unlike `.variance`, the sum of squared deviations is divided by `n - 1` instead of `n`
+    /// (Bessel's correction). Returns zero if this matrix has less than two elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2x3;
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// assert_relative_eq!(m.sample_variance(), 3.5, epsilon = 1.0e-8);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn sample_variance(&self) -> T
+    where
+        T: Field + SupersetOf<f64>,
+    {
+        let n = self.len();
+
+        if n < 2 {
+            T::zero()
+        } else {
+            self.variance() * crate::convert::<_, T>(n as f64 / (n - 1) as f64)
+        }
+    }
+
+    /// The sample variance of all the rows of this matrix.
+    ///
+    /// Use `.row_sample_variance_tr` if you need the result in a column vector instead.
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, RowVector3};
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// assert_eq!(m.row_sample_variance(), RowVector3::new(4.5, 4.5, 4.5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_sample_variance(&self) -> RowOVector<T, C>
+    where
+        T: Field + SupersetOf<f64>,
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        self.compress_rows(|col| col.sample_variance())
+    }
+
+    /// The sample variance of all the rows of this matrix. The result is transposed and returned as a column vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector3};
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// assert_eq!(m.row_sample_variance_tr(), Vector3::new(4.5, 4.5, 4.5));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_sample_variance_tr(&self) -> OVector<T, C>
+    where
+        T: Field + SupersetOf<f64>,
+        DefaultAllocator: Allocator<T, C>,
+    {
+        self.compress_rows_tr(|col| col.sample_variance())
+    }
+
+    /// The sample variance of all the columns of this matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// assert_relative_eq!(m.column_sample_variance(), Vector2::new(1.0, 1.0), epsilon = 1.0e-8);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_sample_variance(&self) -> OVector<T, R>
+    where
+        T: Field + SupersetOf<f64>,
+        DefaultAllocator: Allocator<T, R>,
+    {
+        let n = self.ncols();
+        let mut variance = self.column_variance();
+
+        if n < 2 {
+            variance.fill(T::zero());
+        } else {
+            variance *= crate::convert::<_, T>(n as f64 / (n - 1) as f64);
+        }
+
+        variance
+    }
+
     /*
      *
      * Mean computation.
@@ -520,3 +701,837 @@ impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> Matrix<T, R, C, S> {
         })
     }
 }
+
+/// The normalization applied to the sum of squared deviations when estimating a variance or a
+/// covariance.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Normalization {
+    /// Divides by the number of observations `n`.
+    ///
+    /// This is the variance of the observations themselves.
+    Population,
+    /// Divides by `n - 1` (Bessel's correction).
+    ///
+    /// This is the unbiased estimator of the variance of the distribution the observations are
+    /// sampled from.
+    Sample,
+}
+
+impl Normalization {
+    /// The number of observations subtracted from `n` by this normalization.
+    #[inline]
+    #[must_use]
+    pub fn ddof(self) -> usize {
+        match self {
+            Normalization::Population => 0,
+            Normalization::Sample => 1,
+        }
+    }
+}
+
+/// # Covariance and correlation
+impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// The covariance matrix of the columns of this matrix, each row being an observation.
+    ///
+    /// The result is a square matrix with as many rows as this matrix has columns, and its
+    /// diagonal is `.row_variance()` (for `Normalization::Population`) or
+    /// `.row_sample_variance()` (for `Normalization::Sample`). Returns zero if there are not
+    /// enough observations for the requested normalization.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3x2, Matrix2, Normalization};
+    ///
+    /// let m = Matrix3x2::new(1.0, 2.0,
+    ///                        2.0, 4.0,
+    ///                        3.0, 3.0);
+    /// let expected = Matrix2::new(1.0, 0.5,
+    ///                             0.5, 1.0);
+    /// assert_relative_eq!(m.row_covariance(Normalization::Sample), expected, epsilon = 1.0e-8);
+    /// ```
+    #[must_use]
+    pub fn row_covariance(&self, normalization: Normalization) -> OMatrix<T, C, C>
+    where
+        T: ComplexField,
+        DefaultAllocator: Allocator<T, R, C> + Allocator<T, U1, C> + Allocator<T, C, C>,
+    {
+        let ncols = self.shape_generic().1;
+        let nobs = self.nrows();
+
+        if nobs <= normalization.ddof() {
+            return OMatrix::zeros_generic(ncols, ncols);
+        }
+
+        let centered = self.sub_row_broadcast(&self.row_mean());
+        let mut covariance = centered.tr_mul(&centered.conjugate());
+        covariance /= crate::convert::<_, T>((nobs - normalization.ddof()) as f64);
+        covariance
+    }
+
+    /// The covariance matrix of the rows of this matrix, each column being an observation.
+    ///
+    /// The result is a square matrix with as many rows as this matrix, and its diagonal is
+    /// `.column_variance()` (for `Normalization::Population`) or `.column_sample_variance()`
+    /// (for `Normalization::Sample`). Returns zero if there are not enough observations for the
+    /// requested normalization.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2x3, Matrix2, Normalization};
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        2.0, 4.0, 3.0);
+    /// let expected = Matrix2::new(1.0, 0.5,
+    ///                             0.5, 1.0);
+    /// assert_relative_eq!(m.column_covariance(Normalization::Sample), expected, epsilon = 1.0e-8);
+    /// ```
+    #[must_use]
+    pub fn column_covariance(&self, normalization: Normalization) -> OMatrix<T, R, R>
+    where
+        T: ComplexField,
+        DefaultAllocator:
+            Allocator<T, R, C> + Allocator<T, C, R> + Allocator<T, R> + Allocator<T, R, R>,
+    {
+        let nrows = self.shape_generic().0;
+        let nobs = self.ncols();
+
+        if nobs <= normalization.ddof() {
+            return OMatrix::zeros_generic(nrows, nrows);
+        }
+
+        let centered = self.sub_column_broadcast(&self.column_mean());
+        let mut covariance = &centered * centered.adjoint();
+        covariance /= crate::convert::<_, T>((nobs - normalization.ddof()) as f64);
+        covariance
+    }
+
+    /// The Pearson correlation matrix of the columns of this matrix, each row being an
+    /// observation.
+    ///
+    /// Columns with a zero variance have an undefined correlation and yield NaN entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3x2, Matrix2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 2.0,
+    ///                        2.0, 4.0,
+    ///                        3.0, 6.0);
+    /// assert_relative_eq!(m.row_correlation(), Matrix2::repeat(1.0), epsilon = 1.0e-8);
+    /// ```
+    #[must_use]
+    pub fn row_correlation(&self) -> OMatrix<T, C, C>
+    where
+        T: ComplexField,
+        DefaultAllocator:
+            Allocator<T, R, C> + Allocator<T, U1, C> + Allocator<T, C, C> + Allocator<T, C>,
+    {
+        covariance_to_correlation(self.row_covariance(Normalization::Population))
+    }
+
+    /// The Pearson correlation matrix of the rows of this matrix, each column being an
+    /// observation.
+    ///
+    /// Rows with a zero variance have an undefined correlation and yield NaN entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2x3, Matrix2};
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        3.0, 2.0, 1.0);
+    /// let expected = Matrix2::new(1.0, -1.0,
+    ///                             -1.0, 1.0);
+    /// assert_relative_eq!(m.column_correlation(), expected, epsilon = 1.0e-8);
+    /// ```
+    #[must_use]
+    pub fn column_correlation(&self) -> OMatrix<T, R, R>
+    where
+        T: ComplexField,
+        DefaultAllocator:
+            Allocator<T, R, C> + Allocator<T, C, R> + Allocator<T, R> + Allocator<T, R, R>,
+    {
+        covariance_to_correlation(self.column_covariance(Normalization::Population))
+    }
+}
+
+/// Divides each entry `(i, j)` of `covariance` by the standard deviations of the variables `i`
+/// and `j`.
+fn covariance_to_correlation<T: ComplexField, D: Dim>(
+    mut covariance: OMatrix<T, D, D>,
+) -> OMatrix<T, D, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+{
+    let std_dev = covariance.map_diagonal(|e| T::from_real(e.real().sqrt()));
+
+    for j in 0..covariance.ncols() {
+        for i in 0..covariance.nrows() {
+            covariance[(i, j)] /= std_dev[i].clone() * std_dev[j].clone();
+        }
+    }
+
+    covariance
+}
+
+/// # Weighted moments
+impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// The weighted mean of all the rows of this matrix, where `weights[i]` is the weight of the
+    /// `i`-th row.
+    ///
+    /// The weights must have a nonzero sum.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, RowVector3, Vector2};
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let weights = Vector2::new(2.0, 1.0);
+    /// assert_eq!(m.row_weighted_mean(&weights), RowVector3::new(2.0, 3.0, 4.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_weighted_mean<D: Dim, SW>(&self, weights: &Vector<T, D, SW>) -> RowOVector<T, C>
+    where
+        T: Field,
+        SW: RawStorage<T, D>,
+        ShapeConstraint: DimEq<R, D>,
+        DefaultAllocator: Allocator<T, U1, C>,
+    {
+        assert_eq!(
+            weights.len(),
+            self.nrows(),
+            "The number of weights must match the number of rows."
+        );
+        let denom = T::one() / weights.sum();
+        self.compress_rows(|col| col.dot(weights) * denom.clone())
+    }
+
+    /// The weighted mean of all the columns of this matrix, where `weights[j]` is the weight of
+    /// the `j`-th column.
+    ///
+    /// The weights must have a nonzero sum.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2, Vector3};
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let weights = Vector3::new(1.0, 0.0, 1.0);
+    /// assert_eq!(m.column_weighted_mean(&weights), Vector2::new(2.0, 5.0));
+    /// ```
+    #[must_use]
+    pub fn column_weighted_mean<D: Dim, SW>(&self, weights: &Vector<T, D, SW>) -> OVector<T, R>
+    where
+        T: Field,
+        SW: RawStorage<T, D>,
+        ShapeConstraint: DimEq<C, D>,
+        DefaultAllocator: Allocator<T, R>,
+    {
+        assert_eq!(
+            weights.len(),
+            self.ncols(),
+            "The number of weights must match the number of columns."
+        );
+        let nrows = self.shape_generic().0;
+        let denom = T::one() / weights.sum();
+        let mut res = OVector::zeros_generic(nrows, Const::<1>);
+
+        for (j, w) in weights.iter().enumerate() {
+            res.axpy(w.clone() * denom.clone(), &self.column(j), T::one());
+        }
+
+        res
+    }
+
+    /// The weighted covariance matrix of the columns of this matrix, each row being an
+    /// observation with the weight `weights[i]`.
+    ///
+    /// With `Normalization::Population` the sum of weighted squared deviations is divided by the
+    /// sum of the weights `V1`. With `Normalization::Sample`, it is divided by `V1 - V2 / V1`
+    /// where `V2` is the sum of the squared weights, which is the unbiased estimator for
+    /// reliability weights. Equal weights give the same result as `.row_covariance`. Returns
+    /// zero if this denominator is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3x2, Matrix2, Normalization, Vector3};
+    ///
+    /// let m = Matrix3x2::new(1.0, 2.0,
+    ///                        2.0, 4.0,
+    ///                        3.0, 3.0);
+    /// // Doubling the weight of an observation is the same as observing it twice.
+    /// let weights = Vector3::new(2.0, 1.0, 1.0);
+    /// let repeated = nalgebra::Matrix4x2::new(1.0, 2.0,
+    ///                                         1.0, 2.0,
+    ///                                         2.0, 4.0,
+    ///                                         3.0, 3.0);
+    /// assert_relative_eq!(
+    ///     m.row_weighted_covariance(&weights, Normalization::Population),
+    ///     repeated.row_covariance(Normalization::Population),
+    ///     epsilon = 1.0e-8
+    /// );
+    /// ```
+    #[must_use]
+    pub fn row_weighted_covariance<D: Dim, SW>(
+        &self,
+        weights: &Vector<T, D, SW>,
+        normalization: Normalization,
+    ) -> OMatrix<T, C, C>
+    where
+        T: ComplexField,
+        SW: RawStorage<T, D>,
+        ShapeConstraint: DimEq<R, D>,
+        DefaultAllocator: Allocator<T, R, C> + Allocator<T, U1, C> + Allocator<T, C, C>,
+    {
+        let ncols = self.shape_generic().1;
+        let denom = weighted_denominator(weights, normalization);
+
+        if denom.is_zero() {
+            return OMatrix::zeros_generic(ncols, ncols);
+        }
+
+        let centered = self.sub_row_broadcast(&self.row_weighted_mean(weights));
+        let mut weighted = centered.conjugate();
+
+        for (i, w) in weights.iter().enumerate() {
+            let mut row = weighted.row_mut(i);
+            row *= w.clone();
+        }
+
+        let mut covariance = centered.tr_mul(&weighted);
+        covariance /= denom;
+        covariance
+    }
+
+    /// The weighted covariance matrix of the rows of this matrix, each column being an
+    /// observation with the weight `weights[j]`.
+    ///
+    /// See `.row_weighted_covariance` for the meaning of `normalization`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2x3, Matrix2, Normalization, Vector3};
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        2.0, 4.0, 3.0);
+    /// let weights = Vector3::repeat(0.5);
+    /// assert_relative_eq!(
+    ///     m.column_weighted_covariance(&weights, Normalization::Sample),
+    ///     m.column_covariance(Normalization::Sample),
+    ///     epsilon = 1.0e-8
+    /// );
+    /// ```
+    #[must_use]
+    pub fn column_weighted_covariance<D: Dim, SW>(
+        &self,
+        weights: &Vector<T, D, SW>,
+        normalization: Normalization,
+    ) -> OMatrix<T, R, R>
+    where
+        T: ComplexField,
+        SW: RawStorage<T, D>,
+        ShapeConstraint: DimEq<C, D>,
+        DefaultAllocator:
+            Allocator<T, R, C> + Allocator<T, C, R> + Allocator<T, R> + Allocator<T, R, R>,
+    {
+        let nrows = self.shape_generic().0;
+        let denom = weighted_denominator(weights, normalization);
+
+        if denom.is_zero() {
+            return OMatrix::zeros_generic(nrows, nrows);
+        }
+
+        let centered = self.sub_column_broadcast(&self.column_weighted_mean(weights));
+        let mut weighted = centered.clone();
+
+        for (mut column, w) in weighted.column_iter_mut().zip(weights.iter()) {
+            column *= w.clone();
+        }
+
+        let mut covariance = weighted * centered.adjoint();
+        covariance /= denom;
+        covariance
+    }
+}
+
+/// The denominator of a weighted covariance estimate with the given normalization.
+fn weighted_denominator<T: ComplexField, D: Dim, S: RawStorage<T, D>>(
+    weights: &Vector<T, D, S>,
+    normalization: Normalization,
+) -> T {
+    let v1 = weights.sum();
+
+    match normalization {
+        Normalization::Population => v1,
+        Normalization::Sample => {
+            if v1.is_zero() {
+                v1
+            } else {
+                let v2 = weights.dot(weights);
+                v1.clone() - v2 / v1
+            }
+        }
+    }
+}
+
+/// # Quantiles
+impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// The `q`-th quantile of all the elements of this matrix, with `0 <= q <= 1`.
+    ///
+    /// The quantile is linearly interpolated between the two closest elements, i.e., it is
+    /// `x[k] + f * (x[k + 1] - x[k])` where `x` are the sorted elements and `k + f = q * (n - 1)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this matrix is empty, if it contains NaN, or if `q` is not in `[0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    ///
+    /// let m = Matrix2x3::new(6.0, 2.0, 4.0,
+    ///                        3.0, 5.0, 1.0);
+    /// assert_eq!(m.quantile(0.0), 1.0);
+    /// assert_eq!(m.quantile(0.3), 2.5);
+    /// assert_eq!(m.quantile(1.0), 6.0);
+    /// ```
+    #[must_use]
+    pub fn quantile(&self, q: T) -> T
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        quantile_mut(self.clone_owned().as_mut_slice(), q)
+    }
+
+    /// The median of all the elements of this matrix.
+    ///
+    /// This is `.quantile(0.5)`: the mean of the two middle elements is returned if this matrix
+    /// has an even number of elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this matrix is empty or if it contains NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    ///
+    /// let m = Matrix2x3::new(6.0, 2.0, 4.0,
+    ///                        3.0, 5.0, 1.0);
+    /// assert_eq!(m.median(), 3.5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn median(&self) -> T
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        self.quantile(crate::convert(0.5))
+    }
+
+    /// The `q`-th quantile of all the rows of this matrix.
+    ///
+    /// See `.quantile` for the interpolation method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this matrix has no rows, if it contains NaN, or if `q` is not in `[0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, RowVector2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 6.0,
+    ///                        3.0, 5.0,
+    ///                        2.0, 4.0);
+    /// assert_eq!(m.row_quantile(0.75), RowVector2::new(2.5, 5.5));
+    /// ```
+    #[must_use]
+    pub fn row_quantile(&self, q: T) -> RowOVector<T, C>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R> + Allocator<T, U1, C>,
+    {
+        self.compress_rows(|col| quantile_mut(col.clone_owned().as_mut_slice(), q.clone()))
+    }
+
+    /// The median of all the rows of this matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this matrix has no rows or if it contains NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix3x2, RowVector2};
+    ///
+    /// let m = Matrix3x2::new(1.0, 6.0,
+    ///                        3.0, 5.0,
+    ///                        2.0, 4.0);
+    /// assert_eq!(m.row_median(), RowVector2::new(2.0, 5.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn row_median(&self) -> RowOVector<T, C>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R> + Allocator<T, U1, C>,
+    {
+        self.row_quantile(crate::convert(0.5))
+    }
+
+    /// The `q`-th quantile of all the columns of this matrix.
+    ///
+    /// See `.quantile` for the interpolation method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this matrix has no columns, if it contains NaN, or if `q` is not in `[0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    ///
+    /// let m = Matrix2x3::new(1.0, 3.0, 2.0,
+    ///                        6.0, 5.0, 4.0);
+    /// assert_eq!(m.column_quantile(0.25), Vector2::new(1.5, 4.5));
+    /// ```
+    #[must_use]
+    pub fn column_quantile(&self, q: T) -> OVector<T, R>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R> + Allocator<T, U1, C>,
+    {
+        let nrows = self.shape_generic().0;
+        OVector::from_fn_generic(nrows, Const::<1>, |i, _| {
+            quantile_mut(self.row(i).clone_owned().as_mut_slice(), q.clone())
+        })
+    }
+
+    /// The median of all the columns of this matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this matrix has no columns or if it contains NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2x3, Vector2};
+    ///
+    /// let m = Matrix2x3::new(1.0, 3.0, 2.0,
+    ///                        6.0, 5.0, 4.0);
+    /// assert_eq!(m.column_median(), Vector2::new(2.0, 5.0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn column_median(&self) -> OVector<T, R>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R> + Allocator<T, U1, C>,
+    {
+        self.column_quantile(crate::convert(0.5))
+    }
+}
+
+/// Computes the `q`-th quantile of `data`, reordering its elements in the process.
+fn quantile_mut<T: RealField>(data: &mut [T], q: T) -> T {
+    assert!(
+        !data.is_empty(),
+        "Cannot compute the quantile of an empty set of values."
+    );
+    assert!(
+        q >= T::zero() && q <= T::one(),
+        "The quantile must be in the interval [0, 1]."
+    );
+    assert!(
+        data.iter().all(|e| e.partial_cmp(e).is_some()),
+        "Cannot compute the quantile of a set of values containing NaN."
+    );
+
+    let h = q * crate::convert::<_, T>((data.len() - 1) as f64);
+    let lo = h.clone().floor();
+    let frac = h - lo.clone();
+    let k = (lo.to_subset_unchecked() as usize).min(data.len() - 1);
+
+    let (_, lo_val, above) = data.select_nth_unstable_by(k, |a, b| {
+        a.partial_cmp(b)
+            .expect("NaN values have been checked above.")
+    });
+    let lo_val = lo_val.clone();
+
+    match above.split_first() {
+        Some((first, rest)) if !frac.is_zero() => {
+            let hi_val = rest.iter().fold(first.clone(), |m, e| m.min(e.clone()));
+            lo_val.clone() + frac * (hi_val - lo_val)
+        }
+        _ => lo_val,
+    }
+}
+
+/// # Standardization
+impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// Standardizes each column of this matrix, each row being an observation.
+    ///
+    /// Each element is replaced by its z-score `(x - mean) / std_dev` where `mean` and
+    /// `std_dev` are the mean and standard deviation of its column. Columns with a zero
+    /// standard deviation, or without enough observations for the requested normalization, are
+    /// only centered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3x2, Normalization};
+    ///
+    /// let m = Matrix3x2::new(1.0, 5.0,
+    ///                        2.0, 5.0,
+    ///                        3.0, 5.0);
+    /// let expected = Matrix3x2::new(-1.0, 0.0,
+    ///                               0.0, 0.0,
+    ///                               1.0, 0.0);
+    /// assert_relative_eq!(m.row_standardized(Normalization::Sample), expected, epsilon = 1.0e-8);
+    /// ```
+    #[must_use]
+    pub fn row_standardized(&self, normalization: Normalization) -> OMatrix<T, R, C>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R, C> + Allocator<T, U1, C>,
+    {
+        let mut res = self.sub_row_broadcast(&self.row_mean());
+        let nobs = self.nrows();
+
+        if nobs > normalization.ddof() {
+            let denom = crate::convert::<_, T>((nobs - normalization.ddof()) as f64);
+
+            for mut column in res.column_iter_mut() {
+                let std_dev = (column.norm_squared() / denom.clone()).sqrt();
+
+                if !std_dev.is_zero() {
+                    column /= std_dev;
+                }
+            }
+        }
+
+        res
+    }
+
+    /// Standardizes each row of this matrix, each column being an observation.
+    ///
+    /// See `.row_standardized` for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix2x3, Normalization};
+    ///
+    /// let m = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        2.0, 4.0, 6.0);
+    /// let expected = Matrix2x3::new(-1.0, 0.0, 1.0,
+    ///                               -1.0, 0.0, 1.0);
+    /// assert_relative_eq!(m.column_standardized(Normalization::Sample), expected, epsilon = 1.0e-8);
+    /// ```
+    #[must_use]
+    pub fn column_standardized(&self, normalization: Normalization) -> OMatrix<T, R, C>
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<T, R, C> + Allocator<T, R>,
+    {
+        let mut res = self.sub_column_broadcast(&self.column_mean());
+        let nobs = self.ncols();
+
+        if nobs > normalization.ddof() {
+            let denom = crate::convert::<_, T>((nobs - normalization.ddof()) as f64);
+
+            for mut row in res.row_iter_mut() {
+                let std_dev = (row.norm_squared() / denom.clone()).sqrt();
+
+                if !std_dev.is_zero() {
+                    row /= std_dev;
+                }
+            }
+        }
+
+        res
+    }
+}
+
+/// An accumulator of the component-wise mean and variance of a stream of vectors.
+///
+/// The statistics are updated in a single pass with Welford's algorithm, which is numerically
+/// stable and does not require storing the observations. Accumulators of separate parts of a
+/// stream can be combined with `.merge`.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{Normalization, Vector2, Welford};
+///
+/// let mut stats = Welford::<f64, _>::new();
+/// stats.push(&Vector2::new(1.0, 4.0));
+/// stats.push(&Vector2::new(2.0, 5.0));
+/// stats.push(&Vector2::new(3.0, 9.0));
+///
+/// assert_eq!(stats.count(), 3);
+/// assert_relative_eq!(*stats.mean(), Vector2::new(2.0, 6.0), epsilon = 1.0e-8);
+/// assert_relative_eq!(stats.variance(Normalization::Sample), Vector2::new(1.0, 7.0), epsilon = 1.0e-8);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Welford<T: Scalar, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    count: usize,
+    mean: OVector<T, D>,
+    m2: OVector<T, D>,
+}
+
+impl<T: RealField, D: DimName> Default for Welford<T, D>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: RealField, D: DimName> Welford<T, D>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    /// Creates an empty accumulator of statistics of statically-sized vectors.
+    #[inline]
+    pub fn new() -> Self {
+        Self::new_generic(D::name())
+    }
+}
+
+impl<T: RealField, D: Dim> Welford<T, D>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    /// Creates an empty accumulator of statistics of vectors with `dim` components.
+    #[inline]
+    pub fn new_generic(dim: D) -> Self {
+        Self {
+            count: 0,
+            mean: OVector::zeros_generic(dim, Const::<1>),
+            m2: OVector::zeros_generic(dim, Const::<1>),
+        }
+    }
+
+    /// The number of vectors accumulated so far.
+    #[inline]
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The component-wise mean of the vectors accumulated so far.
+    ///
+    /// This is zero if no vector has been accumulated.
+    #[inline]
+    #[must_use]
+    pub fn mean(&self) -> &OVector<T, D> {
+        &self.mean
+    }
+
+    /// The component-wise variance of the vectors accumulated so far.
+    ///
+    /// Returns zero if there are not enough observations for the requested normalization.
+    #[must_use]
+    pub fn variance(&self, normalization: Normalization) -> OVector<T, D> {
+        if self.count <= normalization.ddof() {
+            OVector::zeros_generic(self.mean.shape_generic().0, Const::<1>)
+        } else {
+            &self.m2 / crate::convert::<_, T>((self.count - normalization.ddof()) as f64)
+        }
+    }
+
+    /// Accumulates the vector `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` does not have the same dimension as the accumulated vectors.
+    pub fn push<D2: Dim, S2>(&mut self, x: &Vector<T, D2, S2>)
+    where
+        S2: RawStorage<T, D2>,
+        ShapeConstraint: DimEq<D, D2>,
+    {
+        assert_eq!(
+            x.len(),
+            self.mean.len(),
+            "Welford: dimension mismatch of the accumulated vector."
+        );
+
+        self.count += 1;
+        let denom = crate::convert::<_, T>(self.count as f64);
+
+        for ((mean, m2), x) in self.mean.iter_mut().zip(self.m2.iter_mut()).zip(x.iter()) {
+            let delta = x.clone() - mean.clone();
+            *mean += delta.clone() / denom.clone();
+            *m2 += delta * (x.clone() - mean.clone());
+        }
+    }
+
+    /// Combines the statistics of `other` into `self`, as if all the vectors accumulated by
+    /// `other` had been accumulated by `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two accumulators do not have the same dimension.
+    pub fn merge(&mut self, other: &Self) {
+        assert_eq!(
+            other.mean.len(),
+            self.mean.len(),
+            "Welford: dimension mismatch of the merged accumulator."
+        );
+
+        if other.count == 0 {
+            return;
+        }
+
+        if self.count == 0 {
+            *self = other.clone();
+            return;
+        }
+
+        let na = crate::convert::<_, T>(self.count as f64);
+        let nb = crate::convert::<_, T>(other.count as f64);
+        self.count += other.count;
+        let n = crate::convert::<_, T>(self.count as f64);
+
+        let it = self.mean.iter_mut().zip(self.m2.iter_mut());
+        for ((mean, m2), (other_mean, other_m2)) in it.zip(other.mean.iter().zip(other.m2.iter())) {
+            let delta = other_mean.clone() - mean.clone();
+            *mean += delta.clone() * nb.clone() / n.clone();
+            *m2 += other_m2.clone() + delta.clone() * delta * na.clone() * nb.clone() / n.clone();
+        }
+    }
+}
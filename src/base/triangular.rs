@@ -0,0 +1,258 @@
+use std::ops::{Deref, Mul};
+
+use num::{One, Zero};
+use simba::scalar::ComplexField;
+use simba::simd::SimdComplexField;
+
+use crate::base::allocator::Allocator;
+use crate::base::blas::{Diag, Side, Transposition, Uplo};
+use crate::base::constraint::{SameNumberOfRows, ShapeConstraint};
+use crate::base::dimension::Dim;
+use crate::base::storage::{Storage, StorageMut};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, Scalar, SquareMatrix};
+
+macro_rules! triangular_impl(
+    ($Triangular: ident, $Transposed: ident, $uplo: expr, $fill_other: ident,
+     $solve: ident, $solve_mut: ident, $doc: literal) => {
+        #[doc = $doc]
+        ///
+        /// Only its triangular part (including the diagonal) is ever read: the components outside
+        /// of it are assumed to be zero. Operations on this type automatically dispatch to the
+        /// triangular kernels, e.g., for linear system resolution or matrix multiplication.
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct $Triangular<M> {
+            matrix: M,
+        }
+
+        /// # Construction and data extraction
+        impl<T: Scalar, D: Dim, S: Storage<T, D, D>> $Triangular<Matrix<T, D, D, S>> {
+            /// Wraps `matrix` after setting to zero the components outside of its triangular part.
+            ///
+            /// Panics if `matrix` is not square.
+            #[inline]
+            pub fn new(mut matrix: Matrix<T, D, D, S>) -> Self
+            where
+                T: Zero,
+                S: StorageMut<T, D, D>,
+            {
+                assert!(
+                    matrix.is_square(),
+                    "Triangular matrix: the matrix must be square."
+                );
+                matrix.$fill_other(T::zero(), 1);
+                Self { matrix }
+            }
+
+            /// Wraps `matrix` without modifying the components outside of its triangular part.
+            ///
+            /// The caller must make sure that `matrix` is square. The components outside of its
+            /// triangular part are assumed to be zero but are never read by the operations of this
+            /// type.
+            #[inline]
+            pub fn new_unchecked(matrix: Matrix<T, D, D, S>) -> Self {
+                Self { matrix }
+            }
+
+            /// Retrieves the underlying matrix.
+            #[inline]
+            pub fn into_inner(self) -> Matrix<T, D, D, S> {
+                self.matrix
+            }
+
+            /// The determinant of this matrix, i.e., the product of its diagonal elements.
+            #[inline]
+            #[must_use]
+            pub fn determinant(&self) -> T
+            where
+                T: One + std::ops::MulAssign,
+            {
+                let mut res = T::one();
+                for i in 0..self.matrix.nrows() {
+                    res *= self.matrix[(i, i)].clone();
+                }
+                res
+            }
+
+            /// The transpose of this matrix.
+            #[inline]
+            #[must_use]
+            pub fn transpose(&self) -> $Transposed<OMatrix<T, D, D>>
+            where
+                DefaultAllocator: Allocator<T, D, D>,
+            {
+                $Transposed {
+                    matrix: self.matrix.transpose(),
+                }
+            }
+        }
+
+        /// # Linear system resolution
+        impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> $Triangular<Matrix<T, D, D, S>> {
+            /// Solves the linear system `self * x = b` where `x` is the unknown.
+            ///
+            /// Returns `None` if `self` is not invertible.
+            #[inline]
+            #[must_use = "Did you mean to use solve_mut()?"]
+            pub fn solve<R2: Dim, C2: Dim, S2>(
+                &self,
+                b: &Matrix<T, R2, C2, S2>,
+            ) -> Option<OMatrix<T, R2, C2>>
+            where
+                S2: Storage<T, R2, C2>,
+                DefaultAllocator: Allocator<T, R2, C2>,
+                ShapeConstraint: SameNumberOfRows<R2, D>,
+            {
+                self.matrix.$solve(b)
+            }
+
+            /// Solves the linear system `self * x = b` where `x` is the unknown, and overwrites `b`
+            /// with the solution.
+            ///
+            /// Returns `false` and leaves `b` in an unspecified state if `self` is not invertible.
+            #[inline]
+            pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+            where
+                S2: StorageMut<T, R2, C2>,
+                ShapeConstraint: SameNumberOfRows<R2, D>,
+            {
+                self.matrix.$solve_mut(b)
+            }
+
+            /// Computes the inverse of this matrix, which is triangular too.
+            ///
+            /// Returns `None` if `self` is not invertible.
+            #[inline]
+            #[must_use]
+            pub fn try_inverse(&self) -> Option<$Triangular<OMatrix<T, D, D>>>
+            where
+                DefaultAllocator: Allocator<T, D, D>,
+            {
+                let dim = self.matrix.shape_generic().0;
+                let mut res = OMatrix::identity_generic(dim, dim);
+
+                if self.matrix.$solve_mut(&mut res) {
+                    Some($Triangular { matrix: res })
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<M> Deref for $Triangular<M> {
+            type Target = M;
+
+            #[inline]
+            fn deref(&self) -> &M {
+                &self.matrix
+            }
+        }
+
+        impl<M> AsRef<M> for $Triangular<M> {
+            #[inline]
+            fn as_ref(&self) -> &M {
+                &self.matrix
+            }
+        }
+
+        impl<T: Scalar + Zero, D: Dim, S> From<Matrix<T, D, D, S>> for $Triangular<Matrix<T, D, D, S>>
+        where
+            S: StorageMut<T, D, D>,
+        {
+            #[inline]
+            fn from(matrix: Matrix<T, D, D, S>) -> Self {
+                Self::new(matrix)
+            }
+        }
+
+        impl<T: Scalar, D: Dim, S> From<$Triangular<Matrix<T, D, D, S>>> for Matrix<T, D, D, S> {
+            #[inline]
+            fn from(triangular: $Triangular<Matrix<T, D, D, S>>) -> Self {
+                triangular.matrix
+            }
+        }
+
+        impl<'a, 'b, T, D: Dim, S, R2: Dim, C2: Dim, S2> Mul<&'b Matrix<T, R2, C2, S2>>
+            for &'a $Triangular<SquareMatrix<T, D, S>>
+        where
+            T: SimdComplexField,
+            S: Storage<T, D, D>,
+            S2: Storage<T, R2, C2>,
+            DefaultAllocator: Allocator<T, R2, C2>,
+            ShapeConstraint: SameNumberOfRows<R2, D>,
+        {
+            type Output = OMatrix<T, R2, C2>;
+
+            #[inline]
+            fn mul(self, rhs: &'b Matrix<T, R2, C2, S2>) -> Self::Output {
+                let mut res = rhs.clone_owned();
+                res.trmm(
+                    T::one(),
+                    &self.matrix,
+                    Side::Left,
+                    $uplo,
+                    Transposition::NoTranspose,
+                    Diag::NonUnit,
+                );
+                res
+            }
+        }
+    }
+);
+
+triangular_impl!(
+    UpperTriangular,
+    LowerTriangular,
+    Uplo::Upper,
+    fill_lower_triangle,
+    solve_upper_triangular,
+    solve_upper_triangular_mut,
+    "A square matrix statically known to be **upper-triangular**."
+);
+triangular_impl!(
+    LowerTriangular,
+    UpperTriangular,
+    Uplo::Lower,
+    fill_upper_triangle,
+    solve_lower_triangular,
+    solve_lower_triangular_mut,
+    "A square matrix statically known to be **lower-triangular**."
+);
+
+/// # Triangular views
+impl<T: Scalar, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
+    /// Clones the upper-triangular part of this matrix, including its diagonal, into a matrix
+    /// statically known to be upper-triangular.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2, Vector2};
+    /// let m = Matrix2::new(2.0, 1.0,
+    ///                      5.0, 4.0);
+    /// let u = m.to_upper_triangular();
+    /// assert_eq!(*u, Matrix2::new(2.0, 1.0,
+    ///                             0.0, 4.0));
+    /// assert_eq!(u.solve(&Vector2::new(4.0, 8.0)), Some(Vector2::new(1.0, 2.0)));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_upper_triangular(&self) -> UpperTriangular<OMatrix<T, D, D>>
+    where
+        T: Zero,
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        UpperTriangular::new(self.clone_owned())
+    }
+
+    /// Clones the lower-triangular part of this matrix, including its diagonal, into a matrix
+    /// statically known to be lower-triangular.
+    #[inline]
+    #[must_use]
+    pub fn to_lower_triangular(&self) -> LowerTriangular<OMatrix<T, D, D>>
+    where
+        T: Zero,
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        LowerTriangular::new(self.clone_owned())
+    }
+}
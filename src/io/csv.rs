@@ -0,0 +1,755 @@
+//! Reading and writing of dense matrices as CSV or whitespace-delimited text.
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::base::dimension::{Dim, Dynamic};
+use crate::base::storage::RawStorage;
+use crate::base::{DMatrix, Matrix, RowMajorVecStorage, Scalar};
+
+/// The error returned when reading or writing delimited text.
+///
+/// Line numbers start at 1 and count every line of the input, including skipped ones. Columns
+/// are the 0-based indices of the fields of a line, before any column selection.
+#[derive(Debug)]
+pub enum CsvError {
+    /// An I/O error occurred.
+    Io(io::Error),
+    /// A quoted field is not closed before the end of the input.
+    UnterminatedQuote {
+        /// The line where the quoted field starts.
+        line: usize,
+    },
+    /// A line does not have the same number of fields as the first data line, or as the columns
+    /// of the matrix it is appended to with [`CsvRows::read_into`].
+    RaggedRow {
+        /// The line with the unexpected number of fields.
+        line: usize,
+        /// The number of fields of the first data line.
+        expected: usize,
+        /// The number of fields of this line.
+        found: usize,
+    },
+    /// A selected column does not exist in the input.
+    ColumnOutOfRange {
+        /// The first data line.
+        line: usize,
+        /// The selected column.
+        column: usize,
+        /// The number of fields of the first data line.
+        nfields: usize,
+    },
+    /// A field holds a missing value, and missing values are rejected.
+    MissingValue {
+        /// The line of the missing value.
+        line: usize,
+        /// The column of the missing value.
+        column: usize,
+    },
+    /// A field could not be parsed as a matrix component.
+    InvalidValue {
+        /// The line of the field.
+        line: usize,
+        /// The column of the field.
+        column: usize,
+        /// The content of the field.
+        value: String,
+    },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "I/O error: {}", e),
+            Self::UnterminatedQuote { line } => write!(f, "line {}: unterminated quote", line),
+            Self::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: expected {} fields, found {}",
+                line, expected, found
+            ),
+            Self::ColumnOutOfRange {
+                line,
+                column,
+                nfields,
+            } => write!(
+                f,
+                "line {}: cannot select column {} among {} fields",
+                line, column, nfields
+            ),
+            Self::MissingValue { line, column } => {
+                write!(f, "line {}: missing value in column {}", line, column)
+            }
+            Self::InvalidValue {
+                line,
+                column,
+                value,
+            } => write!(
+                f,
+                "line {}: invalid value `{}` in column {}",
+                line, value, column
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    #[inline]
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// What to do with the fields holding a missing value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MissingValues<T> {
+    /// Fail with [`CsvError::MissingValue`]. This is the default.
+    Error,
+    /// Replace each missing value by the given value, e.g., `f64::NAN`.
+    Fill(T),
+    /// Skip the lines holding a missing value.
+    SkipRow,
+}
+
+/// The options for reading a `DMatrix` from delimited text, one matrix row per line.
+///
+/// By default, fields are separated by commas and may be enclosed in double quotes, no line is
+/// skipped except blank ones, and empty fields are rejected as missing values.
+///
+/// # Example
+/// ```
+/// # use nalgebra::DMatrix;
+/// # use nalgebra::io::{CsvReader, MissingValues};
+/// let data = "\
+/// x y z
+/// % A comment.
+/// 1.0 2.0 3.0
+/// 4.0 NA  6.0
+/// ";
+/// let reader = CsvReader::new()
+///     .whitespace()
+///     .header_lines(1)
+///     .comment('%')
+///     .missing_tokens(&["NA"])
+///     .missing_values(MissingValues::Fill(0.0))
+///     .columns(&[1, 0]);
+/// let m: DMatrix<f64> = reader.read_str(data).unwrap();
+/// assert_eq!(m, DMatrix::from_row_slice(2, 2, &[2.0, 1.0, 0.0, 4.0]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CsvReader<T> {
+    delimiter: Option<char>,
+    quote: Option<char>,
+    comment: Option<char>,
+    header_lines: usize,
+    missing_tokens: Vec<String>,
+    missing_values: MissingValues<T>,
+    columns: Option<Vec<usize>>,
+}
+
+impl<T> Default for CsvReader<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CsvReader<T> {
+    /// Creates the default options for reading comma-separated values.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            delimiter: Some(','),
+            quote: Some('"'),
+            comment: None,
+            header_lines: 0,
+            missing_tokens: vec![String::new()],
+            missing_values: MissingValues::Error,
+            columns: None,
+        }
+    }
+
+    /// Separates the fields by the given character, e.g., `';'` or `'\t'`.
+    #[inline]
+    #[must_use]
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Separates the fields by runs of whitespace, ignoring leading and trailing whitespace.
+    #[inline]
+    #[must_use]
+    pub fn whitespace(mut self) -> Self {
+        self.delimiter = None;
+        self
+    }
+
+    /// Sets the character enclosing quoted fields, or disables quoting with `None`.
+    ///
+    /// Quoted fields may contain delimiters and line breaks, and a doubled quote character stands
+    /// for itself.
+    #[inline]
+    #[must_use]
+    pub fn quote(mut self, quote: Option<char>) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Skips the lines starting with the given character, after any leading whitespace.
+    #[inline]
+    #[must_use]
+    pub fn comment(mut self, comment: char) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    /// Skips the first `n` lines of the input, e.g., a header naming the columns.
+    #[inline]
+    #[must_use]
+    pub fn header_lines(mut self, n: usize) -> Self {
+        self.header_lines = n;
+        self
+    }
+
+    /// Sets the field contents denoting a missing value, e.g., `["", "NA"]`.
+    ///
+    /// Fields are compared after removing their surrounding whitespace and quotes. By default,
+    /// only empty fields are missing values.
+    #[inline]
+    #[must_use]
+    pub fn missing_tokens(mut self, tokens: &[&str]) -> Self {
+        self.missing_tokens = tokens.iter().map(|t| t.to_string()).collect();
+        self
+    }
+
+    /// Sets what to do with the fields holding a missing value.
+    #[inline]
+    #[must_use]
+    pub fn missing_values(mut self, missing_values: MissingValues<T>) -> Self {
+        self.missing_values = missing_values;
+        self
+    }
+
+    /// Only reads the given 0-based columns, in the given order.
+    #[inline]
+    #[must_use]
+    pub fn columns(mut self, columns: &[usize]) -> Self {
+        self.columns = Some(columns.to_vec());
+        self
+    }
+
+    /// Splits `line` into `fields`, removing the quotes and surrounding whitespace.
+    fn split_fields(
+        &self,
+        line: &str,
+        line_number: usize,
+        fields: &mut Vec<String>,
+    ) -> Result<(), CsvError> {
+        let is_delimiter = |c: char| self.delimiter.map_or(c.is_whitespace(), |d| c == d);
+        let mut chars = line.chars().peekable();
+        fields.clear();
+
+        loop {
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() && Some(c) != self.delimiter {
+                    let _ = chars.next();
+                } else {
+                    break;
+                }
+            }
+            if self.delimiter.is_none() && chars.peek().is_none() {
+                return Ok(());
+            }
+
+            let mut field = String::new();
+            let mut quoted_len = 0;
+            if self.quote.is_some() && chars.peek().copied() == self.quote {
+                let _ = chars.next();
+                loop {
+                    match chars.next() {
+                        None => return Err(CsvError::UnterminatedQuote { line: line_number }),
+                        Some(c) if Some(c) == self.quote => {
+                            if chars.peek() == Some(&c) {
+                                let _ = chars.next();
+                                field.push(c);
+                            } else {
+                                break;
+                            }
+                        }
+                        Some(c) => field.push(c),
+                    }
+                }
+                quoted_len = field.len();
+            }
+
+            let mut end_of_line = true;
+            for c in &mut chars {
+                if is_delimiter(c) {
+                    end_of_line = false;
+                    break;
+                }
+                field.push(c);
+            }
+
+            let len = field.trim_end().len().max(quoted_len);
+            field.truncate(len);
+            fields.push(field);
+
+            if end_of_line {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl<T: Scalar + FromStr> CsvReader<T> {
+    /// Returns an iterator reading the matrix rows from `reader` one line at a time.
+    #[inline]
+    pub fn rows<Rd: BufRead>(&self, reader: Rd) -> CsvRows<'_, Rd, T> {
+        CsvRows {
+            options: self,
+            reader,
+            line: String::new(),
+            fields: Vec::new(),
+            line_number: 0,
+            record_line: 0,
+            nfields: None,
+            done: false,
+        }
+    }
+
+    /// Reads a matrix from `reader`, one row per line.
+    ///
+    /// The input is read one line at a time, so only the components of the matrix are held in
+    /// memory, not its text. They are gathered row by row in a single buffer that is then
+    /// reordered in-place, so this does not copy the matrix.
+    #[inline]
+    pub fn read<Rd: Read>(&self, reader: Rd) -> Result<DMatrix<T>, CsvError> {
+        let mut rows = self.rows(BufReader::new(reader));
+        match rows.read_chunk(usize::MAX)? {
+            Some(m) => Ok(m),
+            None => Ok(rows.empty()),
+        }
+    }
+
+    /// Reads a matrix from a string, one row per line.
+    #[inline]
+    pub fn read_str(&self, data: &str) -> Result<DMatrix<T>, CsvError> {
+        self.read(data.as_bytes())
+    }
+
+    /// Reads a matrix from the file at the given path, one row per line.
+    #[inline]
+    pub fn load<P: AsRef<Path>>(&self, path: P) -> Result<DMatrix<T>, CsvError> {
+        self.read(File::open(path)?)
+    }
+}
+
+/// An iterator over the rows of a matrix stored as delimited text.
+///
+/// Each item is the content of one data line, after column selection. Only the current line is
+/// held in memory, so that inputs too large to be read at once can be processed row by row, in
+/// chunks of rows with [`CsvRows::read_chunk`], or appended to a growing matrix with
+/// [`CsvRows::read_into`].
+///
+/// This is created by [`CsvReader::rows`].
+///
+/// # Example
+/// ```
+/// # use nalgebra::DMatrix;
+/// # use nalgebra::io::CsvReader;
+/// let data = "1,2\n3,4\n5,6\n";
+/// let reader = CsvReader::<i32>::new();
+/// let mut rows = reader.rows(data.as_bytes());
+/// assert_eq!(rows.next().unwrap().unwrap(), vec![1, 2]);
+///
+/// let mut m = rows.read_chunk(1).unwrap().unwrap();
+/// assert_eq!(m, DMatrix::from_row_slice(1, 2, &[3, 4]));
+///
+/// assert_eq!(rows.read_into(&mut m, 10).unwrap(), 1);
+/// assert_eq!(m, DMatrix::from_row_slice(2, 2, &[3, 4, 5, 6]));
+/// assert!(rows.read_chunk(10).unwrap().is_none());
+/// assert_eq!(rows.read_into(&mut m, 10).unwrap(), 0);
+/// ```
+pub struct CsvRows<'a, Rd, T> {
+    options: &'a CsvReader<T>,
+    reader: Rd,
+    line: String,
+    fields: Vec<String>,
+    line_number: usize,
+    record_line: usize,
+    nfields: Option<usize>,
+    done: bool,
+}
+
+impl<'a, Rd: BufRead, T: Scalar + FromStr> CsvRows<'a, Rd, T> {
+    /// The number of the last line read, starting at 1.
+    #[inline]
+    #[must_use]
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    /// Reads at most `max_rows` rows into a matrix, or returns `None` at the end of the input.
+    ///
+    /// The components are gathered row by row in a buffer that is then reordered in-place into
+    /// the column-major matrix, so the matrix is not copied.
+    ///
+    /// # Panics
+    /// Panics if `max_rows == 0`, since no row could be read even before the end of the input.
+    pub fn read_chunk(&mut self, max_rows: usize) -> Result<Option<DMatrix<T>>, CsvError> {
+        assert!(
+            max_rows > 0,
+            "read_chunk: the number of rows must be positive."
+        );
+        let mut data = Vec::new();
+        let mut nrows = 0;
+        let mut ncols = 0;
+
+        while nrows < max_rows {
+            match self.next() {
+                Some(row) => {
+                    let row = row?;
+                    ncols = row.len();
+                    data.extend(row);
+                    nrows += 1;
+                }
+                None => break,
+            }
+        }
+
+        if nrows == 0 {
+            Ok(None)
+        } else {
+            let data = RowMajorVecStorage::new(Dynamic::new(nrows), Dynamic::new(ncols), data);
+            Ok(Some(Matrix::from_data(data).into_column_major()))
+        }
+    }
+
+    /// Reads at most `max_rows` rows and appends them at the bottom of `matrix`, returning the
+    /// number of rows appended, which is `0` at the end of the input.
+    ///
+    /// If `matrix` is empty, it is replaced by the rows read. Otherwise, they must have as many
+    /// components as `matrix` has columns.
+    ///
+    /// # Panics
+    /// Panics if `max_rows == 0`, since no row could be read even before the end of the input.
+    pub fn read_into(
+        &mut self,
+        matrix: &mut DMatrix<T>,
+        max_rows: usize,
+    ) -> Result<usize, CsvError> {
+        assert!(
+            max_rows > 0,
+            "read_into: the number of rows must be positive."
+        );
+        let chunk = match self.read_chunk(max_rows)? {
+            Some(chunk) => chunk,
+            None => return Ok(0),
+        };
+        let (nrows, ncols) = chunk.shape();
+
+        if matrix.is_empty() {
+            *matrix = chunk;
+        } else if matrix.ncols() != ncols {
+            return Err(CsvError::RaggedRow {
+                line: self.record_line,
+                expected: matrix.ncols(),
+                found: ncols,
+            });
+        } else {
+            let old_nrows = matrix.nrows();
+            matrix.resize_vertically_mut(old_nrows + nrows, chunk[(0, 0)].clone());
+            matrix.rows_mut(old_nrows, nrows).copy_from(&chunk);
+        }
+
+        Ok(nrows)
+    }
+
+    /// The matrix with as many columns as the rows read, but without any row.
+    fn empty(&self) -> DMatrix<T> {
+        let ncols = match &self.options.columns {
+            Some(columns) => columns.len(),
+            None => self.nfields.unwrap_or(0),
+        };
+        DMatrix::from_vec(0, ncols, Vec::new())
+    }
+
+    /// Parses the fields of the current line, or returns `None` if it must be skipped.
+    fn parse_line(&mut self) -> Result<Option<Vec<T>>, CsvError> {
+        let options = self.options;
+        let line = self.line.trim_end_matches(&['\n', '\r'][..]);
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || options.comment.into_iter().any(|c| trimmed.starts_with(c)) {
+            return Ok(None);
+        }
+
+        options.split_fields(line, self.record_line, &mut self.fields)?;
+        let nfields = self.fields.len();
+        match self.nfields {
+            Some(expected) if expected != nfields => {
+                return Err(CsvError::RaggedRow {
+                    line: self.record_line,
+                    expected,
+                    found: nfields,
+                })
+            }
+            Some(_) => {}
+            None => {
+                if let Some(&column) = options.columns.iter().flatten().find(|c| **c >= nfields) {
+                    return Err(CsvError::ColumnOutOfRange {
+                        line: self.record_line,
+                        column,
+                        nfields,
+                    });
+                }
+                self.nfields = Some(nfields);
+            }
+        }
+
+        let parse = |column: usize| -> Result<Option<T>, CsvError> {
+            let field = &self.fields[column];
+            if options.missing_tokens.iter().any(|t| t == field) {
+                match &options.missing_values {
+                    MissingValues::Error => Err(CsvError::MissingValue {
+                        line: self.record_line,
+                        column,
+                    }),
+                    MissingValues::Fill(value) => Ok(Some(value.clone())),
+                    MissingValues::SkipRow => Ok(None),
+                }
+            } else {
+                field.parse().map(Some).map_err(|_| CsvError::InvalidValue {
+                    line: self.record_line,
+                    column,
+                    value: field.clone(),
+                })
+            }
+        };
+
+        match &options.columns {
+            Some(columns) => columns.iter().map(|c| parse(*c)).collect(),
+            None => (0..nfields).map(parse).collect(),
+        }
+    }
+}
+
+impl<'a, Rd: BufRead, T: Scalar + FromStr> Iterator for CsvRows<'a, Rd, T> {
+    type Item = Result<Vec<T>, CsvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => self.done = true,
+                Ok(_) => {
+                    self.line_number += 1;
+                    if self.line_number <= self.options.header_lines {
+                        continue;
+                    }
+
+                    self.record_line = self.line_number;
+                    let mut result = self.parse_line();
+
+                    // A quoted field may span several lines: append the next one and retry.
+                    while let Err(CsvError::UnterminatedQuote { .. }) = result {
+                        match self.reader.read_line(&mut self.line) {
+                            Ok(0) => break,
+                            Ok(_) => {
+                                self.line_number += 1;
+                                result = self.parse_line();
+                            }
+                            Err(e) => {
+                                self.done = true;
+                                return Some(Err(e.into()));
+                            }
+                        }
+                    }
+
+                    match result {
+                        Ok(Some(row)) => return Some(Ok(row)),
+                        Ok(None) => {}
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e.into()));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// The options for writing a matrix as delimited text, one matrix row per line.
+///
+/// By default, components are separated by commas and no header is written. The header names
+/// and the formatted components are enclosed in quotes if they could not be read back otherwise,
+/// e.g., because they contain a delimiter, a quote or a line break.
+///
+/// # Example
+/// ```
+/// # use nalgebra::Matrix2x3;
+/// # use nalgebra::io::CsvWriter;
+/// let m = Matrix2x3::new(1.0, 2.5, 3.0,
+///                        4.0, 5.0, f64::NAN);
+/// let mut out = Vec::new();
+/// CsvWriter::new()
+///     .header(&["x", "y", "z, w"])
+///     .write(&mut out, &m)
+///     .unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "x,y,\"z, w\"\n1,2.5,3\n4,5,NaN\n");
+/// ```
+#[derive(Clone, Debug)]
+pub struct CsvWriter {
+    delimiter: char,
+    quote: char,
+    header: Option<Vec<String>>,
+}
+
+impl Default for CsvWriter {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CsvWriter {
+    /// Creates the default options for writing comma-separated values.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            delimiter: ',',
+            quote: '"',
+            header: None,
+        }
+    }
+
+    /// Separates the components by the given character, e.g., `'\t'` or `' '`.
+    #[inline]
+    #[must_use]
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the character enclosing the fields that contain a delimiter, a quote or a line break.
+    #[inline]
+    #[must_use]
+    pub fn quote(mut self, quote: char) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Writes a first line with the given column names.
+    #[inline]
+    #[must_use]
+    pub fn header(mut self, names: &[&str]) -> Self {
+        self.header = Some(names.iter().map(|n| n.to_string()).collect());
+        self
+    }
+
+    /// Writes `matrix` into `writer`, one row per line, formatting each component with `Display`.
+    pub fn write<W, T, R, C, S>(&self, mut writer: W, matrix: &Matrix<T, R, C, S>) -> io::Result<()>
+    where
+        W: Write,
+        T: Scalar + fmt::Display,
+        R: Dim,
+        C: Dim,
+        S: RawStorage<T, R, C>,
+    {
+        if let Some(names) = &self.header {
+            for (j, name) in names.iter().enumerate() {
+                if j != 0 {
+                    write!(writer, "{}", self.delimiter)?;
+                }
+                self.write_field(&mut writer, name)?;
+            }
+            writeln!(writer)?;
+        }
+
+        for i in 0..matrix.nrows() {
+            for j in 0..matrix.ncols() {
+                if j != 0 {
+                    write!(writer, "{}", self.delimiter)?;
+                }
+                self.write_field(&mut writer, &matrix[(i, j)].to_string())?;
+            }
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes `field`, enclosed in quotes if [`CsvReader`] would not read it back unchanged
+    /// otherwise.
+    fn write_field<W: Write>(&self, writer: &mut W, field: &str) -> io::Result<()> {
+        let needs_quotes = field.contains(&[self.delimiter, self.quote, '\n', '\r'][..])
+            || field.starts_with(char::is_whitespace)
+            || field.ends_with(char::is_whitespace)
+            || (self.delimiter.is_whitespace() && field.contains(char::is_whitespace));
+
+        if needs_quotes {
+            let doubled = format!("{}{}", self.quote, self.quote);
+            let escaped = field.replace(self.quote, &doubled);
+            write!(writer, "{}{}{}", self.quote, escaped, self.quote)
+        } else {
+            write!(writer, "{}", field)
+        }
+    }
+
+    /// Writes `matrix` into a file at the given path, one row per line.
+    pub fn save<T, R, C, S, P>(&self, path: P, matrix: &Matrix<T, R, C, S>) -> io::Result<()>
+    where
+        T: Scalar + fmt::Display,
+        R: Dim,
+        C: Dim,
+        S: RawStorage<T, R, C>,
+        P: AsRef<Path>,
+    {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer, matrix)?;
+        writer.flush()
+    }
+}
+
+/// Reads a matrix from the CSV file at the given path, with the default [`CsvReader`] options.
+#[inline]
+pub fn load_csv<T, P>(path: P) -> Result<DMatrix<T>, CsvError>
+where
+    T: Scalar + FromStr,
+    P: AsRef<Path>,
+{
+    CsvReader::new().load(path)
+}
+
+/// Writes `matrix` into a CSV file at the given path, with the default [`CsvWriter`] options.
+#[inline]
+pub fn save_csv<T, R, C, S, P>(path: P, matrix: &Matrix<T, R, C, S>) -> io::Result<()>
+where
+    T: Scalar + fmt::Display,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+    P: AsRef<Path>,
+{
+    CsvWriter::new().save(path, matrix)
+}
@@ -1,5 +1,11 @@
-//! Parsers for various matrix formats.
+//! Parsers and writers for various matrix formats.
 
+pub use self::csv::{load_csv, save_csv, CsvError, CsvReader, CsvRows, CsvWriter, MissingValues};
 pub use self::matrix_market::{cs_matrix_from_matrix_market, cs_matrix_from_matrix_market_str};
+pub use self::npy::{
+    load_npy, read_npy, save_npy, write_npy, NpyDecoder, NpyElement, NpyError, NpzReader, NpzWriter,
+};
 
+mod csv;
 mod matrix_market;
+mod npy;
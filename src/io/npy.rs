@@ -0,0 +1,630 @@
+//! Reading and writing of NumPy `.npy` files and `.npz` archives.
+
+use std::convert::TryInto;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Seek, Write};
+use std::path::Path;
+
+use num_complex::Complex;
+use zip::result::ZipError;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::Dim;
+use crate::base::storage::RawStorage;
+use crate::base::{DefaultAllocator, Matrix, OMatrix, Scalar, ShapeMismatch};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// The error returned when reading or writing a `.npy` file or a `.npz` archive.
+#[derive(Debug)]
+pub enum NpyError {
+    /// An I/O error occurred.
+    Io(io::Error),
+    /// The `.npz` archive could not be read or written.
+    Zip(ZipError),
+    /// The data does not start with a valid `.npy` header.
+    InvalidHeader(String),
+    /// The dtype of the array cannot be converted losslessly into the requested scalar type.
+    UnsupportedDtype {
+        /// The dtype of the array, e.g., `<f8`.
+        dtype: String,
+        /// The name of the requested scalar type.
+        target: &'static str,
+    },
+    /// The array has more than two dimensions.
+    UnsupportedShape(Vec<usize>),
+    /// The shape of the array does not match the statically-known shape of the target.
+    ShapeMismatch(ShapeMismatch),
+    /// The `.npz` archive does not contain an array with the given name.
+    MissingArray(String),
+}
+
+impl fmt::Display for NpyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "I/O error: {}", e),
+            Self::Zip(e) => write!(f, "invalid npz archive: {}", e),
+            Self::InvalidHeader(msg) => write!(f, "invalid npy header: {}", msg),
+            Self::UnsupportedDtype { dtype, target } => {
+                write!(
+                    f,
+                    "cannot read an array of dtype `{}` as `{}`",
+                    dtype, target
+                )
+            }
+            Self::UnsupportedShape(shape) => {
+                write!(f, "cannot read an array of shape {:?} as a matrix", shape)
+            }
+            Self::ShapeMismatch(e) => write!(
+                f,
+                "cannot read a {}x{} array as a {}x{} matrix",
+                e.lhs.0, e.lhs.1, e.rhs.0, e.rhs.1
+            ),
+            Self::MissingArray(name) => write!(f, "no array named `{}` in the archive", name),
+        }
+    }
+}
+
+impl std::error::Error for NpyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Zip(e) => Some(e),
+            Self::ShapeMismatch(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for NpyError {
+    #[inline]
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<ZipError> for NpyError {
+    #[inline]
+    fn from(e: ZipError) -> Self {
+        match e {
+            ZipError::Io(e) => Self::Io(e),
+            e => Self::Zip(e),
+        }
+    }
+}
+
+/// A function decoding one component from its bytes, given whether they are big-endian.
+pub type NpyDecoder<T> = fn(&[u8], bool) -> T;
+
+/// A scalar type that can be read from and written to `.npy` files.
+///
+/// Reading an array converts its components into this type only when this is lossless, e.g.,
+/// an array of dtype `<f4` or `<i4` can be read as `f64`, but not an array of dtype `<i8`.
+pub trait NpyElement: Scalar {
+    /// The dtype written for this type, e.g., `<f8` for `f64`.
+    const DESCR: &'static str;
+
+    /// The function decoding a component of the dtype of kind `kind` (e.g. `'f'`) and of `size`
+    /// bytes into this type, or `None` if this conversion is not supported.
+    fn decoder(kind: char, size: usize) -> Option<NpyDecoder<Self>>;
+
+    /// Appends the little-endian bytes of `self` to `out`.
+    fn write_le(&self, out: &mut Vec<u8>);
+}
+
+trait FromBytes: Sized {
+    fn from_bytes(bytes: &[u8], big_endian: bool) -> Self;
+}
+
+macro_rules! impl_from_bytes(
+    ($($T: ty),*) => {$(
+        impl FromBytes for $T {
+            #[inline]
+            fn from_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let bytes = bytes.try_into().unwrap();
+                if big_endian {
+                    Self::from_be_bytes(bytes)
+                } else {
+                    Self::from_le_bytes(bytes)
+                }
+            }
+        }
+    )*}
+);
+
+impl_from_bytes!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
+impl<T: FromBytes> FromBytes for Complex<T> {
+    #[inline]
+    fn from_bytes(bytes: &[u8], big_endian: bool) -> Self {
+        let (re, im) = bytes.split_at(bytes.len() / 2);
+        Complex::new(T::from_bytes(re, big_endian), T::from_bytes(im, big_endian))
+    }
+}
+
+macro_rules! impl_npy_element(
+    ($($T: ty, $descr: expr, [$($kind: expr => $Src: ty),*]);* $(;)*) => {$(
+        impl NpyElement for $T {
+            const DESCR: &'static str = $descr;
+
+            #[inline]
+            fn decoder(kind: char, size: usize) -> Option<NpyDecoder<Self>> {
+                $(
+                    if kind == $kind && size == std::mem::size_of::<$Src>() {
+                        return Some(|bytes, big_endian| {
+                            <$T>::from(<$Src as FromBytes>::from_bytes(bytes, big_endian))
+                        });
+                    }
+                )*
+                None
+            }
+
+            #[inline]
+            fn write_le(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes())
+            }
+        }
+    )*}
+);
+
+impl_npy_element!(
+    f64, "<f8", ['f' => f64, 'f' => f32, 'i' => i8, 'i' => i16, 'i' => i32, 'u' => u8, 'u' => u16, 'u' => u32];
+    f32, "<f4", ['f' => f32, 'i' => i8, 'i' => i16, 'u' => u8, 'u' => u16];
+    i64, "<i8", ['i' => i64, 'i' => i32, 'i' => i16, 'i' => i8, 'u' => u32, 'u' => u16, 'u' => u8];
+    i32, "<i4", ['i' => i32, 'i' => i16, 'i' => i8, 'u' => u16, 'u' => u8];
+    i16, "<i2", ['i' => i16, 'i' => i8, 'u' => u8];
+    i8, "|i1", ['i' => i8];
+    u64, "<u8", ['u' => u64, 'u' => u32, 'u' => u16, 'u' => u8];
+    u32, "<u4", ['u' => u32, 'u' => u16, 'u' => u8];
+    u16, "<u2", ['u' => u16, 'u' => u8];
+    u8, "|u1", ['u' => u8];
+);
+
+macro_rules! impl_npy_element_complex(
+    ($($T: ty, $descr: expr, [$($real_kind: expr => $Real: ty),*], [$($complex_kind: expr => $Complex: ty),*]);* $(;)*) => {$(
+        impl NpyElement for Complex<$T> {
+            const DESCR: &'static str = $descr;
+
+            #[inline]
+            fn decoder(kind: char, size: usize) -> Option<NpyDecoder<Self>> {
+                $(
+                    if kind == $real_kind && size == std::mem::size_of::<$Real>() {
+                        return Some(|bytes, big_endian| {
+                            Complex::from(<$T>::from(<$Real>::from_bytes(bytes, big_endian)))
+                        });
+                    }
+                )*
+                $(
+                    if kind == $complex_kind && size == std::mem::size_of::<Complex<$Complex>>() {
+                        return Some(|bytes, big_endian| {
+                            let c = Complex::<$Complex>::from_bytes(bytes, big_endian);
+                            Complex::new(<$T>::from(c.re), <$T>::from(c.im))
+                        });
+                    }
+                )*
+                None
+            }
+
+            #[inline]
+            fn write_le(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.re.to_le_bytes());
+                out.extend_from_slice(&self.im.to_le_bytes());
+            }
+        }
+    )*}
+);
+
+impl_npy_element_complex!(
+    f64, "<c16", ['f' => f64, 'f' => f32], ['c' => f64, 'c' => f32];
+    f32, "<c8", ['f' => f32], ['c' => f32];
+);
+
+/// The dtype, memory order, and shape read from a `.npy` header.
+struct Header {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+/// The value associated to `key` in the Python dictionary literal `dict`.
+fn dict_value<'a>(dict: &'a str, key: &str) -> Result<&'a str, NpyError> {
+    let pattern = format!("'{}':", key);
+    dict.find(&pattern)
+        .map(|start| dict[start + pattern.len()..].trim_start())
+        .ok_or_else(|| NpyError::InvalidHeader(format!("missing key `{}`", key)))
+}
+
+fn parse_header(dict: &str) -> Result<Header, NpyError> {
+    let invalid = |what: &str| NpyError::InvalidHeader(format!("invalid {}", what));
+
+    let descr = dict_value(dict, "descr")?;
+    let descr = match descr.strip_prefix('\'') {
+        Some(descr) => descr.split('\'').next().unwrap(),
+        // Structured dtypes are described by a list.
+        None => descr.split("],").next().unwrap_or(descr),
+    };
+
+    let fortran_order = dict_value(dict, "fortran_order")?;
+    let fortran_order = if fortran_order.starts_with("True") {
+        true
+    } else if fortran_order.starts_with("False") {
+        false
+    } else {
+        return Err(invalid("fortran_order"));
+    };
+
+    let shape = dict_value(dict, "shape")?;
+    let shape = shape
+        .strip_prefix('(')
+        .and_then(|s| s.split(')').next())
+        .ok_or_else(|| invalid("shape"))?
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().map_err(|_| invalid("shape")))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Header {
+        descr: descr.to_string(),
+        fortran_order,
+        shape,
+    })
+}
+
+fn read_header<Rd: Read>(reader: &mut Rd) -> Result<Header, NpyError> {
+    let mut preamble = [0; 8];
+    reader.read_exact(&mut preamble)?;
+    if &preamble[..6] != MAGIC {
+        return Err(NpyError::InvalidHeader("missing magic string".to_string()));
+    }
+
+    let len = match preamble[6] {
+        1 => {
+            let mut len = [0; 2];
+            reader.read_exact(&mut len)?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0; 4];
+            reader.read_exact(&mut len)?;
+            u32::from_le_bytes(len) as usize
+        }
+        v => {
+            return Err(NpyError::InvalidHeader(format!(
+                "unsupported format version {}",
+                v
+            )))
+        }
+    };
+
+    let dict = read_bytes(reader, len)?;
+    let dict = std::str::from_utf8(&dict)
+        .map_err(|_| NpyError::InvalidHeader("the header is not valid UTF-8".to_string()))?;
+
+    parse_header(dict)
+}
+
+/// Reads exactly `len` bytes from `reader`.
+///
+/// The buffer grows with the data actually read, so that a length read from a corrupted or
+/// truncated file results in an error instead of a huge allocation.
+fn read_bytes<Rd: Read>(reader: Rd, len: usize) -> Result<Vec<u8>, NpyError> {
+    let mut bytes = Vec::new();
+    let _ = reader.take(len as u64).read_to_end(&mut bytes)?;
+
+    if bytes.len() == len {
+        Ok(bytes)
+    } else {
+        Err(NpyError::Io(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "the file is shorter than declared in its header",
+        )))
+    }
+}
+
+/// Whether an `nrows x ncols` matrix can be stored into an `OMatrix<T, R, C>`.
+fn fits<R: Dim, C: Dim>(nrows: usize, ncols: usize) -> bool {
+    R::try_to_usize().unwrap_or(nrows) == nrows && C::try_to_usize().unwrap_or(ncols) == ncols
+}
+
+/// Reads a matrix from the content of a `.npy` file.
+///
+/// A 1-dimensional array of length `n` is read as a `n x 1` column vector, or as a `1 x n` row
+/// vector if the target has a single row. More generally, an array with a single row or column
+/// is transposed if this is required to match the statically-known shape of the target, e.g.,
+/// when reading a `1 x n` array as a `DVector`. Arrays in Fortran order are read without any
+/// transposition, and arrays in C order are transposed to the column-major layout of the result.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{DMatrix, Dynamic, U1};
+/// # use nalgebra::io::{read_npy, write_npy};
+/// let m = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+/// let mut bytes = Vec::new();
+/// write_npy(&mut bytes, &m).unwrap();
+/// let read: DMatrix<f64> = read_npy(&bytes[..]).unwrap();
+/// assert_eq!(read, m);
+///
+/// // A 2x3 array cannot be read as a vector.
+/// assert!(read_npy::<_, f64, Dynamic, U1>(&bytes[..]).is_err());
+/// ```
+pub fn read_npy<Rd, T, R, C>(mut reader: Rd) -> Result<OMatrix<T, R, C>, NpyError>
+where
+    Rd: Read,
+    T: NpyElement,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<T, R, C>,
+{
+    let header = read_header(&mut reader)?;
+
+    let unsupported_dtype = || NpyError::UnsupportedDtype {
+        dtype: header.descr.clone(),
+        target: std::any::type_name::<T>(),
+    };
+    let mut descr_chars = header.descr.chars();
+    let big_endian = match descr_chars.next() {
+        Some('>') => true,
+        Some('<') | Some('|') | Some('=') => false,
+        _ => return Err(unsupported_dtype()),
+    };
+    let kind = descr_chars.next().ok_or_else(unsupported_dtype)?;
+    let size: usize = descr_chars
+        .as_str()
+        .parse()
+        .map_err(|_| unsupported_dtype())?;
+    let decode = T::decoder(kind, size).ok_or_else(unsupported_dtype)?;
+
+    let (nrows, ncols) = match header.shape[..] {
+        [] => (1, 1),
+        [n] if R::try_to_usize() == Some(1) && C::try_to_usize() != Some(1) => (1, n),
+        [n] => (n, 1),
+        [nrows, ncols] => (nrows, ncols),
+        _ => return Err(NpyError::UnsupportedShape(header.shape)),
+    };
+
+    let transposed =
+        (nrows == 1 || ncols == 1) && !fits::<R, C>(nrows, ncols) && fits::<R, C>(ncols, nrows);
+    let (res_nrows, res_ncols) = if transposed {
+        (ncols, nrows)
+    } else {
+        (nrows, ncols)
+    };
+
+    if !fits::<R, C>(res_nrows, res_ncols) {
+        return Err(NpyError::ShapeMismatch(ShapeMismatch::new(
+            (nrows, ncols),
+            (
+                R::try_to_usize().unwrap_or(nrows),
+                C::try_to_usize().unwrap_or(ncols),
+            ),
+        )));
+    }
+
+    let len = nrows
+        .checked_mul(ncols)
+        .and_then(|len| len.checked_mul(size))
+        .ok_or_else(|| NpyError::InvalidHeader("the array is too large".to_string()))?;
+    let data = read_bytes(&mut reader, len)?;
+
+    Ok(OMatrix::from_fn_generic(
+        R::from_usize(res_nrows),
+        C::from_usize(res_ncols),
+        |i, j| {
+            let (i, j) = if transposed { (j, i) } else { (i, j) };
+            let id = if header.fortran_order {
+                i + j * nrows
+            } else {
+                i * ncols + j
+            };
+            decode(&data[id * size..(id + 1) * size], big_endian)
+        },
+    ))
+}
+
+/// Reads a matrix from the `.npy` file at the given path.
+///
+/// See [`read_npy`] for details.
+pub fn load_npy<T, R, C, P>(path: P) -> Result<OMatrix<T, R, C>, NpyError>
+where
+    T: NpyElement,
+    R: Dim,
+    C: Dim,
+    P: AsRef<Path>,
+    DefaultAllocator: Allocator<T, R, C>,
+{
+    read_npy(BufReader::new(File::open(path)?))
+}
+
+/// Writes `matrix` in the `.npy` format, with little-endian components in Fortran order.
+///
+/// Matrices with a single column known at compile-time, e.g. `DVector`, are written as
+/// 1-dimensional arrays. Other matrices are written as 2-dimensional arrays.
+pub fn write_npy<W, T, R, C, S>(mut writer: W, matrix: &Matrix<T, R, C, S>) -> Result<(), NpyError>
+where
+    W: Write,
+    T: NpyElement,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    let (nrows, ncols) = matrix.shape();
+    let dict = if C::try_to_usize() == Some(1) {
+        format!(
+            "{{'descr': '{}', 'fortran_order': False, 'shape': ({},), }}",
+            T::DESCR,
+            nrows
+        )
+    } else {
+        format!(
+            "{{'descr': '{}', 'fortran_order': True, 'shape': ({}, {}), }}",
+            T::DESCR,
+            nrows,
+            ncols
+        )
+    };
+
+    // The header, including the preamble and the final new line, is padded to a multiple of 64.
+    let padding = (64 - (MAGIC.len() + 4 + dict.len() + 1) % 64) % 64;
+    let header_len = dict.len() + padding + 1;
+
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 4 + header_len + matrix.len() * 16);
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&(header_len as u16).to_le_bytes());
+    bytes.extend_from_slice(dict.as_bytes());
+    bytes.resize(bytes.len() + padding, b' ');
+    bytes.push(b'\n');
+
+    for e in matrix.iter() {
+        e.write_le(&mut bytes);
+    }
+
+    writer.write_all(&bytes)?;
+    Ok(())
+}
+
+/// Writes `matrix` into a `.npy` file at the given path.
+///
+/// See [`write_npy`] for details.
+pub fn save_npy<T, R, C, S, P>(path: P, matrix: &Matrix<T, R, C, S>) -> Result<(), NpyError>
+where
+    T: NpyElement,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+    P: AsRef<Path>,
+{
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_npy(&mut writer, matrix)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// A reader of the arrays of a `.npz` archive, as written by `numpy.savez` or
+/// `numpy.savez_compressed`.
+pub struct NpzReader<Rd: Read + Seek> {
+    archive: ZipArchive<Rd>,
+}
+
+impl NpzReader<BufReader<File>> {
+    /// Opens the `.npz` archive at the given path.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, NpyError> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<Rd: Read + Seek> NpzReader<Rd> {
+    /// Reads the `.npz` archive provided by `reader`.
+    pub fn new(reader: Rd) -> Result<Self, NpyError> {
+        Ok(Self {
+            archive: ZipArchive::new(reader)?,
+        })
+    }
+
+    /// The names of the arrays of this archive.
+    #[must_use]
+    pub fn names(&self) -> Vec<String> {
+        self.archive
+            .file_names()
+            .map(|name| name.strip_suffix(".npy").unwrap_or(name).to_string())
+            .collect()
+    }
+
+    /// Reads the array with the given name, as [`read_npy`] would.
+    pub fn by_name<T, R, C>(&mut self, name: &str) -> Result<OMatrix<T, R, C>, NpyError>
+    where
+        T: NpyElement,
+        R: Dim,
+        C: Dim,
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let file_name = format!("{}.npy", name);
+        let file_name = if self.archive.file_names().any(|n| n == file_name) {
+            &file_name
+        } else {
+            name
+        };
+
+        match self.archive.by_name(file_name) {
+            Ok(file) => read_npy(file),
+            Err(ZipError::FileNotFound) => Err(NpyError::MissingArray(name.to_string())),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// A writer of `.npz` archives, readable with `numpy.load`.
+///
+/// # Example
+/// ```
+/// # use std::io::Cursor;
+/// # use nalgebra::{DMatrix, DVector};
+/// # use nalgebra::io::{NpzReader, NpzWriter};
+/// let m = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+/// let v = DVector::from_vec(vec![1i64, 2, 3]);
+///
+/// let mut writer = NpzWriter::new_compressed(Cursor::new(Vec::new()));
+/// writer.add("m", &m).unwrap();
+/// writer.add("v", &v).unwrap();
+/// let bytes = writer.finish().unwrap().into_inner();
+///
+/// let mut reader = NpzReader::new(Cursor::new(bytes)).unwrap();
+/// let read_m: DMatrix<f64> = reader.by_name("m").unwrap();
+/// let read_v: DVector<i64> = reader.by_name("v").unwrap();
+/// assert_eq!((read_m, read_v), (m, v));
+/// ```
+pub struct NpzWriter<W: Write + Seek> {
+    archive: ZipWriter<W>,
+    compression: CompressionMethod,
+}
+
+impl NpzWriter<BufWriter<File>> {
+    /// Creates a `.npz` archive at the given path, like `numpy.savez`.
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self, NpyError> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write + Seek> NpzWriter<W> {
+    /// Creates a writer of uncompressed `.npz` archives, like `numpy.savez`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            archive: ZipWriter::new(writer),
+            compression: CompressionMethod::Stored,
+        }
+    }
+
+    /// Creates a writer of compressed `.npz` archives, like `numpy.savez_compressed`.
+    pub fn new_compressed(writer: W) -> Self {
+        Self {
+            archive: ZipWriter::new(writer),
+            compression: CompressionMethod::Deflated,
+        }
+    }
+
+    /// Adds `matrix` to the archive under the given name, as [`write_npy`] would.
+    pub fn add<T, R, C, S>(
+        &mut self,
+        name: &str,
+        matrix: &Matrix<T, R, C, S>,
+    ) -> Result<(), NpyError>
+    where
+        T: NpyElement,
+        R: Dim,
+        C: Dim,
+        S: RawStorage<T, R, C>,
+    {
+        let options = FileOptions::default().compression_method(self.compression);
+        self.archive.start_file(format!("{}.npy", name), options)?;
+        write_npy(&mut self.archive, matrix)
+    }
+
+    /// Completes the archive and returns the underlying writer.
+    pub fn finish(mut self) -> Result<W, NpyError> {
+        Ok(self.archive.finish()?)
+    }
+}
@@ -94,6 +94,9 @@ an optimized set of tools for computer graphics and physics. Those features incl
 #[cfg(feature = "rand-no-std")]
 extern crate rand_package as rand;
 
+#[cfg(feature = "rayon")]
+extern crate rayon_package as rayon;
+
 #[cfg(feature = "serde-serialize-no-std")]
 #[macro_use]
 extern crate serde;
@@ -138,7 +141,7 @@ pub use crate::sparse::*;
 pub use base as core;
 
 #[cfg(feature = "macros")]
-pub use nalgebra_macros::{dmatrix, dvector, matrix, point, vector};
+pub use nalgebra_macros::{dmatrix, dvector, matrix, point, stack, vector};
 
 use simba::scalar::SupersetOf;
 use std::cmp::{self, Ordering, PartialOrd};
@@ -0,0 +1,407 @@
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use num::Zero;
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{BandMatrix, DefaultAllocator, Matrix, OMatrix};
+use crate::dimension::Dim;
+use crate::storage::{Storage, StorageMut};
+
+/// LU decomposition with partial (row) pivoting of a square band matrix.
+///
+/// With `kl` subdiagonals and `ku` superdiagonals, the factorization takes `O(n * kl * (kl + ku))`
+/// operations. Because of the row interchanges, the `U` factor has `kl + ku` superdiagonals.
+#[derive(Clone, Debug)]
+pub struct BandLU<T: ComplexField> {
+    // The multipliers of `L` are stored below the diagonal, and `U` on and above it.
+    lu: BandMatrix<T>,
+    // The row exchanged with the row `k` at the step `k` of the elimination.
+    pivots: Vec<usize>,
+}
+
+impl<T: ComplexField> BandLU<T> {
+    /// Computes the LU decomposition with partial (row) pivoting of the square band matrix
+    /// `matrix`.
+    pub fn new(matrix: BandMatrix<T>) -> Self {
+        assert_eq!(
+            matrix.nrows(),
+            matrix.ncols(),
+            "Band LU: the matrix must be square."
+        );
+
+        let n = matrix.nrows();
+        let kl = matrix.lower_bandwidth();
+        let ku = matrix.upper_bandwidth() + kl;
+
+        // Make room for the fill-in due to the row interchanges.
+        let mut lu = BandMatrix::zeros(n, n, kl, ku);
+        for j in 0..n {
+            for i in matrix.band_rows(j) {
+                lu[(i, j)] = matrix[(i, j)].clone();
+            }
+        }
+
+        let mut pivots = Vec::with_capacity(n);
+
+        for k in 0..n {
+            let last_row = (k + kl).min(n - 1);
+            let last_col = (k + ku).min(n - 1);
+
+            let mut piv = k;
+            let mut max = lu[(k, k)].clone().norm1();
+            for i in k + 1..=last_row {
+                let val = lu[(i, k)].clone().norm1();
+                if val > max {
+                    piv = i;
+                    max = val;
+                }
+            }
+            pivots.push(piv);
+
+            if max.is_zero() {
+                // The matrix is singular: there is nothing to eliminate in this column.
+                continue;
+            }
+
+            if piv != k {
+                for j in k..=last_col {
+                    let tmp = lu[(k, j)].clone();
+                    lu[(k, j)] = lu[(piv, j)].clone();
+                    lu[(piv, j)] = tmp;
+                }
+            }
+
+            let inv_diag = T::one() / lu[(k, k)].clone();
+            for i in k + 1..=last_row {
+                let coeff = lu[(i, k)].clone() * inv_diag.clone();
+                lu[(i, k)] = coeff.clone();
+
+                for j in k + 1..=last_col {
+                    let update = coeff.clone() * lu[(k, j)].clone();
+                    lu[(i, j)] -= update;
+                }
+            }
+        }
+
+        Self { lu, pivots }
+    }
+
+    /// The dimension of the decomposed matrix.
+    #[inline]
+    #[must_use]
+    pub fn dim(&self) -> usize {
+        self.lu.nrows()
+    }
+
+    /// Indicates if the decomposed matrix is invertible.
+    #[must_use]
+    pub fn is_invertible(&self) -> bool {
+        (0..self.dim()).all(|i| !self.lu[(i, i)].is_zero())
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    #[must_use]
+    pub fn determinant(&self) -> T {
+        let mut res = T::one();
+        for (k, piv) in self.pivots.iter().enumerate() {
+            res *= self.lu[(k, k)].clone();
+            if *piv != k {
+                res = -res;
+            }
+        }
+
+        res
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns `None` if `self` is not invertible.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves in-place the linear system `self * x = b`, where `x` is the unknown to be
+    /// determined.
+    ///
+    /// If the decomposed matrix is not invertible, this returns `false` and its input `b` is
+    /// overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+    {
+        let n = self.dim();
+        assert_eq!(
+            b.nrows(),
+            n,
+            "Band LU solve: mismatched matrix and right-hand-side dimensions."
+        );
+
+        if !self.is_invertible() {
+            return false;
+        }
+
+        let kl = self.lu.lower_bandwidth();
+        let ku = self.lu.upper_bandwidth();
+
+        for mut col in b.column_iter_mut() {
+            // Apply the row interchanges and `L^-1` step by step, as the multipliers of a column
+            // are not affected by the interchanges of the later steps.
+            for k in 0..n {
+                col.swap_rows(k, self.pivots[k]);
+                for i in k + 1..(k + kl + 1).min(n) {
+                    let update = self.lu[(i, k)].clone() * col[k].clone();
+                    col[i] -= update;
+                }
+            }
+
+            for k in (0..n).rev() {
+                for j in k + 1..(k + ku + 1).min(n) {
+                    let update = self.lu[(k, j)].clone() * col[j].clone();
+                    col[k] -= update;
+                }
+                col[k] /= self.lu[(k, k)].clone();
+            }
+        }
+
+        true
+    }
+}
+
+/// Cholesky decomposition of a hermitian definite-positive band matrix.
+///
+/// With `kl` subdiagonals, the factorization takes `O(n * kl²)` operations and the `L` factor
+/// keeps the same bandwidth.
+#[derive(Clone, Debug)]
+pub struct BandCholesky<T: ComplexField> {
+    l: BandMatrix<T>,
+}
+
+impl<T: ComplexField> BandCholesky<T> {
+    /// Attempts to compute the Cholesky decomposition of `matrix`.
+    ///
+    /// Returns `None` if the input matrix is not definite-positive. The input matrix is assumed
+    /// to be hermitian and only its lower band, i.e., its diagonal and its `kl` subdiagonals, is
+    /// read.
+    pub fn new(matrix: BandMatrix<T>) -> Option<Self> {
+        assert_eq!(
+            matrix.nrows(),
+            matrix.ncols(),
+            "Band Cholesky: the matrix must be square."
+        );
+
+        let n = matrix.nrows();
+        let kl = matrix.lower_bandwidth();
+        let mut l = BandMatrix::<T>::zeros(n, n, kl, 0);
+
+        for j in 0..n {
+            for i in j..(j + kl + 1).min(n) {
+                let mut val = matrix[(i, j)].clone();
+                for k in i.saturating_sub(kl)..j {
+                    val -= l[(i, k)].clone() * l[(j, k)].clone().conjugate();
+                }
+
+                if i == j {
+                    if val.is_zero() {
+                        return None;
+                    }
+                    l[(j, j)] = val.try_sqrt()?;
+                } else {
+                    l[(i, j)] = val / l[(j, j)].clone();
+                }
+            }
+        }
+
+        Some(Self { l })
+    }
+
+    /// Retrieves the lower-triangular band matrix `L` such that the decomposed matrix is
+    /// `L * L.adjoint()`.
+    #[must_use]
+    pub fn l(&self) -> &BandMatrix<T> {
+        &self.l
+    }
+
+    /// Retrieves the lower-triangular band factor of this decomposition.
+    pub fn unpack(self) -> BandMatrix<T> {
+        self.l
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    #[must_use]
+    pub fn determinant(&self) -> T::RealField {
+        let mut prod_diag = T::one();
+        for i in 0..self.l.nrows() {
+            prod_diag *= self.l[(i, i)].clone();
+        }
+        prod_diag.modulus_squared()
+    }
+
+    /// Solves the system `self * x = b` where `self` is the decomposed matrix and `x` the unknown.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(&self, b: &Matrix<T, R2, C2, S2>) -> OMatrix<T, R2, C2>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        self.solve_mut(&mut res);
+        res
+    }
+
+    /// Solves in-place the system `self * x = b` where `self` is the decomposed matrix and `x`
+    /// the unknown.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>)
+    where
+        S2: StorageMut<T, R2, C2>,
+    {
+        let n = self.l.nrows();
+        let kl = self.l.lower_bandwidth();
+        assert_eq!(
+            b.nrows(),
+            n,
+            "Band Cholesky solve: mismatched matrix and right-hand-side dimensions."
+        );
+
+        for mut col in b.column_iter_mut() {
+            for j in 0..n {
+                col[j] /= self.l[(j, j)].clone();
+                for i in j + 1..(j + kl + 1).min(n) {
+                    let update = self.l[(i, j)].clone() * col[j].clone();
+                    col[i] -= update;
+                }
+            }
+
+            for j in (0..n).rev() {
+                for i in j + 1..(j + kl + 1).min(n) {
+                    let update = self.l[(i, j)].clone().conjugate() * col[i].clone();
+                    col[j] -= update;
+                }
+                col[j] /= self.l[(j, j)].clone().conjugate();
+            }
+        }
+    }
+}
+
+/// # Band matrix decompositions and linear system resolution
+impl<T: ComplexField> BandMatrix<T> {
+    /// Computes the LU decomposition with partial (row) pivoting of this square band matrix.
+    pub fn lu(self) -> BandLU<T> {
+        BandLU::new(self)
+    }
+
+    /// Attempts to compute the Cholesky decomposition of this hermitian band matrix.
+    ///
+    /// Returns `None` if the input matrix is not definite-positive. Only the diagonal and the
+    /// subdiagonals of `self` are read.
+    pub fn cholesky(self) -> Option<BandCholesky<T>> {
+        BandCholesky::new(self)
+    }
+
+    /// Solves the tridiagonal linear system `self * x = b` with the Thomas algorithm.
+    ///
+    /// Returns `None` if a zero pivot is encountered.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{BandMatrix, DVector};
+    /// let a = BandMatrix::tridiagonal(&[1.0, 1.0], &[4.0, 4.0, 4.0], &[1.0, 1.0]);
+    /// let b = DVector::from_vec(vec![5.0, 6.0, 5.0]);
+    /// let x = a.solve_tridiagonal(&b).unwrap();
+    /// assert_relative_eq!(x, DVector::from_element(3, 1.0));
+    /// ```
+    #[must_use = "Did you mean to use solve_tridiagonal_mut()?"]
+    pub fn solve_tridiagonal<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Option<OMatrix<T, R2, C2>>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        if self.solve_tridiagonal_mut(&mut res) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+
+    /// Solves in-place the tridiagonal linear system `self * x = b` with the Thomas algorithm.
+    ///
+    /// This takes `O(n)` operations per column of `b` but, unlike `.lu()`, performs no pivoting:
+    /// it is stable for diagonally dominant or symmetric definite-positive matrices. If a zero
+    /// pivot is encountered, this returns `false` and `b` is left unchanged.
+    ///
+    /// Panics if `self` is not tridiagonal.
+    pub fn solve_tridiagonal_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> bool
+    where
+        S2: StorageMut<T, R2, C2>,
+    {
+        assert!(
+            self.is_tridiagonal(),
+            "Thomas algorithm: the matrix must be tridiagonal."
+        );
+
+        let n = self.nrows();
+        assert_eq!(
+            b.nrows(),
+            n,
+            "Thomas algorithm: mismatched matrix and right-hand-side dimensions."
+        );
+
+        let entry = |i: usize, j: usize| self.get(i, j).cloned().unwrap_or_else(T::zero);
+
+        // The pivots and the modified superdiagonal are the same for every column of `b`.
+        let mut pivots = Vec::with_capacity(n);
+        let mut sup: Vec<T> = Vec::with_capacity(n);
+
+        for i in 0..n {
+            let mut pivot = entry(i, i);
+            if i > 0 {
+                pivot -= entry(i, i - 1) * sup[i - 1].clone();
+            }
+
+            if pivot.is_zero() {
+                return false;
+            }
+
+            if i + 1 < n {
+                sup.push(entry(i, i + 1) / pivot.clone());
+            }
+            pivots.push(pivot);
+        }
+
+        for mut col in b.column_iter_mut() {
+            for i in 0..n {
+                if i > 0 {
+                    let update = entry(i, i - 1) * col[i - 1].clone();
+                    col[i] -= update;
+                }
+                col[i] /= pivots[i].clone();
+            }
+
+            for i in (0..n.saturating_sub(1)).rev() {
+                let update = sup[i].clone() * col[i + 1].clone();
+                col[i] -= update;
+            }
+        }
+
+        true
+    }
+}
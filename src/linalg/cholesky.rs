@@ -1,40 +1,50 @@
 #[cfg(feature = "serde-serialize-no-std")]
 use serde::{Deserialize, Serialize};
 
+use core::ops::Range;
 use num::One;
 use simba::scalar::ComplexField;
 use simba::simd::SimdComplexField;
 
 use crate::allocator::Allocator;
-use crate::base::{Const, DefaultAllocator, Matrix, OMatrix, Vector};
+use crate::base::{Const, DefaultAllocator, Matrix, OMatrix, ShapeMismatch, Vector};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimAdd, DimDiff, DimSub, DimSum, U1};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::linalg::column_blocks::for_each_column_block;
 use crate::storage::{Storage, StorageMut};
 
+/// The smallest dimension from which `Cholesky::new` switches to the blocked algorithm.
+#[cfg(any(feature = "std", feature = "alloc"))]
+const BLOCKED_CHOLESKY_THRESHOLD: usize = 96;
+/// The number of columns factorized at once by the blocked algorithm.
+#[cfg(any(feature = "std", feature = "alloc"))]
+const CHOLESKY_BLOCK_SIZE: usize = 32;
+
 /// The Cholesky decomposition of a symmetric-definite-positive matrix.
 #[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde-serialize-no-std",
-    serde(bound(serialize = "DefaultAllocator: Allocator<T, D>,
-         OMatrix<T, D, D>: Serialize"))
+    serde(bound(serialize = "A: Allocator<T, D, D>,
+         Matrix<T, D, D, A::Buffer>: Serialize"))
 )]
 #[cfg_attr(
     feature = "serde-serialize-no-std",
-    serde(bound(deserialize = "DefaultAllocator: Allocator<T, D>,
-         OMatrix<T, D, D>: Deserialize<'de>"))
+    serde(bound(deserialize = "A: Allocator<T, D, D>,
+         Matrix<T, D, D, A::Buffer>: Deserialize<'de>"))
 )]
 #[derive(Clone, Debug)]
-pub struct Cholesky<T: SimdComplexField, D: Dim>
+pub struct Cholesky<T: SimdComplexField, D: Dim, A = DefaultAllocator>
 where
-    DefaultAllocator: Allocator<T, D, D>,
+    A: Allocator<T, D, D>,
 {
-    chol: OMatrix<T, D, D>,
+    chol: Matrix<T, D, D, A::Buffer>,
 }
 
-impl<T: SimdComplexField, D: Dim> Copy for Cholesky<T, D>
+impl<T: SimdComplexField, D: Dim, A> Copy for Cholesky<T, D, A>
 where
-    DefaultAllocator: Allocator<T, D, D>,
-    OMatrix<T, D, D>: Copy,
+    A: Allocator<T, D, D> + Copy,
+    Matrix<T, D, D, A::Buffer>: Copy,
 {
 }
 
@@ -81,10 +91,15 @@ where
     pub fn pack_dirty(matrix: OMatrix<T, D, D>) -> Self {
         Cholesky { chol: matrix }
     }
+}
 
+impl<T: SimdComplexField, D: Dim, A> Cholesky<T, D, A>
+where
+    A: Allocator<T, D, D>,
+{
     /// Retrieves the lower-triangular factor of the Cholesky decomposition with its strictly
     /// upper-triangular part filled with zeros.
-    pub fn unpack(mut self) -> OMatrix<T, D, D> {
+    pub fn unpack(mut self) -> Matrix<T, D, D, A::Buffer> {
         self.chol.fill_upper_triangle(T::zero(), 1);
         self.chol
     }
@@ -94,14 +109,17 @@ where
     ///
     /// The values of the strict upper-triangular part are garbage and should be ignored by further
     /// computations.
-    pub fn unpack_dirty(self) -> OMatrix<T, D, D> {
+    pub fn unpack_dirty(self) -> Matrix<T, D, D, A::Buffer> {
         self.chol
     }
 
     /// Retrieves the lower-triangular factor of the Cholesky decomposition with its strictly
     /// uppen-triangular part filled with zeros.
     #[must_use]
-    pub fn l(&self) -> OMatrix<T, D, D> {
+    pub fn l(&self) -> OMatrix<T, D, D>
+    where
+        DefaultAllocator: Allocator<T, D, D>,
+    {
         self.chol.lower_triangle()
     }
 
@@ -111,7 +129,7 @@ where
     /// This is an allocation-less version of `self.l()`. The values of the strict upper-triangular
     /// part are garbage and should be ignored by further computations.
     #[must_use]
-    pub fn l_dirty(&self) -> &OMatrix<T, D, D> {
+    pub fn l_dirty(&self) -> &Matrix<T, D, D, A::Buffer> {
         &self.chol
     }
 
@@ -141,9 +159,41 @@ where
         res
     }
 
+    /// Solves the system `self * x = b` like `.solve`, or returns an error if `b` does not have
+    /// as many rows as the decomposed matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, DVector, ShapeMismatch};
+    /// let chol = DMatrix::from_row_slice(2, 2, &[4.0, 0.0, 0.0, 1.0]).cholesky().unwrap();
+    /// let x = chol.try_solve(&DVector::from_vec(vec![2.0, 2.0]));
+    /// assert_eq!(x, Ok(DVector::from_vec(vec![0.5, 2.0])));
+    ///
+    /// let err = chol.try_solve(&DMatrix::<f64>::zeros(3, 2));
+    /// assert_eq!(err, Err(ShapeMismatch::new((2, 2), (3, 2))));
+    /// ```
+    pub fn try_solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Result<OMatrix<T, R2, C2>, ShapeMismatch>
+    where
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        if b.nrows() != self.chol.nrows() {
+            return Err(ShapeMismatch::new(self.chol.shape(), b.shape()));
+        }
+
+        Ok(self.solve(b))
+    }
+
     /// Computes the inverse of the decomposed matrix.
     #[must_use]
-    pub fn inverse(&self) -> OMatrix<T, D, D> {
+    pub fn inverse(&self) -> OMatrix<T, D, D>
+    where
+        DefaultAllocator: Allocator<T, D, D>,
+    {
         let shape = self.chol.shape_generic();
         let mut res = OMatrix::identity_generic(shape.0, shape.1);
 
@@ -194,15 +244,98 @@ where
     pub fn new_with_substitute(matrix: OMatrix<T, D, D>, substitute: T) -> Option<Self> {
         Self::new_internal(matrix, Some(substitute))
     }
+}
+
+impl<T: ComplexField, D: Dim, A> Cholesky<T, D, A>
+where
+    A: Allocator<T, D, D>,
+{
+    /// Attempts to compute the Cholesky decomposition of `matrix`, which owns a buffer allocated
+    /// by `A`.
+    ///
+    /// This is the same as `Cholesky::new` but for any allocator, e.g., an `InlineAllocator` in
+    /// environments without any heap allocator. See also `Matrix::cholesky_in`.
+    pub fn new_in(matrix: Matrix<T, D, D, A::Buffer>) -> Option<Self> {
+        Self::new_internal(matrix, None)
+    }
 
-    /// Common implementation for `new` and `new_with_substitute`.
-    fn new_internal(mut matrix: OMatrix<T, D, D>, substitute: Option<T>) -> Option<Self> {
+    /// Common implementation for `new`, `new_with_substitute` and `new_in`.
+    fn new_internal(matrix: Matrix<T, D, D, A::Buffer>, substitute: Option<T>) -> Option<Self> {
         assert!(matrix.is_square(), "The input matrix must be square.");
 
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        {
+            if matrix.nrows() >= BLOCKED_CHOLESKY_THRESHOLD {
+                return Self::new_blocked(matrix, substitute);
+            }
+        }
+
+        Self::new_unblocked(matrix, substitute)
+    }
+
+    /// Unblocked Cholesky decomposition.
+    fn new_unblocked(
+        mut matrix: Matrix<T, D, D, A::Buffer>,
+        substitute: Option<T>,
+    ) -> Option<Self> {
         let n = matrix.nrows();
 
-        for j in 0..n {
-            for k in 0..j {
+        if Self::factorize_columns(&mut matrix, 0..n, substitute.as_ref()) {
+            Some(Cholesky { chol: matrix })
+        } else {
+            None
+        }
+    }
+
+    /// Right-looking blocked Cholesky decomposition.
+    ///
+    /// Each block of columns is factorized with the unblocked algorithm, then the lower-triangular
+    /// part of the rest of the matrix is updated at once using `gemm`.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn new_blocked(mut matrix: Matrix<T, D, D, A::Buffer>, substitute: Option<T>) -> Option<Self> {
+        let n = matrix.nrows();
+
+        for start in (0..n).step_by(CHOLESKY_BLOCK_SIZE) {
+            let end = (start + CHOLESKY_BLOCK_SIZE).min(n);
+
+            if !Self::factorize_columns(&mut matrix, start..end, substitute.as_ref()) {
+                return None;
+            }
+
+            let (panel, mut right) = matrix.columns_range_pair_mut(start..end, end..);
+            let l21 = panel.rows_range(end..);
+
+            for_each_column_block(right.rows_range_mut(end..), &|offset, mut a22| {
+                for block_start in (0..a22.ncols()).step_by(CHOLESKY_BLOCK_SIZE) {
+                    let block_end = (block_start + CHOLESKY_BLOCK_SIZE).min(a22.ncols());
+                    let rows = offset + block_start..offset + block_end;
+                    let l21_block_ad = l21.rows_range(rows.clone()).adjoint();
+
+                    a22.slice_range_mut(rows.start.., block_start..block_end)
+                        .gemm(
+                            -T::one(),
+                            &l21.rows_range(rows.start..),
+                            &l21_block_ad,
+                            T::one(),
+                        );
+                }
+            });
+        }
+
+        Some(Cholesky { chol: matrix })
+    }
+
+    /// Factorizes the columns `range` of `matrix`, assuming the contributions of the columns
+    /// before `range.start` have already been subtracted from the remaining ones.
+    ///
+    /// Returns `false` if the input matrix is not definite-positive.
+    fn factorize_columns(
+        matrix: &mut Matrix<T, D, D, A::Buffer>,
+        range: Range<usize>,
+        substitute: Option<&T>,
+    ) -> bool {
+        for j in range.clone() {
+            for k in range.start..j {
                 let factor = unsafe { -matrix.get_unchecked((j, k)).clone() };
 
                 let (mut col_j, col_k) = matrix.columns_range_pair_mut(j, k);
@@ -222,7 +355,7 @@ where
             let diag = unsafe { matrix.get_unchecked((j, j)).clone() };
 
             if let Some(denom) =
-                sqrt_denom(diag).or_else(|| substitute.clone().and_then(sqrt_denom))
+                sqrt_denom(diag).or_else(|| substitute.cloned().and_then(sqrt_denom))
             {
                 unsafe {
                     *matrix.get_unchecked_mut((j, j)) = denom.clone();
@@ -235,10 +368,10 @@ where
 
             // The diagonal element is either zero or its square root could not
             // be taken (e.g. for negative real numbers).
-            return None;
+            return false;
         }
 
-        Some(Cholesky { chol: matrix })
+        true
     }
 
     /// Given the Cholesky decomposition of a matrix `M`, a scalar `sigma` and a vector `v`,
@@ -253,6 +386,63 @@ where
         Self::xx_rank_one_update(&mut self.chol, &mut x.clone_owned(), sigma)
     }
 
+    /// Given the Cholesky decomposition of a matrix `M`, a scalar `sigma` and a vector `x`,
+    /// performs a rank one update such that we end up with the decomposition of `M + sigma * (x * x.adjoint())`.
+    ///
+    /// This helper method is called by `rank_one_update` but also `insert_column` and `remove_column`
+    /// where it is used on a square slice of the decomposition
+    fn xx_rank_one_update<Dm, Sm, Rx, Sx>(
+        chol: &mut Matrix<T, Dm, Dm, Sm>,
+        x: &mut Vector<T, Rx, Sx>,
+        sigma: T::RealField,
+    ) where
+        //T: ComplexField,
+        Dm: Dim,
+        Rx: Dim,
+        Sm: StorageMut<T, Dm, Dm>,
+        Sx: StorageMut<T, Rx, U1>,
+    {
+        // heavily inspired by Eigen's `llt_rank_update_lower` implementation https://eigen.tuxfamily.org/dox/LLT_8h_source.html
+        let n = x.nrows();
+        assert_eq!(
+            n,
+            chol.nrows(),
+            "The input vector must be of the same size as the factorized matrix."
+        );
+
+        let mut beta = crate::one::<T::RealField>();
+
+        for j in 0..n {
+            // updates the diagonal
+            let diag = T::real(unsafe { chol.get_unchecked((j, j)).clone() });
+            let diag2 = diag.clone() * diag.clone();
+            let xj = unsafe { x.get_unchecked(j).clone() };
+            let sigma_xj2 = sigma.clone() * T::modulus_squared(xj.clone());
+            let gamma = diag2.clone() * beta.clone() + sigma_xj2.clone();
+            let new_diag = (diag2.clone() + sigma_xj2.clone() / beta.clone()).sqrt();
+            unsafe { *chol.get_unchecked_mut((j, j)) = T::from_real(new_diag.clone()) };
+            beta += sigma_xj2 / diag2;
+            // updates the terms of L
+            let mut xjplus = x.rows_range_mut(j + 1..);
+            let mut col_j = chol.slice_range_mut(j + 1.., j);
+            // temp_jplus -= (wj / T::from_real(diag)) * col_j;
+            xjplus.axpy(-xj.clone() / T::from_real(diag.clone()), &col_j, T::one());
+            if gamma != crate::zero::<T::RealField>() {
+                // col_j = T::from_real(nljj / diag) * col_j  + (T::from_real(nljj * sigma / gamma) * T::conjugate(wj)) * temp_jplus;
+                col_j.axpy(
+                    T::from_real(new_diag.clone() * sigma.clone() / gamma) * T::conjugate(xj),
+                    &xjplus,
+                    T::from_real(new_diag / diag),
+                );
+            }
+        }
+    }
+}
+
+impl<T: ComplexField, D: Dim> Cholesky<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
     /// Updates the decomposition such that we get the decomposition of a matrix with the given column `col` in the `j`th position.
     /// Since the matrix is square, an identical row will be added in the `j`th row.
     pub fn insert_column<R2, S2>(
@@ -365,56 +555,32 @@ where
 
         Cholesky { chol }
     }
+}
 
-    /// Given the Cholesky decomposition of a matrix `M`, a scalar `sigma` and a vector `x`,
-    /// performs a rank one update such that we end up with the decomposition of `M + sigma * (x * x.adjoint())`.
-    ///
-    /// This helper method is called by `rank_one_update` but also `insert_column` and `remove_column`
-    /// where it is used on a square slice of the decomposition
-    fn xx_rank_one_update<Dm, Sm, Rx, Sx>(
-        chol: &mut Matrix<T, Dm, Dm, Sm>,
-        x: &mut Vector<T, Rx, Sx>,
-        sigma: T::RealField,
-    ) where
-        //T: ComplexField,
-        Dm: Dim,
-        Rx: Dim,
-        Sm: StorageMut<T, Dm, Dm>,
-        Sx: StorageMut<T, Rx, U1>,
-    {
-        // heavily inspired by Eigen's `llt_rank_update_lower` implementation https://eigen.tuxfamily.org/dox/LLT_8h_source.html
-        let n = x.nrows();
-        assert_eq!(
-            n,
-            chol.nrows(),
-            "The input vector must be of the same size as the factorized matrix."
-        );
-
-        let mut beta = crate::one::<T::RealField>();
-
-        for j in 0..n {
-            // updates the diagonal
-            let diag = T::real(unsafe { chol.get_unchecked((j, j)).clone() });
-            let diag2 = diag.clone() * diag.clone();
-            let xj = unsafe { x.get_unchecked(j).clone() };
-            let sigma_xj2 = sigma.clone() * T::modulus_squared(xj.clone());
-            let gamma = diag2.clone() * beta.clone() + sigma_xj2.clone();
-            let new_diag = (diag2.clone() + sigma_xj2.clone() / beta.clone()).sqrt();
-            unsafe { *chol.get_unchecked_mut((j, j)) = T::from_real(new_diag.clone()) };
-            beta += sigma_xj2 / diag2;
-            // updates the terms of L
-            let mut xjplus = x.rows_range_mut(j + 1..);
-            let mut col_j = chol.slice_range_mut(j + 1.., j);
-            // temp_jplus -= (wj / T::from_real(diag)) * col_j;
-            xjplus.axpy(-xj.clone() / T::from_real(diag.clone()), &col_j, T::one());
-            if gamma != crate::zero::<T::RealField>() {
-                // col_j = T::from_real(nljj / diag) * col_j  + (T::from_real(nljj * sigma / gamma) * T::conjugate(wj)) * temp_jplus;
-                col_j.axpy(
-                    T::from_real(new_diag.clone() * sigma.clone() / gamma) * T::conjugate(xj),
-                    &xjplus,
-                    T::from_real(new_diag / diag),
-                );
-            }
+#[cfg(test)]
+mod test {
+    use super::Cholesky;
+    use crate::linalg::column_blocks::test_matrix;
+    use crate::{DMatrix, Dynamic};
+
+    #[test]
+    fn cholesky_blocked_matches_unblocked() {
+        // Sizes around `BLOCKED_CHOLESKY_THRESHOLD` that are not all multiples of
+        // `CHOLESKY_BLOCK_SIZE`.
+        for &n in [95, 96, 97, 130].iter() {
+            let m = test_matrix(n, n, 42);
+            let m = &m * m.transpose() + DMatrix::identity(n, n);
+            let b = test_matrix(n, 3, 7);
+
+            let blocked: Cholesky<f64, Dynamic> = Cholesky::new_blocked(m.clone(), None).unwrap();
+            let unblocked: Cholesky<f64, Dynamic> =
+                Cholesky::new_unblocked(m.clone(), None).unwrap();
+
+            assert!(relative_eq!(blocked.l(), unblocked.l(), epsilon = 1.0e-10));
+
+            let x = blocked.solve(&b);
+            assert!(relative_eq!(x, unblocked.solve(&b), epsilon = 1.0e-8));
+            assert!(relative_eq!(m * x, b, epsilon = 1.0e-8));
         }
     }
 }
@@ -0,0 +1,75 @@
+//! Splitting of the trailing updates of the blocked factorizations into independent blocks of
+//! columns.
+
+use crate::base::dimension::{Dim, Dynamic};
+use crate::base::{MatrixSliceMut, Scalar};
+
+/// The minimum number of columns of a block processed by a single thread.
+#[cfg(feature = "rayon")]
+const PAR_MIN_BLOCK_COLUMNS: usize = 64;
+
+/// Calls `f(offset, block)` on disjoint blocks of columns of `matrix` covering all its columns,
+/// where `offset` is the index of the first column of `block` in `matrix`.
+///
+/// If the `rayon` feature is enabled, large matrices are split into several blocks processed in
+/// parallel. Otherwise, `f` is called once on the whole matrix.
+pub(crate) fn for_each_column_block<T, R, RStride, CStride, F>(
+    matrix: MatrixSliceMut<'_, T, R, Dynamic, RStride, CStride>,
+    f: &F,
+) where
+    T: Scalar + Send + Sync,
+    R: Dim,
+    RStride: Dim,
+    CStride: Dim,
+    F: Fn(usize, MatrixSliceMut<'_, T, R, Dynamic, RStride, CStride>) + Sync,
+{
+    #[cfg(feature = "rayon")]
+    {
+        if rayon::current_num_threads() > 1 {
+            return par_for_each_column_block(0, matrix, f);
+        }
+    }
+
+    f(0, matrix)
+}
+
+#[cfg(feature = "rayon")]
+fn par_for_each_column_block<T, R, RStride, CStride, F>(
+    offset: usize,
+    mut matrix: MatrixSliceMut<'_, T, R, Dynamic, RStride, CStride>,
+    f: &F,
+) where
+    T: Scalar + Send + Sync,
+    R: Dim,
+    RStride: Dim,
+    CStride: Dim,
+    F: Fn(usize, MatrixSliceMut<'_, T, R, Dynamic, RStride, CStride>) + Sync,
+{
+    let ncols = matrix.ncols();
+
+    if ncols < 2 * PAR_MIN_BLOCK_COLUMNS {
+        return f(offset, matrix);
+    }
+
+    let mid = ncols / 2;
+    let (left, right) = matrix.columns_range_pair_mut(0..mid, mid..ncols);
+    let _ = rayon::join(
+        || par_for_each_column_block(offset, left, f),
+        || par_for_each_column_block(offset + mid, right, f),
+    );
+}
+
+/// A deterministic matrix with pseudo-random components in `[-0.5, 0.5)`.
+///
+/// This is used to compare the blocked and unblocked factorizations without the `rand` feature.
+#[cfg(test)]
+pub(crate) fn test_matrix(nrows: usize, ncols: usize, seed: u64) -> crate::DMatrix<f64> {
+    let mut state = seed;
+
+    crate::DMatrix::from_fn(nrows, ncols, |_, _| {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+    })
+}
@@ -3,8 +3,10 @@ use std::cmp;
 use crate::base::allocator::Allocator;
 use crate::base::default_allocator::DefaultAllocator;
 use crate::base::dimension::{Const, Dim, DimAdd, DimDiff, DimSub, DimSum};
-use crate::storage::Storage;
-use crate::{zero, OVector, RealField, Vector, U1};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::linalg::fft;
+use crate::storage::{Storage, StorageMut};
+use crate::{zero, Matrix, OMatrix, OVector, RealField, Scalar, Vector, U1};
 
 impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
     /// Returns the convolution of the target vector and a kernel.
@@ -106,6 +108,12 @@ impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
     /// Returns the convolution of the target vector and a kernel.
     ///
     /// The output convolution is the same size as vector, centered with respect to the ‘full’ output.
+    /// It keeps the components `(m - 1) / 2 .. (m - 1) / 2 + n` of
+    /// [`convolve_full`](Vector::convolve_full), where `n` and `m` are the lengths of the vector and
+    /// of the kernel, like the "same" mode of NumPy and SciPy. This is the convention of every
+    /// "same" convolution and correlation of this module, e.g.,
+    /// [`convolve_same_2d`](Matrix::convolve_same_2d) on a single column gives the same result.
+    ///
     /// # Arguments
     ///
     /// * `kernel` - A Vector with size > 0
@@ -127,13 +135,15 @@ impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
         }
 
         let mut conv = OVector::zeros_generic(self.shape_generic().0, Const::<1>);
+        let offset = (ker - 1) / 2;
 
         for i in 0..vec {
             for j in 0..ker {
-                let val = if i + j < 1 || i + j >= vec + 1 {
+                let id = i + j + offset + 1;
+                let val = if id < ker || id >= vec + ker {
                     zero::<T>()
                 } else {
-                    self[i + j - 1].clone()
+                    self[id - ker].clone()
                 };
                 conv[i] += val * kernel[ker - j - 1].clone();
             }
@@ -141,4 +151,614 @@ impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
 
         conv
     }
+
+    /// Returns the convolution of the target vector and a kernel, computed with FFTs.
+    ///
+    /// This gives the same result as [`convolve_full`](Vector::convolve_full) in
+    /// `O((n + m) log(n + m))` instead of `O(n m)` operations, up to rounding errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - A Vector with size > 0
+    ///
+    /// # Errors
+    /// Inputs must satisfy `vector.len() >= kernel.len() > 0`.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[must_use]
+    pub fn convolve_full_fft<D2, S2>(
+        &self,
+        kernel: Vector<T, D2, S2>,
+    ) -> OVector<T, DimDiff<DimSum<D1, D2>, U1>>
+    where
+        D1: DimAdd<D2>,
+        D2: DimAdd<D1, Output = DimSum<D1, D2>>,
+        DimSum<D1, D2>: DimSub<U1>,
+        S2: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, DimDiff<DimSum<D1, D2>, U1>>,
+    {
+        let vec = self.len();
+        let ker = kernel.len();
+
+        if ker == 0 || ker > vec {
+            panic!("convolve_full_fft expects `self.len() >= kernel.len() > 0`, received {} and {} respectively.", vec, ker);
+        }
+
+        let result_len = self
+            .data
+            .shape()
+            .0
+            .add(kernel.shape_generic().0)
+            .sub(Const::<1>);
+        let full = fft::convolve_full_2d_fft(
+            |i, _| self[i].clone(),
+            (vec, 1),
+            |i, _| kernel[i].clone(),
+            (ker, 1),
+        );
+
+        OVector::from_iterator_generic(result_len, Const::<1>, full)
+    }
+
+    /// Returns the convolution of the target vector and a kernel, computed with FFTs.
+    ///
+    /// The output convolution is the same size as vector, centered with respect to the ‘full’ output
+    /// as described in [`convolve_same`](Vector::convolve_same). This gives the same result as
+    /// [`convolve_same`](Vector::convolve_same) in
+    /// `O((n + m) log(n + m))` instead of `O(n m)` operations, up to rounding errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - A Vector with size > 0
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.len() >= kernel.len() > 0`.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[must_use]
+    pub fn convolve_same_fft<D2, S2>(&self, kernel: Vector<T, D2, S2>) -> OVector<T, D1>
+    where
+        D2: Dim,
+        S2: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, D1>,
+    {
+        let vec = self.len();
+        let ker = kernel.len();
+
+        if ker == 0 || ker > vec {
+            panic!("convolve_same_fft expects `self.len() >= kernel.len() > 0`, received {} and {} respectively.",vec,ker);
+        }
+
+        let full = fft::convolve_full_2d_fft(
+            |i, _| self[i].clone(),
+            (vec, 1),
+            |i, _| kernel[i].clone(),
+            (ker, 1),
+        );
+
+        let offset = (ker - 1) / 2;
+        OVector::from_fn_generic(self.shape_generic().0, Const::<1>, |i, _| {
+            full[i + offset].clone()
+        })
+    }
+}
+
+impl<T: RealField, R1: Dim, C1: Dim, S1: Storage<T, R1, C1>> Matrix<T, R1, C1, S1> {
+    /// Returns the 2D convolution of the target matrix and a kernel.
+    ///
+    /// Large kernels are automatically handled with a FFT-based algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - A non-empty matrix
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.nrows() >= kernel.nrows() > 0` and
+    /// `self.ncols() >= kernel.ncols() > 0`.
+    #[must_use]
+    pub fn convolve_full_2d<R2, C2, S2>(
+        &self,
+        kernel: &Matrix<T, R2, C2, S2>,
+    ) -> OMatrix<T, DimDiff<DimSum<R1, R2>, U1>, DimDiff<DimSum<C1, C2>, U1>>
+    where
+        R1: DimAdd<R2>,
+        C1: DimAdd<C2>,
+        R2: Dim,
+        C2: Dim,
+        DimSum<R1, R2>: DimSub<U1>,
+        DimSum<C1, C2>: DimSub<U1>,
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, DimDiff<DimSum<R1, R2>, U1>, DimDiff<DimSum<C1, C2>, U1>>,
+    {
+        check_kernel_2d("convolve_full_2d", self.shape(), kernel.shape());
+        let (nrows, ncols) = self.shape_generic();
+        let (krows, kcols) = kernel.shape_generic();
+        let mut conv = OMatrix::zeros_generic(
+            nrows.add(krows).sub(Const::<1>),
+            ncols.add(kcols).sub(Const::<1>),
+        );
+
+        convolve_2d_to(
+            self,
+            kernel.shape(),
+            |i, j| kernel[(i, j)].clone(),
+            &mut conv,
+            (0, 0),
+        );
+        conv
+    }
+
+    /// Returns the 2D convolution of the target matrix and a kernel.
+    ///
+    /// The output convolution consists only of those elements that do not rely on the
+    /// zero-padding. Large kernels are automatically handled with a FFT-based algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - A non-empty matrix
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.nrows() >= kernel.nrows() > 0` and
+    /// `self.ncols() >= kernel.ncols() > 0`.
+    #[must_use]
+    pub fn convolve_valid_2d<R2, C2, S2>(
+        &self,
+        kernel: &Matrix<T, R2, C2, S2>,
+    ) -> OMatrix<T, DimDiff<DimSum<R1, U1>, R2>, DimDiff<DimSum<C1, U1>, C2>>
+    where
+        R1: DimAdd<U1>,
+        C1: DimAdd<U1>,
+        R2: Dim,
+        C2: Dim,
+        DimSum<R1, U1>: DimSub<R2>,
+        DimSum<C1, U1>: DimSub<C2>,
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, DimDiff<DimSum<R1, U1>, R2>, DimDiff<DimSum<C1, U1>, C2>>,
+    {
+        check_kernel_2d("convolve_valid_2d", self.shape(), kernel.shape());
+        let (nrows, ncols) = self.shape_generic();
+        let (krows, kcols) = kernel.shape_generic();
+        let mut conv = OMatrix::zeros_generic(
+            nrows.add(Const::<1>).sub(krows),
+            ncols.add(Const::<1>).sub(kcols),
+        );
+
+        let offset = (krows.value() - 1, kcols.value() - 1);
+        convolve_2d_to(
+            self,
+            kernel.shape(),
+            |i, j| kernel[(i, j)].clone(),
+            &mut conv,
+            offset,
+        );
+        conv
+    }
+
+    /// Returns the 2D convolution of the target matrix and a kernel.
+    ///
+    /// The output convolution is the same size as the target matrix, centered with respect to
+    /// the ‘full’ output: for a `p × q` kernel, it starts at the row `(p - 1) / 2` and the column
+    /// `(q - 1) / 2` of [`convolve_full_2d`](Matrix::convolve_full_2d), as described in
+    /// [`convolve_same`](Vector::convolve_same). Large kernels are automatically handled with a
+    /// FFT-based algorithm.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - A non-empty matrix
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.nrows() >= kernel.nrows() > 0` and
+    /// `self.ncols() >= kernel.ncols() > 0`.
+    #[must_use]
+    pub fn convolve_same_2d<R2, C2, S2>(&self, kernel: &Matrix<T, R2, C2, S2>) -> OMatrix<T, R1, C1>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R1, C1>,
+    {
+        check_kernel_2d("convolve_same_2d", self.shape(), kernel.shape());
+        let (nrows, ncols) = self.shape_generic();
+        let (krows, kcols) = kernel.shape();
+        let mut conv = OMatrix::zeros_generic(nrows, ncols);
+
+        let offset = ((krows - 1) / 2, (kcols - 1) / 2);
+        convolve_2d_to(
+            self,
+            kernel.shape(),
+            |i, j| kernel[(i, j)].clone(),
+            &mut conv,
+            offset,
+        );
+        conv
+    }
+
+    /// Returns the 2D cross-correlation of the target matrix and a kernel.
+    ///
+    /// This is the convolution of the target matrix with the kernel rotated by 180 degrees.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - A non-empty matrix
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.nrows() >= kernel.nrows() > 0` and
+    /// `self.ncols() >= kernel.ncols() > 0`.
+    #[must_use]
+    pub fn correlate_full_2d<R2, C2, S2>(
+        &self,
+        kernel: &Matrix<T, R2, C2, S2>,
+    ) -> OMatrix<T, DimDiff<DimSum<R1, R2>, U1>, DimDiff<DimSum<C1, C2>, U1>>
+    where
+        R1: DimAdd<R2>,
+        C1: DimAdd<C2>,
+        R2: Dim,
+        C2: Dim,
+        DimSum<R1, R2>: DimSub<U1>,
+        DimSum<C1, C2>: DimSub<U1>,
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, DimDiff<DimSum<R1, R2>, U1>, DimDiff<DimSum<C1, C2>, U1>>,
+    {
+        check_kernel_2d("correlate_full_2d", self.shape(), kernel.shape());
+        let (nrows, ncols) = self.shape_generic();
+        let (krows, kcols) = kernel.shape_generic();
+        let mut corr = OMatrix::zeros_generic(
+            nrows.add(krows).sub(Const::<1>),
+            ncols.add(kcols).sub(Const::<1>),
+        );
+
+        let flipped = flipped_kernel(kernel);
+        convolve_2d_to(self, kernel.shape(), flipped, &mut corr, (0, 0));
+        corr
+    }
+
+    /// Returns the 2D cross-correlation of the target matrix and a kernel.
+    ///
+    /// The output correlation consists only of those elements that do not rely on the
+    /// zero-padding.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - A non-empty matrix
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.nrows() >= kernel.nrows() > 0` and
+    /// `self.ncols() >= kernel.ncols() > 0`.
+    #[must_use]
+    pub fn correlate_valid_2d<R2, C2, S2>(
+        &self,
+        kernel: &Matrix<T, R2, C2, S2>,
+    ) -> OMatrix<T, DimDiff<DimSum<R1, U1>, R2>, DimDiff<DimSum<C1, U1>, C2>>
+    where
+        R1: DimAdd<U1>,
+        C1: DimAdd<U1>,
+        R2: Dim,
+        C2: Dim,
+        DimSum<R1, U1>: DimSub<R2>,
+        DimSum<C1, U1>: DimSub<C2>,
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, DimDiff<DimSum<R1, U1>, R2>, DimDiff<DimSum<C1, U1>, C2>>,
+    {
+        check_kernel_2d("correlate_valid_2d", self.shape(), kernel.shape());
+        let (nrows, ncols) = self.shape_generic();
+        let (krows, kcols) = kernel.shape_generic();
+        let mut corr = OMatrix::zeros_generic(
+            nrows.add(Const::<1>).sub(krows),
+            ncols.add(Const::<1>).sub(kcols),
+        );
+
+        let offset = (krows.value() - 1, kcols.value() - 1);
+        let flipped = flipped_kernel(kernel);
+        convolve_2d_to(self, kernel.shape(), flipped, &mut corr, offset);
+        corr
+    }
+
+    /// Returns the 2D cross-correlation of the target matrix and a kernel.
+    ///
+    /// The output correlation is the same size as the target matrix, centered with respect to
+    /// the ‘full’ output: for a `p × q` kernel, it starts at the row `(p - 1) / 2` and the column
+    /// `(q - 1) / 2` of [`correlate_full_2d`](Matrix::correlate_full_2d).
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - A non-empty matrix
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.nrows() >= kernel.nrows() > 0` and
+    /// `self.ncols() >= kernel.ncols() > 0`.
+    #[must_use]
+    pub fn correlate_same_2d<R2, C2, S2>(
+        &self,
+        kernel: &Matrix<T, R2, C2, S2>,
+    ) -> OMatrix<T, R1, C1>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R1, C1>,
+    {
+        check_kernel_2d("correlate_same_2d", self.shape(), kernel.shape());
+        let (nrows, ncols) = self.shape_generic();
+        let (krows, kcols) = kernel.shape();
+        let mut corr = OMatrix::zeros_generic(nrows, ncols);
+
+        let offset = ((krows - 1) / 2, (kcols - 1) / 2);
+        let flipped = flipped_kernel(kernel);
+        convolve_2d_to(self, kernel.shape(), flipped, &mut corr, offset);
+        corr
+    }
+
+    /// Returns the 2D convolution of the target matrix and the separable kernel
+    /// `col_kernel * row_kernel.transpose()`.
+    ///
+    /// This performs one 1D convolution along each column followed by one 1D convolution along
+    /// each row, which is much faster than `convolve_full_2d` on the equivalent dense kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `col_kernel` - A non-empty vector applied along the columns of `self`.
+    /// * `row_kernel` - A non-empty vector applied along the rows of `self`.
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.nrows() >= col_kernel.len() > 0` and
+    /// `self.ncols() >= row_kernel.len() > 0`.
+    #[must_use]
+    pub fn convolve_separable_full_2d<R2, C2, S2, S3>(
+        &self,
+        col_kernel: &Vector<T, R2, S2>,
+        row_kernel: &Vector<T, C2, S3>,
+    ) -> OMatrix<T, DimDiff<DimSum<R1, R2>, U1>, DimDiff<DimSum<C1, C2>, U1>>
+    where
+        R1: DimAdd<R2>,
+        C1: DimAdd<C2>,
+        R2: Dim,
+        C2: Dim,
+        DimSum<R1, R2>: DimSub<U1>,
+        DimSum<C1, C2>: DimSub<U1>,
+        S2: Storage<T, R2>,
+        S3: Storage<T, C2>,
+        DefaultAllocator: Allocator<T, DimDiff<DimSum<R1, R2>, U1>, DimDiff<DimSum<C1, C2>, U1>>
+            + Allocator<T, DimDiff<DimSum<R1, R2>, U1>, C1>,
+    {
+        check_kernel_2d(
+            "convolve_separable_full_2d",
+            self.shape(),
+            (col_kernel.len(), row_kernel.len()),
+        );
+        let (nrows, ncols) = self.shape_generic();
+        let mut conv = OMatrix::zeros_generic(
+            nrows.add(col_kernel.shape_generic().0).sub(Const::<1>),
+            ncols.add(row_kernel.shape_generic().0).sub(Const::<1>),
+        );
+
+        convolve_separable_2d_to(self, col_kernel, row_kernel, &mut conv, (0, 0));
+        conv
+    }
+
+    /// Returns the 2D convolution of the target matrix and the separable kernel
+    /// `col_kernel * row_kernel.transpose()`.
+    ///
+    /// The output convolution consists only of those elements that do not rely on the
+    /// zero-padding.
+    ///
+    /// # Arguments
+    ///
+    /// * `col_kernel` - A non-empty vector applied along the columns of `self`.
+    /// * `row_kernel` - A non-empty vector applied along the rows of `self`.
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.nrows() >= col_kernel.len() > 0` and
+    /// `self.ncols() >= row_kernel.len() > 0`.
+    #[must_use]
+    pub fn convolve_separable_valid_2d<R2, C2, S2, S3>(
+        &self,
+        col_kernel: &Vector<T, R2, S2>,
+        row_kernel: &Vector<T, C2, S3>,
+    ) -> OMatrix<T, DimDiff<DimSum<R1, U1>, R2>, DimDiff<DimSum<C1, U1>, C2>>
+    where
+        R1: DimAdd<U1>,
+        C1: DimAdd<U1>,
+        R2: Dim,
+        C2: Dim,
+        DimSum<R1, U1>: DimSub<R2>,
+        DimSum<C1, U1>: DimSub<C2>,
+        S2: Storage<T, R2>,
+        S3: Storage<T, C2>,
+        DefaultAllocator: Allocator<T, DimDiff<DimSum<R1, U1>, R2>, DimDiff<DimSum<C1, U1>, C2>>
+            + Allocator<T, DimDiff<DimSum<R1, U1>, R2>, C1>,
+    {
+        check_kernel_2d(
+            "convolve_separable_valid_2d",
+            self.shape(),
+            (col_kernel.len(), row_kernel.len()),
+        );
+        let (nrows, ncols) = self.shape_generic();
+        let mut conv = OMatrix::zeros_generic(
+            nrows.add(Const::<1>).sub(col_kernel.shape_generic().0),
+            ncols.add(Const::<1>).sub(row_kernel.shape_generic().0),
+        );
+
+        let offset = (col_kernel.len() - 1, row_kernel.len() - 1);
+        convolve_separable_2d_to(self, col_kernel, row_kernel, &mut conv, offset);
+        conv
+    }
+
+    /// Returns the 2D convolution of the target matrix and the separable kernel
+    /// `col_kernel * row_kernel.transpose()`.
+    ///
+    /// The output convolution is the same size as the target matrix, centered with respect to
+    /// the ‘full’ output: it starts at the row `(col_kernel.len() - 1) / 2` and the column
+    /// `(row_kernel.len() - 1) / 2` of
+    /// [`convolve_separable_full_2d`](Matrix::convolve_separable_full_2d).
+    ///
+    /// # Arguments
+    ///
+    /// * `col_kernel` - A non-empty vector applied along the columns of `self`.
+    /// * `row_kernel` - A non-empty vector applied along the rows of `self`.
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.nrows() >= col_kernel.len() > 0` and
+    /// `self.ncols() >= row_kernel.len() > 0`.
+    #[must_use]
+    pub fn convolve_separable_same_2d<R2, C2, S2, S3>(
+        &self,
+        col_kernel: &Vector<T, R2, S2>,
+        row_kernel: &Vector<T, C2, S3>,
+    ) -> OMatrix<T, R1, C1>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2>,
+        S3: Storage<T, C2>,
+        DefaultAllocator: Allocator<T, R1, C1>,
+    {
+        check_kernel_2d(
+            "convolve_separable_same_2d",
+            self.shape(),
+            (col_kernel.len(), row_kernel.len()),
+        );
+        let (nrows, ncols) = self.shape_generic();
+        let mut conv = OMatrix::zeros_generic(nrows, ncols);
+
+        let offset = ((col_kernel.len() - 1) / 2, (row_kernel.len() - 1) / 2);
+        convolve_separable_2d_to(self, col_kernel, row_kernel, &mut conv, offset);
+        conv
+    }
+}
+
+fn check_kernel_2d(name: &str, shape: (usize, usize), kernel_shape: (usize, usize)) {
+    if kernel_shape.0 == 0
+        || kernel_shape.1 == 0
+        || kernel_shape.0 > shape.0
+        || kernel_shape.1 > shape.1
+    {
+        panic!(
+            "{} expects a non-empty kernel not larger than the input matrix, received {:?} and {:?} respectively.",
+            name, shape, kernel_shape
+        );
+    }
+}
+
+fn flipped_kernel<'a, T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>>(
+    kernel: &'a Matrix<T, R, C, S>,
+) -> impl Fn(usize, usize) -> T + 'a {
+    let (nrows, ncols) = kernel.shape();
+    move |i, j| kernel[(nrows - 1 - i, ncols - 1 - j)].clone()
+}
+
+/// Writes into `out` the block of the full 2D convolution of `matrix` and `kernel` starting at
+/// `offset`.
+fn convolve_2d_to<T, R1, C1, S1, R3, C3, S3>(
+    matrix: &Matrix<T, R1, C1, S1>,
+    kernel_shape: (usize, usize),
+    kernel: impl Fn(usize, usize) -> T,
+    out: &mut Matrix<T, R3, C3, S3>,
+    offset: (usize, usize),
+) where
+    T: RealField,
+    R1: Dim,
+    C1: Dim,
+    R3: Dim,
+    C3: Dim,
+    S1: Storage<T, R1, C1>,
+    S3: StorageMut<T, R3, C3>,
+{
+    let (nrows, ncols) = matrix.shape();
+    let (krows, kcols) = kernel_shape;
+    let (out_rows, out_cols) = out.shape();
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    {
+        let full_rows = nrows + krows - 1;
+        let full_len = full_rows * (ncols + kcols - 1);
+
+        if fft::prefer_fft(out_rows * out_cols, krows * kcols, full_len) {
+            let full = fft::convolve_full_2d_fft(
+                |i, j| matrix[(i, j)].clone(),
+                (nrows, ncols),
+                &kernel,
+                kernel_shape,
+            );
+
+            for j in 0..out_cols {
+                for i in 0..out_rows {
+                    out[(i, j)] = full[i + offset.0 + (j + offset.1) * full_rows].clone();
+                }
+            }
+
+            return;
+        }
+    }
+
+    for j in 0..out_cols {
+        let fj = j + offset.1;
+        let kj_range = (fj + 1).saturating_sub(ncols)..cmp::min(kcols, fj + 1);
+
+        for i in 0..out_rows {
+            let fi = i + offset.0;
+            let ki_range = (fi + 1).saturating_sub(nrows)..cmp::min(krows, fi + 1);
+            let mut acc = T::zero();
+
+            for kj in kj_range.clone() {
+                for ki in ki_range.clone() {
+                    acc += matrix[(fi - ki, fj - kj)].clone() * kernel(ki, kj);
+                }
+            }
+
+            out[(i, j)] = acc;
+        }
+    }
+}
+
+/// Writes into `out` the block of the full 2D convolution of `matrix` and the separable kernel
+/// `col_kernel * row_kernel.transpose()` starting at `offset`.
+fn convolve_separable_2d_to<T, R1, C1, S1, R2, S2, C2, S3, R3, C3, S4>(
+    matrix: &Matrix<T, R1, C1, S1>,
+    col_kernel: &Vector<T, R2, S2>,
+    row_kernel: &Vector<T, C2, S3>,
+    out: &mut Matrix<T, R3, C3, S4>,
+    offset: (usize, usize),
+) where
+    T: RealField,
+    R1: Dim,
+    C1: Dim,
+    R2: Dim,
+    C2: Dim,
+    R3: Dim,
+    C3: Dim,
+    S1: Storage<T, R1, C1>,
+    S2: Storage<T, R2>,
+    S3: Storage<T, C2>,
+    S4: StorageMut<T, R3, C3>,
+    DefaultAllocator: Allocator<T, R3, C1>,
+{
+    let (nrows, ncols) = matrix.shape();
+    let (krows, kcols) = (col_kernel.len(), row_kernel.len());
+    let (out_rows, out_cols) = out.shape();
+
+    // Convolve each column with `col_kernel`, keeping only the rows needed for the output.
+    let mut tmp = OMatrix::zeros_generic(out.shape_generic().0, matrix.shape_generic().1);
+
+    for j in 0..ncols {
+        for i in 0..out_rows {
+            let fi = i + offset.0;
+
+            for ki in (fi + 1).saturating_sub(nrows)..cmp::min(krows, fi + 1) {
+                tmp[(i, j)] += matrix[(fi - ki, j)].clone() * col_kernel[ki].clone();
+            }
+        }
+    }
+
+    // Convolve each row with `row_kernel`.
+    for j in 0..out_cols {
+        let fj = j + offset.1;
+
+        for i in 0..out_rows {
+            let mut acc = T::zero();
+
+            for kj in (fj + 1).saturating_sub(ncols)..cmp::min(kcols, fj + 1) {
+                acc += tmp[(i, fj - kj)].clone() * row_kernel[kj].clone();
+            }
+
+            out[(i, j)] = acc;
+        }
+    }
 }
@@ -1,8 +1,12 @@
+use num::Signed;
+use num_integer::Integer;
+
 use crate::storage::Storage;
 use crate::{
     Allocator, Bidiagonal, Cholesky, ColPivQR, ComplexField, DefaultAllocator, Dim, DimDiff,
-    DimMin, DimMinimum, DimSub, FullPivLU, Hessenberg, Matrix, OMatrix, RealField, Schur,
-    SymmetricEigen, SymmetricTridiagonal, LU, QR, SVD, U1, UDU,
+    DimMin, DimMinimum, DimSub, FullPivLU, HermiteNormalForm, Hessenberg, Matrix, OMatrix,
+    RealField, Scalar, Schur, SimdComplexField, SimdLU, SimdQR, SmithNormalForm, SymmetricEigen,
+    SymmetricTridiagonal, LU, QR, SVD, U1, UDU,
 };
 
 /// # Rectangular matrix decomposition
@@ -270,6 +274,32 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
         Cholesky::new(self.into_owned())
     }
 
+    /// Attempts to compute the Cholesky decomposition of this matrix, which owns a buffer
+    /// allocated by `A`.
+    ///
+    /// Unlike `cholesky`, the decomposition keeps the buffer of `self` and does not need the
+    /// `DefaultAllocator`, e.g., it works for matrices backed by an `InlineAllocator` without any
+    /// heap allocator.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Dynamic, InlineAllocator, InlineDMatrix, InlineStorage, Matrix};
+    /// let m: InlineDMatrix<f64, 9> = Matrix::from_data(InlineStorage::from_row_slice(
+    ///     Dynamic::new(2),
+    ///     Dynamic::new(2),
+    ///     &[4.0, 2.0, 2.0, 5.0],
+    /// ));
+    /// let chol = m.cholesky_in::<InlineAllocator<9>>().unwrap();
+    /// assert_eq!(chol.l_dirty()[(1, 0)], 1.0);
+    /// assert_eq!(chol.determinant(), 16.0);
+    /// ```
+    pub fn cholesky_in<A>(self) -> Option<Cholesky<T, D, A>>
+    where
+        A: Allocator<T, D, D, Buffer = S>,
+    {
+        Cholesky::new_in(self)
+    }
+
     /// Attempts to compute the UDU decomposition of this matrix.
     ///
     /// The input matrix `self` is assumed to be symmetric and this decomposition will only read
@@ -376,3 +406,59 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
         SymmetricTridiagonal::new(self.into_owned())
     }
 }
+
+/// # SIMD square matrix decomposition
+///
+/// This section contains the methods for computing some common decompositions of square
+/// matrices with SIMD-lane components, e.g., `Matrix4<WideF32x8>`, where each lane holds an
+/// independent matrix. The decompositions are computed without branching on any lane, and the
+/// operations that may fail return a per-lane success mask. The following are currently supported:
+///
+/// | Decomposition            | Factors             | Details |
+/// | -------------------------|---------------------|--------------|
+/// | QR                       | `Q * R`             | `Q` is an unitary matrix, and `R` is upper-triangular. |
+/// | LU with partial pivoting | `P⁻¹ * L * U`       | `L` is lower-triangular with a diagonal filled with `1` and `U` is upper-triangular. `P` is a permutation matrix. |
+impl<T: SimdComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
+    /// Computes the LU decomposition with lane-wise partial (row) pivoting of this matrix.
+    pub fn simd_lu(self) -> SimdLU<T, D>
+    where
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        SimdLU::new(self.into_owned())
+    }
+
+    /// Computes the QR decomposition of this matrix.
+    pub fn simd_qr(self) -> SimdQR<T, D>
+    where
+        DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+    {
+        SimdQR::new(self.into_owned())
+    }
+}
+
+/// # Integer matrix decomposition
+///
+/// This section contains the methods for computing some common decompositions of matrices with
+/// integer components. The following are currently supported:
+///
+/// | Decomposition            | Factors             | Details |
+/// | -------------------------|---------------------|--------------|
+/// | Hermite normal form      | `H = U * A`         | `U` is unimodular, and `H` is in row echelon form. |
+/// | Smith normal form        | `D = U * A * V`     | `U` and `V` are unimodular, and `D` is a diagonal matrix. |
+impl<I: Scalar + Integer + Signed, R: Dim, C: Dim, S: Storage<I, R, C>> Matrix<I, R, C, S> {
+    /// Computes the Hermite normal form of this integer matrix.
+    pub fn hermite_normal_form(self) -> HermiteNormalForm<I, R, C>
+    where
+        DefaultAllocator: Allocator<I, R, C> + Allocator<I, R, R>,
+    {
+        HermiteNormalForm::new(self.into_owned())
+    }
+
+    /// Computes the Smith normal form of this integer matrix.
+    pub fn smith_normal_form(self) -> SmithNormalForm<I, R, C>
+    where
+        DefaultAllocator: Allocator<I, R, C> + Allocator<I, R, R> + Allocator<I, C, C>,
+    {
+        SmithNormalForm::new(self.into_owned())
+    }
+}
@@ -0,0 +1,228 @@
+//! Exact linear algebra for integer and rational matrices.
+//!
+//! The algorithms from this module never round: they only perform divisions that are known to
+//! be exact, so they are suitable for matrices of integers (`i64`, big integers, etc.) or of
+//! rational numbers (`Ratio<_>`).
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use num::{One, Zero};
+use num_integer::Integer;
+use num_rational::Ratio;
+use simba::scalar::{ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg, ClosedSub};
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::Dim;
+use crate::base::storage::{Storage, StorageMut};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, Scalar, SquareMatrix};
+
+/// Runs the fraction-free Gaussian elimination of Bareiss on `m` (in-place).
+///
+/// Returns the rank of `m` and `true` if an odd number of row interchanges was performed.
+fn bareiss_eliminate<T, R: Dim, C: Dim, S>(m: &mut Matrix<T, R, C, S>) -> (usize, bool)
+where
+    T: Scalar + Zero + One + ClosedAdd + ClosedSub + ClosedMul + ClosedDiv,
+    S: StorageMut<T, R, C>,
+{
+    let (nrows, ncols) = m.shape();
+    let mut prev_pivot = T::one();
+    let mut odd_swaps = false;
+    let mut rank = 0;
+
+    for j in 0..ncols {
+        if rank == nrows {
+            break;
+        }
+
+        let piv = match (rank..nrows).find(|&i| !m[(i, j)].is_zero()) {
+            Some(piv) => piv,
+            None => continue,
+        };
+
+        if piv != rank {
+            m.swap_rows(piv, rank);
+            odd_swaps = !odd_swaps;
+        }
+
+        let pivot = m[(rank, j)].clone();
+
+        for i in rank + 1..nrows {
+            let factor = m[(i, j)].clone();
+
+            for k in j + 1..ncols {
+                // NOTE: this division is always exact: the result is a minor of the input matrix.
+                let val = (m[(i, k)].clone() * pivot.clone()
+                    - factor.clone() * m[(rank, k)].clone())
+                    / prev_pivot.clone();
+                m[(i, k)] = val;
+            }
+
+            m[(i, j)] = T::zero();
+        }
+
+        prev_pivot = pivot;
+        rank += 1;
+    }
+
+    (rank, odd_swaps)
+}
+
+/// # Exact linear algebra over integral domains
+impl<T, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S>
+where
+    T: Scalar + Zero + One + ClosedAdd + ClosedSub + ClosedMul + ClosedDiv,
+{
+    /// Computes the rank of this matrix exactly, using fraction-free Gaussian elimination.
+    ///
+    /// Unlike rank estimations based on floating-point decompositions, no tolerance is involved
+    /// here: the components must be exact integers or rationals (e.g. `i64` or `Ratio<i64>`).
+    #[must_use]
+    pub fn exact_rank(&self) -> usize
+    where
+        DefaultAllocator: Allocator<T, R, C>,
+    {
+        let mut m = self.clone_owned();
+        bareiss_eliminate(&mut m).0
+    }
+}
+
+impl<T, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S>
+where
+    T: Scalar + Zero + One + ClosedAdd + ClosedSub + ClosedMul + ClosedDiv + ClosedNeg,
+{
+    /// Computes the determinant of this matrix with the fraction-free Bareiss algorithm.
+    ///
+    /// All the intermediate divisions are exact so this works on integer matrices (e.g.
+    /// `Matrix3<i64>`) as well as rational ones. The magnitude of the intermediate values never
+    /// exceeds the magnitude of the minors of `self`.
+    #[must_use]
+    pub fn bareiss_determinant(&self) -> T
+    where
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        assert!(
+            self.is_square(),
+            "Unable to compute the determinant of a non-square matrix."
+        );
+
+        let dim = self.nrows();
+
+        if dim == 0 {
+            return T::one();
+        }
+
+        let mut m = self.clone_owned();
+        let (rank, odd_swaps) = bareiss_eliminate(&mut m);
+
+        if rank < dim {
+            T::zero()
+        } else if odd_swaps {
+            -m[(dim - 1, dim - 1)].clone()
+        } else {
+            m[(dim - 1, dim - 1)].clone()
+        }
+    }
+}
+
+/// # Exact linear algebra over the rationals
+impl<I, R: Dim, C: Dim, S: Storage<Ratio<I>, R, C>> Matrix<Ratio<I>, R, C, S>
+where
+    I: Integer + Clone + Scalar,
+{
+    /// Computes the reduced row echelon form of this matrix.
+    ///
+    /// Returns the reduced row echelon form as well as the indices of its pivot columns, in
+    /// increasing order. The number of pivot columns is the rank of `self`.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[must_use]
+    pub fn rref(&self) -> (OMatrix<Ratio<I>, R, C>, Vec<usize>)
+    where
+        DefaultAllocator: Allocator<Ratio<I>, R, C>,
+    {
+        let (nrows, ncols) = self.shape();
+        let mut m = self.clone_owned();
+        let mut pivots = Vec::new();
+
+        for j in 0..ncols {
+            let rank = pivots.len();
+
+            if rank == nrows {
+                break;
+            }
+
+            let piv = match (rank..nrows).find(|&i| !m[(i, j)].is_zero()) {
+                Some(piv) => piv,
+                None => continue,
+            };
+
+            m.swap_rows(piv, rank);
+
+            let pivot = m[(rank, j)].clone();
+            m.row_mut(rank).apply(|e| *e = e.clone() / pivot.clone());
+
+            for i in (0..nrows).filter(|&i| i != rank) {
+                let factor = m[(i, j)].clone();
+
+                if !factor.is_zero() {
+                    for k in j..ncols {
+                        let val = m[(i, k)].clone() - factor.clone() * m[(rank, k)].clone();
+                        m[(i, k)] = val;
+                    }
+                }
+            }
+
+            pivots.push(j);
+        }
+
+        (m, pivots)
+    }
+}
+
+impl<I, D: Dim, S: Storage<Ratio<I>, D, D>> SquareMatrix<Ratio<I>, D, S>
+where
+    I: Integer + Clone + Scalar,
+{
+    /// Computes the exact inverse of this matrix using Gauss-Jordan elimination.
+    ///
+    /// Returns `None` if `self` is singular.
+    #[must_use]
+    pub fn try_exact_inverse(&self) -> Option<OMatrix<Ratio<I>, D, D>>
+    where
+        DefaultAllocator: Allocator<Ratio<I>, D, D>,
+    {
+        assert!(self.is_square(), "Unable to invert a non-square matrix.");
+
+        let dim = self.shape_generic().0;
+        let mut m = self.clone_owned();
+        let mut inv = OMatrix::identity_generic(dim, dim);
+
+        for j in 0..dim.value() {
+            let piv = (j..dim.value()).find(|&i| !m[(i, j)].is_zero())?;
+
+            if piv != j {
+                m.swap_rows(piv, j);
+                inv.swap_rows(piv, j);
+            }
+
+            let pivot = m[(j, j)].clone();
+            m.row_mut(j).apply(|e| *e = e.clone() / pivot.clone());
+            inv.row_mut(j).apply(|e| *e = e.clone() / pivot.clone());
+
+            for i in (0..dim.value()).filter(|&i| i != j) {
+                let factor = m[(i, j)].clone();
+
+                if !factor.is_zero() {
+                    for k in 0..dim.value() {
+                        let val = m[(i, k)].clone() - factor.clone() * m[(j, k)].clone();
+                        m[(i, k)] = val;
+                        let val = inv[(i, k)].clone() - factor.clone() * inv[(j, k)].clone();
+                        inv[(i, k)] = val;
+                    }
+                }
+            }
+        }
+
+        Some(inv)
+    }
+}
@@ -0,0 +1,586 @@
+//! Fast Fourier transforms.
+//!
+//! The transforms work with any length: lengths with small prime factors use a mixed-radix
+//! Cooley-Tukey algorithm, and other lengths fall back to Bluestein's algorithm. Both run in
+//! `O(n log n)`. Only the `alloc` crate is required.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{boxed::Box, vec::Vec};
+
+use num::Zero;
+use num_complex::Complex;
+use simba::scalar::{ComplexField, RealField};
+
+use crate::allocator::Allocator;
+use crate::base::dimension::Dim;
+use crate::base::storage::{Storage, StorageMut};
+use crate::base::{DVector, DefaultAllocator, OVector, Vector};
+
+/// Prime factors larger than this are handled with Bluestein's algorithm.
+const MAX_RADIX: usize = 31;
+
+/// A precomputed plan for computing discrete Fourier transforms of a given length.
+///
+/// Creating a plan factorizes the length and computes the twiddle factors, which can then be
+/// reused by every transform of this length.
+#[derive(Clone, Debug)]
+pub struct FftPlan<T> {
+    len: usize,
+    algorithm: Algorithm<T>,
+}
+
+#[derive(Clone, Debug)]
+enum Algorithm<T> {
+    MixedRadix {
+        factors: Vec<usize>,
+        /// `exp(-2iπk / len)` for `k` in `0..len`.
+        twiddles: Vec<Complex<T>>,
+    },
+    Bluestein(Box<Bluestein<T>>),
+}
+
+#[derive(Clone, Debug)]
+struct Bluestein<T> {
+    /// The plan of the power-of-two length used for the chirp convolution.
+    inner: FftPlan<T>,
+    /// `exp(-iπk² / len)` for `k` in `0..len`.
+    chirp: Vec<Complex<T>>,
+    /// The transform of the conjugate chirp, scaled by `1 / inner.len`.
+    kernel: Vec<Complex<T>>,
+}
+
+impl<T: RealField> FftPlan<T> {
+    /// Creates a plan for transforms of length `len`.
+    pub fn new(len: usize) -> Self {
+        let factors = factorize(len);
+
+        let algorithm = if factors.iter().all(|&p| p <= MAX_RADIX) {
+            Algorithm::MixedRadix {
+                factors,
+                twiddles: twiddles(len),
+            }
+        } else {
+            Algorithm::Bluestein(Box::new(Bluestein::new(len)))
+        };
+
+        Self { len, algorithm }
+    }
+
+    /// The length of the transforms computed by this plan.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if this plan is for transforms of length zero.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Computes in-place the discrete Fourier transform `X_k = Σ_j x_j exp(-2iπjk / n)`.
+    ///
+    /// Panics if `data.len()` differs from the length of this plan.
+    pub fn forward(&self, data: &mut [Complex<T>]) {
+        self.process(data, false);
+    }
+
+    /// Computes in-place the inverse discrete Fourier transform
+    /// `x_j = 1 / n Σ_k X_k exp(2iπjk / n)`.
+    ///
+    /// Panics if `data.len()` differs from the length of this plan.
+    pub fn inverse(&self, data: &mut [Complex<T>]) {
+        self.process(data, true);
+
+        if self.len > 1 {
+            let scale = T::one() / crate::convert(self.len as f64);
+
+            for e in data.iter_mut() {
+                *e = e.clone().scale(scale.clone());
+            }
+        }
+    }
+
+    /// Computes in-place the unnormalized forward or inverse transform of `data`.
+    fn process(&self, data: &mut [Complex<T>], inverse: bool) {
+        assert_eq!(
+            data.len(),
+            self.len,
+            "FFT: the data length must match the length of the plan."
+        );
+
+        if self.len <= 1 {
+            return;
+        }
+
+        match &self.algorithm {
+            Algorithm::MixedRadix { factors, twiddles } => {
+                let input = data.to_vec();
+                let mut scratch = Vec::new();
+                mixed_radix(&input, 1, data, factors, twiddles, inverse, &mut scratch);
+            }
+            Algorithm::Bluestein(bluestein) => bluestein.process(data, inverse),
+        }
+    }
+}
+
+impl<T: RealField> Bluestein<T> {
+    fn new(len: usize) -> Self {
+        let inner = FftPlan::new((2 * len - 1).next_power_of_two());
+        let m = inner.len;
+        let base = -T::pi() / crate::convert(len as f64);
+
+        let chirp: Vec<_> = (0..len)
+            .map(|k| {
+                // NOTE: reduce k² modulo 2 * len to keep the angle accurate.
+                let k2 = (k as u128 * k as u128) % (2 * len as u128);
+                let (sin, cos) = (base.clone() * crate::convert(k2 as f64)).sin_cos();
+                Complex::new(cos, sin)
+            })
+            .collect();
+
+        let mut kernel = zeros(m);
+        kernel[0] = chirp[0].conj();
+
+        for k in 1..len {
+            kernel[k] = chirp[k].conj();
+            kernel[m - k] = chirp[k].conj();
+        }
+
+        inner.process(&mut kernel, false);
+        let scale = T::one() / crate::convert(m as f64);
+
+        for e in kernel.iter_mut() {
+            *e = e.clone().scale(scale.clone());
+        }
+
+        Self {
+            inner,
+            chirp,
+            kernel,
+        }
+    }
+
+    fn process(&self, data: &mut [Complex<T>], inverse: bool) {
+        // The inverse transform is the conjugate of the forward transform of the conjugate.
+        if inverse {
+            data.iter_mut().for_each(|e| *e = e.conj());
+        }
+
+        let mut buf = zeros(self.inner.len);
+
+        for (b, (x, c)) in buf.iter_mut().zip(data.iter().zip(self.chirp.iter())) {
+            *b = x.clone() * c.clone();
+        }
+
+        self.inner.process(&mut buf, false);
+
+        for (b, k) in buf.iter_mut().zip(self.kernel.iter()) {
+            *b = b.clone() * k.clone();
+        }
+
+        self.inner.process(&mut buf, true);
+
+        for (x, (b, c)) in data.iter_mut().zip(buf.into_iter().zip(self.chirp.iter())) {
+            *x = b * c.clone();
+        }
+
+        if inverse {
+            data.iter_mut().for_each(|e| *e = e.conj());
+        }
+    }
+}
+
+/// Recursive decimation-in-time step: writes into `output` the transform of the elements
+/// `input[0]`, `input[stride]`, `input[2 * stride]`, etc.
+fn mixed_radix<T: RealField>(
+    input: &[Complex<T>],
+    stride: usize,
+    output: &mut [Complex<T>],
+    factors: &[usize],
+    twiddles: &[Complex<T>],
+    inverse: bool,
+    scratch: &mut Vec<Complex<T>>,
+) {
+    let n = output.len();
+    let step = twiddles.len() / n;
+    // The powers of `exp(∓2iπ / n)`.
+    let w = |e: usize| {
+        let w = twiddles[(e % n) * step].clone();
+        if inverse {
+            w.conj()
+        } else {
+            w
+        }
+    };
+
+    let p = factors[0];
+    let m = n / p;
+
+    if m == 1 {
+        for (k, out) in output.iter_mut().enumerate() {
+            let mut acc = input[0].clone();
+
+            for r in 1..p {
+                acc += input[r * stride].clone() * w(r * k);
+            }
+
+            *out = acc;
+        }
+
+        return;
+    }
+
+    for r in 0..p {
+        mixed_radix(
+            &input[r * stride..],
+            stride * p,
+            &mut output[r * m..(r + 1) * m],
+            &factors[1..],
+            twiddles,
+            inverse,
+            scratch,
+        );
+    }
+
+    if p == 2 {
+        for k in 0..m {
+            let u = output[k].clone();
+            let v = output[k + m].clone() * w(k);
+            output[k] = u.clone() + v.clone();
+            output[k + m] = u - v;
+        }
+
+        return;
+    }
+
+    scratch.clear();
+    scratch.resize(p, Complex::zero());
+
+    for k in 0..m {
+        for r in 0..p {
+            scratch[r] = output[r * m + k].clone() * w(r * k);
+        }
+
+        for q in 0..p {
+            let mut acc = scratch[0].clone();
+
+            for (r, s) in scratch.iter().enumerate().skip(1) {
+                acc += s.clone() * w(((r * q) % p) * m);
+            }
+
+            output[k + q * m] = acc;
+        }
+    }
+}
+
+/// The prime factors of `n`, in increasing order.
+fn factorize(mut n: usize) -> Vec<usize> {
+    let mut factors = Vec::new();
+    let mut p = 2;
+
+    while p * p <= n {
+        while n % p == 0 {
+            factors.push(p);
+            n /= p;
+        }
+
+        p += 1;
+    }
+
+    if n > 1 {
+        factors.push(n);
+    }
+
+    factors
+}
+
+/// The smallest integer greater or equal to `n` that has no prime factor other than 2, 3, and 5.
+fn fast_len(n: usize) -> usize {
+    let is_smooth = |mut m: usize| {
+        for &p in &[2, 3, 5] {
+            while m % p == 0 {
+                m /= p;
+            }
+        }
+
+        m == 1
+    };
+
+    (n.max(1)..).find(|&m| is_smooth(m)).unwrap()
+}
+
+/// `exp(-2iπk / n)` for `k` in `0..n`.
+fn twiddles<T: RealField>(n: usize) -> Vec<Complex<T>> {
+    let base = -T::two_pi() / crate::convert(n as f64);
+
+    (0..n)
+        .map(|k| {
+            let (sin, cos) = (base.clone() * crate::convert(k as f64)).sin_cos();
+            Complex::new(cos, sin)
+        })
+        .collect()
+}
+
+fn zeros<T: RealField>(n: usize) -> Vec<Complex<T>> {
+    std::iter::repeat_with(Complex::zero).take(n).collect()
+}
+
+/// Computes in-place the unnormalized 2D transform of the column-major `nrows × ncols` buffer
+/// `data`.
+fn fft2<T: RealField>(data: &mut [Complex<T>], nrows: usize, ncols: usize, inverse: bool) {
+    if nrows > 1 {
+        let plan = FftPlan::new(nrows);
+
+        for column in data.chunks_mut(nrows) {
+            plan.process(column, inverse);
+        }
+    }
+
+    if ncols > 1 {
+        let plan = FftPlan::new(ncols);
+        let mut row = Vec::with_capacity(ncols);
+
+        for i in 0..nrows {
+            row.clear();
+            row.extend((0..ncols).map(|j| data[i + j * nrows].clone()));
+            plan.process(&mut row, inverse);
+
+            for (j, val) in row.iter().enumerate() {
+                data[i + j * nrows] = val.clone();
+            }
+        }
+    }
+}
+
+/// Computes the full 2D linear convolution of the matrices `a` and `b`, given by their shapes
+/// and accessors, using FFTs.
+///
+/// The result is returned as a column-major buffer of shape
+/// `(a_shape.0 + b_shape.0 - 1, a_shape.1 + b_shape.1 - 1)`.
+pub(crate) fn convolve_full_2d_fft<T: RealField>(
+    a: impl Fn(usize, usize) -> T,
+    a_shape: (usize, usize),
+    b: impl Fn(usize, usize) -> T,
+    b_shape: (usize, usize),
+) -> Vec<T> {
+    let shape = (a_shape.0 + b_shape.0 - 1, a_shape.1 + b_shape.1 - 1);
+    let (nrows, ncols) = (fast_len(shape.0), fast_len(shape.1));
+
+    // Both real inputs are packed into a single complex buffer `a + ib`.
+    let mut buf = zeros(nrows * ncols);
+
+    for j in 0..a_shape.1 {
+        for i in 0..a_shape.0 {
+            buf[i + j * nrows].re = a(i, j);
+        }
+    }
+
+    for j in 0..b_shape.1 {
+        for i in 0..b_shape.0 {
+            buf[i + j * nrows].im = b(i, j);
+        }
+    }
+
+    fft2(&mut buf, nrows, ncols, false);
+
+    // With `Z` the transform of `a + ib` and `Z*_k = conj(Z_{-k})`, the transforms of `a` and
+    // `b` are `(Z + Z*) / 2` and `(Z - Z*) / 2i` so their product is `(Z² - Z*²) / 4i`.
+    let spectrum = buf.clone();
+    let quarter: T = crate::convert(0.25);
+    let factor = Complex::new(T::zero(), -quarter);
+
+    for j in 0..ncols {
+        for i in 0..nrows {
+            let z = spectrum[i + j * nrows].clone();
+            let zc = spectrum[(nrows - i) % nrows + ((ncols - j) % ncols) * nrows].conj();
+            buf[i + j * nrows] = (z.clone() * z - zc.clone() * zc) * factor.clone();
+        }
+    }
+
+    fft2(&mut buf, nrows, ncols, true);
+
+    let scale = T::one() / crate::convert((nrows * ncols) as f64);
+    let mut res = Vec::with_capacity(shape.0 * shape.1);
+
+    for j in 0..shape.1 {
+        for i in 0..shape.0 {
+            res.push(buf[i + j * nrows].re.clone() * scale.clone());
+        }
+    }
+
+    res
+}
+
+/// Heuristic deciding if an FFT-based convolution is cheaper than a direct one.
+///
+/// Here `full_len` is the number of elements of the full convolution.
+pub(crate) fn prefer_fft(output_len: usize, kernel_len: usize, full_len: usize) -> bool {
+    // NOTE: the constant factor roughly accounts for the two complex transforms.
+    let log2 = (usize::BITS - full_len.leading_zeros()) as usize;
+    output_len.saturating_mul(kernel_len) > 16 * full_len * log2
+}
+
+/// Copies `v` into a buffer, applies `f` to it, then copies the result back.
+fn transform_in_place<T, D, S>(v: &mut Vector<Complex<T>, D, S>, f: impl FnOnce(&mut [Complex<T>]))
+where
+    T: RealField,
+    D: Dim,
+    S: StorageMut<Complex<T>, D>,
+{
+    let mut buf: Vec<_> = v.iter().cloned().collect();
+    f(&mut buf);
+
+    for (e, val) in v.iter_mut().zip(buf) {
+        *e = val;
+    }
+}
+
+impl<T: RealField, D: Dim, S: Storage<Complex<T>, D>> Vector<Complex<T>, D, S> {
+    /// Computes the discrete Fourier transform `X_k = Σ_j x_j exp(-2iπjk / n)` of this vector.
+    #[must_use]
+    pub fn fft(&self) -> OVector<Complex<T>, D>
+    where
+        DefaultAllocator: Allocator<Complex<T>, D>,
+    {
+        let mut res = self.clone_owned();
+        res.fft_mut();
+        res
+    }
+
+    /// Computes the inverse discrete Fourier transform `x_j = 1 / n Σ_k X_k exp(2iπjk / n)` of
+    /// this vector.
+    #[must_use]
+    pub fn ifft(&self) -> OVector<Complex<T>, D>
+    where
+        DefaultAllocator: Allocator<Complex<T>, D>,
+    {
+        let mut res = self.clone_owned();
+        res.ifft_mut();
+        res
+    }
+
+    /// Computes the real signal of length `len` whose [`rfft`](Vector::rfft) is this vector.
+    ///
+    /// The imaginary parts of the first component, and of the last one if `len` is even, are
+    /// ignored since they are always zero for the transform of a real signal.
+    ///
+    /// # Errors
+    /// This vector must have `len / 2 + 1` components.
+    #[must_use]
+    pub fn irfft(&self, len: usize) -> DVector<T> {
+        assert_eq!(
+            self.len(),
+            len / 2 + 1,
+            "irfft expects a vector with `len / 2 + 1` components."
+        );
+
+        let mut spectrum: Vec<_> = self.iter().cloned().collect();
+        spectrum[0].im = T::zero();
+
+        if len % 2 == 0 && len > 0 {
+            let h = len / 2;
+            spectrum[h].im = T::zero();
+
+            // Rebuild the transform of the half-length complex signal `x_{2j} + i x_{2j + 1}`.
+            let half: T = crate::convert(0.5);
+            let base = T::two_pi() / crate::convert(len as f64);
+            let mut z: Vec<_> = (0..h)
+                .map(|k| {
+                    let x = spectrum[k].clone();
+                    let xc = spectrum[h - k].conj();
+                    let (sin, cos) = (base.clone() * crate::convert(k as f64)).sin_cos();
+                    let even = (x.clone() + xc.clone()).scale(half.clone());
+                    let odd = (x - xc) * Complex::new(cos, sin).scale(half.clone());
+                    even + odd * Complex::i()
+                })
+                .collect();
+
+            FftPlan::new(h).inverse(&mut z);
+
+            DVector::from_fn(len, |i, _| {
+                if i % 2 == 0 {
+                    z[i / 2].re.clone()
+                } else {
+                    z[i / 2].im.clone()
+                }
+            })
+        } else {
+            let mut full = zeros(len);
+
+            for (k, x) in spectrum.iter().enumerate() {
+                if k < len {
+                    full[k] = x.clone();
+                }
+
+                if k > 0 && k < len - k {
+                    full[len - k] = x.conj();
+                }
+            }
+
+            FftPlan::new(len).inverse(&mut full);
+            DVector::from_iterator(len, full.into_iter().map(|e| e.re))
+        }
+    }
+}
+
+impl<T: RealField, D: Dim, S: StorageMut<Complex<T>, D>> Vector<Complex<T>, D, S> {
+    /// Computes in-place the discrete Fourier transform of this vector.
+    ///
+    /// See [`fft`](Vector::fft) for details.
+    pub fn fft_mut(&mut self) {
+        let plan = FftPlan::new(self.len());
+        transform_in_place(self, |buf| plan.forward(buf));
+    }
+
+    /// Computes in-place the inverse discrete Fourier transform of this vector.
+    ///
+    /// See [`ifft`](Vector::ifft) for details.
+    pub fn ifft_mut(&mut self) {
+        let plan = FftPlan::new(self.len());
+        transform_in_place(self, |buf| plan.inverse(buf));
+    }
+}
+
+impl<T: RealField, D: Dim, S: Storage<T, D>> Vector<T, D, S> {
+    /// Computes the discrete Fourier transform of this real vector.
+    ///
+    /// Since the transform of a real signal of length `n` satisfies `X_{n - k} = conj(X_k)`,
+    /// only its `n / 2 + 1` first components are returned.
+    #[must_use]
+    pub fn rfft(&self) -> DVector<Complex<T>> {
+        let len = self.len();
+
+        if len % 2 == 0 && len > 0 {
+            // Transform the half-length complex signal `x_{2j} + i x_{2j + 1}` and untangle
+            // the transforms of the even and odd components.
+            let h = len / 2;
+            let mut z: Vec<_> = (0..h)
+                .map(|j| Complex::new(self[2 * j].clone(), self[2 * j + 1].clone()))
+                .collect();
+            FftPlan::new(h).forward(&mut z);
+
+            let half: T = crate::convert(0.5);
+            let base = -T::two_pi() / crate::convert(len as f64);
+
+            DVector::from_fn(h + 1, |k, _| {
+                let zk = z[k % h].clone();
+                let zc = z[(h - k) % h].conj();
+                let (sin, cos) = (base.clone() * crate::convert(k as f64)).sin_cos();
+                let even = (zk.clone() + zc.clone()).scale(half.clone());
+                let odd = (zk - zc) * Complex::new(T::zero(), -half.clone());
+                even + odd * Complex::new(cos, sin)
+            })
+        } else {
+            let mut full: Vec<_> = self
+                .iter()
+                .map(|e| Complex::new(e.clone(), T::zero()))
+                .collect();
+            FftPlan::new(len).forward(&mut full);
+            full.truncate(len / 2 + 1);
+            DVector::from_vec(full)
+        }
+    }
+}
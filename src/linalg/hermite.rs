@@ -0,0 +1,251 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use num::Signed;
+use num_integer::Integer;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, Matrix, OMatrix, Scalar};
+use crate::dimension::Dim;
+use crate::storage::{Storage, StorageMut};
+
+/// The Hermite normal form of an integer matrix.
+///
+/// This is the row-style Hermite normal form `H = U * A` where `U` is unimodular (i.e., it is an
+/// integer matrix with a determinant equal to `±1`) and `H` is in row echelon form, with
+/// positive pivots and with every entry above a pivot lying in `[0, pivot)`.
+///
+/// The entries of `U` can be much larger than the entries of `A`, so consider using a wide or
+/// arbitrary-precision integer type to avoid overflows.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "OMatrix<I, R, C>: Serialize, OMatrix<I, R, R>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(
+        deserialize = "OMatrix<I, R, C>: Deserialize<'de>, OMatrix<I, R, R>: Deserialize<'de>"
+    ))
+)]
+#[derive(Clone, Debug)]
+pub struct HermiteNormalForm<I: Scalar, R: Dim, C: Dim>
+where
+    DefaultAllocator: Allocator<I, R, C> + Allocator<I, R, R>,
+{
+    /// The Hermite normal form `H` of the input matrix.
+    pub h: OMatrix<I, R, C>,
+    /// The unimodular matrix `U` such that `H = U * A`.
+    pub u: OMatrix<I, R, R>,
+}
+
+impl<I: Scalar, R: Dim, C: Dim> Copy for HermiteNormalForm<I, R, C>
+where
+    DefaultAllocator: Allocator<I, R, C> + Allocator<I, R, R>,
+    OMatrix<I, R, C>: Copy,
+    OMatrix<I, R, R>: Copy,
+{
+}
+
+impl<I, R: Dim, C: Dim> HermiteNormalForm<I, R, C>
+where
+    I: Scalar + Integer + Signed,
+    DefaultAllocator: Allocator<I, R, C> + Allocator<I, R, R>,
+{
+    /// Computes the Hermite normal form of the integer matrix `a`.
+    pub fn new(a: OMatrix<I, R, C>) -> Self {
+        let nrows_dim = a.shape_generic().0;
+        let mut h = a;
+        let mut u = OMatrix::identity_generic(nrows_dim, nrows_dim);
+        let _ = hermite_reduce(&mut h, &mut u, false);
+
+        Self { h, u }
+    }
+
+    /// The rank of the decomposed matrix, i.e., the number of non-zero rows of `H`.
+    #[must_use]
+    pub fn rank(&self) -> usize {
+        self.h
+            .row_iter()
+            .filter(|row| row.iter().any(|e| !e.is_zero()))
+            .count()
+    }
+
+    /// Retrieves the `H` and `U` matrices of this decomposition.
+    pub fn unpack(self) -> (OMatrix<I, R, C>, OMatrix<I, R, R>) {
+        (self.h, self.u)
+    }
+}
+
+/// Reduces `h` to its row-style Hermite normal form, or to its column-style Hermite normal form
+/// if `columns` is `true`, and applies the same elementary operations to `u`.
+///
+/// Returns the rank of `h`.
+pub(crate) fn hermite_reduce<I, R1: Dim, C1: Dim, S1, R2: Dim, C2: Dim, S2>(
+    h: &mut Matrix<I, R1, C1, S1>,
+    u: &mut Matrix<I, R2, C2, S2>,
+    columns: bool,
+) -> usize
+where
+    I: Scalar + Integer + Signed,
+    S1: StorageMut<I, R1, C1>,
+    S2: StorageMut<I, R2, C2>,
+{
+    let (nlines, npos) = if columns {
+        (h.ncols(), h.nrows())
+    } else {
+        (h.nrows(), h.ncols())
+    };
+    let mut rank = 0;
+
+    for j in 0..npos {
+        if rank == nlines {
+            break;
+        }
+
+        // Euclid's algorithm on the lines, until only the pivot is non-zero after the current
+        // line.
+        loop {
+            let piv = (rank..nlines)
+                .filter(|&i| !line_entry(h, i, j, columns).is_zero())
+                .min_by(|&i1, &i2| {
+                    let a1 = line_entry(h, i1, j, columns).abs();
+                    a1.cmp(&line_entry(h, i2, j, columns).abs())
+                });
+
+            let piv = match piv {
+                Some(piv) => piv,
+                None => break,
+            };
+
+            swap_lines(h, rank, piv, columns);
+            swap_lines(u, rank, piv, columns);
+
+            let mut reduced = true;
+
+            for i in rank + 1..nlines {
+                let q = line_entry(h, i, j, columns).div_floor(line_entry(h, rank, j, columns));
+                sub_scaled_line(h, i, rank, &q, columns);
+                sub_scaled_line(u, i, rank, &q, columns);
+                reduced = reduced && line_entry(h, i, j, columns).is_zero();
+            }
+
+            if reduced {
+                break;
+            }
+        }
+
+        if line_entry(h, rank, j, columns).is_zero() {
+            continue;
+        }
+
+        if line_entry(h, rank, j, columns).is_negative() {
+            negate_line(h, rank, columns);
+            negate_line(u, rank, columns);
+        }
+
+        for i in 0..rank {
+            let q = line_entry(h, i, j, columns).div_floor(line_entry(h, rank, j, columns));
+            sub_scaled_line(h, i, rank, &q, columns);
+            sub_scaled_line(u, i, rank, &q, columns);
+        }
+
+        rank += 1;
+    }
+
+    rank
+}
+
+/// The `k`-th entry of the row `i` of `m`, or of the column `i` if `columns` is `true`.
+fn line_entry<I, R: Dim, C: Dim, S>(m: &Matrix<I, R, C, S>, i: usize, k: usize, columns: bool) -> &I
+where
+    I: Scalar,
+    S: Storage<I, R, C>,
+{
+    if columns {
+        &m[(k, i)]
+    } else {
+        &m[(i, k)]
+    }
+}
+
+/// Swaps the rows `i` and `k` of `m`, or its columns if `columns` is `true`.
+pub(crate) fn swap_lines<I, R: Dim, C: Dim, S>(
+    m: &mut Matrix<I, R, C, S>,
+    i: usize,
+    k: usize,
+    columns: bool,
+) where
+    I: Scalar,
+    S: StorageMut<I, R, C>,
+{
+    if columns {
+        m.swap_columns(i, k)
+    } else {
+        m.swap_rows(i, k)
+    }
+}
+
+/// Negates the row `i` of `m`, or its column `i` if `columns` is `true`.
+pub(crate) fn negate_line<I, R: Dim, C: Dim, S>(m: &mut Matrix<I, R, C, S>, i: usize, columns: bool)
+where
+    I: Scalar + Signed,
+    S: StorageMut<I, R, C>,
+{
+    if columns {
+        m.column_mut(i).apply(|e| *e = -e.clone());
+    } else {
+        m.row_mut(i).apply(|e| *e = -e.clone());
+    }
+}
+
+/// Replaces the row `i` of `m` by `row_i - q * row_k`, or does the same to its columns if
+/// `columns` is `true`.
+fn sub_scaled_line<I, R: Dim, C: Dim, S>(
+    m: &mut Matrix<I, R, C, S>,
+    i: usize,
+    k: usize,
+    q: &I,
+    columns: bool,
+) where
+    I: Scalar + Integer,
+    S: StorageMut<I, R, C>,
+{
+    if columns {
+        sub_scaled_column(m, i, k, q)
+    } else {
+        sub_scaled_row(m, i, k, q)
+    }
+}
+
+/// Replaces the row `i` of `m` by `row_i - q * row_k`.
+fn sub_scaled_row<I, R: Dim, C: Dim, S>(m: &mut Matrix<I, R, C, S>, i: usize, k: usize, q: &I)
+where
+    I: Scalar + Integer,
+    S: StorageMut<I, R, C>,
+{
+    if q.is_zero() {
+        return;
+    }
+
+    for j in 0..m.ncols() {
+        let val = m[(i, j)].clone() - q.clone() * m[(k, j)].clone();
+        m[(i, j)] = val;
+    }
+}
+
+/// Replaces the column `j` of `m` by `col_j - q * col_k`.
+fn sub_scaled_column<I, R: Dim, C: Dim, S>(m: &mut Matrix<I, R, C, S>, j: usize, k: usize, q: &I)
+where
+    I: Scalar + Integer,
+    S: StorageMut<I, R, C>,
+{
+    if q.is_zero() {
+        return;
+    }
+
+    for i in 0..m.nrows() {
+        let val = m[(i, j)].clone() - q.clone() * m[(i, k)].clone();
+        m[(i, j)] = val;
+    }
+}
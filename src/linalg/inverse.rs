@@ -1,11 +1,13 @@
+use num::Zero;
 use simba::scalar::ComplexField;
+use simba::simd::{SimdComplexField, SimdPartialOrd};
 
 use crate::base::allocator::Allocator;
 use crate::base::dimension::Dim;
 use crate::base::storage::{Storage, StorageMut};
 use crate::base::{DefaultAllocator, OMatrix, SquareMatrix};
 
-use crate::linalg::lu;
+use crate::linalg::{lu, SimdLU};
 
 impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
     /// Attempts to invert this matrix.
@@ -267,3 +269,83 @@ where
         false
     }
 }
+
+/*
+ *
+ * SIMD-compatible versions.
+ *
+ */
+impl<T: SimdComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
+    /// Attempts to invert this matrix on each SIMD lane.
+    ///
+    /// Returns the inverse together with a mask indicating on which lanes the inversion
+    /// succeeded. The lanes where it failed are left equal to the corresponding lanes of `self`.
+    #[inline]
+    #[must_use = "Did you mean to use simd_try_inverse_mut()?"]
+    pub fn simd_try_inverse(self) -> (OMatrix<T, D, D>, T::SimdBool)
+    where
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        let mut me = self.into_owned();
+        let mask = me.simd_try_inverse_mut();
+        (me, mask)
+    }
+}
+
+impl<T: SimdComplexField, D: Dim, S: StorageMut<T, D, D>> SquareMatrix<T, D, S> {
+    /// Attempts to invert this matrix in-place on each SIMD lane, without branching.
+    ///
+    /// Returns a mask indicating on which lanes the inversion succeeded. The lanes where it
+    /// failed are left untouched.
+    pub fn simd_try_inverse_mut(&mut self) -> T::SimdBool
+    where
+        DefaultAllocator: Allocator<T, D, D>,
+    {
+        assert!(self.is_square(), "Unable to invert a non-square matrix.");
+
+        let dim = self.shape().0;
+        let zero = T::SimdRealField::zero();
+
+        let (inverse, mask) = match dim {
+            0 => return zero.clone().simd_eq(zero),
+            1..=3 => {
+                let mut inverse = self.clone_owned();
+
+                match dim {
+                    1 => inverse[(0, 0)] = T::one(),
+                    2 => {
+                        inverse[(0, 0)] = self[(1, 1)].clone();
+                        inverse[(0, 1)] = -self[(0, 1)].clone();
+                        inverse[(1, 0)] = -self[(1, 0)].clone();
+                        inverse[(1, 1)] = self[(0, 0)].clone();
+                    }
+                    _ => {
+                        // The adjugate, i.e., the transposed matrix of cofactors.
+                        for i in 0..3 {
+                            for j in 0..3 {
+                                let (r1, r2) = ((j + 1) % 3, (j + 2) % 3);
+                                let (c1, c2) = ((i + 1) % 3, (i + 2) % 3);
+                                inverse[(i, j)] = self[(r1, c1)].clone() * self[(r2, c2)].clone()
+                                    - self[(r1, c2)].clone() * self[(r2, c1)].clone();
+                            }
+                        }
+                    }
+                }
+
+                // Laplace expansion along the first row.
+                let mut determinant = T::zero();
+                for k in 0..dim {
+                    determinant += self[(0, k)].clone() * inverse[(k, 0)].clone();
+                }
+
+                let mask = determinant.clone().simd_norm1().simd_ne(zero);
+                inverse *= T::one() / determinant.select(mask, T::one());
+                (inverse, mask)
+            }
+            _ => SimdLU::new(self.clone_owned()).try_inverse(),
+        };
+
+        self.zip_apply(&inverse, |a, b| *a = b.select(mask, a.clone()));
+        mask
+    }
+}
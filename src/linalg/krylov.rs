@@ -0,0 +1,463 @@
+//! Implicitly restarted Krylov eigensolvers for large or matrix-free operators.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use num_complex::Complex;
+use simba::scalar::{ComplexField, RealField};
+use std::cmp::Ordering;
+
+use crate::base::{DMatrix, DVector};
+use crate::linalg::{LinearOperator, Schur, SymmetricEigen, QR};
+
+/// The part of the spectrum computed by the Krylov eigensolvers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KrylovTarget {
+    /// The eigenvalues with the largest modulus.
+    LargestMagnitude,
+    /// The eigenvalues with the largest real part.
+    LargestReal,
+    /// The eigenvalues with the smallest real part.
+    SmallestReal,
+}
+
+impl KrylovTarget {
+    /// Orders `a` before `b` if `a` is more wanted than `b`.
+    fn compare<T: RealField>(self, a: &Complex<T>, b: &Complex<T>) -> Ordering {
+        let ord = match self {
+            KrylovTarget::LargestMagnitude => b.clone().modulus().partial_cmp(&a.clone().modulus()),
+            KrylovTarget::LargestReal => b.re.partial_cmp(&a.re),
+            KrylovTarget::SmallestReal => a.re.partial_cmp(&b.re),
+        };
+
+        // Keep complex conjugate pairs next to each other, the one with a positive
+        // imaginary part first.
+        ord.unwrap_or(Ordering::Equal)
+            .then_with(|| b.im.partial_cmp(&a.im).unwrap_or(Ordering::Equal))
+    }
+}
+
+/// Partial eigendecomposition of a symmetric operator computed with the implicitly restarted
+/// Lanczos method.
+#[derive(Clone, Debug)]
+pub struct Lanczos<T: RealField> {
+    /// The computed eigenvalues, sorted according to the requested `KrylovTarget`.
+    pub eigenvalues: DVector<T>,
+    /// The computed unit eigenvectors, one per column.
+    pub eigenvectors: DMatrix<T>,
+}
+
+impl<T: RealField> Lanczos<T> {
+    /// Computes `nev` eigenpairs of the symmetric operator `op`, from the part of its spectrum
+    /// selected by `target`.
+    ///
+    /// This uses a Krylov subspace of dimension `max(2 * nev + 1, 20)` (clamped to the
+    /// operator dimension), a relative tolerance equal to `ε^(2/3)` where `ε` is
+    /// `T::default_epsilon()`, and at most 1000 restarts. Returns `None` if the iteration did not
+    /// converge.
+    pub fn new<O: LinearOperator<T>>(op: &O, nev: usize, target: KrylovTarget) -> Option<Self> {
+        let ncv = default_ncv(op.nrows(), nev);
+        Self::try_new(op, nev, target, ncv, default_eps(), 1000)
+    }
+
+    /// Computes `nev` eigenpairs of the symmetric operator `op`, from the part of its spectrum
+    /// selected by `target`.
+    ///
+    /// The Lanczos basis is fully reorthogonalized, and the projected tridiagonal problems are
+    /// solved with `SymmetricEigen`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ncv` − the dimension of the Krylov subspace. Must satisfy `nev < ncv` unless `ncv` is
+    ///   equal to the operator dimension. It is clamped to the operator dimension.
+    /// * `eps` − the relative tolerance on the residual of each eigenpair.
+    /// * `max_niter` − maximum number of restarts. If this number of restarts is exceeded, `None`
+    ///   is returned. If `max_niter == 0`, then the algorithm continues indefinitely until
+    ///   convergence.
+    pub fn try_new<O: LinearOperator<T>>(
+        op: &O,
+        nev: usize,
+        target: KrylovTarget,
+        ncv: usize,
+        eps: T,
+        max_niter: usize,
+    ) -> Option<Self> {
+        let n = op.nrows();
+        assert_eq!(n, op.ncols(), "Lanczos: the operator must be square.");
+        assert!(
+            nev <= n,
+            "Lanczos: cannot compute more eigenvalues than the operator dimension."
+        );
+
+        let ncv = ncv.min(n);
+        assert!(
+            nev < ncv || ncv == n,
+            "Lanczos: the Krylov subspace dimension must be larger than the number of eigenvalues."
+        );
+
+        let mut fact = ArnoldiFactorization::new(op, ncv);
+        let mut niter = 0;
+
+        loop {
+            let h = (&fact.h + fact.h.transpose()) * crate::convert::<_, T>(0.5);
+            let eigen = SymmetricEigen::new(h);
+            let values: Vec<_> = eigen
+                .eigenvalues
+                .iter()
+                .map(|e| Complex::new(e.clone(), T::zero()))
+                .collect();
+            let order = sorted_indices(&values, target);
+            let beta = fact.f.norm();
+
+            let nconv = order[..nev]
+                .iter()
+                .filter(|&&i| {
+                    let resid = beta.clone() * eigen.eigenvectors[(ncv - 1, i)].clone().abs();
+                    resid <= relative_threshold(&values[i], eps.clone())
+                })
+                .count();
+
+            // NOTE: if the Krylov subspace is the whole space, the Ritz pairs are exact.
+            if nconv == nev || ncv == n {
+                let eigenvalues = DVector::from_fn(nev, |i, _| values[order[i]].re.clone());
+                let mut y = DMatrix::zeros(ncv, nev);
+
+                for (j, &i) in order[..nev].iter().enumerate() {
+                    y.set_column(j, &eigen.eigenvectors.column(i));
+                }
+
+                return Some(Self {
+                    eigenvalues,
+                    eigenvectors: &fact.v * y,
+                });
+            }
+
+            niter += 1;
+            if niter == max_niter {
+                return None;
+            }
+
+            // Keep some of the unwanted Ritz vectors to avoid stagnation (like ARPACK does).
+            let nkeep = nev + nconv.min((ncv - nev) / 2);
+            let shifts: Vec<_> = order[nkeep..]
+                .iter()
+                .map(|&i| Shift::Real(values[i].re.clone()))
+                .collect();
+            fact.restart(op, &shifts, nkeep);
+        }
+    }
+}
+
+/// Partial eigendecomposition of a general real operator computed with the implicitly restarted
+/// Arnoldi method.
+#[derive(Clone, Debug)]
+pub struct Arnoldi<T: RealField> {
+    /// The computed eigenvalues, sorted according to the requested `KrylovTarget`.
+    ///
+    /// Complex eigenvalues come in conjugate pairs so one more eigenvalue than requested may be
+    /// returned to avoid splitting a pair.
+    pub eigenvalues: DVector<Complex<T>>,
+    /// The computed unit eigenvectors, one per column.
+    pub eigenvectors: DMatrix<Complex<T>>,
+}
+
+impl<T: RealField> Arnoldi<T> {
+    /// Computes `nev` eigenpairs of the operator `op`, from the part of its spectrum selected by
+    /// `target`.
+    ///
+    /// This uses a Krylov subspace of dimension `max(2 * nev + 1, 20)` (clamped to the
+    /// operator dimension), a relative tolerance equal to `ε^(2/3)` where `ε` is
+    /// `T::default_epsilon()`, and at most 1000 restarts. Returns `None` if the iteration did not
+    /// converge.
+    pub fn new<O: LinearOperator<T>>(op: &O, nev: usize, target: KrylovTarget) -> Option<Self> {
+        let ncv = default_ncv(op.nrows(), nev);
+        Self::try_new(op, nev, target, ncv, default_eps(), 1000)
+    }
+
+    /// Computes `nev` eigenpairs of the operator `op`, from the part of its spectrum selected by
+    /// `target`.
+    ///
+    /// The projected Hessenberg problems are solved with `Schur`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ncv` − the dimension of the Krylov subspace. Must satisfy `nev + 2 <= ncv` unless `ncv`
+    ///   is equal to the operator dimension. It is clamped to the operator dimension.
+    /// * `eps` − the relative tolerance on the residual of each eigenpair.
+    /// * `max_niter` − maximum number of restarts. If this number of restarts is exceeded, `None`
+    ///   is returned. If `max_niter == 0`, then the algorithm continues indefinitely until
+    ///   convergence.
+    pub fn try_new<O: LinearOperator<T>>(
+        op: &O,
+        nev: usize,
+        target: KrylovTarget,
+        ncv: usize,
+        eps: T,
+        max_niter: usize,
+    ) -> Option<Self> {
+        let n = op.nrows();
+        assert_eq!(n, op.ncols(), "Arnoldi: the operator must be square.");
+        assert!(
+            nev <= n,
+            "Arnoldi: cannot compute more eigenvalues than the operator dimension."
+        );
+
+        let ncv = ncv.min(n);
+        assert!(
+            nev + 2 <= ncv || ncv == n,
+            "Arnoldi: the Krylov subspace dimension must be at least two more than the number of eigenvalues."
+        );
+
+        let mut fact = ArnoldiFactorization::new(op, ncv);
+        let mut niter = 0;
+
+        loop {
+            let values = Schur::new(fact.h.clone()).complex_eigenvalues();
+            let values: Vec<_> = values.iter().cloned().collect();
+            let order = sorted_indices(&values, target);
+            let beta = fact.f.norm();
+
+            // Don't split a pair of complex conjugate eigenvalues.
+            let splits_pair = |k: usize| {
+                k > 0 && k < ncv && {
+                    let last = &values[order[k - 1]];
+                    !last.im.is_zero() && values[order[k]] == last.clone().conj()
+                }
+            };
+            let nwanted = if splits_pair(nev) { nev + 1 } else { nev };
+
+            let hc = fact.h.map(|e| Complex::new(e, T::zero()));
+            let mut y = DMatrix::zeros(ncv, nwanted);
+            let mut nconv = 0;
+
+            for (j, &i) in order[..nwanted].iter().enumerate() {
+                let yi = hessenberg_eigenvector(&hc, &values[i]);
+                let resid = beta.clone() * yi[ncv - 1].clone().modulus();
+
+                if resid <= relative_threshold(&values[i], eps.clone()) {
+                    nconv += 1;
+                }
+
+                y.set_column(j, &yi);
+            }
+
+            // NOTE: if the Krylov subspace is the whole space, the Ritz pairs are exact.
+            if nconv == nwanted || ncv == n {
+                let eigenvalues = DVector::from_fn(nwanted, |i, _| values[order[i]].clone());
+                let v = fact.v.map(|e| Complex::new(e, T::zero()));
+                let mut eigenvectors = v * y;
+
+                for mut col in eigenvectors.column_iter_mut() {
+                    let _ = col.normalize_mut();
+                }
+
+                return Some(Self {
+                    eigenvalues,
+                    eigenvectors,
+                });
+            }
+
+            niter += 1;
+            if niter == max_niter {
+                return None;
+            }
+
+            // Keep some of the unwanted Ritz vectors to avoid stagnation (like ARPACK does).
+            let mut nkeep = nwanted + nconv.min((ncv - nwanted) / 2);
+            if splits_pair(nkeep) {
+                nkeep -= 1;
+            }
+
+            let shifts: Vec<_> = order[nkeep..]
+                .iter()
+                .map(|&i| &values[i])
+                // Complex shifts are applied in conjugate pairs.
+                .filter(|val| val.im >= T::zero())
+                .map(|val| {
+                    if val.im.is_zero() {
+                        Shift::Real(val.re.clone())
+                    } else {
+                        Shift::ConjugatePair(val.clone())
+                    }
+                })
+                .collect();
+            fact.restart(op, &shifts, nkeep);
+        }
+    }
+}
+
+/// A shift applied to the Krylov subspace during a restart.
+enum Shift<T: RealField> {
+    Real(T),
+    ConjugatePair(Complex<T>),
+}
+
+/// An Arnoldi factorization `A * V = V * H + f * eₘᵀ` where `V` has orthonormal columns and `H`
+/// is an upper-Hessenberg matrix.
+struct ArnoldiFactorization<T: RealField> {
+    v: DMatrix<T>,
+    h: DMatrix<T>,
+    f: DVector<T>,
+    /// An estimate of the norm of the operator, used to detect breakdowns.
+    scale: T,
+    seed: u64,
+}
+
+impl<T: RealField> ArnoldiFactorization<T> {
+    fn new<O: LinearOperator<T>>(op: &O, ncv: usize) -> Self {
+        let n = op.nrows();
+        let mut fact = Self {
+            v: DMatrix::zeros(n, ncv),
+            h: DMatrix::zeros(ncv, ncv),
+            f: DVector::zeros(n),
+            scale: T::zero(),
+            seed: 0x2545_f491_4f6c_dd1d,
+        };
+
+        // NOTE: the starting vector is chosen by `extend`.
+        fact.extend(op, 0);
+        fact
+    }
+
+    /// A deterministic pseudo-random vector with components in `[-1, 1]`.
+    fn random_vector(&mut self, n: usize) -> DVector<T> {
+        DVector::from_fn(n, |_, _| {
+            // xorshift64*
+            self.seed ^= self.seed >> 12;
+            self.seed ^= self.seed << 25;
+            self.seed ^= self.seed >> 27;
+            let val = self.seed.wrapping_mul(0x2545_f491_4f6c_dd1d) >> 11;
+            crate::convert(val as f64 / (1u64 << 52) as f64 - 1.0)
+        })
+    }
+
+    /// Extends the factorization from `k` to `self.v.ncols()` columns.
+    fn extend<O: LinearOperator<T>>(&mut self, op: &O, k: usize) {
+        let (n, ncv) = self.v.shape();
+        let mut w = DVector::zeros(n);
+
+        for j in k..ncv {
+            let mut beta = self.f.norm();
+
+            // If the residual vanishes, we found an invariant subspace. Continue with a new
+            // random direction orthogonal to the current basis.
+            if j == 0 || beta <= T::default_epsilon() * self.scale.clone() {
+                self.f = self.random_vector(n);
+                for _ in 0..2 {
+                    let basis = self.v.columns(0, j);
+                    let coeffs = basis.tr_mul(&self.f);
+                    self.f -= basis * coeffs;
+                }
+
+                let _ = self.f.normalize_mut();
+                beta = T::zero();
+            }
+
+            if j > 0 {
+                self.h[(j, j - 1)] = beta.clone();
+            }
+
+            if !beta.is_zero() {
+                self.f /= beta;
+            }
+
+            self.v.set_column(j, &self.f);
+            op.apply_to(&self.f, &mut w);
+            self.scale = self.scale.clone().max(w.norm());
+
+            // Classical Gram-Schmidt with one step of reorthogonalization.
+            let basis = self.v.columns(0, j + 1);
+            let mut coeffs = basis.tr_mul(&w);
+            self.f.copy_from(&w);
+            self.f -= &basis * &coeffs;
+
+            let correction = basis.tr_mul(&self.f);
+            self.f -= &basis * &correction;
+            coeffs += correction;
+
+            self.h.slice_range_mut(..j + 1, j).copy_from(&coeffs);
+        }
+    }
+
+    /// Applies the given shifts to the factorization, truncates it to `k` columns and extends
+    /// it back to its full size.
+    fn restart<O: LinearOperator<T>>(&mut self, op: &O, shifts: &[Shift<T>], k: usize) {
+        let ncv = self.v.ncols();
+        let mut q = DMatrix::<T>::identity(ncv, ncv);
+
+        for shift in shifts {
+            let id = DMatrix::<T>::identity(ncv, ncv);
+            let p = match shift {
+                Shift::Real(mu) => &self.h - id * mu.clone(),
+                Shift::ConjugatePair(mu) => {
+                    &self.h * &self.h - &self.h * (mu.re.clone() * crate::convert(2.0))
+                        + id * mu.norm_sqr()
+                }
+            };
+
+            let qi = QR::new(p).q();
+            self.h = qi.tr_mul(&self.h) * &qi;
+            q *= qi;
+        }
+
+        let sigma = q[(ncv - 1, k - 1)].clone();
+        let beta = self.h[(k, k - 1)].clone();
+        self.v = &self.v * q;
+
+        self.f *= sigma;
+        self.f.axpy(beta, &self.v.column(k), T::one());
+
+        self.v.columns_mut(k, ncv - k).fill(T::zero());
+        self.h.columns_mut(k, ncv - k).fill(T::zero());
+        self.h.rows_mut(k, ncv - k).fill(T::zero());
+        // Remove the rounding errors below the subdiagonal.
+        self.h.fill_lower_triangle(T::zero(), 2);
+
+        self.extend(op, k);
+    }
+}
+
+fn default_ncv(n: usize, nev: usize) -> usize {
+    (2 * nev + 1).max(20).min(n)
+}
+
+fn default_eps<T: RealField>() -> T {
+    T::default_epsilon().powf(crate::convert(2.0 / 3.0))
+}
+
+fn sorted_indices<T: RealField>(values: &[Complex<T>], target: KrylovTarget) -> Vec<usize> {
+    let mut order: Vec<_> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| target.compare(&values[a], &values[b]));
+    order
+}
+
+fn relative_threshold<T: RealField>(value: &Complex<T>, eps: T) -> T {
+    eps * value.clone().modulus().max(default_eps())
+}
+
+/// Computes a unit eigenvector of the small matrix `h` associated to the eigenvalue `lambda`,
+/// using inverse iteration.
+fn hessenberg_eigenvector<T: RealField>(
+    h: &DMatrix<Complex<T>>,
+    lambda: &Complex<T>,
+) -> DVector<Complex<T>> {
+    let m = h.nrows();
+    let scale = h.norm().max(T::one());
+    let perturbation = T::default_epsilon() * scale;
+    let shifted = h - DMatrix::identity(m, m) * (lambda.clone() + Complex::from(perturbation));
+    let lu = shifted.lu();
+    let mut y = DVector::from_element(m, Complex::from(T::one()));
+    let _ = y.normalize_mut();
+
+    for _ in 0..3 {
+        if let Some(mut sol) = lu.solve(&y) {
+            if sol.normalize_mut().is_zero() {
+                break;
+            }
+
+            y = sol;
+        }
+    }
+
+    y
+}
@@ -0,0 +1,74 @@
+use num::{One, Zero};
+use simba::scalar::{ClosedAdd, ClosedMul};
+
+use crate::base::constraint::{AreMultipliable, DimEq, ShapeConstraint};
+use crate::base::dimension::{Dim, Dynamic, U1};
+use crate::base::storage::Storage;
+use crate::base::{DVector, Matrix, Scalar};
+
+/// A linear map acting on dynamically-sized vectors.
+///
+/// This is the only thing iterative solvers need to know about a matrix, so it can be
+/// implemented by operators that are never stored explicitly, e.g., Hessian-vector products,
+/// sparse matrices, or compositions of other operators.
+pub trait LinearOperator<T: Scalar> {
+    /// The dimension of the output vectors of this operator.
+    fn nrows(&self) -> usize;
+
+    /// The dimension of the input vectors of this operator.
+    fn ncols(&self) -> usize;
+
+    /// Computes `out = self * x`.
+    ///
+    /// The vector `x` has `self.ncols()` components and `out` has `self.nrows()` components.
+    fn apply_to(&self, x: &DVector<T>, out: &mut DVector<T>);
+
+    /// Computes `self * x`.
+    fn mul_vector(&self, x: &DVector<T>) -> DVector<T>
+    where
+        T: Zero,
+    {
+        let mut out = DVector::zeros(self.nrows());
+        self.apply_to(x, &mut out);
+        out
+    }
+}
+
+impl<T, R: Dim, C: Dim, S> LinearOperator<T> for Matrix<T, R, C, S>
+where
+    T: Scalar + Zero + One + ClosedAdd + ClosedMul,
+    S: Storage<T, R, C>,
+    ShapeConstraint: DimEq<Dynamic, R> + AreMultipliable<R, C, Dynamic, U1>,
+{
+    #[inline]
+    fn nrows(&self) -> usize {
+        self.nrows()
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        self.ncols()
+    }
+
+    #[inline]
+    fn apply_to(&self, x: &DVector<T>, out: &mut DVector<T>) {
+        out.gemv(T::one(), self, x, T::zero())
+    }
+}
+
+impl<T: Scalar, O: LinearOperator<T> + ?Sized> LinearOperator<T> for &O {
+    #[inline]
+    fn nrows(&self) -> usize {
+        (**self).nrows()
+    }
+
+    #[inline]
+    fn ncols(&self) -> usize {
+        (**self).ncols()
+    }
+
+    #[inline]
+    fn apply_to(&self, x: &DVector<T>, out: &mut DVector<T>) {
+        (**self).apply_to(x, out)
+    }
+}
@@ -2,13 +2,18 @@
 use serde::{Deserialize, Serialize};
 
 use crate::allocator::{Allocator, Reallocator};
-use crate::base::{DefaultAllocator, Matrix, OMatrix, Scalar};
+use crate::base::{
+    DefaultAllocator, Diag, Matrix, OMatrix, Scalar, ShapeMismatch, Side, Transposition, Uplo,
+};
 use crate::constraint::{SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Dim, DimMin, DimMinimum};
 use crate::storage::{Storage, StorageMut};
+use num::Zero;
 use simba::scalar::{ComplexField, Field};
+use simba::simd::{SimdComplexField, SimdPartialOrd};
 use std::mem;
 
+use crate::linalg::column_blocks::for_each_column_block;
 use crate::linalg::PermutationSequence;
 
 /// LU decomposition with partial (row) pivoting.
@@ -44,6 +49,97 @@ where
 {
 }
 
+/// The smallest dimension from which `LU::new` switches to the blocked algorithm.
+const BLOCKED_LU_THRESHOLD: usize = 96;
+/// The number of columns factorized at once by the blocked algorithm.
+const LU_BLOCK_SIZE: usize = 32;
+
+/// Unblocked LU decomposition with partial (row) pivoting.
+fn lu_unblocked<T: ComplexField, R: DimMin<C>, C: Dim>(
+    matrix: &mut OMatrix<T, R, C>,
+    p: &mut PermutationSequence<DimMinimum<R, C>>,
+) where
+    DefaultAllocator: Allocator<T, R, C> + Allocator<(usize, usize), DimMinimum<R, C>>,
+{
+    let min_nrows_ncols = matrix.nrows().min(matrix.ncols());
+
+    for i in 0..min_nrows_ncols {
+        let piv = matrix.slice_range(i.., i).icamax() + i;
+        let diag = matrix[(piv, i)].clone();
+
+        if diag.is_zero() {
+            // No non-zero entries on this column.
+            continue;
+        }
+
+        if piv != i {
+            p.append_permutation(i, piv);
+            matrix.columns_range_mut(..i).swap_rows(i, piv);
+            gauss_step_swap(matrix, diag, i, piv);
+        } else {
+            gauss_step(matrix, diag, i);
+        }
+    }
+}
+
+/// Right-looking blocked LU decomposition with partial (row) pivoting.
+///
+/// Each block of columns is factorized with the unblocked algorithm, then the rest of the
+/// matrix is updated at once using a triangular solve and a `gemm`.
+fn lu_blocked<T: ComplexField, R: DimMin<C>, C: Dim>(
+    matrix: &mut OMatrix<T, R, C>,
+    p: &mut PermutationSequence<DimMinimum<R, C>>,
+) where
+    DefaultAllocator: Allocator<T, R, C> + Allocator<(usize, usize), DimMinimum<R, C>>,
+{
+    let (nrows, ncols) = matrix.shape();
+    let min_nrows_ncols = nrows.min(ncols);
+
+    for start in (0..min_nrows_ncols).step_by(LU_BLOCK_SIZE) {
+        let end = (start + LU_BLOCK_SIZE).min(min_nrows_ncols);
+
+        for i in start..end {
+            let piv = matrix.slice_range(i.., i).icamax() + i;
+            let diag = matrix[(piv, i)].clone();
+
+            if diag.is_zero() {
+                // No non-zero entries on this column.
+                continue;
+            }
+
+            if piv != i {
+                p.append_permutation(i, piv);
+                matrix.swap_rows(i, piv);
+            }
+
+            gauss_step(&mut matrix.slice_range_mut(i.., i..end), diag, 0);
+        }
+
+        if end < ncols {
+            let (panel, mut right) = matrix.columns_range_pair_mut(start..end, end..);
+            let l11 = panel.rows_range(start..end);
+            let l21 = panel.rows_range(end..);
+
+            for_each_column_block(right.rows_range_mut(start..), &|_, mut block| {
+                let (mut u12, mut a22) = block.rows_range_pair_mut(0..end - start, end - start..);
+
+                u12.trsm(
+                    T::one(),
+                    &l11,
+                    Side::Left,
+                    Uplo::Lower,
+                    Transposition::NoTranspose,
+                    Diag::Unit,
+                );
+
+                if end < nrows {
+                    a22.gemm(-T::one(), &l21, &u12, T::one());
+                }
+            });
+        }
+    }
+}
+
 /// Performs a LU decomposition to overwrite `out` with the inverse of `matrix`.
 ///
 /// If `matrix` is not invertible, `false` is returned and `out` may contain invalid data.
@@ -84,6 +180,54 @@ where
     matrix.solve_upper_triangular_mut(out)
 }
 
+/// Performs a LU decomposition of `matrix` in-place to overwrite `b` with the solution `x` of
+/// `matrix * x = b`.
+///
+/// Unlike `LU::new`, this does not allocate any buffer, so it can be used with any storage,
+/// including the heap-free `InlineStorage`. After this call, `matrix` contains the packed `L` and
+/// `U` factors of the row-permuted input. If `matrix` is not invertible, `false` is returned and
+/// `b` may contain invalid data.
+pub fn lu_solve_in_place<T: ComplexField, D: Dim, S, R2: Dim, C2: Dim, S2>(
+    matrix: &mut Matrix<T, D, D, S>,
+    b: &mut Matrix<T, R2, C2, S2>,
+) -> bool
+where
+    S: StorageMut<T, D, D>,
+    S2: StorageMut<T, R2, C2>,
+    ShapeConstraint: SameNumberOfRows<R2, D>,
+{
+    assert!(
+        matrix.is_square(),
+        "LU solve: unable to solve a system with a rectangular matrix."
+    );
+    assert_eq!(
+        matrix.nrows(),
+        b.nrows(),
+        "LU solve: mismatched matrix and right-hand-side dimensions."
+    );
+    let dim = matrix.nrows();
+
+    for i in 0..dim {
+        let piv = matrix.slice_range(i.., i).icamax() + i;
+        let diag = matrix[(piv, i)].clone();
+
+        if diag.is_zero() {
+            return false;
+        }
+
+        if piv != i {
+            b.swap_rows(i, piv);
+            matrix.columns_range_mut(..i).swap_rows(i, piv);
+            gauss_step_swap(matrix, diag, i, piv);
+        } else {
+            gauss_step(matrix, diag, i);
+        }
+    }
+
+    let _ = matrix.solve_lower_triangular_with_diag_mut(b, T::one());
+    matrix.solve_upper_triangular_mut(b)
+}
+
 impl<T: ComplexField, R: DimMin<C>, C: Dim> LU<T, R, C>
 where
     DefaultAllocator: Allocator<T, R, C> + Allocator<(usize, usize), DimMinimum<R, C>>,
@@ -99,22 +243,10 @@ where
             return LU { lu: matrix, p };
         }
 
-        for i in 0..min_nrows_ncols.value() {
-            let piv = matrix.slice_range(i.., i).icamax() + i;
-            let diag = matrix[(piv, i)].clone();
-
-            if diag.is_zero() {
-                // No non-zero entries on this column.
-                continue;
-            }
-
-            if piv != i {
-                p.append_permutation(i, piv);
-                matrix.columns_range_mut(..i).swap_rows(i, piv);
-                gauss_step_swap(&mut matrix, diag, i, piv);
-            } else {
-                gauss_step(&mut matrix, diag, i);
-            }
+        if min_nrows_ncols.value() >= BLOCKED_LU_THRESHOLD {
+            lu_blocked(&mut matrix, &mut p);
+        } else {
+            lu_unblocked(&mut matrix, &mut p);
         }
 
         LU { lu: matrix, p }
@@ -234,6 +366,37 @@ where
         }
     }
 
+    /// Solves the linear system `self * x = b` like `.solve`, or returns an error if `b` does
+    /// not have as many rows as the decomposed matrix.
+    ///
+    /// The returned solution is `None` if `self` is not invertible.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, DVector, ShapeMismatch};
+    /// let lu = DMatrix::from_row_slice(2, 2, &[2.0, 0.0, 0.0, 4.0]).lu();
+    /// let x = lu.try_solve(&DVector::from_vec(vec![2.0, 2.0]));
+    /// assert_eq!(x, Ok(Some(DVector::from_vec(vec![1.0, 0.5]))));
+    ///
+    /// let err = lu.try_solve(&DVector::from_vec(vec![1.0, 2.0, 3.0]));
+    /// assert_eq!(err, Err(ShapeMismatch::new((2, 2), (3, 1))));
+    /// ```
+    pub fn try_solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Result<Option<OMatrix<T, R2, C2>>, ShapeMismatch>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        if b.nrows() != self.lu.nrows() {
+            return Err(ShapeMismatch::new(self.lu.shape(), b.shape()));
+        }
+
+        Ok(self.solve(b))
+    }
+
     /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
     ///
     /// If the decomposed matrix is not invertible, this returns `false` and its input `b` may
@@ -386,3 +549,307 @@ pub fn gauss_step_swap<T, R: Dim, C: Dim, S>(
             .axpy(-pivot_row[k].clone(), &coeffs, T::one());
     }
 }
+
+/// LU decomposition with partial (row) pivoting of a square matrix with SIMD-lane entries.
+///
+/// Each SIMD lane is pivoted independently without branching: every candidate row swap is
+/// recorded as a lane mask and replayed with lane-wise selects, so that `P * A = L * U` holds in
+/// every lane. Operations that may fail return a per-lane success mask instead of an `Option` or
+/// a `bool`.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, D, D>,
+         OMatrix<T, D, D>: Serialize,
+         T: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, D, D>,
+         OMatrix<T, D, D>: Deserialize<'de>,
+         T: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct SimdLU<T: SimdComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    lu: OMatrix<T, D, D>,
+    // The entry `(k, i)` is one on the lanes where the rows `i` and `k` are swapped, zero otherwise.
+    swaps: OMatrix<T, D, D>,
+    p_determinant: T,
+}
+
+impl<T: SimdComplexField, D: Dim> Copy for SimdLU<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+    OMatrix<T, D, D>: Copy,
+    T: Copy,
+{
+}
+
+impl<T: SimdComplexField, D: Dim> SimdLU<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    /// Computes the LU decomposition with partial (row) pivoting of the square `matrix`.
+    ///
+    /// Pivoting is performed lane-wise: on each lane, the pivot is the entry with the greatest
+    /// 1-norm on the current column. Lanes where the matrix is singular produce a zero on the
+    /// diagonal of `U`.
+    pub fn new(mut matrix: OMatrix<T, D, D>) -> Self {
+        assert!(
+            matrix.is_square(),
+            "SIMD LU: unable to decompose a non-square matrix."
+        );
+
+        let (nrows, ncols) = matrix.shape_generic();
+        let dim = nrows.value();
+        let mut swaps = OMatrix::zeros_generic(nrows, ncols);
+        let mut p_determinant = T::one();
+
+        for i in 0..dim {
+            // Branch-free partial pivoting: conditionally swap the rows `i` and `k` on the lanes
+            // where the entry of the row `k` is greater than the current pivot.
+            for k in i + 1..dim {
+                let swap = matrix[(k, i)]
+                    .clone()
+                    .simd_norm1()
+                    .simd_gt(matrix[(i, i)].clone().simd_norm1());
+
+                select_swap_rows(&mut matrix, i, k, swap);
+                swaps[(k, i)] = T::one().select(swap, T::zero());
+                p_determinant = (-p_determinant.clone()).select(swap, p_determinant);
+            }
+
+            // If the pivot is zero then the whole remaining column is zero on that lane, so the
+            // elimination step is a no-op there. Use one as a safe divisor.
+            let diag = matrix[(i, i)].clone();
+            let is_zero = diag.clone().simd_norm1().simd_eq(T::SimdRealField::zero());
+            let inv_diag = T::one() / T::one().select(is_zero, diag);
+
+            let mut submat = matrix.slice_range_mut(i.., i..);
+            let (mut coeffs, mut submat) = submat.columns_range_pair_mut(0, 1..);
+            let mut coeffs = coeffs.rows_range_mut(1..);
+            coeffs *= inv_diag;
+
+            let (pivot_row, mut down) = submat.rows_range_pair_mut(0, 1..);
+
+            for k in 0..pivot_row.ncols() {
+                down.column_mut(k)
+                    .axpy(-pivot_row[k].clone(), &coeffs, T::one());
+            }
+        }
+
+        SimdLU {
+            lu: matrix,
+            swaps,
+            p_determinant,
+        }
+    }
+
+    /// The lower triangular matrix of this decomposition.
+    #[inline]
+    #[must_use]
+    pub fn l(&self) -> OMatrix<T, D, D> {
+        let mut m = self.lu.clone();
+        m.fill_upper_triangle(T::zero(), 1);
+        m.fill_diagonal(T::one());
+        m
+    }
+
+    /// The upper triangular matrix of this decomposition.
+    #[inline]
+    #[must_use]
+    pub fn u(&self) -> OMatrix<T, D, D> {
+        self.lu.upper_triangle()
+    }
+
+    /// The row permutations of this decomposition, as a permutation matrix `P` such that
+    /// `P * A = L * U`.
+    #[inline]
+    #[must_use]
+    pub fn p(&self) -> OMatrix<T, D, D> {
+        let (nrows, ncols) = self.lu.shape_generic();
+        let mut p = OMatrix::identity_generic(nrows, ncols);
+        self.permute_rows(&mut p);
+        p
+    }
+
+    /// Applies the row permutations of this decomposition to `rhs`, i.e., computes `P * rhs`.
+    pub fn permute_rows<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<T, R2, C2, S2>)
+    where
+        S2: StorageMut<T, R2, C2>,
+    {
+        let zero = T::SimdRealField::zero();
+        let dim = self.lu.nrows();
+
+        for i in 0..dim {
+            for k in i + 1..dim {
+                let swap = self.swaps[(k, i)]
+                    .clone()
+                    .simd_norm1()
+                    .simd_ne(zero.clone());
+                select_swap_rows(rhs, i, k, swap);
+            }
+        }
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns the solution together with a mask indicating on which lanes the decomposed
+    /// matrix is invertible. The solution on the other lanes is unspecified.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> (OMatrix<T, R2, C2>, T::SimdBool)
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        let mask = self.solve_mut(&mut res);
+        (res, mask)
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns a mask indicating on which lanes the decomposed matrix is invertible. The lanes
+    /// of `b` where it is not are overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> T::SimdBool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.lu.nrows(),
+            b.nrows(),
+            "SIMD LU solve matrix dimension mismatch."
+        );
+
+        let mask = self.is_invertible();
+
+        if self.lu.nrows() != 0 {
+            self.permute_rows(b);
+            self.lu
+                .solve_lower_triangular_with_diag_unchecked_mut(b, T::one());
+            self.lu.solve_upper_triangular_unchecked_mut(b);
+        }
+
+        mask
+    }
+
+    /// Computes the inverse of the decomposed matrix.
+    ///
+    /// Returns the inverse together with a mask indicating on which lanes the decomposed
+    /// matrix is invertible. The inverse on the other lanes is unspecified.
+    #[must_use]
+    pub fn try_inverse(&self) -> (OMatrix<T, D, D>, T::SimdBool) {
+        let (nrows, ncols) = self.lu.shape_generic();
+        let mut res = OMatrix::identity_generic(nrows, ncols);
+        let mask = self.solve_mut(&mut res);
+        (res, mask)
+    }
+
+    /// Computes the determinant of the decomposed matrix.
+    #[must_use]
+    pub fn determinant(&self) -> T {
+        let mut res = self.p_determinant.clone();
+        for i in 0..self.lu.nrows() {
+            res *= unsafe { self.lu.get_unchecked((i, i)).clone() };
+        }
+
+        res
+    }
+
+    /// Indicates on which lanes the decomposed matrix is invertible.
+    #[must_use]
+    pub fn is_invertible(&self) -> T::SimdBool {
+        let zero = T::SimdRealField::zero();
+        let mut mask = zero.clone().simd_eq(zero.clone());
+
+        for i in 0..self.lu.nrows() {
+            mask = mask & self.lu[(i, i)].clone().simd_norm1().simd_ne(zero.clone());
+        }
+
+        mask
+    }
+}
+
+/// Swaps the rows `i` and `k` of `matrix` on the lanes selected by `cond`.
+fn select_swap_rows<T: SimdComplexField, R: Dim, C: Dim, S: StorageMut<T, R, C>>(
+    matrix: &mut Matrix<T, R, C, S>,
+    i: usize,
+    k: usize,
+    cond: T::SimdBool,
+) {
+    for j in 0..matrix.ncols() {
+        let a = matrix[(i, j)].clone();
+        let b = matrix[(k, j)].clone();
+        matrix[(i, j)] = b.clone().select(cond, a.clone());
+        matrix[(k, j)] = a.select(cond, b);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{lu_blocked, lu_unblocked, LU};
+    use crate::linalg::column_blocks::test_matrix;
+    use crate::{DMatrix, Dynamic, PermutationSequence};
+
+    #[test]
+    fn lu_blocked_matches_unblocked() {
+        // Sizes around `BLOCKED_LU_THRESHOLD` that are not all multiples of `LU_BLOCK_SIZE`.
+        let shapes = [
+            (95, 95),
+            (96, 96),
+            (97, 97),
+            (130, 130),
+            (95, 130),
+            (130, 97),
+        ];
+
+        for &(nrows, ncols) in shapes.iter() {
+            let m = test_matrix(nrows, ncols, 42);
+            let dim = nrows.min(ncols);
+
+            let mut blocked = m.clone();
+            let mut p_blocked = PermutationSequence::identity_generic(Dynamic::new(dim));
+            lu_blocked(&mut blocked, &mut p_blocked);
+
+            let mut unblocked = m.clone();
+            let mut p_unblocked = PermutationSequence::identity_generic(Dynamic::new(dim));
+            lu_unblocked(&mut unblocked, &mut p_unblocked);
+
+            assert!(relative_eq!(blocked, unblocked, epsilon = 1.0e-10));
+
+            let mut perm_blocked = DMatrix::<f64>::identity(nrows, nrows);
+            let mut perm_unblocked = perm_blocked.clone();
+            p_blocked.permute_rows(&mut perm_blocked);
+            p_unblocked.permute_rows(&mut perm_unblocked);
+            assert_eq!(perm_blocked, perm_unblocked);
+
+            if nrows == ncols {
+                let b = test_matrix(nrows, 3, 7);
+                let blocked = LU {
+                    lu: blocked,
+                    p: p_blocked,
+                };
+                let unblocked = LU {
+                    lu: unblocked,
+                    p: p_unblocked,
+                };
+                let x = blocked.solve(&b).unwrap();
+
+                assert!(relative_eq!(
+                    x,
+                    unblocked.solve(&b).unwrap(),
+                    epsilon = 1.0e-8
+                ));
+                assert!(relative_eq!(m * x, b, epsilon = 1.0e-8));
+            }
+        }
+    }
+}
@@ -1,8 +1,11 @@
 //! [Reexported at the root of this crate.] Factorization of real matrices.
 
 pub mod balancing;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod band;
 mod bidiagonal;
 mod cholesky;
+mod column_blocks;
 mod convolution;
 mod determinant;
 // TODO: this should not be needed. However, the exp uses
@@ -10,23 +13,34 @@ mod determinant;
 // get rid of these to allow exp to be used on a no-std context.
 mod col_piv_qr;
 mod decomposition;
+mod exact;
 #[cfg(feature = "std")]
 mod exp;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod fft;
 mod full_piv_lu;
 pub mod givens;
+mod hermite;
 mod hessenberg;
 pub mod householder;
 mod inverse;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod krylov;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod linear_operator;
 mod lu;
 mod permutation_sequence;
 mod pow;
 mod qr;
 mod schur;
+mod smith;
 mod solve;
 mod svd;
 mod svd2;
 mod svd3;
 mod symmetric_eigen;
+mod symmetric_eigen2;
+mod symmetric_eigen3;
 mod symmetric_tridiagonal;
 mod udu;
 
@@ -34,6 +48,8 @@ mod udu;
 //// This handles only cases where each eigenvalue has multiplicity one.
 // mod eigen;
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::band::*;
 pub use self::bidiagonal::*;
 pub use self::cholesky::*;
 pub use self::col_piv_qr::*;
@@ -41,12 +57,18 @@ pub use self::convolution::*;
 #[cfg(feature = "std")]
 pub use self::exp::*;
 pub use self::full_piv_lu::*;
+pub use self::hermite::HermiteNormalForm;
 pub use self::hessenberg::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::krylov::*;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::linear_operator::*;
 pub use self::lu::*;
 pub use self::permutation_sequence::*;
 pub use self::pow::*;
 pub use self::qr::*;
 pub use self::schur::*;
+pub use self::smith::*;
 pub use self::svd::*;
 pub use self::symmetric_eigen::*;
 pub use self::symmetric_tridiagonal::*;
@@ -5,7 +5,7 @@ use num::One;
 use simba::scalar::ClosedNeg;
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, Matrix, OVector, Scalar};
+use crate::base::{DefaultAllocator, Matrix, OVector, PermutationMatrix, Scalar};
 #[cfg(any(feature = "std", feature = "alloc"))]
 use crate::dimension::Dynamic;
 use crate::dimension::{Const, Dim, DimName};
@@ -162,3 +162,17 @@ where
         }
     }
 }
+
+impl<'a, D: Dim> From<&'a PermutationSequence<D>> for PermutationMatrix<D>
+where
+    DefaultAllocator: Allocator<(usize, usize), D> + Allocator<usize, D>,
+{
+    /// The permutation matrix `P` such that `P * m` is the result of `seq.permute_rows(&mut m)`.
+    #[inline]
+    fn from(seq: &'a PermutationSequence<D>) -> Self {
+        let dim = seq.ipiv.shape_generic().0;
+        let mut indices = OVector::from_fn_generic(dim, Const::<1>, |i, _| i);
+        seq.permute_rows(&mut indices);
+        PermutationMatrix::new_unchecked(indices)
+    }
+}
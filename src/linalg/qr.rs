@@ -1,18 +1,119 @@
-use num::Zero;
+use num::{One, Zero};
 #[cfg(feature = "serde-serialize-no-std")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
 use crate::allocator::{Allocator, Reallocator};
-use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector, Unit};
-use crate::constraint::{SameNumberOfRows, ShapeConstraint};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::{DMatrix, Diag, Side, Transposition, Uplo};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector, ShapeMismatch, Unit};
+use crate::constraint::{DimEq, SameNumberOfRows, ShapeConstraint};
 use crate::dimension::{Const, Dim, DimMin, DimMinimum};
 use crate::storage::{Storage, StorageMut};
 use simba::scalar::ComplexField;
+use simba::simd::{SimdComplexField, SimdPartialOrd, SimdValue};
 
 use crate::geometry::Reflection;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::linalg::column_blocks::for_each_column_block;
 use crate::linalg::householder;
 use std::mem::MaybeUninit;
 
+/// The smallest dimension from which `QR::new` switches to the blocked algorithm.
+#[cfg(any(feature = "std", feature = "alloc"))]
+const BLOCKED_QR_THRESHOLD: usize = 96;
+/// The number of columns factorized at once by the blocked algorithm.
+#[cfg(any(feature = "std", feature = "alloc"))]
+const QR_BLOCK_SIZE: usize = 32;
+
+/// Blocked QR decomposition where the householder reflections of each block of columns are
+/// applied at once to the rest of the matrix in their compact WY form `I - V * T * V.adjoint()`.
+///
+/// This yields the same decomposition as the unblocked algorithm, including the sign changes
+/// applied to the rows of `R` after each reflection.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn qr_blocked<T: ComplexField, R: DimMin<C>, C: Dim>(
+    matrix: &mut OMatrix<T, R, C>,
+    diag: &mut OVector<T, DimMinimum<R, C>>,
+) where
+    DefaultAllocator: Allocator<T, R, C> + Allocator<T, DimMinimum<R, C>>,
+{
+    let (nrows, ncols) = matrix.shape();
+    let min_nrows_ncols = nrows.min(ncols);
+
+    for start in (0..min_nrows_ncols).step_by(QR_BLOCK_SIZE) {
+        let end = (start + QR_BLOCK_SIZE).min(min_nrows_ncols);
+        let block_size = end - start;
+
+        // The unblocked algorithm multiplies the rows `i..` by `signs[i]` after the `i`-th
+        // reflection. `scales[k]` is the product of the first `k` signs of this block.
+        let mut taus = Vec::with_capacity(block_size);
+        let mut scales = Vec::with_capacity(block_size + 1);
+        scales.push(T::one());
+
+        for i in start..end {
+            let (mut left, mut right) = matrix.columns_range_pair_mut(i, i + 1..end);
+            let mut axis = left.rows_range_mut(i..);
+            let (reflection_norm, not_zero) = householder::reflection_axis_mut(&mut axis);
+            let mut scale = scales[i - start].clone();
+
+            if not_zero {
+                let refl = Reflection::new(Unit::new_unchecked(axis), T::zero());
+                let sign = reflection_norm.clone().signum().conjugate();
+                refl.reflect_with_sign(&mut right.rows_range_mut(i..), sign.clone());
+                scale *= sign;
+                taus.push(crate::convert(2.0));
+            } else {
+                taus.push(T::zero());
+            }
+
+            scales.push(scale);
+            diag[i] = reflection_norm;
+        }
+
+        if end == ncols {
+            continue;
+        }
+
+        // The householder axes, stored below the diagonal of the block.
+        let mut v = matrix.slice_range(start.., start..end).into_owned();
+        v.fill_upper_triangle(T::zero(), 1);
+
+        // The upper-triangular factor such that `H_start * … * H_end = I - V * T * V.adjoint()`.
+        let mut t = DMatrix::zeros(block_size, block_size);
+        for i in 0..block_size {
+            let w = v.columns(0, i).ad_mul(&v.column(i)) * -taus[i].clone();
+            let tw = t.slice((0, 0), (i, i)).upper_triangle() * w;
+            t.slice_mut((0, i), (i, 1)).copy_from(&tw);
+            t[(i, i)] = taus[i].clone();
+        }
+
+        // Apply `H_end * … * H_start = I - V * T.adjoint() * V.adjoint()` to the rest of the
+        // matrix, then the sign changes.
+        let v_ad = v.adjoint();
+
+        for_each_column_block(matrix.slice_range_mut(start.., end..), &|_, mut right| {
+            let mut w = DMatrix::zeros(block_size, right.ncols());
+            w.gemm(T::one(), &v_ad, &right, T::zero());
+            w.trmm(
+                T::one(),
+                &t,
+                Side::Left,
+                Uplo::Upper,
+                Transposition::Adjoint,
+                Diag::NonUnit,
+            );
+            right.gemm(-T::one(), &v, &w, T::one());
+
+            for (k, mut row) in right.row_iter_mut().enumerate() {
+                row *= scales[(k + 1).min(block_size)].clone();
+            }
+        });
+    }
+}
+
 /// The QR decomposition of a general matrix.
 #[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
 #[cfg_attr(
@@ -51,7 +152,7 @@ where
     DefaultAllocator: Allocator<T, R, C> + Allocator<T, R> + Allocator<T, DimMinimum<R, C>>,
 {
     /// Computes the QR decomposition using householder reflections.
-    pub fn new(mut matrix: OMatrix<T, R, C>) -> Self {
+    pub fn new(matrix: OMatrix<T, R, C>) -> Self {
         let (nrows, ncols) = matrix.shape_generic();
         let min_nrows_ncols = nrows.min(ncols);
 
@@ -62,6 +163,24 @@ where
             };
         }
 
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        {
+            if min_nrows_ncols.value() >= BLOCKED_QR_THRESHOLD {
+                let mut matrix = matrix;
+                let mut diag = Matrix::zeros_generic(min_nrows_ncols, Const::<1>);
+                qr_blocked(&mut matrix, &mut diag);
+                return QR { qr: matrix, diag };
+            }
+        }
+
+        Self::new_unblocked(matrix)
+    }
+
+    /// Unblocked QR decomposition, applying each householder reflection to the rest of the matrix
+    /// right away.
+    fn new_unblocked(mut matrix: OMatrix<T, R, C>) -> Self {
+        let (nrows, ncols) = matrix.shape_generic();
+        let min_nrows_ncols = nrows.min(ncols);
         let mut diag = Matrix::uninit(min_nrows_ncols, Const::<1>);
 
         for i in 0..min_nrows_ncols.value() {
@@ -196,6 +315,37 @@ where
         }
     }
 
+    /// Solves the linear system `self * x = b` like `.solve`, or returns an error if `b` does
+    /// not have as many rows as the decomposed matrix.
+    ///
+    /// The returned solution is `None` if `self` is not invertible.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, DVector, ShapeMismatch};
+    /// let qr = DMatrix::from_row_slice(2, 2, &[2.0, 0.0, 0.0, 4.0]).qr();
+    /// let x = qr.try_solve(&DVector::from_vec(vec![2.0, 2.0]));
+    /// assert_eq!(x, Ok(Some(DVector::from_vec(vec![1.0, 0.5]))));
+    ///
+    /// let err = qr.try_solve(&DVector::from_vec(vec![1.0, 2.0, 3.0]));
+    /// assert_eq!(err, Err(ShapeMismatch::new((2, 2), (3, 1))));
+    /// ```
+    pub fn try_solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> Result<Option<OMatrix<T, R2, C2>>, ShapeMismatch>
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        if b.nrows() != self.qr.nrows() {
+            return Err(ShapeMismatch::new(self.qr.shape(), b.shape()));
+        }
+
+        Ok(self.solve(b))
+    }
+
     /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
     ///
     /// If the decomposed matrix is not invertible, this returns `false` and its input `b` is
@@ -305,3 +455,256 @@ where
     //     res self.q_determinant()
     // }
 }
+
+/// The QR decomposition of a square matrix with SIMD-lane entries.
+///
+/// The householder reflections are computed without branching on any lane. Operations that may
+/// fail return a per-lane success mask instead of an `Option` or a `bool`.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, D, D> +
+                           Allocator<T, D>,
+         OMatrix<T, D, D>: Serialize,
+         OVector<T, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, D, D> +
+                           Allocator<T, D>,
+         OMatrix<T, D, D>: Deserialize<'de>,
+         OVector<T, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct SimdQR<T: SimdComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+{
+    qr: OMatrix<T, D, D>,
+    diag: OVector<T, D>,
+}
+
+impl<T: SimdComplexField, D: Dim> Copy for SimdQR<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+    OMatrix<T, D, D>: Copy,
+    OVector<T, D>: Copy,
+{
+}
+
+impl<T: SimdComplexField, D: Dim> SimdQR<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+{
+    /// Computes the QR decomposition of the square `matrix` using householder reflections.
+    pub fn new(mut matrix: OMatrix<T, D, D>) -> Self {
+        assert!(
+            matrix.is_square(),
+            "SIMD QR: unable to decompose a non-square matrix."
+        );
+
+        let (nrows, _) = matrix.shape_generic();
+        let mut diag = OVector::zeros_generic(nrows, Const::<1>);
+        let zero = T::SimdRealField::zero();
+        let two: T::SimdRealField = crate::convert(2.0);
+
+        for i in 0..nrows.value() {
+            let (mut left, mut right) = matrix.columns_range_pair_mut(i, i + 1..);
+            let mut axis = left.rows_range_mut(i..);
+
+            let sq_norm = axis.norm_squared();
+            let norm = sq_norm.clone().simd_sqrt();
+            let (modulus, sign) = axis[0].clone().simd_to_exp();
+            let signed_norm = sign.simd_scale(norm.clone());
+            let factor = ((sq_norm + modulus * norm) * two.clone()).simd_sqrt();
+
+            // On the lanes where the column is zero, the axis is zero as well so the reflection
+            // is the identity. Use one as a safe divisor.
+            let is_zero = factor.clone().simd_eq(zero.clone());
+            let factor = T::SimdRealField::one().select(is_zero, factor);
+
+            axis[0] += signed_norm.clone();
+            axis *= T::from_simd_real(T::SimdRealField::one() / factor);
+            diag[i] = -signed_norm;
+
+            reflect_columns(&axis, &mut right.rows_range_mut(i..));
+        }
+
+        SimdQR { qr: matrix, diag }
+    }
+
+    /// Retrieves the upper triangular matrix `R` of this decomposition.
+    #[inline]
+    #[must_use]
+    pub fn r(&self) -> OMatrix<T, D, D> {
+        let mut res = self.qr.upper_triangle();
+        res.set_partial_diagonal(self.diag.iter().cloned());
+        res
+    }
+
+    /// Computes the unitary matrix `Q` of this decomposition.
+    #[must_use]
+    pub fn q(&self) -> OMatrix<T, D, D> {
+        let (nrows, ncols) = self.qr.shape_generic();
+        let mut res = OMatrix::identity_generic(nrows, ncols);
+
+        for i in (0..self.diag.len()).rev() {
+            let axis = self.qr.slice_range(i.., i);
+            reflect_columns(&axis, &mut res.slice_range_mut(i.., i..));
+        }
+
+        res
+    }
+
+    /// Multiplies the provided matrix by the adjoint of the `Q` matrix of this decomposition.
+    pub fn q_tr_mul<R2: Dim, C2: Dim, S2>(&self, rhs: &mut Matrix<T, R2, C2, S2>)
+    where
+        S2: StorageMut<T, R2, C2>,
+    {
+        for i in 0..self.diag.len() {
+            let axis = self.qr.slice_range(i.., i);
+            reflect_columns(&axis, &mut rhs.rows_range_mut(i..));
+        }
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns the solution together with a mask indicating on which lanes the decomposed
+    /// matrix is invertible. The solution on the other lanes is unspecified.
+    #[must_use = "Did you mean to use solve_mut()?"]
+    pub fn solve<R2: Dim, C2: Dim, S2>(
+        &self,
+        b: &Matrix<T, R2, C2, S2>,
+    ) -> (OMatrix<T, R2, C2>, T::SimdBool)
+    where
+        S2: Storage<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+        DefaultAllocator: Allocator<T, R2, C2>,
+    {
+        let mut res = b.clone_owned();
+        let mask = self.solve_mut(&mut res);
+        (res, mask)
+    }
+
+    /// Solves the linear system `self * x = b`, where `x` is the unknown to be determined.
+    ///
+    /// Returns a mask indicating on which lanes the decomposed matrix is invertible. The lanes
+    /// of `b` where it is not are overwritten with garbage.
+    pub fn solve_mut<R2: Dim, C2: Dim, S2>(&self, b: &mut Matrix<T, R2, C2, S2>) -> T::SimdBool
+    where
+        S2: StorageMut<T, R2, C2>,
+        ShapeConstraint: SameNumberOfRows<R2, D>,
+    {
+        assert_eq!(
+            self.qr.nrows(),
+            b.nrows(),
+            "SIMD QR solve matrix dimension mismatch."
+        );
+
+        self.q_tr_mul(b);
+
+        let dim = self.qr.nrows();
+
+        for k in 0..b.ncols() {
+            let mut b = b.column_mut(k);
+            for i in (0..dim).rev() {
+                let coeff = b[i].clone() / self.diag[i].clone();
+                b[i] = coeff.clone();
+                b.rows_range_mut(..i)
+                    .axpy(-coeff, &self.qr.slice_range(..i, i), T::one());
+            }
+        }
+
+        self.is_invertible()
+    }
+
+    /// Computes the inverse of the decomposed matrix.
+    ///
+    /// Returns the inverse together with a mask indicating on which lanes the decomposed
+    /// matrix is invertible. The inverse on the other lanes is unspecified.
+    #[must_use]
+    pub fn try_inverse(&self) -> (OMatrix<T, D, D>, T::SimdBool) {
+        let (nrows, ncols) = self.qr.shape_generic();
+        let mut res = OMatrix::identity_generic(nrows, ncols);
+        let mask = self.solve_mut(&mut res);
+        (res, mask)
+    }
+
+    /// Indicates on which lanes the decomposed matrix is invertible.
+    #[must_use]
+    pub fn is_invertible(&self) -> T::SimdBool {
+        let zero = T::SimdRealField::zero();
+        let mut mask = zero.clone().simd_eq(zero.clone());
+
+        for i in 0..self.diag.len() {
+            mask = mask & self.diag[i].clone().simd_norm1().simd_ne(zero.clone());
+        }
+
+        mask
+    }
+}
+
+/// Applies to the columns of `rhs` the householder reflection with the given unit (or zero)
+/// `axis`.
+fn reflect_columns<T, R: Dim, R2: Dim, C2: Dim, S, S2>(
+    axis: &Matrix<T, R, Const<1>, S>,
+    rhs: &mut Matrix<T, R2, C2, S2>,
+) where
+    T: SimdComplexField,
+    S: Storage<T, R, Const<1>>,
+    S2: StorageMut<T, R2, C2>,
+    ShapeConstraint: DimEq<R, R2> + DimEq<R2, R>,
+{
+    let m_two: T = crate::convert(-2.0f64);
+
+    for j in 0..rhs.ncols() {
+        let mut column = rhs.column_mut(j);
+        let factor = axis.dotc(&column) * m_two.clone();
+        column.axpy(factor, axis, T::one());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{qr_blocked, QR};
+    use crate::linalg::column_blocks::test_matrix;
+    use crate::{Const, Dynamic, Matrix};
+
+    #[test]
+    fn qr_blocked_matches_unblocked() {
+        // Sizes around `BLOCKED_QR_THRESHOLD` that are not all multiples of `QR_BLOCK_SIZE`.
+        let shapes = [
+            (95, 95),
+            (96, 96),
+            (97, 97),
+            (130, 130),
+            (95, 130),
+            (130, 97),
+        ];
+
+        for &(nrows, ncols) in shapes.iter() {
+            let m = test_matrix(nrows, ncols, 42);
+
+            let mut qr = m.clone();
+            let mut diag = Matrix::zeros_generic(Dynamic::new(nrows.min(ncols)), Const::<1>);
+            qr_blocked(&mut qr, &mut diag);
+            let blocked = QR { qr, diag };
+            let unblocked = QR::new_unblocked(m.clone());
+
+            assert!(relative_eq!(blocked.q(), unblocked.q(), epsilon = 1.0e-10));
+            assert!(relative_eq!(blocked.r(), unblocked.r(), epsilon = 1.0e-10));
+
+            if nrows == ncols {
+                let b = test_matrix(nrows, 3, 7);
+                let x = blocked.solve(&b).unwrap();
+
+                assert!(relative_eq!(
+                    x,
+                    unblocked.solve(&b).unwrap(),
+                    epsilon = 1.0e-8
+                ));
+                assert!(relative_eq!(m * x, b, epsilon = 1.0e-8));
+            }
+        }
+    }
+}
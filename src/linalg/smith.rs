@@ -0,0 +1,189 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use num::Signed;
+use num_integer::{ExtendedGcd, Integer};
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, OMatrix, OVector, Scalar};
+use crate::dimension::{Const, Dim, DimMin, DimMinimum};
+
+use crate::linalg::hermite::{hermite_reduce, negate_line, swap_lines};
+
+/// The Smith normal form of an integer matrix.
+///
+/// This computes `D = U * A * V` where `U` and `V` are unimodular (i.e., they are integer
+/// matrices with a determinant equal to `±1`) and `D` is diagonal. The diagonal entries of `D`
+/// (the invariant factors of `A`) are non-negative and each one divides the next.
+///
+/// The entries of `U` and `V` can be much larger than the entries of `A`, so consider using
+/// a wide or arbitrary-precision integer type to avoid overflows.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "OMatrix<I, R, C>: Serialize,
+         OMatrix<I, R, R>: Serialize,
+         OMatrix<I, C, C>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "OMatrix<I, R, C>: Deserialize<'de>,
+         OMatrix<I, R, R>: Deserialize<'de>,
+         OMatrix<I, C, C>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct SmithNormalForm<I: Scalar, R: Dim, C: Dim>
+where
+    DefaultAllocator: Allocator<I, R, C> + Allocator<I, R, R> + Allocator<I, C, C>,
+{
+    /// The unimodular matrix `U` applied to the rows of the input matrix.
+    pub u: OMatrix<I, R, R>,
+    /// The diagonal Smith normal form `D` of the input matrix.
+    pub d: OMatrix<I, R, C>,
+    /// The unimodular matrix `V` applied to the columns of the input matrix.
+    pub v: OMatrix<I, C, C>,
+}
+
+impl<I: Scalar, R: Dim, C: Dim> Copy for SmithNormalForm<I, R, C>
+where
+    DefaultAllocator: Allocator<I, R, C> + Allocator<I, R, R> + Allocator<I, C, C>,
+    OMatrix<I, R, C>: Copy,
+    OMatrix<I, R, R>: Copy,
+    OMatrix<I, C, C>: Copy,
+{
+}
+
+impl<I, R: Dim, C: Dim> SmithNormalForm<I, R, C>
+where
+    I: Scalar + Integer + Signed,
+    DefaultAllocator: Allocator<I, R, C> + Allocator<I, R, R> + Allocator<I, C, C>,
+{
+    /// Computes the Smith normal form of the integer matrix `a`.
+    pub fn new(a: OMatrix<I, R, C>) -> Self {
+        let (nrows, ncols) = a.shape();
+        let (nrows_dim, ncols_dim) = a.shape_generic();
+        let mut d = a;
+        let mut u = OMatrix::identity_generic(nrows_dim, nrows_dim);
+        let mut v = OMatrix::identity_generic(ncols_dim, ncols_dim);
+
+        // Alternate between the row-style and column-style Hermite normal forms until `D` is
+        // diagonal. Compared to eliminating one pivot at a time, this keeps the entries of `U`
+        // and `V` much smaller.
+        let mut columns = false;
+
+        while !is_diagonal(&d) {
+            if columns {
+                let _ = hermite_reduce(&mut d, &mut v, true);
+            } else {
+                let _ = hermite_reduce(&mut d, &mut u, false);
+            }
+
+            columns = !columns;
+        }
+
+        // Make each diagonal entry divide the next ones. The pair `(a, b)` of diagonal entries is
+        // replaced by `(gcd(a, b), lcm(a, b))`.
+        let dim = nrows.min(ncols);
+
+        for i in 0..dim {
+            for j in i + 1..dim {
+                let (a, b) = (d[(i, i)].clone(), d[(j, j)].clone());
+
+                if b.is_multiple_of(&a) {
+                    continue;
+                }
+
+                if a.is_zero() {
+                    swap_lines(&mut d, i, j, false);
+                    swap_lines(&mut u, i, j, false);
+                    swap_lines(&mut d, i, j, true);
+                    swap_lines(&mut v, i, j, true);
+                    continue;
+                }
+
+                // With `x * a + y * b = g`, this is `diag(g, a * b / g) = P * diag(a, b) * Q`
+                // where `P = [x, y; -b/g, a/g]` and `Q = [1, -y*b/g; 1, x*a/g]` are unimodular.
+                let ExtendedGcd { gcd, x, y, .. } = a.extended_gcd(&b);
+                let (a_g, b_g) = (a / gcd.clone(), b.clone() / gcd.clone());
+
+                combine_lines(
+                    &mut u,
+                    i,
+                    j,
+                    [x.clone(), y.clone(), -b_g.clone(), a_g.clone()],
+                    false,
+                );
+                combine_lines(
+                    &mut v,
+                    i,
+                    j,
+                    [I::one(), I::one(), -y * b_g, x * a_g.clone()],
+                    true,
+                );
+                d[(i, i)] = gcd;
+                d[(j, j)] = a_g * b;
+            }
+        }
+
+        for i in 0..dim {
+            if d[(i, i)].is_negative() {
+                negate_line(&mut d, i, false);
+                negate_line(&mut u, i, false);
+            }
+        }
+
+        Self { u, d, v }
+    }
+
+    /// The invariant factors of the decomposed matrix, i.e., the diagonal of `D`.
+    #[must_use]
+    pub fn invariant_factors(&self) -> OVector<I, DimMinimum<R, C>>
+    where
+        R: DimMin<C>,
+        DefaultAllocator: Allocator<I, DimMinimum<R, C>>,
+    {
+        let (nrows, ncols) = self.d.shape_generic();
+        OVector::from_fn_generic(nrows.min(ncols), Const::<1>, |i, _| self.d[(i, i)].clone())
+    }
+
+    /// Retrieves the `U`, `D` and `V` matrices of this decomposition.
+    pub fn unpack(self) -> (OMatrix<I, R, R>, OMatrix<I, R, C>, OMatrix<I, C, C>) {
+        (self.u, self.d, self.v)
+    }
+}
+
+/// Whether every off-diagonal entry of `m` is zero.
+fn is_diagonal<I, R: Dim, C: Dim>(m: &OMatrix<I, R, C>) -> bool
+where
+    I: Scalar + Integer,
+    DefaultAllocator: Allocator<I, R, C>,
+{
+    let (nrows, ncols) = m.shape();
+    (0..ncols).all(|j| (0..nrows).all(|i| i == j || m[(i, j)].is_zero()))
+}
+
+/// Replaces the rows `i` and `j` of `m` by `c[0] * row_i + c[1] * row_j` and
+/// `c[2] * row_i + c[3] * row_j`, or does the same to its columns if `columns` is `true`.
+fn combine_lines<I, R: Dim, C: Dim>(
+    m: &mut OMatrix<I, R, C>,
+    i: usize,
+    j: usize,
+    c: [I; 4],
+    columns: bool,
+) where
+    I: Scalar + Integer,
+    DefaultAllocator: Allocator<I, R, C>,
+{
+    let len = if columns { m.nrows() } else { m.ncols() };
+
+    for k in 0..len {
+        let (ik, jk) = if columns {
+            ((k, i), (k, j))
+        } else {
+            ((i, k), (j, k))
+        };
+        let (ei, ej) = (m[ik].clone(), m[jk].clone());
+        m[ik] = c[0].clone() * ei.clone() + c[1].clone() * ej.clone();
+        m[jk] = c[2].clone() * ei + c[3].clone() * ej;
+    }
+}
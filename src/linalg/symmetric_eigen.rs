@@ -3,12 +3,16 @@ use serde::{Deserialize, Serialize};
 
 use approx::AbsDiffEq;
 use num::Zero;
+use std::any::TypeId;
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, Matrix2, OMatrix, OVector, SquareMatrix, Vector2};
+use crate::base::{
+    DefaultAllocator, Matrix2, Matrix3, OMatrix, OVector, SquareMatrix, Vector2, Vector3,
+};
 use crate::dimension::{Dim, DimDiff, DimSub, U1};
 use crate::storage::Storage;
 use simba::scalar::ComplexField;
+use simba::simd::SimdRealField;
 
 use crate::linalg::givens::GivensRotation;
 use crate::linalg::SymmetricTridiagonal;
@@ -49,10 +53,59 @@ where
 {
 }
 
+/// Eigendecomposition of a symmetric matrix with SIMD real components.
+///
+/// This is computed by `Matrix2::simd_symmetric_eigen` and `Matrix3::simd_symmetric_eigen`, which
+/// decompose each lane of the matrix independently.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, D, D> +
+                           Allocator<T, D>,
+         OVector<T, D>: Serialize,
+         OMatrix<T, D, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, D, D> +
+                           Allocator<T, D>,
+         OVector<T, D>: Deserialize<'de>,
+         OMatrix<T, D, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct SimdSymmetricEigen<T: SimdRealField, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+{
+    /// The eigenvectors of the decomposed matrix.
+    pub eigenvectors: OMatrix<T, D, D>,
+
+    /// The unsorted eigenvalues of the decomposed matrix.
+    pub eigenvalues: OVector<T, D>,
+}
+
+impl<T: SimdRealField, D: Dim> Copy for SimdSymmetricEigen<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+    OMatrix<T, D, D>: Copy,
+    OVector<T, D>: Copy,
+{
+}
+
 impl<T: ComplexField, D: Dim> SymmetricEigen<T, D>
 where
     DefaultAllocator: Allocator<T, D, D> + Allocator<T::RealField, D>,
 {
+    fn use_special_symmetric_eigen2() -> bool {
+        TypeId::of::<OMatrix<T, D, D>>() == TypeId::of::<Matrix2<T::RealField>>()
+            && TypeId::of::<OVector<T::RealField, D>>() == TypeId::of::<Vector2<T::RealField>>()
+    }
+
+    fn use_special_symmetric_eigen3() -> bool {
+        TypeId::of::<OMatrix<T, D, D>>() == TypeId::of::<Matrix3<T::RealField>>()
+            && TypeId::of::<OVector<T::RealField, D>>() == TypeId::of::<Vector3<T::RealField>>()
+    }
+
     /// Computes the eigendecomposition of the given symmetric matrix.
     ///
     /// Only the lower-triangular parts (including its diagonal) of `m` is read.
@@ -100,6 +153,34 @@ where
             matrix.is_square(),
             "Unable to compute the eigendecomposition of a non-square matrix."
         );
+
+        // NOTE: the Jacobi fast paths don't count iterations the same way as the general
+        // algorithm, so they are only used when the iterations are not capped. They fall back to
+        // the general algorithm if they did not converge, e.g., because of non-finite inputs.
+        if max_niter == 0 && Self::use_special_symmetric_eigen2() {
+            // SAFETY: the reference transmutes are OK since we checked that the types match exactly.
+            let m2: &Matrix2<T::RealField> = unsafe { std::mem::transmute(&matrix) };
+            let result = super::symmetric_eigen2::symmetric_eigen2(m2);
+
+            if result.eigenvalues.iter().all(|e| e.is_finite()) {
+                let vals: &OVector<T::RealField, D> =
+                    unsafe { std::mem::transmute(&result.eigenvalues) };
+                let vecs: &OMatrix<T, D, D> = unsafe { std::mem::transmute(&result.eigenvectors) };
+                return Some((vals.clone(), Some(vecs.clone()).filter(|_| eigenvectors)));
+            }
+        } else if max_niter == 0 && Self::use_special_symmetric_eigen3() {
+            // SAFETY: the reference transmutes are OK since we checked that the types match exactly.
+            let m3: &Matrix3<T::RealField> = unsafe { std::mem::transmute(&matrix) };
+            let (result, converged) = super::symmetric_eigen3::symmetric_eigen3(m3, eps.clone(), 0);
+
+            if converged {
+                let vals: &OVector<T::RealField, D> =
+                    unsafe { std::mem::transmute(&result.eigenvalues) };
+                let vecs: &OMatrix<T, D, D> = unsafe { std::mem::transmute(&result.eigenvectors) };
+                return Some((vals.clone(), Some(vecs.clone()).filter(|_| eigenvectors)));
+            }
+        }
+
         let dim = matrix.nrows();
         let m_amax = matrix.camax();
 
@@ -0,0 +1,49 @@
+use simba::simd::SimdRealField;
+
+use crate::{Matrix2, SimdSymmetricEigen, Vector2, U2};
+
+/// Computes the Jacobi rotation that cancels the off-diagonal element of the symmetric matrix
+/// `[app apq; apq aqq]`.
+///
+/// Returns the cosine `c` and sine `s` of the rotation as well as `t = s / c`. The rotated
+/// diagonal elements are `app - t * apq` and `aqq + t * apq`. This does not branch, and is
+/// accurate even if the diagonal elements are nearly equal.
+pub(crate) fn jacobi_rotation<T: SimdRealField>(app: T, aqq: T, apq: T) -> (T, T, T) {
+    let one = T::one();
+    let is_zero = apq.clone().simd_eq(T::zero());
+
+    // NOTE: replace the denominator by 1 if apq == 0 so we don't divide by zero.
+    let denom = one.clone().select(is_zero, apq.clone() + apq);
+    let tau = (aqq - app) / denom;
+    let t = one.clone().simd_copysign(tau.clone())
+        / (tau.clone().simd_abs() + (one.clone() + tau.clone() * tau).simd_sqrt());
+    let t = T::zero().select(is_zero, t);
+    let c = one.clone() / (one + t.clone() * t.clone()).simd_sqrt();
+    let s = t.clone() * c.clone();
+
+    (c, s, t)
+}
+
+/// Computes the eigendecomposition of the symmetric 2x2 matrix `m` with a single Jacobi
+/// rotation. Only the lower-triangular part of `m` is read.
+pub fn symmetric_eigen2<T: SimdRealField>(m: &Matrix2<T>) -> SimdSymmetricEigen<T, U2> {
+    let (app, aqq, apq) = (m.m11.clone(), m.m22.clone(), m.m21.clone());
+    let (c, s, t) = jacobi_rotation(app.clone(), aqq.clone(), apq.clone());
+
+    SimdSymmetricEigen {
+        eigenvalues: Vector2::new(app - t.clone() * apq.clone(), aqq + t * apq),
+        eigenvectors: Matrix2::new(c.clone(), s.clone(), -s, c),
+    }
+}
+
+impl<T: SimdRealField> Matrix2<T> {
+    /// Computes the eigendecomposition of this symmetric matrix with a closed-form Jacobi
+    /// rotation.
+    ///
+    /// Only the lower-triangular part of the matrix is read. This does not branch so it
+    /// supports SIMD scalar types, e.g., to decompose several matrices at once.
+    #[must_use]
+    pub fn simd_symmetric_eigen(&self) -> SimdSymmetricEigen<T, U2> {
+        symmetric_eigen2(self)
+    }
+}
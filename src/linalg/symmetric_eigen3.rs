@@ -0,0 +1,126 @@
+use simba::simd::{SimdBool, SimdRealField};
+
+use crate::linalg::symmetric_eigen2::jacobi_rotation;
+use crate::{Matrix3, SimdSymmetricEigen, Vector3, U3};
+
+/// Maximum number of sweeps performed by `Matrix3::simd_symmetric_eigen`, and by
+/// `symmetric_eigen3` if `max_sweeps == 0`. The cyclic Jacobi method converges quadratically so
+/// this is never reached in practice for finite inputs.
+const MAX_SIMD_SWEEPS: usize = 16;
+
+/// Computes the eigendecomposition of the symmetric 3x3 matrix `m` with the cyclic Jacobi
+/// method. Only the lower-triangular part of `m` is read.
+///
+/// The eigenvalues are computed to high accuracy even if they are nearly repeated, and the
+/// eigenvectors are orthonormal up to rounding errors. The iterations stop when the off-diagonal
+/// elements become negligible with respect to `eps` in every lane, when every lane that did not
+/// converge contains a non-finite value, or after `max_sweeps` sweeps (`MAX_SIMD_SWEEPS` if
+/// `max_sweeps == 0`). The returned boolean indicates if every lane converged.
+pub fn symmetric_eigen3<T: SimdRealField>(
+    m: &Matrix3<T>,
+    eps: T,
+    max_sweeps: usize,
+) -> (SimdSymmetricEigen<T, U3>, bool) {
+    let one = T::one();
+    let mut a = Matrix3::new(
+        m.m11.clone(),
+        m.m21.clone(),
+        m.m31.clone(),
+        m.m21.clone(),
+        m.m22.clone(),
+        m.m32.clone(),
+        m.m31.clone(),
+        m.m32.clone(),
+        m.m33.clone(),
+    );
+
+    // Rescale to avoid overflows and underflows in the convergence criterion.
+    let amax = a
+        .iter()
+        .fold(T::zero(), |acc, e| acc.simd_max(e.clone().simd_abs()));
+    let scale = one.select(amax.clone().simd_eq(T::zero()), amax);
+    a /= scale.clone();
+
+    let mut v = Matrix3::identity();
+    let tol = eps.clone() * eps;
+    let max_sweeps = if max_sweeps == 0 {
+        MAX_SIMD_SWEEPS
+    } else {
+        max_sweeps
+    };
+    let mut sweep = 0;
+
+    let converged = loop {
+        let off = a.m21.clone() * a.m21.clone()
+            + a.m31.clone() * a.m31.clone()
+            + a.m32.clone() * a.m32.clone();
+        let diag = a.m11.clone() * a.m11.clone()
+            + a.m22.clone() * a.m22.clone()
+            + a.m33.clone() * a.m33.clone();
+
+        // NOTE: `x - x` is zero iff `x` is finite. The rotations can't recover from NaNs or
+        // infinities, so lanes containing them would otherwise never converge.
+        let finite = (off.clone() - off.clone()).simd_eq(T::zero())
+            & (diag.clone() - diag.clone()).simd_eq(T::zero());
+        let lane_converged = off.simd_le(tol.clone() * diag);
+
+        if lane_converged.all() {
+            break true;
+        }
+
+        if sweep == max_sweeps || (lane_converged | !finite).all() {
+            break false;
+        }
+
+        jacobi_rotate(&mut a, &mut v, 0, 1);
+        jacobi_rotate(&mut a, &mut v, 0, 2);
+        jacobi_rotate(&mut a, &mut v, 1, 2);
+        sweep += 1;
+    };
+
+    let eigen = SimdSymmetricEigen {
+        eigenvalues: Vector3::new(a.m11.clone(), a.m22.clone(), a.m33.clone()) * scale,
+        eigenvectors: v,
+    };
+
+    (eigen, converged)
+}
+
+/// Applies in-place to `a` the Jacobi rotation that cancels `a[(p, q)]`, and accumulates it
+/// into `v`.
+fn jacobi_rotate<T: SimdRealField>(a: &mut Matrix3<T>, v: &mut Matrix3<T>, p: usize, q: usize) {
+    let r = 3 - p - q;
+    let apq = a[(p, q)].clone();
+    let (c, s, t) = jacobi_rotation(a[(p, p)].clone(), a[(q, q)].clone(), apq.clone());
+
+    a[(p, p)] -= t.clone() * apq.clone();
+    a[(q, q)] += t * apq;
+    a[(p, q)] = T::zero();
+    a[(q, p)] = T::zero();
+
+    let arp = a[(r, p)].clone();
+    let arq = a[(r, q)].clone();
+    a[(r, p)] = c.clone() * arp.clone() - s.clone() * arq.clone();
+    a[(p, r)] = a[(r, p)].clone();
+    a[(r, q)] = s.clone() * arp + c.clone() * arq;
+    a[(q, r)] = a[(r, q)].clone();
+
+    for k in 0..3 {
+        let vkp = v[(k, p)].clone();
+        let vkq = v[(k, q)].clone();
+        v[(k, p)] = c.clone() * vkp.clone() - s.clone() * vkq.clone();
+        v[(k, q)] = s.clone() * vkp + c.clone() * vkq;
+    }
+}
+
+impl<T: SimdRealField> Matrix3<T> {
+    /// Computes the eigendecomposition of this symmetric matrix with the cyclic Jacobi method.
+    ///
+    /// Only the lower-triangular part of the matrix is read. The eigenvalues are accurate even
+    /// if they are nearly repeated. This supports SIMD scalar types, e.g., to decompose several
+    /// matrices at once: the iterations continue until every lane converged.
+    #[must_use]
+    pub fn simd_symmetric_eigen(&self) -> SimdSymmetricEigen<T, U3> {
+        symmetric_eigen3(self, T::simd_default_epsilon(), MAX_SIMD_SWEEPS).0
+    }
+}
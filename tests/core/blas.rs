@@ -1,4 +1,6 @@
-use na::{geometry::Quaternion, Matrix2, Vector3};
+use na::{
+    geometry::Quaternion, Complex, DMatrix, Diag, Matrix2, Side, Transposition, Uplo, Vector3,
+};
 use num_traits::{One, Zero};
 
 #[test]
@@ -21,10 +23,68 @@ fn gemm_noncommutative() {
     assert_eq!(res, Matrix2::zero());
 }
 
+#[test]
+fn level3_complex_views() {
+    let random =
+        |r, c| DMatrix::<f64>::new_random(r, c).zip_map(&DMatrix::new_random(r, c), Complex::new);
+    let a = random(6, 6);
+    let b = random(6, 6);
+    let alpha = Complex::new(2.0, 0.0);
+    let beta = Complex::new(0.5, 0.0);
+
+    // Operate on views of larger matrices.
+    let a = a.slice((1, 1), (4, 3));
+    let mut c = random(6, 6);
+    let c0 = c.clone();
+    c.slice_mut((2, 2), (4, 4))
+        .herk(alpha, &a, beta, Uplo::Lower);
+    let expected = a * a.adjoint() * alpha + c0.slice((2, 2), (4, 4)) * beta;
+    assert_relative_eq!(
+        c.slice((2, 2), (4, 4)).lower_triangle(),
+        expected.lower_triangle(),
+        epsilon = 1.0e-10
+    );
+    assert_eq!(c.rows(0, 2), c0.rows(0, 2));
+
+    let mut c = DMatrix::<Complex<f64>>::zeros(3, 3);
+    c.herk_ad(alpha, &a, beta, Uplo::Upper);
+    assert_relative_eq!(
+        c.upper_triangle(),
+        (a.adjoint() * a * alpha).upper_triangle(),
+        epsilon = 1.0e-10
+    );
+
+    let h = b.slice((0, 0), (3, 3));
+    let h = h + h.adjoint();
+    let rhs = b.slice((3, 0), (3, 4));
+    let mut c = DMatrix::<Complex<f64>>::zeros(3, 4);
+    c.hemm(
+        alpha,
+        &h.upper_triangle(),
+        &rhs,
+        beta,
+        Side::Left,
+        Uplo::Upper,
+    );
+    assert_relative_eq!(c, &h * rhs * alpha, epsilon = 1.0e-10);
+
+    let tri = h.lower_triangle() + DMatrix::identity(3, 3) * Complex::new(3.0, 0.0);
+    let mut x = rhs.clone_owned();
+    x.trsm(
+        alpha,
+        &tri,
+        Side::Left,
+        Uplo::Lower,
+        Transposition::Adjoint,
+        Diag::NonUnit,
+    );
+    assert_relative_eq!(tri.adjoint() * x, rhs * alpha, epsilon = 1.0e-10);
+}
+
 #[cfg(feature = "proptest-support")]
 mod blas_proptest {
     use crate::proptest::{PROPTEST_F64, PROPTEST_MATRIX_DIM};
-    use na::{DMatrix, DVector};
+    use na::{DMatrix, DVector, Diag, Side, Transposition, Uplo};
     use proptest::{prop_assert, proptest};
 
     proptest! {
@@ -116,5 +176,90 @@ mod blas_proptest {
 
             prop_assert!(relative_eq!(res, expected, epsilon = 1.0e-7))
         }
+
+        /*
+         *
+         * Level-3 operators.
+         *
+         */
+        #[test]
+        fn syrk(n in PROPTEST_MATRIX_DIM, alpha in PROPTEST_F64, beta in PROPTEST_F64) {
+            let a = DMatrix::<f64>::new_random(n, 7);
+            let c = DMatrix::<f64>::new_random(n, n);
+            let expected = &a * a.transpose() * alpha + &c * beta;
+            let expected_tr = a.transpose() * &a * alpha;
+
+            let mut lower = c.clone();
+            let mut upper = c.clone();
+            let mut upper_tr = DMatrix::new_random(7, 7);
+            lower.syrk(alpha, &a, beta, Uplo::Lower);
+            upper.syrk(alpha, &a, beta, Uplo::Upper);
+            upper_tr.syrk_tr(alpha, &a, 0.0, Uplo::Upper);
+
+            prop_assert!(relative_eq!(lower.lower_triangle(), expected.lower_triangle(), epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(&lower - lower.lower_triangle(), &c - c.lower_triangle()));
+            prop_assert!(relative_eq!(upper.upper_triangle(), expected.upper_triangle(), epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(&upper - upper.upper_triangle(), &c - c.upper_triangle()));
+            prop_assert!(relative_eq!(upper_tr.upper_triangle(), expected_tr.upper_triangle(), epsilon = 1.0e-7))
+        }
+
+        #[test]
+        fn symm(n in PROPTEST_MATRIX_DIM, alpha in PROPTEST_F64, beta in PROPTEST_F64) {
+            let a = DMatrix::<f64>::new_random(n, n);
+            let a = &a + a.transpose();
+            let b = DMatrix::<f64>::new_random(n, 5);
+            let c = DMatrix::<f64>::new_random(n, 5);
+
+            let mut res = c.clone();
+            res.symm(alpha, &a.lower_triangle(), &b, beta, Side::Left, Uplo::Lower);
+            prop_assert!(relative_eq!(res, &a * &b * alpha + &c * beta, epsilon = 1.0e-7));
+
+            let (b, c) = (b.transpose(), c.transpose());
+            let mut res = c.clone();
+            res.symm(alpha, &a.upper_triangle(), &b, beta, Side::Right, Uplo::Upper);
+            prop_assert!(relative_eq!(res, &b * &a * alpha + &c * beta, epsilon = 1.0e-7))
+        }
+
+        #[test]
+        fn trmm_trsm(n in PROPTEST_MATRIX_DIM, alpha in PROPTEST_F64) {
+            // Keep the matrix well-conditioned so that the solve is accurate.
+            let a = DMatrix::<f64>::new_random(n, n) + DMatrix::identity(n, n) * (n as f64);
+            let left = DMatrix::<f64>::new_random(n, 4);
+            let right = left.transpose();
+
+            for &uplo in &[Uplo::Lower, Uplo::Upper] {
+                for &transposition in &[Transposition::NoTranspose, Transposition::Transpose, Transposition::Adjoint] {
+                    for &diag in &[Diag::NonUnit, Diag::Unit] {
+                        let mut tri = match uplo {
+                            Uplo::Lower => a.lower_triangle(),
+                            Uplo::Upper => a.upper_triangle(),
+                        };
+                        if diag == Diag::Unit {
+                            tri.fill_diagonal(1.0);
+                        }
+                        let op = match transposition {
+                            Transposition::NoTranspose => tri,
+                            _ => tri.transpose(),
+                        };
+
+                        let mut res = left.clone();
+                        res.trmm(alpha, &a, Side::Left, uplo, transposition, diag);
+                        prop_assert!(relative_eq!(res, &op * &left * alpha, epsilon = 1.0e-7));
+
+                        let mut res = right.clone();
+                        res.trmm(alpha, &a, Side::Right, uplo, transposition, diag);
+                        prop_assert!(relative_eq!(res, &right * &op * alpha, epsilon = 1.0e-7));
+
+                        let mut res = left.clone();
+                        res.trsm(2.0, &a, Side::Left, uplo, transposition, diag);
+                        prop_assert!(relative_eq!(&op * res, &left * 2.0, epsilon = 1.0e-7));
+
+                        let mut res = right.clone();
+                        res.trsm(2.0, &a, Side::Right, uplo, transposition, diag);
+                        prop_assert!(relative_eq!(res * &op, &right * 2.0, epsilon = 1.0e-7));
+                    }
+                }
+            }
+        }
     }
 }
@@ -0,0 +1,242 @@
+use na::io::{CsvError, CsvReader, CsvWriter, MissingValues};
+use na::{DMatrix, Matrix2x3};
+
+#[test]
+fn csv_read_quotes_and_delimiters() {
+    let expected = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    let comma = "1, \"2\" ,3\r\n\n4,5,6\n";
+    assert_eq!(CsvReader::new().read_str(comma).unwrap(), expected);
+
+    let tabs = "1\t2\t3\n4\t5\t6";
+    assert_eq!(
+        CsvReader::new().delimiter('\t').read_str(tabs).unwrap(),
+        expected
+    );
+
+    let spaces = "  1   2\t3  \n4 5 6\n";
+    assert_eq!(
+        CsvReader::new().whitespace().read_str(spaces).unwrap(),
+        expected
+    );
+
+    let quoted = "\"a;b\";\"say \"\"hi\"\"\"\n";
+    let reader = CsvReader::<String>::new().delimiter(';');
+    let m = reader.read_str(quoted).unwrap();
+    assert_eq!(m[(0, 0)], "a;b");
+    assert_eq!(m[(0, 1)], "say \"hi\"");
+
+    let m = reader.clone().quote(None).read_str("\"a;b\"").unwrap();
+    assert_eq!(m.shape(), (1, 2));
+    assert!(matches!(
+        reader.read_str("1;\"2"),
+        Err(CsvError::UnterminatedQuote { line: 1 })
+    ));
+}
+
+#[test]
+fn csv_read_header_comments_and_columns() {
+    let data = "a,b,c\n% comment\n1,2,3\n  % indented comment\n4,5,6\n";
+    let reader = CsvReader::<i32>::new().header_lines(1).comment('%');
+    assert_eq!(
+        reader.read_str(data).unwrap(),
+        DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6])
+    );
+
+    let selected = reader.clone().columns(&[2, 0, 2]);
+    assert_eq!(
+        selected.read_str(data).unwrap(),
+        DMatrix::from_row_slice(2, 3, &[3, 1, 3, 6, 4, 6])
+    );
+
+    let empty = reader.clone().columns(&[1]).read_str("a,b,c\n").unwrap();
+    assert_eq!(empty.shape(), (0, 1));
+    assert_eq!(reader.read_str("").unwrap().shape(), (0, 0));
+
+    assert!(matches!(
+        reader.clone().columns(&[0, 3]).read_str(data),
+        Err(CsvError::ColumnOutOfRange {
+            line: 3,
+            column: 3,
+            nfields: 3
+        })
+    ));
+}
+
+#[test]
+fn csv_read_missing_values() {
+    let data = "1,,3\n4,NA,6\n7,8,9\n";
+    let reader = CsvReader::<f64>::new().missing_tokens(&["", "NA"]);
+
+    assert!(matches!(
+        reader.read_str(data),
+        Err(CsvError::MissingValue { line: 1, column: 1 })
+    ));
+
+    let filled = reader
+        .clone()
+        .missing_values(MissingValues::Fill(f64::NAN))
+        .read_str(data)
+        .unwrap();
+    assert_eq!(filled.shape(), (3, 3));
+    assert!(filled[(0, 1)].is_nan() && filled[(1, 1)].is_nan());
+    assert_eq!(filled[(2, 1)], 8.0);
+
+    let skipped = reader
+        .clone()
+        .missing_values(MissingValues::SkipRow)
+        .read_str(data)
+        .unwrap();
+    assert_eq!(skipped, DMatrix::from_row_slice(1, 3, &[7.0, 8.0, 9.0]));
+
+    // Columns that are not selected may hold missing values.
+    let selected = reader.columns(&[2, 0]).read_str(data).unwrap();
+    assert_eq!(
+        selected,
+        DMatrix::from_row_slice(3, 2, &[3.0, 1.0, 6.0, 4.0, 9.0, 7.0])
+    );
+
+    // NaN is a value, unless listed as a missing token.
+    let nan = CsvReader::<f64>::new().read_str("NaN,1").unwrap();
+    assert!(nan[(0, 0)].is_nan());
+}
+
+#[test]
+fn csv_read_errors() {
+    let reader = CsvReader::<i32>::new();
+    assert!(matches!(
+        reader.read_str("1,2\n3,4\n5\n"),
+        Err(CsvError::RaggedRow {
+            line: 3,
+            expected: 2,
+            found: 1
+        })
+    ));
+
+    match reader.header_lines(1).read_str("x\n1,2\n3,2.5\n") {
+        Err(CsvError::InvalidValue {
+            line: 3,
+            column: 1,
+            value,
+        }) => assert_eq!(value, "2.5"),
+        res => panic!("unexpected result: {:?}", res),
+    }
+}
+
+#[test]
+fn csv_read_rows_in_chunks() {
+    let data: String = (0..10).map(|i| format!("{},{}\n", i, i * i)).collect();
+    let reader = CsvReader::<i64>::new();
+
+    let mut rows = reader.rows(data.as_bytes());
+    let mut chunks = Vec::new();
+    while let Some(chunk) = rows.read_chunk(4).unwrap() {
+        chunks.push(chunk);
+    }
+    assert_eq!(rows.line_number(), 10);
+    assert_eq!(
+        chunks.iter().map(|c| c.nrows()).collect::<Vec<_>>(),
+        vec![4, 4, 2]
+    );
+    assert_eq!(chunks[2], DMatrix::from_row_slice(2, 2, &[8, 64, 9, 81]));
+
+    let mut rows = reader.rows(data.as_bytes());
+    let mut m = DMatrix::from_row_slice(1, 2, &[-1, -1]);
+    while rows.read_into(&mut m, 3).unwrap() != 0 {}
+    assert_eq!(m.nrows(), 11);
+    assert_eq!(m.row(0), DMatrix::from_row_slice(1, 2, &[-1, -1]));
+    assert_eq!(m.row(10), DMatrix::from_row_slice(1, 2, &[9, 81]));
+
+    let mut wide = DMatrix::<i64>::zeros(1, 3);
+    assert!(matches!(
+        reader.rows(data.as_bytes()).read_into(&mut wide, 3),
+        Err(CsvError::RaggedRow {
+            line: 3,
+            expected: 3,
+            found: 2
+        })
+    ));
+    assert!(std::panic::catch_unwind(|| reader.rows(data.as_bytes()).read_chunk(0)).is_err());
+
+    let sums: Vec<i64> = reader
+        .rows(data.as_bytes())
+        .map(|row| row.unwrap().iter().sum())
+        .collect();
+    assert_eq!(sums[3], 12);
+}
+
+#[test]
+fn csv_write_roundtrip() {
+    let m = Matrix2x3::new(1.5, -2.0, 1.0e-10, 4.0, f64::INFINITY, 0.1);
+
+    let mut out = Vec::new();
+    CsvWriter::new().write(&mut out, &m).unwrap();
+    assert_eq!(
+        String::from_utf8(out.clone()).unwrap(),
+        "1.5,-2,0.0000000001\n4,inf,0.1\n"
+    );
+    let read: DMatrix<f64> = CsvReader::new().read(&out[..]).unwrap();
+    assert_eq!(read, m);
+
+    let mut out = Vec::new();
+    CsvWriter::new()
+        .delimiter(' ')
+        .quote('\'')
+        .header(&["a", "b c", "it's"])
+        .write(&mut out, &m)
+        .unwrap();
+    let text = String::from_utf8(out).unwrap();
+    assert!(text.starts_with("a 'b c' 'it''s'\n"));
+    let read: DMatrix<f64> = CsvReader::new()
+        .whitespace()
+        .header_lines(1)
+        .read_str(&text)
+        .unwrap();
+    assert_eq!(read, m);
+
+    let mut out = Vec::new();
+    CsvWriter::new()
+        .write(&mut out, &DMatrix::<f64>::zeros(0, 3))
+        .unwrap();
+    assert!(out.is_empty());
+}
+
+#[test]
+fn csv_write_string_roundtrip() {
+    let m = DMatrix::from_row_slice(
+        2,
+        3,
+        &[
+            "plain",
+            "a,b",
+            "say \"hi\"",
+            " padded ",
+            "two\nlines",
+            "crlf\r\nline",
+        ],
+    )
+    .map(|s| s.to_string());
+
+    let mut out = Vec::new();
+    CsvWriter::new().write(&mut out, &m).unwrap();
+    let text = String::from_utf8(out).unwrap();
+    assert!(text.starts_with("plain,\"a,b\",\"say \"\"hi\"\"\"\n"));
+    let reader = CsvReader::<String>::new();
+    let mut rows = reader.rows(text.as_bytes());
+    assert_eq!(rows.read_chunk(10).unwrap().unwrap(), m);
+    assert_eq!(rows.line_number(), 4);
+
+    let mut out = Vec::new();
+    CsvWriter::new()
+        .delimiter('\t')
+        .write(&mut out, &m)
+        .unwrap();
+    let read: DMatrix<String> = CsvReader::new().delimiter('\t').read(&out[..]).unwrap();
+    assert_eq!(read, m);
+
+    let m = m.map(|s| s.replace('\n', " and "));
+    let mut out = Vec::new();
+    CsvWriter::new().delimiter(' ').write(&mut out, &m).unwrap();
+    let read: DMatrix<String> = CsvReader::new().whitespace().read(&out[..]).unwrap();
+    assert_eq!(read, m);
+}
@@ -0,0 +1,82 @@
+use na::{
+    DMatrix, DVector, DiagonalMatrix, Dynamic, Matrix3, Matrix3x4, Matrix4x3, PermutationMatrix,
+    PermutationSequence, Vector3, U3,
+};
+
+#[test]
+fn diagonal_products() {
+    let d = DiagonalMatrix::new(Vector3::new(2.0, -1.0, 0.5));
+    let dense = d.to_dense();
+    let m = Matrix3x4::from_fn(|i, j| (i * 4 + j) as f64);
+    let n = Matrix4x3::from_fn(|i, j| (i * 3 + j) as f64 - 2.0);
+
+    assert_eq!(&d * &m, dense * m);
+    assert_eq!(&n * &d, n * dense);
+    assert_eq!(&d * m, dense * m);
+    assert_eq!(n * &d, n * dense);
+    assert_eq!((&d * &d).to_dense(), dense * dense);
+    assert_eq!(Matrix3::from(d), dense);
+    assert_eq!(
+        DiagonalMatrix::<f64, U3>::identity().to_dense(),
+        Matrix3::identity()
+    );
+}
+
+#[test]
+fn diagonal_inverse_and_determinant() {
+    let d = DiagonalMatrix::from(DVector::from_vec(vec![2.0, 4.0, -0.5]));
+    assert_eq!(d.determinant(), -4.0);
+
+    let inv = d.try_inverse().unwrap();
+    assert_eq!(inv.diagonal(), &DVector::from_vec(vec![0.5, 0.25, -2.0]));
+    assert_eq!((&d * &inv).to_dense(), DMatrix::identity(3, 3));
+
+    let singular = DiagonalMatrix::new(DVector::from_vec(vec![1.0, 0.0]));
+    assert!(singular.try_inverse().is_none());
+}
+
+#[test]
+fn permutation_products() {
+    let p = PermutationMatrix::new(Vector3::new(1, 2, 0)).unwrap();
+    let q = PermutationMatrix::new(Vector3::new(0, 2, 1)).unwrap();
+    let m = Matrix3x4::from_fn(|i, j| (i * 4 + j) as f64);
+    let n = Matrix4x3::from_fn(|i, j| (i * 3 + j) as f64);
+    let (dp, dq): (Matrix3<f64>, Matrix3<f64>) = (p.to_dense(), q.to_dense());
+
+    assert_eq!(&p * &m, dp * m);
+    assert_eq!(&n * &p, n * dp);
+    assert_eq!((&p * &q).to_dense::<f64>(), dp * dq);
+    assert_eq!(p.inverse().to_dense::<f64>(), dp.transpose());
+    assert_eq!(&p * &p.inverse(), PermutationMatrix::<U3>::identity());
+
+    assert_eq!(p.determinant::<f64>(), dp.determinant());
+    assert_eq!(q.determinant::<f64>(), dq.determinant());
+
+    assert!(PermutationMatrix::new(Vector3::new(0, 1, 3)).is_none());
+    assert!(PermutationMatrix::new(Vector3::new(1, 1, 0)).is_none());
+}
+
+#[test]
+fn permutation_from_lu() {
+    let m = DMatrix::from_row_slice(
+        4,
+        4,
+        &[
+            0.0, 2.0, 1.0, 3.0, //
+            4.0, 1.0, 0.0, 2.0, //
+            1.0, 5.0, 2.0, 1.0, //
+            2.0, 0.0, 6.0, 1.0,
+        ],
+    );
+    let lu = m.clone().lu();
+    let p = PermutationMatrix::from(lu.p());
+
+    let mut permuted = m.clone();
+    lu.p().permute_rows(&mut permuted);
+    assert_eq!(&p * &m, permuted);
+    assert_eq!(p.determinant::<f64>(), lu.p().determinant::<f64>());
+    assert_eq!(
+        PermutationMatrix::<Dynamic>::identity(4),
+        PermutationMatrix::from(&PermutationSequence::<Dynamic>::identity(4))
+    );
+}
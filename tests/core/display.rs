@@ -0,0 +1,77 @@
+use na::display::{Alignment, Style};
+use na::{DMatrix, Matrix2x3, Matrix3x2};
+
+#[test]
+fn display_default_matches_display_impl() {
+    let m = Matrix2x3::new(1.0, 2.5, 3.0, 4.0, 5.0, 6.25);
+    assert_eq!(format!("{}", m.display()), format!("{}", m));
+    assert_eq!(format!("{:.2}", m.display()), format!("{:.2}", m));
+    assert_eq!(format!("{}", m.display().scientific()), format!("{:e}", m));
+    assert_eq!(DMatrix::<f64>::zeros(0, 3).display().to_string(), "[ ]");
+}
+
+#[test]
+fn display_styles() {
+    let m = Matrix3x2::new(1.0, -2.0, 3.5, 4.0, 5.0, 60.0);
+
+    assert_eq!(
+        m.display().style(Style::Compact).to_string(),
+        "[1, -2; 3.5, 4; 5, 60]"
+    );
+    assert_eq!(
+        m.display().style(Style::Markdown).precision(1).to_string(),
+        "|    0 |    1 |\n\
+         | ---: | ---: |\n\
+         |  1.0 | -2.0 |\n\
+         |  3.5 |  4.0 |\n\
+         |  5.0 | 60.0 |"
+    );
+    assert_eq!(
+        m.display().style(Style::Latex).to_string(),
+        "\\begin{bmatrix}\n    1 &  -2 \\\\\n  3.5 &   4 \\\\\n    5 &  60\n\\end{bmatrix}"
+    );
+    assert_eq!(
+        m.display().style(Style::NumPy).to_string(),
+        "np.array([[  1,  -2],\n          [3.5,   4],\n          [  5,  60]])"
+    );
+    assert_eq!(
+        m.display()
+            .style(Style::Matlab)
+            .align(Alignment::Left)
+            .to_string(),
+        "[1   -2\n 3.5 4\n 5   60]"
+    );
+    assert_eq!(
+        m.display().style(Style::Rust).precision(1).to_string(),
+        "matrix![ 1.0, -2.0;\n         3.5,  4.0;\n         5.0, 60.0]"
+    );
+}
+
+#[test]
+fn display_elision() {
+    let m = DMatrix::from_fn(1000, 1000, |i, j| i * 1000 + j);
+
+    assert_eq!(
+        m.display()
+            .max_rows(3)
+            .max_cols(2)
+            .style(Style::Compact)
+            .to_string(),
+        "[0, …, 999; 1000, …, 1999; …, …, …; 999000, …, 999999]"
+    );
+    assert_eq!(
+        m.display().max_rows(2).max_cols(2).style(Style::Latex).to_string(),
+        "\\begin{bmatrix}\n       0 & \\cdots &    999 \\\\\n  \\vdots & \\ddots & \\vdots \\\\\n  999000 & \\cdots & 999999\n\\end{bmatrix}"
+    );
+
+    // Small matrices are never elided.
+    let small = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    assert_eq!(
+        small.display().max_rows(2).max_cols(3).to_string(),
+        small.to_string()
+    );
+    assert_eq!(
+        small.display().max_cols(1).unlimited().to_string(),
+        small.to_string()
+    );
+}
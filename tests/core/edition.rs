@@ -210,6 +210,68 @@ fn swap_columns() {
     assert_eq!(m, expected);
 }
 
+#[test]
+fn permute_rows_and_columns() {
+    let m = DMatrix::from_fn(6, 5, |i, j| i * 10 + j);
+    // A permutation made of a 3-cycle, a transposition and a fixed point.
+    let row_perm = [2, 0, 1, 4, 3, 5];
+    let col_perm = [4, 3, 2, 1, 0];
+
+    let permuted = m.permute_rows(&row_perm);
+    assert_eq!(permuted, m.select_rows(&row_perm));
+
+    let mut in_place = m.clone();
+    in_place.permute_rows_mut(&row_perm);
+    assert_eq!(in_place, permuted);
+
+    let permuted = m.permute_columns(&col_perm);
+    assert_eq!(permuted, m.select_columns(&col_perm));
+
+    let mut in_place = m.clone();
+    in_place.permute_columns_mut(&col_perm);
+    assert_eq!(in_place, permuted);
+
+    // Sorting by an index recovers the original matrix.
+    in_place.sort_columns_by(|a, b| a[0].cmp(&b[0]));
+    assert_eq!(in_place, m);
+
+    let mut in_place = m.permute_rows(&row_perm);
+    in_place.sort_rows_by(|a, b| a[0].cmp(&b[0]));
+    assert_eq!(in_place, m);
+}
+
+#[test]
+#[should_panic]
+fn permute_rows_repeated_index() {
+    let _ = Matrix3::<f64>::identity().permute_rows(&[0, 1, 1]);
+}
+
+#[test]
+#[should_panic]
+fn permute_columns_wrong_length() {
+    Matrix3::<f64>::identity().permute_columns_mut(&[0, 1]);
+}
+
+#[test]
+fn argsort() {
+    let v = na::DVector::from_vec(vec![4, 1, 3, 1, 0]);
+    let perm = v.argsort();
+    assert_eq!(perm, vec![4, 1, 3, 2, 0]);
+    assert_eq!(
+        v.permute_rows(&perm),
+        na::DVector::from_vec(vec![0, 1, 1, 3, 4])
+    );
+
+    let mut m = Matrix3x4::new(
+        5, 1, 7, 3, //
+        0, 0, 0, 0, //
+        1, 2, 3, 4,
+    );
+    let perm = m.row(0).transpose().argsort();
+    m.permute_columns_mut(&perm);
+    assert_eq!(m, Matrix3x4::new(1, 3, 5, 7, 0, 0, 0, 0, 2, 4, 1, 3));
+}
+
 #[test]
 #[rustfmt::skip]
 fn remove_columns() {
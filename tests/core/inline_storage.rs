@@ -0,0 +1,280 @@
+use na::allocator::{Allocator, Reallocator};
+use na::dimension::DimName;
+use na::{
+    DMatrix, DVector, Dynamic, InlineAllocator, InlineDMatrix, InlineDVector, InlineOMatrix,
+    InlineStorage, Matrix, U1, U2, U3,
+};
+use std::rc::Rc;
+
+fn inline_dmatrix<const N: usize>(
+    nrows: usize,
+    ncols: usize,
+    data: &[f64],
+) -> InlineDMatrix<f64, N> {
+    Matrix::from_data(InlineStorage::from_row_slice(
+        Dynamic::new(nrows),
+        Dynamic::new(ncols),
+        data,
+    ))
+}
+
+#[test]
+fn inline_storage_construction() {
+    let m: InlineDMatrix<i32, 16> = Matrix::from_data(InlineStorage::from_fn(
+        Dynamic::new(2),
+        Dynamic::new(3),
+        |i, j| (i * 3 + j) as i32,
+    ));
+    assert_eq!(m, DMatrix::from_row_slice(2, 3, &[0, 1, 2, 3, 4, 5]));
+    assert_eq!(m.data.capacity(), 16);
+    assert_eq!(m.data.len(), 6);
+    assert_eq!(m.as_slice(), &[0, 3, 1, 4, 2, 5]);
+    assert_eq!(m.clone(), m);
+
+    let v: InlineDVector<f32, 4> = Matrix::from_data(InlineStorage::from_element(
+        Dynamic::new(3),
+        U1::name(),
+        1.5,
+    ));
+    assert_eq!(v, DVector::from_element(3, 1.5));
+
+    let s: InlineOMatrix<i32, U2, U3, 6> = Matrix::from_data(InlineStorage::from_column_slice(
+        U2::name(),
+        U3::name(),
+        &[1, 2, 3, 4, 5, 6],
+    ));
+    assert_eq!(s, na::Matrix2x3::new(1, 3, 5, 2, 4, 6));
+}
+
+#[test]
+#[should_panic]
+fn inline_storage_capacity_exceeded() {
+    let _ = InlineStorage::<f64, Dynamic, Dynamic, 5>::from_element(
+        Dynamic::new(2),
+        Dynamic::new(3),
+        0.0,
+    );
+}
+
+#[test]
+fn inline_arithmetic_in_place() {
+    let mut a = inline_dmatrix::<9>(3, 3, &[4.0, 1.0, 2.0, 1.0, 5.0, 3.0, 2.0, 3.0, 6.0]);
+    let b = inline_dmatrix::<9>(3, 3, &[1.0; 9]);
+    let expected = a.clone_owned();
+
+    a += &b;
+    assert_eq!(a, &expected + DMatrix::from_element(3, 3, 1.0));
+    a -= &b;
+    a *= 2.0;
+    assert_eq!(a, &expected * 2.0);
+
+    let mut c = inline_dmatrix::<9>(3, 3, &[0.0; 9]);
+    c.gemm(1.0, &a, &b, 0.0);
+    assert_eq!(c, &expected * 2.0 * DMatrix::from_element(3, 3, 1.0));
+    assert_eq!(&a * &b, c);
+}
+
+#[test]
+fn inline_lu_solve_in_place() {
+    let mut a = inline_dmatrix::<16>(3, 3, &[0.0, 1.0, 2.0, 1.0, 5.0, 3.0, 2.0, 3.0, 6.0]);
+    let expected = a.clone_owned();
+    let mut b: InlineDVector<f64, 4> = Matrix::from_data(InlineStorage::from_column_slice(
+        Dynamic::new(3),
+        U1::name(),
+        &[1.0, 2.0, 3.0],
+    ));
+    let rhs = b.clone_owned();
+
+    assert!(na::linalg::lu_solve_in_place(&mut a, &mut b));
+    assert!((expected * b - rhs).norm() < 1.0e-10);
+
+    let mut singular = inline_dmatrix::<4>(2, 2, &[1.0, 2.0, 2.0, 4.0]);
+    let mut b = inline_dmatrix::<4>(2, 1, &[1.0, 1.0]);
+    assert!(!na::linalg::lu_solve_in_place(&mut singular, &mut b));
+}
+
+#[test]
+fn inline_edition_in_place() {
+    let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let mut m = inline_dmatrix::<20>(2, 3, &data);
+    let mut expected = DMatrix::from_row_slice(2, 3, &data);
+
+    m.insert_rows_in_place(1, 2, 7.0);
+    expected = expected.insert_rows(1, 2, 7.0);
+    assert_eq!(m, expected);
+
+    m.insert_column_in_place(0, 8.0);
+    expected = expected.insert_column(0, 8.0);
+    assert_eq!(m, expected);
+
+    m.remove_rows_in_place(0, 2);
+    expected = expected.remove_rows(0, 2);
+    assert_eq!(m, expected);
+
+    m.remove_columns_in_place(1, 2);
+    expected = expected.remove_columns(1, 2);
+    assert_eq!(m, expected);
+
+    m.insert_row_in_place(2, 9.0);
+    m.insert_columns_in_place(2, 3, 10.0);
+    expected = expected.insert_row(2, 9.0).insert_columns(2, 3, 10.0);
+    assert_eq!(m, expected);
+
+    m.remove_row_in_place(1);
+    m.remove_column_in_place(4);
+    expected = expected.remove_row(1).remove_column(4);
+    assert_eq!(m, expected);
+
+    // Shrinking one dimension while growing the other never exceeds the capacity.
+    let mut m = inline_dmatrix::<16>(2, 8, &[1.0; 16]);
+    m.resize_in_place(8, 2, 0.0);
+    assert_eq!(m, DMatrix::from_element(2, 8, 1.0).resize(8, 2, 0.0));
+    m.resize_in_place(4, 4, 2.0);
+    assert_eq!(
+        m,
+        DMatrix::from_element(2, 8, 1.0)
+            .resize(8, 2, 0.0)
+            .resize(4, 4, 2.0)
+    );
+
+    let mut v: InlineDVector<f64, 5> = Matrix::from_data(InlineStorage::from_element(
+        Dynamic::new(2),
+        U1::name(),
+        1.0,
+    ));
+    v.resize_vertically_in_place(5, 3.0);
+    assert_eq!(v, DVector::from_vec(vec![1.0, 1.0, 3.0, 3.0, 3.0]));
+}
+
+#[test]
+fn inline_edition_reallocating() {
+    let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let m = inline_dmatrix::<12>(2, 3, &data);
+    let expected = DMatrix::from_row_slice(2, 3, &data);
+
+    let m = m.insert_row_in::<InlineAllocator<12>>(1, 7.0);
+    let expected = expected.insert_row(1, 7.0);
+    assert_eq!(m.data.capacity(), 12);
+    assert_eq!(m, expected);
+
+    let m = m.insert_column_in::<InlineAllocator<12>>(3, 8.0);
+    let expected = expected.insert_column(3, 8.0);
+    assert_eq!(m, expected);
+
+    for &(nrows, ncols) in [(3, 4), (2, 4), (4, 3), (2, 6), (1, 1), (0, 5)].iter() {
+        let resized = m
+            .clone()
+            .resize_in::<InlineAllocator<12>>(nrows, ncols, 9.0);
+        assert_eq!(resized, expected.clone().resize(nrows, ncols, 9.0));
+    }
+
+    let v: InlineOMatrix<f64, U3, Dynamic, 12> = Matrix::from_data(InlineStorage::from_element(
+        U3::name(),
+        Dynamic::new(2),
+        1.0,
+    ));
+    let v = v.resize_generic_in::<InlineAllocator<12>, _, _>(U2::name(), Dynamic::new(5), 2.0);
+    assert_eq!(v, DMatrix::from_element(3, 2, 1.0).resize(2, 5, 2.0));
+}
+
+#[test]
+#[should_panic]
+fn inline_edition_reallocating_capacity_exceeded() {
+    let m = inline_dmatrix::<6>(2, 3, &[0.0; 6]);
+    let _ = m.insert_row_in::<InlineAllocator<6>>(0, 1.0);
+}
+
+#[test]
+fn inline_owned_results() {
+    let a = inline_dmatrix::<6>(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let b = inline_dmatrix::<6>(2, 3, &[6.0, 5.0, 4.0, 3.0, 2.0, 1.0]);
+
+    let sum: InlineDMatrix<f64, 6> = a.add_in::<InlineAllocator<6>, _, _, _>(&b);
+    assert_eq!(sum, DMatrix::from_element(2, 3, 7.0));
+    let t: InlineDMatrix<f64, 6> = b.transpose_in::<InlineAllocator<6>>();
+    assert_eq!(t, b.transpose());
+    let prod: InlineDMatrix<f64, 4> = a.mul_in::<InlineAllocator<4>, _, _, _>(&t);
+    assert_eq!(prod, &a * b.transpose());
+    let c: InlineDMatrix<f64, 8> = a.clone_owned_in::<InlineAllocator<8>>();
+    assert_eq!(c, a);
+}
+
+#[test]
+fn inline_cholesky() {
+    let m = inline_dmatrix::<16>(3, 3, &[4.0, 2.0, 2.0, 2.0, 5.0, 3.0, 2.0, 3.0, 6.0]);
+    let expected = m.clone_owned().cholesky().unwrap();
+    let chol = m.clone().cholesky_in::<InlineAllocator<16>>().unwrap();
+
+    assert_eq!(chol.l(), expected.l());
+    assert_eq!(chol.determinant(), expected.determinant());
+
+    let mut b: InlineDVector<f64, 4> = Matrix::from_data(InlineStorage::from_column_slice(
+        Dynamic::new(3),
+        U1::name(),
+        &[1.0, 2.0, 3.0],
+    ));
+    let rhs = b.clone_owned();
+    chol.solve_mut(&mut b);
+    assert!((m.clone_owned() * &b - &rhs).norm() < 1.0e-10);
+    assert_eq!(b, expected.solve(&rhs));
+
+    let l: InlineDMatrix<f64, 16> = chol.unpack();
+    assert_eq!(l, expected.l());
+
+    let not_definite_positive = inline_dmatrix::<4>(2, 2, &[1.0, 2.0, 2.0, 1.0]);
+    assert!(not_definite_positive
+        .cholesky_in::<InlineAllocator<4>>()
+        .is_none());
+}
+
+#[test]
+fn inline_edition_drops_components() {
+    let rc = Rc::new(());
+    let mut m: InlineOMatrix<Rc<()>, Dynamic, Dynamic, 12> = Matrix::from_data(
+        InlineStorage::from_element(Dynamic::new(2), Dynamic::new(2), rc.clone()),
+    );
+    assert_eq!(Rc::strong_count(&rc), 5);
+
+    m.data.as_mut_slice()[0] = Rc::new(());
+    assert_eq!(Rc::strong_count(&rc), 4);
+
+    let mut m2: InlineOMatrix<Rc<()>, Dynamic, Dynamic, 12> = Matrix::from_data(
+        InlineStorage::from_element(Dynamic::new(2), Dynamic::new(2), rc.clone()),
+    );
+    m2.insert_rows_in_place(1, 1, rc.clone());
+    assert_eq!(Rc::strong_count(&rc), 10);
+    m2.insert_columns_in_place(0, 2, rc.clone());
+    assert_eq!(Rc::strong_count(&rc), 16);
+    m2.remove_rows_in_place(0, 2);
+    assert_eq!(Rc::strong_count(&rc), 8);
+    m2.remove_columns_in_place(3, 1);
+    assert_eq!(Rc::strong_count(&rc), 7);
+
+    drop(m2);
+    drop(m);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn inline_allocator() {
+    let buf: InlineStorage<i32, Dynamic, U3, 8> =
+        InlineAllocator::<8>::allocate_from_iterator(Dynamic::new(2), U3::name(), 1..=6);
+    assert_eq!(buf.as_slice(), &[1, 2, 3, 4, 5, 6]);
+
+    let buf: InlineStorage<i32, U2, Dynamic, 8> =
+        InlineAllocator::<8>::allocate_from_row_iterator(U2::name(), Dynamic::new(3), 1..=6);
+    assert_eq!(buf.as_slice(), &[1, 4, 2, 5, 3, 6]);
+
+    let buf = unsafe {
+        <InlineAllocator<8> as Allocator<i32, _, _>>::assume_init(<InlineAllocator<8> as Reallocator<
+            i32,
+            U2,
+            Dynamic,
+            Dynamic,
+            Dynamic,
+        >>::reallocate_copy(
+            Dynamic::new(2), Dynamic::new(2), buf
+        ))
+    };
+    assert_eq!(buf.as_slice(), &[1, 4, 2, 5]);
+}
@@ -1,11 +1,12 @@
 use num::{One, Zero};
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 
 use na::dimension::{U15, U8};
 use na::{
     self, Const, DMatrix, DVector, Matrix2, Matrix2x3, Matrix2x4, Matrix3, Matrix3x2, Matrix3x4,
     Matrix4, Matrix4x3, Matrix4x5, Matrix5, Matrix6, OMatrix, RowVector3, RowVector4, RowVector5,
-    Vector1, Vector2, Vector3, Vector4, Vector5, Vector6,
+    ShapeMismatch, Vector1, Vector2, Vector3, Vector4, Vector5, Vector6,
 };
 
 #[test]
@@ -75,6 +76,15 @@ fn iter() {
     assert_eq!(*it.next().unwrap(), 3.0);
     assert_eq!(*it.next().unwrap(), 6.0);
     assert!(it.next().is_none());
+
+    let mut it = a.column_iter();
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.next_back().unwrap(), a.column(2));
+    assert_eq!(it.next().unwrap(), a.column(0));
+    assert_eq!(it.len(), 1);
+    assert_eq!(it.next_back().unwrap(), a.column(1));
+    assert!(it.next().is_none());
+    assert!(it.next_back().is_none());
 }
 
 #[test]
@@ -757,6 +767,106 @@ fn set_row_column() {
     assert_eq!(expected2, computed);
 }
 
+#[test]
+fn broadcasting() {
+    let m = Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    let col = Vector2::new(10.0, 20.0);
+    let row = RowVector3::new(1.0, 2.0, 4.0);
+
+    let mut expected = m;
+    for mut column in expected.column_iter_mut() {
+        column += col;
+    }
+    assert_eq!(m.add_column_broadcast(&col), expected);
+    assert_eq!(m.add_column_broadcast(&col).sub_column_broadcast(&col), m);
+
+    let mut expected = m;
+    for mut r in expected.row_iter_mut() {
+        r.component_mul_assign(&row);
+    }
+    assert_eq!(m.component_mul_row_broadcast(&row), expected);
+
+    let mut computed = m;
+    computed.component_mul_row_broadcast_assign(&row);
+    computed.component_div_row_broadcast_assign(&row);
+    assert_eq!(computed, m);
+
+    // Mixing static and dynamic dimensions.
+    let dm = DMatrix::from_column_slice(2, 3, m.as_slice());
+    let dcol = DVector::from_column_slice(col.as_slice());
+    assert_eq!(dm.add_column_broadcast(&col), m.add_column_broadcast(&dcol));
+    assert_eq!(
+        dm.sub_row_broadcast(&row.columns(0, 3)),
+        m.sub_row_broadcast(&row)
+    );
+}
+
+#[test]
+#[should_panic]
+fn broadcasting_dimension_mismatch() {
+    let m = DMatrix::<f64>::zeros(2, 3);
+    let _ = m.add_row_broadcast(&na::RowDVector::zeros(2));
+}
+
+#[test]
+fn comparison_masks() {
+    let a = Matrix2x3::new(1.0, 5.0, -2.0, f64::NAN, 0.0, f64::INFINITY);
+    let b = DMatrix::from_element(2, 3, 0.0);
+
+    let lt = a.lt_elementwise(&b);
+    let ge = a.ge_elementwise(&b);
+    assert_eq!(lt, Matrix2x3::new(false, false, true, false, false, false));
+    assert_eq!(ge, Matrix2x3::new(true, true, false, false, true, true));
+    assert_eq!(
+        a.le_elementwise(&b),
+        lt.zip_map(&a.eq_elementwise(&b), |x, y| x || y)
+    );
+    assert_eq!(
+        a.gt_elementwise(&b),
+        ge.zip_map(&a.eq_elementwise(&b), |x, y| x && !y)
+    );
+
+    // NaN compares false with everything.
+    let nan = a.is_nan();
+    assert_eq!(nan.count(), 1);
+    assert!(nan.zip_map(&lt, |x, y| !(x && y)).all());
+    assert!(nan.zip_map(&ge, |x, y| !(x && y)).all());
+    assert_eq!(a.is_finite().count(), 4);
+
+    let mut cleaned = a;
+    cleaned.fill_masked(&a.is_finite().map(|e| !e), 0.0);
+    assert!(cleaned.is_finite().all());
+    assert_eq!(cleaned, a.is_finite().select(&a, &Matrix2x3::zeros()));
+
+    let mut clamped = a;
+    clamped.copy_from_masked(&a.lt_elementwise(&b), &b);
+    assert_eq!(clamped[(0, 2)], 0.0);
+    assert_eq!(clamped[(0, 1)], 5.0);
+
+    let empty = DMatrix::<bool>::from_element(0, 3, true);
+    assert!(empty.all());
+    assert!(!empty.any());
+}
+
+#[test]
+fn comparison_masks_keep_partial_ord() {
+    let a = Vector3::new(1.0, 2.0, 3.0);
+    let b = Vector3::new(2.0, 3.0, 4.0);
+
+    // The method-call syntax still resolves to `PartialOrd`, which compares whole matrices.
+    let lt: bool = a.lt(&b);
+    let ge: bool = a.ge(&b);
+    assert!(lt && !ge);
+    assert!(a.le(&b) && !a.gt(&b));
+    assert!(a.lt_elementwise(&b).all());
+}
+
+#[test]
+#[should_panic]
+fn comparison_dimension_mismatch() {
+    let _ = DMatrix::<f64>::zeros(2, 3).lt_elementwise(&DMatrix::zeros(3, 2));
+}
+
 #[test]
 fn partial_clamp() {
     // NOTE: from #401.
@@ -1136,3 +1246,82 @@ fn omatrix_to_string() {
         (svec.to_string(), smatr.to_string())
     );
 }
+
+#[test]
+fn fallible_arithmetic() {
+    let a = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let b = DMatrix::from_row_slice(3, 2, &[1.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+
+    assert_eq!(a.try_add(&a), Ok(&a + &a));
+    assert_eq!(a.try_sub(&a), Ok(&a - &a));
+    assert_eq!(a.try_mul(&b), Ok(&a * &b));
+
+    assert_eq!(a.try_add(&b), Err(ShapeMismatch::new((2, 3), (3, 2))));
+    assert_eq!(a.try_sub(&b), Err(ShapeMismatch::new((2, 3), (3, 2))));
+    assert_eq!(a.try_mul(&a), Err(ShapeMismatch::new((2, 3), (2, 3))));
+}
+
+#[test]
+fn fallible_gemm() {
+    let a = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let b = DMatrix::from_row_slice(3, 2, &[1.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+    let mut c = DMatrix::from_element(2, 2, 1.0);
+
+    assert_eq!(c.try_gemm(2.0, &a, &b, 1.0), Ok(()));
+    assert_eq!(c, (&a * &b) * 2.0 + DMatrix::from_element(2, 2, 1.0));
+
+    let expected = c.clone();
+    assert_eq!(
+        c.try_gemm(1.0, &a, &a, 0.0),
+        Err(ShapeMismatch::new((2, 3), (2, 3)))
+    );
+    assert_eq!(c, expected);
+
+    let mut wrong = DMatrix::zeros(3, 3);
+    assert_eq!(
+        wrong.try_gemm(1.0, &a, &b, 0.0),
+        Err(ShapeMismatch::new((3, 3), (2, 2)))
+    );
+    assert_eq!(wrong, DMatrix::zeros(3, 3));
+}
+
+#[test]
+fn fallible_conversion_to_static() {
+    let m = DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(
+        Matrix2x3::try_from(m.clone()),
+        Ok(Matrix2x3::new(1, 2, 3, 4, 5, 6))
+    );
+    assert_eq!(
+        Matrix3x2::try_from(m),
+        Err(ShapeMismatch::new((2, 3), (3, 2)))
+    );
+}
+
+#[test]
+fn fallible_solve() {
+    let m = DMatrix::from_row_slice(2, 2, &[4.0, 1.0, 1.0, 3.0]);
+    let b = DVector::from_vec(vec![1.0, 2.0]);
+    let wrong = DMatrix::<f64>::zeros(3, 1);
+    let err = Err(ShapeMismatch::new((2, 2), (3, 1)));
+
+    assert_eq!(m.clone().lu().try_solve(&b), Ok(m.clone().lu().solve(&b)));
+    assert_eq!(m.clone().lu().try_solve(&wrong), err);
+    assert_eq!(m.clone().qr().try_solve(&b), Ok(m.clone().qr().solve(&b)));
+    assert_eq!(m.clone().qr().try_solve(&wrong), err);
+
+    let chol = m.cholesky().unwrap();
+    assert_eq!(chol.try_solve(&b), Ok(chol.solve(&b)));
+    assert_eq!(
+        chol.try_solve(&wrong),
+        Err(ShapeMismatch::new((2, 2), (3, 1)))
+    );
+}
+
+#[test]
+#[should_panic]
+fn operator_shape_mismatch_still_panics() {
+    let a = DMatrix::<f64>::zeros(2, 3);
+    let b = DMatrix::<f64>::zeros(3, 2);
+    let _ = a + b;
+}
@@ -1,13 +1,28 @@
 mod blas;
 mod cg;
 mod conversion;
+#[cfg(feature = "io")]
+mod csv;
+mod diagonal_permutation;
+mod display;
 mod edition;
 mod empty;
+mod inline_storage;
 mod matrix;
 mod matrix_slice;
 #[cfg(feature = "mint")]
 mod mint;
+#[cfg(feature = "io")]
+mod npy;
+mod packed;
+#[cfg(feature = "rayon")]
+mod par_iter;
+mod parse;
+mod row_major;
 mod serde;
+mod stack;
+mod statistics;
+mod triangular;
 
 #[cfg(feature = "compare")]
 mod matrixcompare;
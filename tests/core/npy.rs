@@ -0,0 +1,166 @@
+use std::io::Cursor;
+
+use na::io::{read_npy, write_npy, NpyError, NpzReader, NpzWriter};
+use na::{Complex, DMatrix, DVector, Dynamic, Matrix2x3, RowDVector, Vector3, U1};
+
+/// Builds the content of a `.npy` file with the given header fields and raw data.
+fn npy(descr: &str, fortran_order: bool, shape: &str, data: &[u8]) -> Vec<u8> {
+    let dict = format!(
+        "{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}\n",
+        descr,
+        if fortran_order { "True" } else { "False" },
+        shape
+    );
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&(dict.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(dict.as_bytes());
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+#[test]
+fn npy_read_orders_and_endianness() {
+    let expected = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    let c_order: Vec<u8> = [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0]
+        .iter()
+        .flat_map(|e| e.to_le_bytes().to_vec())
+        .collect();
+    let bytes = npy("<f8", false, "(2, 3)", &c_order);
+    assert_eq!(
+        read_npy::<_, f64, Dynamic, Dynamic>(&bytes[..]).unwrap(),
+        expected
+    );
+
+    let fortran_order: Vec<u8> = [1.0f32, 4.0, 2.0, 5.0, 3.0, 6.0]
+        .iter()
+        .flat_map(|e| e.to_be_bytes().to_vec())
+        .collect();
+    let bytes = npy(">f4", true, "(2, 3)", &fortran_order);
+    assert_eq!(
+        read_npy::<_, f64, Dynamic, Dynamic>(&bytes[..]).unwrap(),
+        expected
+    );
+    let m: Matrix2x3<f32> = read_npy(&bytes[..]).unwrap();
+    assert_eq!(m, Matrix2x3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0));
+
+    let ints: Vec<u8> = [1i16, -2, 3]
+        .iter()
+        .flat_map(|e| e.to_le_bytes().to_vec())
+        .collect();
+    let bytes = npy("<i2", false, "(3,)", &ints);
+    let v: Vector3<i64> = read_npy(&bytes[..]).unwrap();
+    assert_eq!(v, Vector3::new(1, -2, 3));
+    let v: DVector<f64> = read_npy(&bytes[..]).unwrap();
+    assert_eq!(v, DVector::from_vec(vec![1.0, -2.0, 3.0]));
+    let v: RowDVector<i32> = read_npy(&bytes[..]).unwrap();
+    assert_eq!(v, RowDVector::from_vec(vec![1, -2, 3]));
+
+    let complex: Vec<u8> = [1.0f32, -1.0, 0.5, 2.0]
+        .iter()
+        .flat_map(|e| e.to_le_bytes().to_vec())
+        .collect();
+    let bytes = npy("<c8", false, "(1, 2)", &complex);
+    let v: DVector<Complex<f64>> = read_npy(&bytes[..]).unwrap();
+    assert_eq!(
+        v,
+        DVector::from_vec(vec![Complex::new(1.0, -1.0), Complex::new(0.5, 2.0)])
+    );
+}
+
+#[test]
+fn npy_roundtrip() {
+    let mut bytes = Vec::new();
+    let m = DMatrix::from_fn(3, 4, |i, j| (i * 10 + j) as f64 - 5.5);
+    write_npy(&mut bytes, &m).unwrap();
+    assert_eq!(bytes.iter().position(|b| *b == b'\n'), Some(127));
+    assert_eq!(read_npy::<_, f64, Dynamic, Dynamic>(&bytes[..]).unwrap(), m);
+
+    let mut bytes = Vec::new();
+    let v = DVector::from_fn(5, |i, _| Complex::new(i as f32, -(i as f32)));
+    write_npy(&mut bytes, &v).unwrap();
+    assert!(std::str::from_utf8(&bytes[10..70])
+        .unwrap()
+        .contains("'shape': (5,)"));
+    assert_eq!(read_npy::<_, _, Dynamic, U1>(&bytes[..]).unwrap(), v);
+}
+
+#[test]
+fn npz_roundtrip() {
+    let m = DMatrix::from_fn(3, 2, |i, j| (i + j) as u8);
+    let v = DVector::from_vec(vec![1.5f32, 2.5]);
+
+    for compressed in [false, true] {
+        let cursor = Cursor::new(Vec::new());
+        let mut writer = if compressed {
+            NpzWriter::new_compressed(cursor)
+        } else {
+            NpzWriter::new(cursor)
+        };
+        writer.add("m", &m).unwrap();
+        writer.add("v", &v).unwrap();
+        let bytes = writer.finish().unwrap().into_inner();
+
+        let mut reader = NpzReader::new(Cursor::new(bytes)).unwrap();
+        let mut names = reader.names();
+        names.sort();
+        assert_eq!(names, ["m", "v"]);
+        assert_eq!(reader.by_name::<u8, Dynamic, Dynamic>("m").unwrap(), m);
+        assert_eq!(
+            reader.by_name::<u32, Dynamic, Dynamic>("m").unwrap(),
+            m.map(u32::from)
+        );
+        assert_eq!(reader.by_name::<f32, Dynamic, U1>("v").unwrap(), v);
+        assert!(matches!(
+            reader.by_name::<f32, Dynamic, U1>("w"),
+            Err(NpyError::MissingArray(name)) if name == "w"
+        ));
+    }
+}
+
+#[test]
+fn npy_errors() {
+    let bytes = npy("<i8", false, "(1,)", &1i64.to_le_bytes());
+    let err = read_npy::<_, f64, Dynamic, Dynamic>(&bytes[..]).unwrap_err();
+    assert!(matches!(&err, NpyError::UnsupportedDtype { dtype, .. } if dtype == "<i8"));
+    assert_eq!(
+        err.to_string(),
+        "cannot read an array of dtype `<i8` as `f64`"
+    );
+
+    let bytes = npy("<U3", false, "(1,)", &[0; 12]);
+    assert!(matches!(
+        read_npy::<_, f64, Dynamic, Dynamic>(&bytes[..]),
+        Err(NpyError::UnsupportedDtype { .. })
+    ));
+
+    let bytes = npy("<f8", false, "(1, 1, 1)", &1f64.to_le_bytes());
+    assert!(matches!(
+        read_npy::<_, f64, Dynamic, Dynamic>(&bytes[..]),
+        Err(NpyError::UnsupportedShape(shape)) if shape == [1, 1, 1]
+    ));
+
+    let bytes = npy("<f8", false, "(2, 2)", &[0; 32]);
+    assert!(matches!(
+        read_npy::<_, f64, Dynamic, U1>(&bytes[..]),
+        Err(NpyError::ShapeMismatch(_))
+    ));
+
+    let bytes = npy("<f8", false, "(2, 2)", &[0; 16]);
+    assert!(matches!(
+        read_npy::<_, f64, Dynamic, Dynamic>(&bytes[..]),
+        Err(NpyError::Io(_))
+    ));
+
+    // A huge declared shape must not be allocated before the data is read.
+    let bytes = npy("<f8", false, "(1000000, 1000000)", &[0; 16]);
+    assert!(matches!(
+        read_npy::<_, f64, Dynamic, Dynamic>(&bytes[..]),
+        Err(NpyError::Io(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof
+    ));
+
+    assert!(matches!(
+        read_npy::<_, f64, Dynamic, Dynamic>(&b"not a npy file"[..]),
+        Err(NpyError::InvalidHeader(_))
+    ));
+}
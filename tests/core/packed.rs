@@ -0,0 +1,115 @@
+use na::dimension::DimName;
+use na::{
+    Complex, DMatrix, DVector, Matrix3, PackedHermitianMatrix, PackedSymmetricMatrix, Uplo, Vector3,
+};
+
+fn spd_matrix() -> DMatrix<f64> {
+    let m = DMatrix::from_fn(5, 5, |i, j| ((i * 5 + j) as f64).sin());
+    &m * m.transpose() + DMatrix::identity(5, 5)
+}
+
+#[test]
+fn packed_layout() {
+    let m = Matrix3::new(1, 2, 4, 2, 3, 5, 4, 5, 6);
+
+    let upper = PackedSymmetricMatrix::from_matrix(&m, Uplo::Upper);
+    assert_eq!(upper.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    let lower = PackedSymmetricMatrix::from_matrix(&m, Uplo::Lower);
+    assert_eq!(lower.as_slice(), &[1, 2, 4, 3, 5, 6]);
+
+    for packed in [upper, lower].iter() {
+        assert_eq!(packed.dim(), 3);
+        assert_eq!(packed.to_matrix(), m);
+        assert_eq!(packed.get(2, 1), 5);
+        assert_eq!(packed.get(1, 2), 5);
+    }
+
+    let mut lower = PackedSymmetricMatrix::from_vec(na::U3::name(), Uplo::Lower, vec![0; 6]);
+    lower.set(0, 2, 7);
+    assert_eq!(lower.get(2, 0), 7);
+    assert_eq!(lower.as_slice(), &[0, 0, 7, 0, 0, 0]);
+    assert_eq!(Vec::from(lower), vec![0, 0, 7, 0, 0, 0]);
+}
+
+#[test]
+#[should_panic]
+fn packed_wrong_length() {
+    let _ = PackedSymmetricMatrix::from_vec(na::Dynamic::new(3), Uplo::Upper, vec![0.0; 5]);
+}
+
+#[test]
+fn packed_symmetric_blas() {
+    let m = spd_matrix();
+    let x = DVector::from_fn(5, |i, _| i as f64 - 2.0);
+    let y0 = DVector::from_fn(5, |i, _| (i * i) as f64);
+
+    for &uplo in [Uplo::Upper, Uplo::Lower].iter() {
+        let packed = PackedSymmetricMatrix::from_matrix(&m, uplo);
+        assert_relative_eq!(&packed * &x, &m * &x, epsilon = 1.0e-10);
+        assert_relative_eq!(&packed * &m, &m * &m, epsilon = 1.0e-10);
+
+        let mut y = y0.clone();
+        y.sygemv_packed(2.0, &packed, &x, 3.0);
+        assert_relative_eq!(y, &m * &x * 2.0 + &y0 * 3.0, epsilon = 1.0e-10);
+
+        let mut updated = packed.clone();
+        updated.syger(0.5, &x, &x, 2.0);
+        assert_relative_eq!(
+            updated.to_matrix(),
+            &x * x.transpose() * 0.5 + &m * 2.0,
+            epsilon = 1.0e-10
+        );
+
+        let chol = packed.cholesky().unwrap();
+        assert_relative_eq!(chol.solve(&x), m.clone().cholesky().unwrap().solve(&x));
+
+        let eig = packed.symmetric_eigen();
+        assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-7);
+    }
+}
+
+#[test]
+fn packed_hermitian_blas() {
+    let m = Matrix3::new(
+        Complex::new(4.0, 0.0),
+        Complex::new(1.0, -2.0),
+        Complex::new(0.5, 1.0),
+        Complex::new(1.0, 2.0),
+        Complex::new(6.0, 0.0),
+        Complex::new(-1.0, 0.5),
+        Complex::new(0.5, -1.0),
+        Complex::new(-1.0, -0.5),
+        Complex::new(5.0, 0.0),
+    );
+    let x = Vector3::new(
+        Complex::new(1.0, 1.0),
+        Complex::new(-2.0, 0.5),
+        Complex::new(0.0, 3.0),
+    );
+
+    for &uplo in [Uplo::Upper, Uplo::Lower].iter() {
+        let packed = PackedHermitianMatrix::from_matrix(&m, uplo);
+        assert_eq!(packed.to_matrix(), m);
+        assert_eq!(packed.get(0, 1), Complex::new(1.0, -2.0));
+        assert_relative_eq!(&packed * &x, m * x, epsilon = 1.0e-10);
+
+        let mut y = x;
+        y.hegemv_packed(Complex::new(2.0, 0.0), &packed, &x, Complex::new(0.0, 1.0));
+        assert_relative_eq!(
+            y,
+            m * x * Complex::new(2.0, 0.0) + x * Complex::new(0.0, 1.0),
+            epsilon = 1.0e-10
+        );
+
+        let mut updated = packed.clone();
+        updated.hegerc(Complex::new(0.5, 0.0), &x, &x, Complex::new(1.0, 0.0));
+        assert_relative_eq!(
+            updated.to_matrix(),
+            x * x.adjoint() * Complex::new(0.5, 0.0) + m,
+            epsilon = 1.0e-10
+        );
+
+        let chol = packed.cholesky().unwrap();
+        assert_relative_eq!(chol.solve(&x), m.cholesky().unwrap().solve(&x));
+    }
+}
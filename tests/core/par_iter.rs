@@ -0,0 +1,139 @@
+use na::{DMatrix, DVector, Matrix3x4};
+use rayon::prelude::*;
+
+#[test]
+fn par_column_iter() {
+    let m = DMatrix::<f64>::new_random(17, 311);
+    let expected: Vec<_> = m.column_iter().map(|col| col.norm()).collect();
+    let norms: Vec<_> = m.par_column_iter().map(|col| col.norm()).collect();
+    assert_eq!(norms, expected);
+
+    let sum: f64 = m.par_column_iter().map(|col| col.sum()).sum();
+    assert_relative_eq!(sum, m.sum(), epsilon = 1.0e-10);
+
+    let empty = DMatrix::<f64>::zeros(3, 0);
+    assert_eq!(empty.par_column_iter().count(), 0);
+}
+
+#[test]
+fn par_column_iter_mut() {
+    let mut m = Matrix3x4::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+    m.par_column_iter_mut()
+        .enumerate()
+        .for_each(|(j, mut col)| col *= j as i32 + 1);
+    assert_eq!(m, Matrix3x4::new(1, 4, 9, 16, 5, 12, 21, 32, 9, 20, 33, 48));
+
+    let mut m = DMatrix::<f64>::new_random(23, 500);
+    let expected = m.map(|e| e * 2.0 + 1.0);
+    m.par_column_iter_mut()
+        .for_each(|mut col| col.apply(|e| *e = *e * 2.0 + 1.0));
+    assert_eq!(m, expected);
+}
+
+#[test]
+fn par_map() {
+    let a = DMatrix::<f64>::new_random(41, 73);
+    let b = DMatrix::<f64>::new_random(41, 73);
+    assert_eq!(a.par_map(|e| e.sin()), a.map(|e| e.sin()));
+    assert_eq!(
+        a.par_zip_map(&b, |x, y| x * y - x),
+        a.zip_map(&b, |x, y| x * y - x)
+    );
+
+    let v = DVector::from_fn(100, |i, _| i as u32);
+    assert_eq!(v.par_map(|e| e as f32), v.map(|e| e as f32));
+
+    let tr = a.transpose();
+    assert_eq!(
+        tr.rows(1, 20).par_map(|e| e + 1.0),
+        tr.rows(1, 20).map(|e| e + 1.0)
+    );
+}
+
+#[test]
+fn par_reductions() {
+    let m = DMatrix::<f64>::new_random(37, 411);
+    assert_relative_eq!(m.par_sum(), m.sum(), epsilon = 1.0e-10);
+    assert_eq!(m.par_row_sum(), m.row_sum());
+    assert_relative_eq!(m.par_column_sum(), m.column_sum(), epsilon = 1.0e-10);
+    assert_relative_eq!(m.par_norm_squared(), m.norm_squared(), epsilon = 1.0e-10);
+    assert_relative_eq!(m.par_norm(), m.norm(), epsilon = 1.0e-10);
+
+    let m = DMatrix::from_fn(13, 300, |i, j| (i * 300 + j) as i64 - 1000);
+    assert_eq!(m.par_sum(), m.sum());
+    assert_eq!(m.par_row_sum(), m.row_sum());
+    assert_eq!(m.par_column_sum(), m.column_sum());
+
+    let m = Matrix3x4::new(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12);
+    assert_eq!(m.par_row_sum(), m.row_sum());
+    assert_eq!(m.par_column_sum(), m.column_sum());
+
+    let empty = DMatrix::<f64>::zeros(3, 0);
+    assert_eq!(empty.par_sum(), 0.0);
+    assert_eq!(empty.par_row_sum().len(), 0);
+    assert_eq!(empty.par_column_sum(), DVector::zeros(3));
+}
+
+#[test]
+#[should_panic]
+fn par_zip_map_dimension_mismatch() {
+    let a = DMatrix::<f64>::zeros(3, 4);
+    let b = DMatrix::<f64>::zeros(4, 3);
+    let _ = a.par_zip_map(&b, |x, y| x + y);
+}
+
+#[test]
+fn par_gemm() {
+    let (m, k, n) = (157, 201, 163);
+    let a = DMatrix::<f64>::new_random(m, k);
+    let b = DMatrix::<f64>::new_random(k, n);
+    let c = DMatrix::<f64>::new_random(m, n);
+    let expected = DMatrix::from_fn(m, n, |i, j| {
+        2.0 * a.row(i).transpose().dot(&b.column(j)) - 0.5 * c[(i, j)]
+    });
+
+    let mut res = c.clone();
+    res.gemm(2.0, &a, &b, -0.5);
+    assert_relative_eq!(res, expected, epsilon = 1.0e-10);
+
+    // Wide output, split by columns.
+    let bt = DMatrix::<f64>::new_random(k, 4 * n);
+    let expected = DMatrix::from_fn(m, 4 * n, |i, j| a.row(i).transpose().dot(&bt.column(j)));
+    assert_relative_eq!(&a * &bt, expected, epsilon = 1.0e-10);
+
+    // Strided views.
+    let at = a.transpose();
+    let res = at.columns(3, 100).transpose() * b.columns(5, 150);
+    let expected = DMatrix::from_fn(100, 150, |i, j| {
+        a.row(i + 3).transpose().dot(&b.column(j + 5))
+    });
+    assert_relative_eq!(res, expected, epsilon = 1.0e-10);
+
+    let a = a.map(|e| e as f32);
+    let b = b.map(|e| e as f32);
+    let expected = DMatrix::from_fn(m, n, |i, j| a.row(i).transpose().dot(&b.column(j)));
+    assert_relative_eq!(&a * &b, expected, epsilon = 1.0e-3);
+}
+
+#[test]
+fn par_gemv() {
+    let a = DMatrix::<f64>::new_random(1031, 517);
+    let x = DVector::<f64>::new_random(517);
+    let y = DVector::<f64>::new_random(1031);
+    let expected = DVector::from_fn(1031, |i, _| 3.0 * a.row(i).transpose().dot(&x) + 2.0 * y[i]);
+
+    let mut res = y.clone();
+    res.gemv(3.0, &a, &x, 2.0);
+    assert_relative_eq!(res, expected, epsilon = 1.0e-10);
+
+    let mut res = y.clone();
+    res.gemv(3.0, &a, &x, 0.0);
+    assert_relative_eq!(res, expected - y * 2.0, epsilon = 1.0e-10);
+
+    let a = a.map(|e| e as f32);
+    let x = x.map(|e| e as f32);
+    let expected = DVector::from_fn(1031, |i, _| a.row(i).transpose().dot(&x));
+    let mut res = DVector::zeros(1031);
+    res.gemv(1.0, &a, &x, 0.0);
+    assert_relative_eq!(res, expected, epsilon = 1.0e-3);
+}
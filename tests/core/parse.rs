@@ -0,0 +1,106 @@
+use na::parse::{parse_row_major, ParseMatrixError, ParseMatrixErrorKind};
+use na::{DMatrix, DVector, Matrix2x3, Point3, RowVector3, ShapeMismatch, Vector3, U2, U3};
+
+#[test]
+fn parse_syntaxes() {
+    let expected = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, -6.5]);
+
+    for s in [
+        "[1 2 3; 4 5 -6.5]",
+        "[1, 2, 3; 4, 5, -6.5;]",
+        "[[1, 2, 3], [4, 5, -6.5]]",
+        "[[1, 2, 3],\n [4, 5, -6.5],\n]",
+        "1 2 3\n4 5 -6.5\n",
+        "  1, 2, 3\r\n\r\n  4, 5, -6.5",
+        "[1 2 3\n 4 5 -6.5]",
+        "matrix![1, 2, 3;\n        4, 5, -6.5]",
+        "np.array([[1, 2, 3],\n          [4, 5, -6.5]])",
+    ] {
+        assert_eq!(s.parse::<DMatrix<f64>>(), Ok(expected.clone()), "{:?}", s);
+        assert_eq!(
+            s.parse::<Matrix2x3<f64>>(),
+            Ok(expected.fixed_slice::<2, 3>(0, 0).into_owned())
+        );
+    }
+
+    assert_eq!(parse_row_major::<i32>("[]"), Ok((0, 0, vec![])));
+    assert_eq!(parse_row_major::<i32>(""), Ok((0, 0, vec![])));
+    assert_eq!(parse_row_major::<i32>("[[], []]"), Ok((2, 0, vec![])));
+    let (nrows, ncols, data) = parse_row_major::<f64>("inf -inf NaN").unwrap();
+    assert_eq!((nrows, ncols), (1, 3));
+    assert!(data[0] == f64::INFINITY && data[1] == -f64::INFINITY && data[2].is_nan());
+}
+
+#[test]
+fn parse_vectors_and_points() {
+    let v = Vector3::new(1, 2, 3);
+    assert_eq!("[1, 2, 3]".parse(), Ok(v));
+    assert_eq!("[1; 2; 3]".parse(), Ok(v));
+    assert_eq!(
+        "vector![1, 2, 3]".parse(),
+        Ok(DVector::from_column_slice(&[1, 2, 3]))
+    );
+    assert_eq!("[1 2 3]".parse(), Ok(RowVector3::new(1, 2, 3)));
+    assert_eq!(
+        "point![1.0, 2.0, 3.0]".parse(),
+        Ok(Point3::new(1.0, 2.0, 3.0))
+    );
+}
+
+#[test]
+fn parse_errors() {
+    fn err<T: std::str::FromStr + std::fmt::Debug>(s: &str) -> ParseMatrixError<T::Err>
+    where
+        T::Err: std::fmt::Debug,
+    {
+        parse_row_major::<T>(s).unwrap_err()
+    }
+
+    let e = err::<f64>("[1 2; 3 x]");
+    assert!(matches!(e.kind, ParseMatrixErrorKind::InvalidComponent(_)));
+    assert_eq!((e.line, e.column), (1, 9));
+    assert_eq!(
+        e.to_string(),
+        "invalid component: invalid float literal at line 1, column 9"
+    );
+
+    let e = err::<f64>("[1 2;\n 3 4 5]");
+    assert_eq!(
+        e.kind,
+        ParseMatrixErrorKind::RaggedRow {
+            expected: 2,
+            found: 3
+        }
+    );
+    assert_eq!((e.line, e.column), (2, 2));
+
+    let e = err::<f64>("[[1, 2],\n [3]]");
+    assert_eq!((e.line, e.column), (2, 2));
+
+    let e = err::<f64>("[1 2; 3 4");
+    assert_eq!(e.kind, ParseMatrixErrorKind::UnexpectedEnd);
+    assert_eq!((e.line, e.column), (1, 10));
+
+    let e = err::<f64>("[[1, 2]; [3, 4]]");
+    assert_eq!(e.kind, ParseMatrixErrorKind::UnexpectedChar(';'));
+    assert_eq!((e.line, e.column), (1, 8));
+
+    let e = err::<f64>("[1,, 2]");
+    assert_eq!(e.kind, ParseMatrixErrorKind::UnexpectedChar(','));
+    assert_eq!((e.line, e.column), (1, 4));
+
+    let e = err::<f64>("[1 2] 3");
+    assert_eq!(e.kind, ParseMatrixErrorKind::UnexpectedChar('3'));
+
+    let e = "\n [1 2 3; 4 5 6]"
+        .parse::<na::OMatrix<f64, U3, U2>>()
+        .unwrap_err();
+    assert_eq!(
+        e.kind,
+        ParseMatrixErrorKind::ShapeMismatch(ShapeMismatch::new((2, 3), (3, 2)))
+    );
+    assert_eq!((e.line, e.column), (2, 2));
+
+    assert!("[1 2 3 4]".parse::<Vector3<f64>>().is_err());
+    assert!("[1 2]".parse::<Point3<f64>>().is_err());
+}
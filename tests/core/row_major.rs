@@ -0,0 +1,223 @@
+use na::allocator::Allocator;
+use na::dimension::DimName;
+use na::{
+    DMatrix, Dynamic, Matrix, Matrix2x3, Matrix3, RowMajorAllocator, RowMajorArrayStorage,
+    RowMajorDMatrix, RowMajorSMatrix, RowMajorVecStorage, U2, U3,
+};
+
+fn row_major_dmatrix(nrows: usize, ncols: usize, data: &[f64]) -> RowMajorDMatrix<f64> {
+    Matrix::from_data(RowMajorVecStorage::new(
+        Dynamic::new(nrows),
+        Dynamic::new(ncols),
+        data.to_vec(),
+    ))
+}
+
+#[test]
+fn row_major_layout() {
+    let rm: RowMajorSMatrix<i32, 2, 3> =
+        Matrix::from_data(RowMajorArrayStorage([[1, 2, 3], [4, 5, 6]]));
+    let cm = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+
+    assert_eq!(rm.strides(), (3, 1));
+    assert_eq!(rm.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(rm[(0, 1)], 2);
+    assert_eq!(rm[(1, 0)], 4);
+    assert_eq!(rm[1], 4);
+    assert_eq!(rm, cm);
+    assert_eq!(rm.clone_owned(), cm);
+    assert_eq!(rm.into_owned(), cm);
+
+    let rm = row_major_dmatrix(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(rm.strides(), (3, 1));
+    assert_eq!(rm[(1, 2)], 6.0);
+    assert_eq!(rm.transpose(), rm.clone_owned().transpose());
+}
+
+#[test]
+fn row_major_iterators_and_slices() {
+    let cm = Matrix3::new(1, 2, 3, 4, 5, 6, 7, 8, 9);
+    let rm = cm.to_row_major();
+
+    assert!(rm.iter().eq(cm.iter()));
+    assert!(rm.row_iter().zip(cm.row_iter()).all(|(a, b)| a == b));
+    assert!(rm.column_iter().zip(cm.column_iter()).all(|(a, b)| a == b));
+    assert_eq!(rm.slice((1, 0), (2, 2)), cm.slice((1, 0), (2, 2)));
+    assert_eq!(rm.row(2), cm.row(2));
+    assert_eq!(rm.column(1), cm.column(1));
+
+    let mut rm = rm;
+    rm.column_mut(1).fill(0);
+    rm.row_mut(0)[2] = 10;
+    assert_eq!(rm, Matrix3::new(1, 0, 10, 4, 0, 6, 7, 0, 9));
+}
+
+#[test]
+fn row_major_iteration() {
+    let cm = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    let mut rm = cm.to_row_major();
+
+    assert!(rm.iter().eq(cm.iter()));
+    assert!(rm.iter().rev().eq(cm.iter().rev()));
+    assert_eq!(rm.iter().len(), 6);
+
+    // Mix both ends of the iterator.
+    let mut it = rm.iter();
+    assert_eq!(it.next(), Some(&1));
+    assert_eq!(it.next(), Some(&4));
+    assert_eq!(it.next_back(), Some(&6));
+    assert_eq!(it.next(), Some(&2));
+    assert_eq!(it.next_back(), Some(&3));
+    assert_eq!(it.next_back(), Some(&5));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+
+    for (i, e) in rm.iter_mut().enumerate() {
+        *e = i as i32;
+    }
+    assert_eq!(rm.as_slice(), &[0, 2, 4, 1, 3, 5]);
+
+    let cm = DMatrix::from_fn(5, 4, |i, j| i * 4 + j);
+    let rm = cm.to_row_major();
+    let slice = rm.slice((1, 1), (4, 2));
+    assert!(slice.iter().eq(cm.slice((1, 1), (4, 2)).iter()));
+    assert!(slice.iter().rev().eq(cm.slice((1, 1), (4, 2)).iter().rev()));
+}
+
+#[test]
+fn row_major_arithmetic() {
+    let cm = DMatrix::from_fn(9, 7, |i, j| (i * 7 + j) as f64 - 20.0);
+    let rm = cm.to_row_major();
+    let other = DMatrix::from_fn(9, 7, |i, j| (i as f64) * 0.5 - j as f64);
+
+    assert_eq!(&rm + &other, &cm + &other);
+    assert_eq!(&other - &rm, &other - &cm);
+    assert_eq!(&rm - &rm.clone(), DMatrix::zeros(9, 7));
+    assert_eq!(rm.component_mul(&other), cm.component_mul(&other));
+
+    let mut sum = other.clone();
+    sum += &rm;
+    assert_eq!(sum, &other + &cm);
+
+    let rhs = DMatrix::from_fn(7, 5, |i, j| (i + 2 * j) as f64);
+    let rm_rhs = rhs.to_row_major();
+    let expected = &cm * &rhs;
+    assert_eq!(&rm * &rhs, expected);
+    assert_eq!(&cm * &rm_rhs, expected);
+    assert_eq!(&rm * &rm_rhs, expected);
+
+    let mut res = DMatrix::from_element(9, 5, 1.0);
+    res.gemm(2.0, &rm, &rm_rhs, 1.0);
+    assert_eq!(res, expected * 2.0 + DMatrix::from_element(9, 5, 1.0));
+
+    let mut res = DMatrix::zeros(7, 5).to_row_major();
+    res.gemm_tr(
+        1.0,
+        &rm,
+        &DMatrix::from_fn(9, 5, |i, j| (i * j) as f64),
+        0.0,
+    );
+    assert_eq!(
+        res,
+        cm.transpose() * DMatrix::from_fn(9, 5, |i, j| (i * j) as f64)
+    );
+
+    let v = na::DVector::from_fn(7, |i, _| i as f64);
+    assert_eq!(&rm * &v, &cm * &v);
+}
+
+#[test]
+fn row_major_decompositions() {
+    let cm = DMatrix::from_row_slice(3, 3, &[4.0, 1.0, 2.0, 1.0, 5.0, 3.0, 2.0, 3.0, 6.0]);
+    let rm = cm.to_row_major();
+    let b = na::DVector::from_vec(vec![1.0, 2.0, 3.0]);
+
+    assert_eq!(rm.clone().lu().solve(&b), cm.clone().lu().solve(&b));
+    assert_eq!(rm.clone().qr().solve(&b), cm.clone().qr().solve(&b));
+    assert_eq!(
+        rm.clone().cholesky().unwrap().solve(&b),
+        cm.clone().cholesky().unwrap().solve(&b)
+    );
+    assert_eq!(rm.determinant(), cm.determinant());
+}
+
+#[test]
+fn row_major_allocator() {
+    let rm = RowMajorAllocator::allocate_from_iterator(U2::name(), U3::name(), 1..=6);
+    assert_eq!(rm.as_slice(), &[1, 3, 5, 2, 4, 6]);
+    let rm = RowMajorAllocator::allocate_from_row_iterator(U2::name(), U3::name(), 1..=6);
+    assert_eq!(rm.as_slice(), &[1, 2, 3, 4, 5, 6]);
+
+    let rm = RowMajorAllocator::allocate_from_iterator(Dynamic::new(2), U3::name(), 1..=6);
+    assert_eq!(rm.as_slice(), &[1, 3, 5, 2, 4, 6]);
+    let rm = RowMajorAllocator::allocate_from_row_iterator(U2::name(), Dynamic::new(3), 1..=6);
+    assert_eq!(rm.as_slice(), &[1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn row_major_owned_results() {
+    let a = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    let b = Matrix2x3::new(6, 5, 4, 3, 2, 1).to_row_major();
+
+    let sum = a.add_in::<RowMajorAllocator, _, _, _>(&b);
+    assert_eq!(sum.strides(), (3, 1));
+    assert_eq!(sum.as_slice(), &[7; 6]);
+    let diff = b.sub_in::<RowMajorAllocator, _, _, _>(&a);
+    assert_eq!(diff.as_slice(), &[5, 3, 1, -1, -3, -5]);
+    assert_eq!(diff, b - a);
+
+    let t = b.transpose_in::<RowMajorAllocator>();
+    assert_eq!(t, b.transpose());
+    assert_eq!(t.as_slice(), &[6, 3, 5, 2, 4, 1]);
+    let prod = a.mul_in::<RowMajorAllocator, _, _, _>(&t);
+    assert_eq!(prod, a * b.transpose());
+    assert_eq!(prod.as_slice(), &[28, 10, 73, 28]);
+
+    let doubled = b.map_in::<RowMajorAllocator, _, _>(|e| e * 2);
+    assert_eq!(doubled.as_slice(), &[12, 10, 8, 6, 4, 2]);
+    assert_eq!(
+        a.clone_owned_in::<RowMajorAllocator>().as_slice(),
+        &[1, 2, 3, 4, 5, 6]
+    );
+    assert_eq!(
+        a.into_owned_in::<RowMajorAllocator>().as_slice(),
+        &[1, 2, 3, 4, 5, 6]
+    );
+
+    // Large enough to go through `matrixmultiply`, with row-major operands and output.
+    let a = DMatrix::from_fn(7, 9, |i, j| (i * 9 + j) as f64);
+    let b = DMatrix::from_fn(9, 8, |i, j| i as f64 - j as f64);
+    let (ra, rb) = (a.to_row_major(), b.to_row_major());
+    let prod = ra.mul_in::<RowMajorAllocator, _, _, _>(&rb);
+    let expected = &a * &b;
+    assert_eq!(prod.strides(), (8, 1));
+    assert_eq!(prod, expected);
+    assert_eq!(prod.into_column_major(), expected);
+
+    let sum = ra.add_in::<RowMajorAllocator, _, _, _>(&a);
+    assert_eq!(sum.strides(), (9, 1));
+    assert_eq!(sum, &a * 2.0);
+    let slice = ra.rows(1, 3).transpose_in::<RowMajorAllocator>();
+    assert_eq!(slice.strides(), (3, 1));
+    assert_eq!(slice, a.rows(1, 3).transpose());
+}
+
+#[test]
+fn row_major_layout_conversion_in_place() {
+    for &(nrows, ncols) in [(0, 3), (3, 0), (1, 5), (5, 1), (3, 5), (4, 4), (7, 2)].iter() {
+        let cm = DMatrix::from_fn(nrows, ncols, |i, j| format!("{}-{}", i, j));
+        let rm = cm.clone().into_row_major();
+
+        let expected: Vec<_> = (0..nrows)
+            .flat_map(|i| (0..ncols).map(move |j| format!("{}-{}", i, j)))
+            .collect();
+        assert_eq!(rm.data.as_slice(), &expected[..]);
+        assert_eq!(rm, cm);
+        assert_eq!(rm.into_column_major(), cm);
+    }
+
+    let cm = Matrix2x3::new(1, 2, 3, 4, 5, 6).map(|e| e.to_string());
+    let rm = cm.clone().into_row_major();
+    assert_eq!(rm.data.as_slice(), &["1", "2", "3", "4", "5", "6"]);
+    assert_eq!(rm.into_column_major(), cm);
+}
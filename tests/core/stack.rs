@@ -0,0 +1,111 @@
+use na::{
+    block_diagonal, hstack, vstack, DMatrix, Dynamic, Matrix2, Matrix2x3, Matrix2x4, Matrix3x2,
+    Matrix3x5, OMatrix, RowVector2, RowVector3, Vector2, U2,
+};
+
+#[test]
+fn hstack_static() {
+    let a = Matrix2::new(1, 2, 3, 4);
+    let b = Vector2::new(5, 6);
+    let c = Vector2::new(7, 8);
+
+    let m: Matrix2x3<i32> = hstack((&a, &b));
+    assert_eq!(m, Matrix2x3::new(1, 2, 5, 3, 4, 6));
+
+    let m: Matrix2x4<i32> = hstack((a, b, c));
+    assert_eq!(m, Matrix2x4::new(1, 2, 5, 7, 3, 4, 6, 8));
+
+    let m: Matrix2<i32> = hstack((&a,));
+    assert_eq!(m, a);
+
+    // Slices are accepted as blocks.
+    let m: Matrix2x3<i32> = hstack((a.column(1), &b, a.fixed_columns::<1>(0)));
+    assert_eq!(m, Matrix2x3::new(2, 5, 1, 4, 6, 3));
+}
+
+#[test]
+fn hstack_mixed() {
+    let a = Matrix2::new(1, 2, 3, 4);
+    let b = DMatrix::from_row_slice(2, 3, &[5, 6, 7, 8, 9, 10]);
+
+    // The number of rows is known statically thanks to `a`.
+    let m: OMatrix<i32, U2, Dynamic> = hstack((&a, &b, &a));
+    let expected = DMatrix::from_row_slice(2, 7, &[1, 2, 5, 6, 7, 1, 2, 3, 4, 8, 9, 10, 3, 4]);
+    assert_eq!(m, expected);
+
+    let m: DMatrix<i32> = hstack((&b, b.columns(0, 1)));
+    assert_eq!(m, DMatrix::from_row_slice(2, 4, &[5, 6, 7, 5, 8, 9, 10, 8]));
+
+    let empty = DMatrix::<i32>::zeros(2, 0);
+    assert_eq!(hstack((&empty, &a, &empty)), a);
+}
+
+#[test]
+#[should_panic]
+fn hstack_mismatched_rows() {
+    let a = Matrix2::new(1, 2, 3, 4);
+    let b = DMatrix::from_row_slice(3, 1, &[5, 6, 7]);
+    let _ = hstack((&a, &b));
+}
+
+#[test]
+fn vstack_static() {
+    let a = Matrix2::new(1, 2, 3, 4);
+    let b = RowVector2::new(5, 6);
+
+    let m: Matrix3x2<i32> = vstack((&a, &b));
+    assert_eq!(m, Matrix3x2::new(1, 2, 3, 4, 5, 6));
+
+    let m: Matrix3x2<i32> = vstack((b, a.row(1), a.row(0)));
+    assert_eq!(m, Matrix3x2::new(5, 6, 3, 4, 1, 2));
+}
+
+#[test]
+fn vstack_mixed() {
+    let a = Matrix2::new(1, 2, 3, 4);
+    let b = DMatrix::from_row_slice(1, 2, &[5, 6]);
+
+    let m: OMatrix<i32, Dynamic, U2> = vstack((&b, &a));
+    assert_eq!(m, Matrix3x2::new(5, 6, 1, 2, 3, 4));
+
+    // Stacking the rows of a matrix gives back the same matrix.
+    let c = DMatrix::from_fn(4, 3, |i, j| i * 3 + j);
+    let m = vstack((c.rows(0, 1), c.rows(1, 2), c.rows(3, 1)));
+    assert_eq!(m, c);
+}
+
+#[test]
+#[should_panic]
+fn vstack_mismatched_columns() {
+    let a = Matrix2::new(1, 2, 3, 4);
+    let b = DMatrix::from_row_slice(1, 3, &[5, 6, 7]);
+    let _ = vstack((&a, &b));
+}
+
+#[test]
+fn block_diagonal_mixed() {
+    let a = Matrix2::new(1, 2, 3, 4);
+    let b = RowVector3::new(5, 6, 7);
+
+    let m: Matrix3x5<i32> = block_diagonal((&a, &b));
+    assert_eq!(
+        m,
+        Matrix3x5::new(1, 2, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 5, 6, 7)
+    );
+
+    let d = DMatrix::from_row_slice(2, 1, &[8.0, 9.0]);
+    let m = block_diagonal((&d, &Matrix2::identity(), &d));
+    let expected = DMatrix::from_row_slice(
+        6,
+        4,
+        &[
+            8.0, 0.0, 0.0, 0.0, //
+            9.0, 0.0, 0.0, 0.0, //
+            0.0, 1.0, 0.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0, //
+            0.0, 0.0, 0.0, 8.0, //
+            0.0, 0.0, 0.0, 9.0,
+        ],
+    );
+    assert_eq!(m, expected);
+}
@@ -0,0 +1,258 @@
+use na::{
+    DMatrix, DVector, Dynamic, Matrix2, Matrix3x2, Matrix4x3, Normalization, RowDVector,
+    RowVector2, Vector2, Vector3, Welford,
+};
+
+#[test]
+fn covariance_diagonal_is_variance() {
+    let m = Matrix4x3::new(
+        1.0, 2.0, -1.0, //
+        4.0, 0.5, 3.0, //
+        2.0, 7.0, 0.0, //
+        -3.0, 1.0, 2.5,
+    );
+
+    let population = m.row_covariance(Normalization::Population);
+    let sample = m.row_covariance(Normalization::Sample);
+    assert_relative_eq!(
+        population.diagonal(),
+        m.row_variance_tr(),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        sample.diagonal(),
+        m.row_sample_variance_tr(),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(population, population.transpose(), epsilon = 1.0e-10);
+
+    let tr = m.transpose();
+    assert_relative_eq!(
+        tr.column_covariance(Normalization::Sample),
+        sample,
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        tr.column_correlation(),
+        m.row_correlation(),
+        epsilon = 1.0e-10
+    );
+
+    let corr = m.row_correlation();
+    assert_relative_eq!(corr.diagonal(), Vector3::repeat(1.0), epsilon = 1.0e-10);
+    assert!(corr.iter().all(|e: &f64| e.abs() <= 1.0 + 1.0e-10));
+}
+
+#[test]
+fn covariance_not_enough_observations() {
+    let m = RowVector2::new(1.0, 2.0);
+    assert_eq!(m.row_covariance(Normalization::Sample), Matrix2::zeros());
+    assert_eq!(m.row_sample_variance(), RowVector2::zeros());
+
+    let empty = DMatrix::<f64>::zeros(0, 3);
+    assert_eq!(
+        empty.row_covariance(Normalization::Population),
+        DMatrix::zeros(3, 3)
+    );
+}
+
+#[test]
+fn weighted_moments_with_equal_weights() {
+    let m = Matrix4x3::new(
+        1.0, 2.0, -1.0, //
+        4.0, 0.5, 3.0, //
+        2.0, 7.0, 0.0, //
+        -3.0, 1.0, 2.5,
+    );
+    let weights = DVector::repeat(4, 3.0);
+
+    assert_relative_eq!(
+        m.row_weighted_mean(&weights),
+        m.row_mean(),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        m.transpose().column_weighted_mean(&weights),
+        m.row_mean_tr(),
+        epsilon = 1.0e-10
+    );
+
+    for normalization in [Normalization::Population, Normalization::Sample].iter() {
+        assert_relative_eq!(
+            m.row_weighted_covariance(&weights, *normalization),
+            m.row_covariance(*normalization),
+            epsilon = 1.0e-10
+        );
+    }
+}
+
+#[test]
+fn weighted_mean_ignores_zero_weights() {
+    let m = Matrix3x2::new(1.0, 2.0, 100.0, 200.0, 3.0, 4.0);
+    let weights = Vector3::new(1.0, 0.0, 1.0);
+    assert_eq!(m.row_weighted_mean(&weights), RowVector2::new(2.0, 3.0));
+}
+
+#[test]
+#[should_panic]
+fn weighted_mean_dimension_mismatch() {
+    let m = DMatrix::<f64>::zeros(3, 2);
+    let _ = m.row_weighted_mean(&DVector::repeat(2, 1.0));
+}
+
+#[test]
+fn quantiles() {
+    let v = DVector::from_vec(vec![7.0, 1.0, 3.0, 5.0, 9.0]);
+    assert_eq!(v.median(), 5.0);
+    assert_eq!(v.quantile(0.0), 1.0);
+    assert_eq!(v.quantile(0.25), 3.0);
+    assert_eq!(v.quantile(0.375), 4.0);
+    assert_eq!(v.quantile(1.0), 9.0);
+
+    let single = DVector::from_element(1, 2.0);
+    assert_eq!(single.quantile(0.7), 2.0);
+
+    let m = Matrix3x2::new(1.0, 6.0, 3.0, 5.0, 2.0, 4.0);
+    assert_eq!(m.row_median(), m.transpose().column_median().transpose());
+    assert_eq!(m.row_quantile(1.0), RowVector2::new(3.0, 6.0));
+}
+
+#[test]
+#[should_panic]
+fn quantile_out_of_range() {
+    let _ = DVector::from_element(3, 1.0).quantile(1.5);
+}
+
+#[test]
+#[should_panic]
+fn quantile_nan() {
+    let _ = DVector::from_vec(vec![1.0, f64::NAN, 2.0]).median();
+}
+
+#[test]
+fn standardized() {
+    let m = Matrix4x3::new(
+        1.0, 2.0, -1.0, //
+        4.0, 0.5, 3.0, //
+        2.0, 7.0, 0.0, //
+        -3.0, 1.0, 2.5,
+    );
+
+    let z = m.row_standardized(Normalization::Sample);
+    assert_relative_eq!(z.row_mean(), na::RowVector3::zeros(), epsilon = 1.0e-10);
+    assert_relative_eq!(
+        z.row_sample_variance(),
+        na::RowVector3::repeat(1.0),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        m.transpose().column_standardized(Normalization::Sample),
+        z.transpose(),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn welford_matches_batch_statistics() {
+    // The large offset makes the `E[x²] - E[x]²` formula of `.column_variance` inaccurate, so the
+    // reference variance is computed on the data without the offset.
+    let centered = DMatrix::from_fn(5, 40, |i, j| ((i * 7 + j * 13) % 11) as f64 * 0.5);
+    let data = centered.add_scalar(1.0e6);
+
+    let mut all = Welford::new_generic(Dynamic::new(5));
+    let mut left = Welford::new_generic(Dynamic::new(5));
+    let mut right = Welford::new_generic(Dynamic::new(5));
+
+    for (j, column) in data.column_iter().enumerate() {
+        all.push(&column);
+
+        if j < 15 {
+            left.push(&column);
+        } else {
+            right.push(&column);
+        }
+    }
+
+    assert_eq!(all.count(), 40);
+    assert_relative_eq!(*all.mean(), data.column_mean(), epsilon = 1.0e-7);
+    assert_relative_eq!(
+        all.variance(Normalization::Sample),
+        centered.column_sample_variance(),
+        epsilon = 1.0e-7
+    );
+
+    left.merge(&right);
+    assert_eq!(left.count(), 40);
+    assert_relative_eq!(*left.mean(), *all.mean(), epsilon = 1.0e-7);
+    assert_relative_eq!(
+        left.variance(Normalization::Population),
+        all.variance(Normalization::Population),
+        epsilon = 1.0e-7
+    );
+
+    let mut empty = Welford::new_generic(Dynamic::new(5));
+    empty.merge(&all);
+    assert_eq!(empty, all);
+}
+
+#[test]
+fn welford_empty() {
+    let stats = Welford::<f64, na::U3>::default();
+    assert_eq!(stats.count(), 0);
+    assert_eq!(stats.variance(Normalization::Population), Vector3::zeros());
+}
+
+#[test]
+fn fold_rows_and_columns() {
+    let m = Matrix3x2::new(1, 2, 3, 4, 5, 6);
+    assert_eq!(m.fold_rows(0, |acc, e| acc + e), m.row_sum());
+    assert_eq!(m.fold_columns(0, |acc, e| acc + e), m.column_sum());
+
+    // The accumulator does not need to have the type of the components.
+    let any_even = m.fold_columns(false, |acc, e| acc || e % 2 == 0);
+    assert_eq!(any_even, Vector3::new(true, true, true));
+
+    let empty = DMatrix::<i32>::zeros(0, 2);
+    assert_eq!(
+        empty.fold_rows(7, |acc, e| acc + e),
+        RowDVector::repeat(2, 7)
+    );
+    assert_eq!(empty.fold_columns(7, |acc, e| acc + e).len(), 0);
+}
+
+#[test]
+fn row_and_column_extrema() {
+    let m = Matrix3x2::new(
+        1.0, 6.0, //
+        -3.0, 5.0, //
+        2.0, 9.0,
+    );
+
+    assert_eq!(m.row_max(), RowVector2::new(2.0, 9.0));
+    assert_eq!(m.row_min(), RowVector2::new(-3.0, 5.0));
+    assert_eq!(m.column_max(), Vector3::new(6.0, 5.0, 9.0));
+    assert_eq!(m.column_min(), Vector3::new(1.0, -3.0, 2.0));
+
+    let (indices, values) = m.row_argmin();
+    assert_eq!(indices, RowVector2::new(1, 1));
+    assert_eq!(values, m.row_min());
+
+    let (indices, values) = m.transpose().column_argmax();
+    assert_eq!(indices, Vector2::new(2, 2));
+    assert_eq!(values, m.row_max().transpose());
+
+    // Ties are resolved to the first index, as for vectors.
+    let ties = Matrix2::new(1, 1, 0, 0);
+    assert_eq!(ties.column_argmax().0, Vector2::new(0, 0));
+    assert_eq!(ties.row_argmin().0, RowVector2::new(1, 1));
+
+    let empty = DMatrix::<f64>::zeros(0, 3);
+    assert_eq!(empty.row_max(), RowDVector::zeros(3));
+    assert_eq!(empty.column_argmax().0.len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn row_argmax_empty_columns() {
+    let _ = DMatrix::<f64>::zeros(0, 3).row_argmax();
+}
@@ -0,0 +1,54 @@
+use na::{DMatrix, DVector, LowerTriangular, Matrix3, UpperTriangular, Vector3};
+
+#[test]
+fn triangular_construction() {
+    let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+
+    let upper = UpperTriangular::new(m);
+    assert_eq!(*upper, m.upper_triangle());
+    assert_eq!(m.to_upper_triangular(), upper);
+    assert_eq!(Matrix3::from(upper), m.upper_triangle());
+
+    let lower = LowerTriangular::from(m);
+    assert_eq!(*lower, m.lower_triangle());
+    assert_eq!(m.to_lower_triangular(), lower);
+    assert_eq!(lower.into_inner(), m.lower_triangle());
+
+    assert_eq!(upper.transpose(), m.transpose().to_lower_triangular());
+    assert_eq!(upper.determinant(), 45.0);
+    assert_eq!(lower.determinant(), 45.0);
+
+    // The components outside of the triangle are never read.
+    let unchecked = UpperTriangular::new_unchecked(m);
+    assert_eq!(unchecked.determinant(), 45.0);
+    let b = Vector3::new(1.0, 2.0, 3.0);
+    assert_eq!(unchecked.solve(&b), upper.solve(&b));
+    assert_eq!(&unchecked * &b, &upper * &b);
+}
+
+#[test]
+fn triangular_solve_and_mul() {
+    let m = DMatrix::from_fn(6, 6, |i, j| {
+        ((i * 6 + j) as f64).cos() + if i == j { 4.0 } else { 0.0 }
+    });
+    let b = DMatrix::from_fn(6, 2, |i, j| (i + j) as f64);
+
+    let upper = m.to_upper_triangular();
+    let lower = m.to_lower_triangular();
+
+    let x = upper.solve(&b).unwrap();
+    assert_relative_eq!(&upper * &x, b, epsilon = 1.0e-10);
+    assert_relative_eq!(&*upper * &x, b, epsilon = 1.0e-10);
+
+    let mut x = b.clone();
+    assert!(lower.solve_mut(&mut x));
+    assert_relative_eq!(&lower * &x, b, epsilon = 1.0e-10);
+
+    let inv = upper.try_inverse().unwrap();
+    assert_relative_eq!(&*inv * &*upper, DMatrix::identity(6, 6), epsilon = 1.0e-10);
+    assert_eq!(*inv, inv.upper_triangle());
+
+    let singular = UpperTriangular::new(DMatrix::from_diagonal(&DVector::from_vec(vec![1.0, 0.0])));
+    assert!(singular.try_inverse().is_none());
+    assert!(singular.solve(&DVector::from_vec(vec![1.0, 1.0])).is_none());
+}
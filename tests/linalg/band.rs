@@ -0,0 +1,123 @@
+use na::{BandMatrix, Complex, DMatrix, DVector};
+
+fn band_dmatrix(n: usize, kl: usize, ku: usize) -> DMatrix<f64> {
+    DMatrix::from_fn(n, n, |i, j| {
+        if i > j + kl || j > i + ku {
+            0.0
+        } else if i == j {
+            (kl + ku + 2) as f64
+        } else {
+            ((i * n + j) as f64).sin()
+        }
+    })
+}
+
+#[test]
+fn band_matrix_conversion_and_gemv() {
+    let m = DMatrix::from_fn(6, 5, |i, j| {
+        if i <= j + 2 && j <= i + 1 {
+            (i * 5 + j + 1) as f64
+        } else {
+            0.0
+        }
+    });
+
+    let band = BandMatrix::from(&m);
+    assert_eq!(band.lower_bandwidth(), 2);
+    assert_eq!(band.upper_bandwidth(), 1);
+    assert_eq!(band.shape(), (6, 5));
+    assert_eq!(band.as_slice().len(), 4 * 5);
+    assert_eq!(band.get(0, 3), None);
+    assert_eq!(band.get(3, 1), Some(&17.0));
+    assert_eq!(band.to_matrix(), m);
+    assert_eq!(DMatrix::from(band.clone()), m);
+
+    let x = DVector::from_fn(5, |i, _| i as f64 - 1.0);
+    let mut y = DVector::from_element(6, 1.0);
+    y.gemv_band(2.0, &band, &x, 3.0);
+    assert_eq!(y, &m * &x * 2.0 + DVector::from_element(6, 3.0));
+    assert_eq!(&band * &x, &m * &x);
+
+    let rhs = DMatrix::from_fn(5, 3, |i, j| (i + j) as f64);
+    assert_eq!(&band * &rhs, &m * &rhs);
+
+    // Components outside of the band are dropped.
+    let narrow = BandMatrix::from_matrix(&m, 0, 1);
+    let mut expected = m.upper_triangle();
+    expected.fill_upper_triangle(0.0, 2);
+    assert_eq!(narrow.to_matrix(), expected);
+}
+
+#[test]
+fn band_lu() {
+    for &(n, kl, ku) in [(1, 0, 0), (7, 1, 1), (10, 2, 1), (10, 1, 3), (12, 3, 0)].iter() {
+        // Small diagonal values force row interchanges.
+        let mut m = band_dmatrix(n, kl, ku);
+        for i in (0..n).step_by(2) {
+            m[(i, i)] = 0.1;
+        }
+
+        let lu = BandMatrix::from_matrix(&m, kl, ku).lu();
+        let b = DMatrix::from_fn(n, 2, |i, j| (i * 2 + j) as f64);
+        let x = lu.solve(&b).unwrap();
+
+        assert_relative_eq!(&m * x, b, epsilon = 1.0e-8);
+        assert_relative_eq!(lu.determinant(), m.determinant(), max_relative = 1.0e-8);
+    }
+
+    let singular = BandMatrix::tridiagonal(&[1.0, 0.0], &[1.0, 1.0, 0.0], &[1.0, 0.0]);
+    let lu = singular.lu();
+    assert!(!lu.is_invertible());
+    assert!(lu.solve(&DVector::from_element(3, 1.0)).is_none());
+}
+
+#[test]
+fn band_cholesky() {
+    let n = 9;
+    let m = band_dmatrix(n, 2, 2);
+    let m = (&m + m.transpose()) * 0.5;
+    let b = DVector::from_fn(n, |i, _| i as f64);
+
+    // Only the lower band is read.
+    let band = BandMatrix::from_matrix(&m, 2, 0);
+    let chol = band.cholesky().unwrap();
+    let l = chol.l().to_matrix();
+
+    assert_eq!(chol.l().lower_bandwidth(), 2);
+    assert_relative_eq!(&l * l.transpose(), m, epsilon = 1.0e-10);
+    assert_relative_eq!(&m * chol.solve(&b), b, epsilon = 1.0e-10);
+    assert_relative_eq!(chol.determinant(), m.determinant(), max_relative = 1.0e-10);
+
+    let indefinite = BandMatrix::tridiagonal(&[2.0], &[1.0, 1.0], &[2.0]);
+    assert!(indefinite.cholesky().is_none());
+
+    let hermitian = BandMatrix::tridiagonal(
+        &[Complex::new(1.0, 1.0)],
+        &[Complex::new(4.0, 0.0), Complex::new(5.0, 0.0)],
+        &[Complex::new(1.0, -1.0)],
+    );
+    let dense = hermitian.to_matrix();
+    let b = DVector::from_vec(vec![Complex::new(1.0, 2.0), Complex::new(-1.0, 0.5)]);
+    let x = hermitian.cholesky().unwrap().solve(&b);
+    assert_relative_eq!(dense * x, b, epsilon = 1.0e-10);
+}
+
+#[test]
+fn band_thomas() {
+    let n = 8;
+    let sub: Vec<f64> = (0..n - 1).map(|i| 1.0 + i as f64 * 0.1).collect();
+    let diag = vec![4.0; n];
+    let sup: Vec<f64> = (0..n - 1).map(|i| -1.0 + i as f64 * 0.2).collect();
+    let a = BandMatrix::tridiagonal(&sub, &diag, &sup);
+    let m = a.to_matrix();
+
+    let b = DMatrix::from_fn(n, 3, |i, j| (i as f64) - (j as f64));
+    let x = a.solve_tridiagonal(&b).unwrap();
+    assert_relative_eq!(&m * &x, b, epsilon = 1.0e-10);
+    assert_relative_eq!(x, a.clone().lu().solve(&b).unwrap(), epsilon = 1.0e-10);
+
+    let zero_pivot = BandMatrix::tridiagonal(&[1.0], &[0.0, 1.0], &[1.0]);
+    let mut b = DVector::from_element(2, 1.0);
+    assert!(!zero_pivot.solve_tridiagonal_mut(&mut b));
+    assert_eq!(b, DVector::from_element(2, 1.0));
+}
@@ -11,6 +11,44 @@ fn cholesky_with_substitute() {
     assert!(na::Cholesky::new_with_substitute(m, 1e-8).is_some());
 }
 
+#[test]
+fn cholesky_blocked() {
+    // Large enough to use the blocked algorithm.
+    let m = na::DMatrix::<f64>::new_random(150, 150);
+    let m = &m * m.transpose() + na::DMatrix::identity(150, 150);
+    let chol = m.clone().cholesky().unwrap();
+    let l = chol.l();
+
+    assert_relative_eq!(&l * l.transpose(), m, epsilon = 1.0e-9);
+
+    let mut indefinite = m;
+    indefinite[(120, 120)] = -1.0e3;
+    assert!(indefinite.cholesky().is_none());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn cholesky_blocked_rayon() {
+    // Large enough for the trailing updates to be split between several threads.
+    let m = na::DMatrix::<f64>::new_random(300, 300);
+    let m = &m * m.transpose() + na::DMatrix::identity(300, 300);
+    let b = na::DMatrix::<f64>::new_random(300, 3);
+    let pool = |n| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(n)
+            .build()
+            .unwrap()
+    };
+
+    let sequential = pool(1).install(|| m.clone().cholesky().unwrap());
+    let parallel = pool(4).install(|| m.clone().cholesky().unwrap());
+    let l = parallel.l();
+
+    assert_relative_eq!(l, sequential.l(), epsilon = 1.0e-10);
+    assert_relative_eq!(&l * l.transpose(), m, epsilon = 1.0e-9);
+    assert_relative_eq!(parallel.solve(&b), sequential.solve(&b), epsilon = 1.0e-8);
+}
+
 macro_rules! gen_tests(
     ($module: ident, $scalar: ty) => {
         mod $module {
@@ -1,4 +1,4 @@
-use na::{DVector, Vector2, Vector3, Vector4, Vector5};
+use na::{DMatrix, DVector, Matrix2, Matrix3, Matrix4, Vector2, Vector3, Vector4, Vector5};
 use std::panic;
 
 //
@@ -46,6 +46,61 @@ fn convolve_same_check() {
 
 // >>> convolve([1,2,3,4],[1,2],"full")
 // array([ 1, 4,  7, 10, 8])
+// >>> convolve([1,2,3,4,5,6],[1,1,1,1],"same")
+// array([ 3,  6, 10, 14, 18, 15])
+// >>> convolve([1,2,3,4,5,6],[1,1,1,1,1],"same")
+// array([ 6, 10, 15, 20, 18, 15])
+#[test]
+fn convolve_same_matches_2d() {
+    let a = DVector::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_relative_eq!(
+        a.convolve_same(DVector::from_element(4, 1.0)),
+        DVector::from_vec(vec![3.0, 6.0, 10.0, 14.0, 18.0, 15.0]),
+        epsilon = 1.0e-7
+    );
+    assert_relative_eq!(
+        a.convolve_same(DVector::from_element(5, 1.0)),
+        DVector::from_vec(vec![6.0, 10.0, 15.0, 20.0, 18.0, 15.0]),
+        epsilon = 1.0e-7
+    );
+
+    for ker in 1..=7 {
+        let a = DVector::<f64>::new_random(9);
+        let k = DVector::<f64>::new_random(ker);
+        let same = a.convolve_same(k.clone());
+        let a_col = DMatrix::from_column_slice(9, 1, a.as_slice());
+        let k_col = DMatrix::from_column_slice(ker, 1, k.as_slice());
+
+        assert_relative_eq!(
+            a_col.convolve_same_2d(&k_col).column(0).into_owned(),
+            same,
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(
+            a_col
+                .transpose()
+                .convolve_same_2d(&k_col.transpose())
+                .row(0)
+                .transpose(),
+            same,
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(
+            a_col
+                .convolve_separable_same_2d(&k, &DVector::from_element(1, 1.0))
+                .column(0)
+                .into_owned(),
+            same,
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(
+            a_col.correlate_same_2d(&k_col).column(0).into_owned(),
+            a.convolve_same(DVector::from_iterator(ker, k.iter().rev().cloned())),
+            epsilon = 1.0e-10
+        );
+    }
+}
+
 #[test]
 fn convolve_full_check() {
     // Static Tests
@@ -117,3 +172,262 @@ fn convolve_valid_check() {
     })
     .is_err());
 }
+
+/// Reference implementation of the full 2D convolution.
+fn convolve_full_2d_naive(a: &DMatrix<f64>, k: &DMatrix<f64>) -> DMatrix<f64> {
+    let mut res = DMatrix::zeros(a.nrows() + k.nrows() - 1, a.ncols() + k.ncols() - 1);
+
+    for (i, j) in (0..a.nrows()).flat_map(|i| (0..a.ncols()).map(move |j| (i, j))) {
+        for (p, q) in (0..k.nrows()).flat_map(|p| (0..k.ncols()).map(move |q| (p, q))) {
+            res[(i + p, j + q)] += a[(i, j)] * k[(p, q)];
+        }
+    }
+
+    res
+}
+
+// >>> from scipy.signal import convolve2d
+// >>> convolve2d([[1,2,3],[4,5,6],[7,8,9]], [[1,0],[0,-1]], "full")
+// array([[ 1,  2,  3,  0],
+//        [ 4,  4,  4, -3],
+//        [ 7,  4,  4, -6],
+//        [ 0, -7, -8, -9]])
+#[test]
+fn convolve_2d_check() {
+    let a = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let k = Matrix2::new(1.0, 0.0, 0.0, -1.0);
+
+    #[rustfmt::skip]
+    let full = Matrix4::new(
+        1.0,  2.0,  3.0,  0.0,
+        4.0,  4.0,  4.0, -3.0,
+        7.0,  4.0,  4.0, -6.0,
+        0.0, -7.0, -8.0, -9.0,
+    );
+
+    // Static Tests
+    assert_relative_eq!(a.convolve_full_2d(&k), full, epsilon = 1.0e-7);
+    assert_relative_eq!(
+        a.convolve_valid_2d(&k),
+        Matrix2::new(4.0, 4.0, 4.0, 4.0),
+        epsilon = 1.0e-7
+    );
+    assert_relative_eq!(
+        a.convolve_same_2d(&k),
+        Matrix3::new(1.0, 2.0, 3.0, 4.0, 4.0, 4.0, 7.0, 4.0, 4.0),
+        epsilon = 1.0e-7
+    );
+
+    // Dynamic Tests
+    let a_d = DMatrix::from_iterator(3, 3, a.iter().cloned());
+    let k_d = DMatrix::from_iterator(2, 2, k.iter().cloned());
+    let full_d = DMatrix::from_iterator(4, 4, full.iter().cloned());
+    assert_relative_eq!(a_d.convolve_full_2d(&k_d), full_d, epsilon = 1.0e-7);
+    assert_relative_eq!(
+        a_d.convolve_valid_2d(&k_d),
+        full_d.slice((1, 1), (2, 2)).into_owned(),
+        epsilon = 1.0e-7
+    );
+    assert_relative_eq!(
+        a_d.convolve_same_2d(&k_d),
+        full_d.slice((0, 0), (3, 3)).into_owned(),
+        epsilon = 1.0e-7
+    );
+
+    // Panic Tests
+    assert!(panic::catch_unwind(|| {
+        let _ = k_d.convolve_full_2d(&a_d);
+    })
+    .is_err());
+
+    assert!(panic::catch_unwind(|| {
+        let _ = a_d.convolve_same_2d(&DMatrix::<f64>::zeros(0, 2));
+    })
+    .is_err());
+
+    assert!(panic::catch_unwind(|| {
+        let _ = DMatrix::<f64>::zeros(4, 1).convolve_valid_2d(&k_d);
+    })
+    .is_err());
+}
+
+// >>> from scipy.signal import correlate2d
+// >>> correlate2d([[1,2,3],[4,5,6],[7,8,9]], [[1,0],[0,-1]], "same")
+// array([[-1, -2, -3],
+//        [-4, -4, -4],
+//        [-7, -4, -4]])
+#[test]
+fn correlate_2d_check() {
+    let a = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let k = Matrix2::new(1.0, 0.0, 0.0, -1.0);
+
+    assert_relative_eq!(a.correlate_full_2d(&k), -a.convolve_full_2d(&k));
+    assert_relative_eq!(
+        a.correlate_same_2d(&k),
+        Matrix3::new(-1.0, -2.0, -3.0, -4.0, -4.0, -4.0, -7.0, -4.0, -4.0),
+        epsilon = 1.0e-7
+    );
+    assert_relative_eq!(
+        a.correlate_valid_2d(&k),
+        Matrix2::new(-4.0, -4.0, -4.0, -4.0),
+        epsilon = 1.0e-7
+    );
+
+    // Correlating with a non-symmetric kernel is convolving with the flipped kernel.
+    let a = DMatrix::<f64>::new_random(7, 6);
+    let k = DMatrix::<f64>::new_random(3, 4);
+    let flipped = DMatrix::from_fn(3, 4, |i, j| k[(2 - i, 3 - j)]);
+
+    assert_relative_eq!(
+        a.correlate_full_2d(&k),
+        a.convolve_full_2d(&flipped),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        a.correlate_same_2d(&k),
+        a.convolve_same_2d(&flipped),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        a.correlate_valid_2d(&k),
+        a.convolve_valid_2d(&flipped),
+        epsilon = 1.0e-10
+    );
+}
+
+#[test]
+fn convolve_2d_matches_direct_implementation() {
+    // Small kernels use the direct summation, large ones use FFTs.
+    for &(nrows, ncols, krows, kcols) in &[
+        (9, 7, 3, 2),
+        (40, 40, 3, 3),
+        (40, 37, 20, 25),
+        (64, 60, 31, 33),
+    ] {
+        let a = DMatrix::<f64>::new_random(nrows, ncols);
+        let k = DMatrix::<f64>::new_random(krows, kcols);
+        let full = convolve_full_2d_naive(&a, &k);
+
+        assert_relative_eq!(a.convolve_full_2d(&k), full, epsilon = 1.0e-9);
+        assert_relative_eq!(
+            a.convolve_valid_2d(&k),
+            full.slice(
+                (krows - 1, kcols - 1),
+                (nrows - krows + 1, ncols - kcols + 1)
+            )
+            .into_owned(),
+            epsilon = 1.0e-9
+        );
+        assert_relative_eq!(
+            a.convolve_same_2d(&k),
+            full.slice(((krows - 1) / 2, (kcols - 1) / 2), (nrows, ncols))
+                .into_owned(),
+            epsilon = 1.0e-9
+        );
+    }
+}
+
+#[test]
+fn convolve_separable_2d_check() {
+    let a = DMatrix::<f64>::new_random(11, 8);
+    let col_kernel = DVector::<f64>::new_random(4);
+    let row_kernel = DVector::<f64>::new_random(3);
+    let k = &col_kernel * row_kernel.transpose();
+
+    assert_relative_eq!(
+        a.convolve_separable_full_2d(&col_kernel, &row_kernel),
+        a.convolve_full_2d(&k),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        a.convolve_separable_valid_2d(&col_kernel, &row_kernel),
+        a.convolve_valid_2d(&k),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        a.convolve_separable_same_2d(&col_kernel, &row_kernel),
+        a.convolve_same_2d(&k),
+        epsilon = 1.0e-10
+    );
+
+    // Static Tests
+    let a = Matrix4::from_fn(|i, j| (i * 4 + j) as f64);
+    let col_kernel = Vector2::new(1.0, -1.0);
+    let row_kernel = Vector3::new(1.0, 2.0, 1.0);
+    let k = col_kernel * row_kernel.transpose();
+
+    assert_relative_eq!(
+        a.convolve_separable_valid_2d(&col_kernel, &row_kernel),
+        a.convolve_valid_2d(&k),
+        epsilon = 1.0e-10
+    );
+
+    assert!(panic::catch_unwind(|| {
+        let _ = DMatrix::<f64>::zeros(2, 2)
+            .convolve_separable_full_2d(&DVector::zeros(3), &DVector::zeros(1));
+    })
+    .is_err());
+}
+
+// >>> convolve([1,2,3,4,5],[1,2,3,4],"full")
+// array([ 1,  4, 10, 20, 30, 34, 31, 20])
+#[test]
+fn convolve_fft_check() {
+    // Static Tests
+    let a = Vector5::new(1.0, 2.0, 3.0, 4.0, 5.0);
+    let k = Vector4::new(1.0, 2.0, 3.0, 4.0);
+
+    assert_relative_eq!(
+        a.convolve_full_fft(k),
+        a.convolve_full(k),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        a.convolve_same_fft(k),
+        a.convolve_same(k),
+        epsilon = 1.0e-10
+    );
+
+    // Dynamic Tests, with odd and even kernel lengths.
+    for &(len, ker) in &[
+        (1, 1),
+        (8, 1),
+        (8, 2),
+        (8, 3),
+        (8, 4),
+        (8, 5),
+        (8, 6),
+        (8, 8),
+        (64, 2),
+        (101, 37),
+        (300, 149),
+        (300, 150),
+    ] {
+        let a = DVector::<f64>::new_random(len);
+        let k = DVector::<f64>::new_random(ker);
+
+        assert_relative_eq!(
+            a.convolve_full_fft(k.clone()),
+            a.convolve_full(k.clone()),
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(
+            a.convolve_same_fft(k.clone()),
+            a.convolve_same(k.clone()),
+            epsilon = 1.0e-10
+        );
+    }
+
+    // Panic Tests
+    assert!(panic::catch_unwind(|| {
+        let _ = DVector::from_vec(vec![1.0, 2.0])
+            .convolve_full_fft(DVector::from_vec(vec![1.0, 2.0, 3.0, 4.0]));
+    })
+    .is_err());
+
+    assert!(panic::catch_unwind(|| {
+        let _ = DVector::from_vec(vec![1.0, 2.0, 3.0, 4.0])
+            .convolve_same_fft(DVector::<f64>::from_vec(vec![]));
+    })
+    .is_err());
+}
@@ -1,4 +1,4 @@
-use na::DMatrix;
+use na::{DMatrix, Matrix2, Matrix3, SimdValue, SymmetricEigen, Vector2, Vector3};
 
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
@@ -201,3 +201,151 @@ fn symmetric_eigen_singular_24x24() {
 //
 //     relative_eq!(eig.eigenvectors, mv, epsilon = 1.0e-5)
 // }
+
+fn sorted(mut vals: Vec<f64>) -> Vec<f64> {
+    vals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    vals
+}
+
+#[test]
+fn symmetric_eigen_2x2_nearly_repeated() {
+    let rot = na::Rotation2::new(0.3).into_inner();
+    let m = rot * Matrix2::new(1.0, 0.0, 0.0, 1.0 + 1.0e-12) * rot.transpose();
+
+    let eig = m.symmetric_eigen();
+    let vals = sorted(eig.eigenvalues.iter().cloned().collect());
+
+    assert_relative_eq!(vals[0], 1.0, epsilon = 1.0e-15);
+    assert_relative_eq!(vals[1], 1.0 + 1.0e-12, epsilon = 1.0e-15);
+    assert_relative_eq!(
+        eig.eigenvectors.transpose() * eig.eigenvectors,
+        Matrix2::identity(),
+        epsilon = 1.0e-15
+    );
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-15);
+}
+
+#[test]
+fn symmetric_eigen_3x3_nearly_repeated() {
+    let rot = na::Rotation3::new(Vector3::new(0.3, -1.2, 0.7)).into_inner();
+
+    for &(a, b, c) in &[
+        (1.0, 1.0 + 1.0e-12, 3.0),
+        (2.0, 2.0, 2.0 + 1.0e-10),
+        (-1.0, 1.0e-8, 1.0e-8 + 1.0e-14),
+    ] {
+        let m = rot * Matrix3::from_diagonal(&Vector3::new(a, b, c)) * rot.transpose();
+        let eig = m.symmetric_eigen();
+        let vals = sorted(eig.eigenvalues.iter().cloned().collect());
+
+        assert_relative_eq!(vals[0], a, epsilon = 1.0e-14);
+        assert_relative_eq!(vals[1], b, epsilon = 1.0e-14);
+        assert_relative_eq!(vals[2], c, epsilon = 1.0e-14);
+        assert_relative_eq!(
+            eig.eigenvectors.transpose() * eig.eigenvectors,
+            Matrix3::identity(),
+            epsilon = 1.0e-14
+        );
+        assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-14);
+    }
+}
+
+#[test]
+fn symmetric_eigen_3x3_matches_general_algorithm() {
+    for _ in 0..100 {
+        let m = Matrix3::<f64>::new_random();
+        let m = m + m.transpose();
+        let dm = DMatrix::from_column_slice(3, 3, m.as_slice());
+
+        let vals = sorted(m.symmetric_eigenvalues().iter().cloned().collect());
+        let expected = sorted(dm.symmetric_eigenvalues().iter().cloned().collect());
+
+        for (val, expected) in vals.iter().zip(expected.iter()) {
+            assert_relative_eq!(val, expected, epsilon = 1.0e-12);
+        }
+    }
+}
+
+#[test]
+fn symmetric_eigen_3x3_simd() {
+    use simba::simd::WideF64x4;
+
+    let ms: Vec<_> = (0..4)
+        .map(|_| {
+            let m = Matrix3::<f64>::new_random();
+            m + m.transpose()
+        })
+        .collect();
+    let packed = Matrix3::from_fn(|i, j| {
+        WideF64x4::from([ms[0][(i, j)], ms[1][(i, j)], ms[2][(i, j)], ms[3][(i, j)]])
+    });
+
+    let eig = packed.simd_symmetric_eigen();
+
+    for (lane, m) in ms.iter().enumerate() {
+        let vals = eig.eigenvalues.map(|e| e.extract(lane));
+        let vecs = eig.eigenvectors.map(|e| e.extract(lane));
+
+        assert_relative_eq!(
+            vecs.transpose() * vecs,
+            Matrix3::identity(),
+            epsilon = 1.0e-12
+        );
+        assert_relative_eq!(
+            vecs * Matrix3::from_diagonal(&vals) * vecs.transpose(),
+            m,
+            epsilon = 1.0e-12
+        );
+    }
+
+    let eig2 = Matrix2::new(2.0, 1.0, 1.0, 2.0).simd_symmetric_eigen();
+    assert_relative_eq!(eig2.eigenvalues, Vector2::new(1.0, 3.0), epsilon = 1.0e-15);
+}
+
+#[test]
+fn symmetric_eigen_nan_terminates() {
+    let m2 = Matrix2::new(f64::NAN, 1.0, 1.0, 2.0);
+    let m3 = Matrix3::new(f64::NAN, 1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 3.0);
+
+    assert!(m2.symmetric_eigen().eigenvalues[0].is_nan());
+    let eig2 = SymmetricEigen::try_new(m2, 1.0e-15, 0).unwrap();
+    assert!(eig2.eigenvalues[0].is_nan());
+
+    for eig3 in &[
+        m3.symmetric_eigen(),
+        SymmetricEigen::try_new(m3, 1.0e-15, 0).unwrap(),
+    ] {
+        assert!(eig3.eigenvalues[0].is_nan());
+        assert_relative_eq!(eig3.eigenvalues[1], 2.0, epsilon = 1.0e-12);
+        assert_relative_eq!(eig3.eigenvalues[2], 3.0, epsilon = 1.0e-12);
+    }
+
+    // The Jacobi iterations must also stop with a zero tolerance.
+    let m = Matrix3::new(2.0, 1.0, 0.5, 1.0, 3.0, 0.25, 0.5, 0.25, 4.0);
+    let eig = SymmetricEigen::try_new(m, 0.0, 0).unwrap();
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-12);
+}
+
+#[test]
+fn symmetric_eigen_3x3_simd_nan_lane() {
+    use simba::simd::WideF64x4;
+
+    let m = Matrix3::new(2.0, 1.0, 0.5, 1.0, 3.0, 0.25, 0.5, 0.25, 4.0);
+    let packed = Matrix3::from_fn(|i, j| {
+        let nan = if (i, j) == (0, 0) {
+            f64::NAN
+        } else {
+            m[(i, j)]
+        };
+        WideF64x4::from([m[(i, j)], nan, m[(i, j)], m[(i, j)]])
+    });
+
+    let eig = packed.simd_symmetric_eigen();
+    let expected = m.simd_symmetric_eigen();
+
+    for lane in [0, 2, 3].iter().copied() {
+        let vals = eig.eigenvalues.map(|e| e.extract(lane));
+        assert_relative_eq!(vals, expected.eigenvalues, epsilon = 1.0e-12);
+    }
+    assert!(eig.eigenvalues.iter().any(|e| e.extract(1).is_nan()));
+}
@@ -0,0 +1,220 @@
+use na::{DMatrix, Matrix2x3, Matrix3, Matrix3x4, Matrix4};
+use num_rational::Ratio;
+
+#[test]
+#[rustfmt::skip]
+fn bareiss_determinant_integer() {
+    let m = Matrix4::new(
+        2i64, -1,  0,  3,
+        4,     5, -2,  1,
+        -3,    0,  7,  2,
+        1,     8,  6, -4);
+
+    // Computed with floating-point LU on the same matrix.
+    let expected = m.map(|e| e as f64).determinant().round() as i64;
+    assert_eq!(m.bareiss_determinant(), expected);
+
+    // Requires a row interchange.
+    let m = Matrix3::new(
+        0i64, 1, 2,
+        1,    0, 3,
+        4,   -3, 8);
+    assert_eq!(m.bareiss_determinant(), -2);
+
+    let singular = Matrix3::new(
+        1i64, 2, 3,
+        4,    5, 6,
+        7,    8, 9);
+    assert_eq!(singular.bareiss_determinant(), 0);
+
+    assert_eq!(DMatrix::<i64>::zeros(0, 0).bareiss_determinant(), 1);
+}
+
+#[test]
+#[rustfmt::skip]
+fn bareiss_determinant_rational() {
+    let m = Matrix3::new(
+        1i64, 2, 0,
+        3,    1, 1,
+        0,    2, 5).map(|e| Ratio::new(e, 2));
+
+    assert_eq!(m.bareiss_determinant(), Ratio::new(-27, 8));
+}
+
+#[test]
+#[rustfmt::skip]
+fn exact_rank() {
+    let m = Matrix3x4::new(
+        1i64, 2, 3, 4,
+        2,    4, 6, 8,
+        0,    1, 1, 1);
+    assert_eq!(m.exact_rank(), 2);
+
+    let m = DMatrix::from_row_slice(3, 3, &[
+        0i64, 0, 1,
+        0,    0, 2,
+        0,    3, 0]);
+    assert_eq!(m.exact_rank(), 2);
+    assert_eq!(DMatrix::<i64>::zeros(3, 2).exact_rank(), 0);
+}
+
+#[test]
+#[rustfmt::skip]
+fn rref() {
+    let m = Matrix3x4::new(
+        1i64, 2, 1, -1,
+        2,    4, 0,  2,
+        1,    2, 2, -3).map(Ratio::from_integer);
+
+    let (rref, pivots) = m.rref();
+    let expected = Matrix3x4::new(
+        1i64, 2, 0,  1,
+        0,    0, 1, -2,
+        0,    0, 0,  0).map(Ratio::from_integer);
+
+    assert_eq!(rref, expected);
+    assert_eq!(pivots, vec![0, 2]);
+}
+
+#[test]
+#[rustfmt::skip]
+fn exact_inverse() {
+    let m = Matrix3::new(
+        2i64, 1, 0,
+        0,    3, 1,
+        1,    0, 4).map(Ratio::from_integer);
+
+    let inv = m.try_exact_inverse().unwrap();
+    assert_eq!(m * inv, Matrix3::identity());
+    assert_eq!(inv * m, Matrix3::identity());
+
+    let singular = Matrix3::new(
+        1i64, 2, 3,
+        2,    4, 6,
+        0,    1, 1).map(Ratio::from_integer);
+    assert!(singular.try_exact_inverse().is_none());
+}
+
+#[test]
+#[rustfmt::skip]
+fn hermite_normal_form() {
+    let a = Matrix3x4::new(
+        2i64,  3, 6,  2,
+        5,     6, 1,  6,
+        8,     3, 1,  1);
+
+    let hnf = a.hermite_normal_form();
+    assert_eq!(hnf.u * a, hnf.h);
+    assert_eq!(hnf.u.bareiss_determinant().abs(), 1);
+    assert_eq!(hnf.rank(), 3);
+
+    let expected = Matrix3x4::new(
+        1i64, 0, 50, -11,
+        0,    3, 28,  -2,
+        0,    0, 61, -13);
+    assert_eq!(hnf.h, expected);
+}
+
+#[test]
+#[rustfmt::skip]
+fn smith_normal_form() {
+    let a = Matrix3::new(
+        2i64, 4,  4,
+        -6,   6, 12,
+        10,  -4, -16);
+
+    let snf = a.smith_normal_form();
+    assert_eq!(snf.u * a * snf.v, snf.d);
+    assert_eq!(snf.u.bareiss_determinant().abs(), 1);
+    assert_eq!(snf.v.bareiss_determinant().abs(), 1);
+    assert_eq!(snf.d, Matrix3::from_diagonal(&na::Vector3::new(2, 6, 12)));
+
+    let a = Matrix2x3::new(
+        4i64, 6, 0,
+        6,    4, 2);
+    let snf = a.smith_normal_form();
+    assert_eq!(snf.u * a * snf.v, snf.d);
+    assert_eq!(snf.invariant_factors(), na::Vector2::new(2, 2));
+}
+
+#[test]
+fn normal_forms_regression_5x5() {
+    // Shrunk failure case recorded in `proptest-regressions/linalg/exact.txt`.
+    let a = DMatrix::from_vec(
+        5,
+        5,
+        vec![
+            -2i128, -2, 3, 1, -3, -7, 1, 2, -9, -5, 3, 6, 7, 7, 7, -6, 9, 3, 8, -5, 5, 0, -1, -8,
+            -2,
+        ],
+    );
+
+    let hnf = a.clone().hermite_normal_form();
+    assert_eq!(&hnf.u * &a, hnf.h);
+    assert_eq!(hnf.u.bareiss_determinant().abs(), 1);
+    assert_eq!(hnf.rank(), a.exact_rank());
+
+    let snf = a.clone().smith_normal_form();
+    assert_eq!(&snf.u * &a * &snf.v, snf.d);
+    assert_eq!(snf.u.bareiss_determinant().abs(), 1);
+    assert_eq!(snf.v.bareiss_determinant().abs(), 1);
+    // The unimodular factors used to reach entries around `1e13` on this input.
+    assert!(snf
+        .u
+        .iter()
+        .chain(snf.v.iter())
+        .all(|e| e.abs() < 1_000_000));
+
+    let factors = snf.invariant_factors();
+    for i in 1..factors.len() {
+        assert!(factors[i - 1] >= 0);
+        if factors[i - 1] == 0 {
+            assert_eq!(factors[i], 0);
+        } else {
+            assert_eq!(factors[i] % factors[i - 1], 0);
+        }
+    }
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    use na::DMatrix;
+    use proptest::prelude::*;
+
+    // NOTE: the entries of the unimodular factors can grow quickly, so use `i128`.
+    fn integer_matrix() -> impl Strategy<Value = DMatrix<i128>> {
+        (1usize..6, 1usize..6).prop_flat_map(|(nrows, ncols)| {
+            proptest::collection::vec(-9i128..10, nrows * ncols)
+                .prop_map(move |data| DMatrix::from_vec(nrows, ncols, data))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn hermite_normal_form(a in integer_matrix()) {
+            let hnf = a.clone().hermite_normal_form();
+            prop_assert_eq!(&hnf.u * &a, hnf.h.clone());
+            prop_assert_eq!(hnf.u.bareiss_determinant().abs(), 1);
+            prop_assert_eq!(hnf.rank(), a.exact_rank());
+        }
+
+        #[test]
+        fn smith_normal_form(a in integer_matrix()) {
+            let snf = a.clone().smith_normal_form();
+            prop_assert_eq!(&snf.u * &a * &snf.v, snf.d.clone());
+            prop_assert_eq!(snf.u.bareiss_determinant().abs(), 1);
+            prop_assert_eq!(snf.v.bareiss_determinant().abs(), 1);
+
+            let factors = snf.invariant_factors();
+            for i in 1..factors.len() {
+                prop_assert!(factors[i - 1] >= 0);
+
+                if factors[i - 1] == 0 {
+                    prop_assert_eq!(factors[i], 0);
+                } else {
+                    prop_assert_eq!(factors[i] % factors[i - 1], 0);
+                }
+            }
+        }
+    }
+}
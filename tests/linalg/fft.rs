@@ -0,0 +1,106 @@
+use na::linalg::fft::FftPlan;
+use na::{Complex, DVector, Vector4};
+use std::f64::consts::PI;
+
+/// Reference implementation of the discrete Fourier transform.
+fn dft(x: &DVector<Complex<f64>>, inverse: bool) -> DVector<Complex<f64>> {
+    let n = x.len();
+    let sign = if inverse { 1.0 } else { -1.0 };
+
+    DVector::from_fn(n, |k, _| {
+        x.iter()
+            .enumerate()
+            .map(|(j, e)| {
+                let angle = sign * 2.0 * PI * ((j * k) % n) as f64 / n as f64;
+                e * Complex::new(angle.cos(), angle.sin())
+            })
+            .sum()
+    })
+}
+
+fn random_signal(n: usize) -> DVector<Complex<f64>> {
+    DVector::<f64>::new_random(n).zip_map(&DVector::<f64>::new_random(n), |re, im| {
+        Complex::new(re - 0.5, im - 0.5)
+    })
+}
+
+// >>> numpy.fft.fft([1, 2, 3, 4])
+// array([10.+0.j, -2.+2.j, -2.+0.j, -2.-2.j])
+#[test]
+fn fft_check() {
+    let x = Vector4::new(1.0, 2.0, 3.0, 4.0).map(|e| Complex::new(e, 0.0));
+    let expected = Vector4::new(
+        Complex::new(10.0, 0.0),
+        Complex::new(-2.0, 2.0),
+        Complex::new(-2.0, 0.0),
+        Complex::new(-2.0, -2.0),
+    );
+
+    assert_relative_eq!(x.fft(), expected, epsilon = 1.0e-12);
+    assert_relative_eq!(expected.ifft(), x, epsilon = 1.0e-12);
+}
+
+#[test]
+fn fft_matches_dft() {
+    // Power-of-two, mixed-radix, prime and Bluestein lengths.
+    for &n in &[0, 1, 2, 3, 5, 6, 8, 12, 30, 31, 37, 64, 74, 97, 210, 256] {
+        let x = random_signal(n);
+        let n = n as f64;
+
+        assert_relative_eq!(x.fft(), dft(&x, false), epsilon = 1.0e-10 * n.max(1.0));
+        assert_relative_eq!(
+            x.ifft(),
+            dft(&x, true) / Complex::new(n.max(1.0), 0.0),
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(x.fft().ifft(), x, epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn fft_mut_on_slice() {
+    let mut x = random_signal(10);
+    let expected = x.rows(2, 6).fft();
+
+    x.rows_mut(2, 6).fft_mut();
+    assert_relative_eq!(x.rows(2, 6).into_owned(), expected, epsilon = 1.0e-12);
+
+    x.rows_mut(2, 6).ifft_mut();
+    assert_relative_eq!(x.rows(2, 6).fft(), expected, epsilon = 1.0e-12);
+}
+
+#[test]
+fn fft_plan() {
+    let plan = FftPlan::new(45);
+    let x = random_signal(45);
+    let mut buf: Vec<_> = x.iter().cloned().collect();
+
+    assert_eq!(plan.len(), 45);
+    plan.forward(&mut buf);
+    assert_relative_eq!(DVector::from_vec(buf.clone()), x.fft(), epsilon = 1.0e-12);
+    plan.inverse(&mut buf);
+    assert_relative_eq!(DVector::from_vec(buf), x, epsilon = 1.0e-12);
+}
+
+#[test]
+fn rfft_matches_fft() {
+    for &n in &[1, 2, 3, 4, 7, 10, 16, 41, 100] {
+        let x = DVector::<f64>::new_random(n);
+        let spectrum = x.rfft();
+        let expected = x.map(|e| Complex::new(e, 0.0)).fft();
+
+        assert_eq!(spectrum.len(), n / 2 + 1);
+        assert_relative_eq!(
+            spectrum,
+            expected.rows(0, n / 2 + 1).into_owned(),
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(spectrum.irfft(n), x, epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+#[should_panic]
+fn irfft_wrong_length() {
+    let _ = DVector::<Complex<f64>>::zeros(4).irfft(8);
+}
@@ -140,3 +140,47 @@ fn matrix5_try_inverse_scaled_identity() {
 
     assert_relative_eq!(a_inv, expected_inverse);
 }
+
+#[test]
+fn simd_try_inverse() {
+    use na::{Matrix6, SimdValue};
+    use simba::simd::{SimdBool, WideF64x4};
+
+    macro_rules! check_simd_inverse(
+        ($mat: ident) => {{
+            let mut ms: Vec<_> = (0..4).map(|_| $mat::<f64>::new_random()).collect();
+            // Make the third lane singular.
+            ms[2].row_mut(1).fill(0.0);
+
+            let packed = $mat::from_fn(|i, j| {
+                WideF64x4::from([ms[0][(i, j)], ms[1][(i, j)], ms[2][(i, j)], ms[3][(i, j)]])
+            });
+            let (inv, mask) = packed.simd_try_inverse();
+            assert_eq!(mask.bitmask(), 0b1011);
+
+            for (lane, m) in ms.iter().enumerate() {
+                let inv = inv.map(|e| e.extract(lane));
+
+                if lane == 2 {
+                    // The failed lanes are left untouched.
+                    assert_eq!(inv, *m);
+                } else {
+                    assert_relative_eq!(inv, m.try_inverse().unwrap(), epsilon = 1.0e-7);
+                }
+            }
+        }}
+    );
+
+    check_simd_inverse!(Matrix2);
+    check_simd_inverse!(Matrix3);
+    check_simd_inverse!(Matrix4);
+    check_simd_inverse!(Matrix6);
+
+    let mut m = Matrix1::new(WideF64x4::from([2.0, 0.0, -4.0, 0.5]));
+    let mask = m.simd_try_inverse_mut();
+    assert_eq!(mask.bitmask(), 0b1101);
+    assert_eq!(m[0].extract(0), 0.5);
+    assert_eq!(m[0].extract(1), 0.0);
+    assert_eq!(m[0].extract(2), -0.25);
+    assert_eq!(m[0].extract(3), 2.0);
+}
@@ -0,0 +1,146 @@
+use na::{Arnoldi, Complex, DMatrix, DVector, KrylovTarget, Lanczos, LinearOperator};
+use std::f64::consts::PI;
+
+/// The matrix-free 1D Laplacian with Dirichlet boundary conditions.
+struct Laplacian1D {
+    n: usize,
+}
+
+impl LinearOperator<f64> for Laplacian1D {
+    fn nrows(&self) -> usize {
+        self.n
+    }
+
+    fn ncols(&self) -> usize {
+        self.n
+    }
+
+    fn apply_to(&self, x: &DVector<f64>, out: &mut DVector<f64>) {
+        for i in 0..self.n {
+            let left = if i > 0 { x[i - 1] } else { 0.0 };
+            let right = if i + 1 < self.n { x[i + 1] } else { 0.0 };
+            out[i] = 2.0 * x[i] - left - right;
+        }
+    }
+}
+
+fn laplacian_eigenvalue(n: usize, k: usize) -> f64 {
+    2.0 - 2.0 * (k as f64 * PI / (n as f64 + 1.0)).cos()
+}
+
+#[test]
+fn matrix_linear_operator() {
+    let m = DMatrix::from_fn(3, 4, |i, j| (i * 4 + j) as f64);
+    let x = DVector::from_fn(4, |i, _| i as f64 + 1.0);
+
+    assert_eq!(LinearOperator::nrows(&m), 3);
+    assert_eq!(LinearOperator::ncols(&m), 4);
+    assert_eq!(m.mul_vector(&x), &m * &x);
+}
+
+#[test]
+fn lanczos_matrix_free_laplacian() {
+    let n = 200;
+    let op = Laplacian1D { n };
+
+    let largest = Lanczos::new(&op, 4, KrylovTarget::LargestReal).unwrap();
+    let smallest = Lanczos::new(&op, 3, KrylovTarget::SmallestReal).unwrap();
+
+    for i in 0..4 {
+        let expected = laplacian_eigenvalue(n, n - i);
+        assert_relative_eq!(largest.eigenvalues[i], expected, epsilon = 1.0e-10);
+    }
+
+    for i in 0..3 {
+        let expected = laplacian_eigenvalue(n, i + 1);
+        assert_relative_eq!(smallest.eigenvalues[i], expected, epsilon = 1.0e-10);
+    }
+
+    for (val, vec) in smallest
+        .eigenvalues
+        .iter()
+        .zip(smallest.eigenvectors.column_iter())
+    {
+        let vec = vec.into_owned();
+        assert_relative_eq!(op.mul_vector(&vec), &vec * *val, epsilon = 1.0e-6);
+    }
+}
+
+#[test]
+fn lanczos_dense_matches_symmetric_eigen() {
+    let n = 60;
+    let m = DMatrix::<f64>::new_random(n, n);
+    let m = &m + m.transpose();
+
+    let lanczos = Lanczos::new(&m, 5, KrylovTarget::LargestMagnitude).unwrap();
+    let mut expected: Vec<_> = m
+        .clone()
+        .symmetric_eigen()
+        .eigenvalues
+        .iter()
+        .cloned()
+        .collect();
+    expected.sort_by(|a, b| b.abs().partial_cmp(&a.abs()).unwrap());
+
+    for i in 0..5 {
+        assert_relative_eq!(lanczos.eigenvalues[i], expected[i], epsilon = 1.0e-8);
+    }
+}
+
+#[test]
+fn lanczos_small_operator() {
+    // The Krylov subspace spans the whole space.
+    let m = DMatrix::<f64>::from_row_slice(3, 3, &[2.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 4.0]);
+    let lanczos = Lanczos::new(&m, 3, KrylovTarget::SmallestReal).unwrap();
+    let mut expected: Vec<_> = m
+        .clone()
+        .symmetric_eigen()
+        .eigenvalues
+        .iter()
+        .cloned()
+        .collect();
+    expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    for i in 0..3 {
+        assert_relative_eq!(lanczos.eigenvalues[i], expected[i], epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn arnoldi_nonsymmetric() {
+    let n = 80;
+    let mut m = DMatrix::<f64>::new_random(n, n);
+    // Make a few eigenvalues stand out.
+    m[(0, 0)] += 40.0;
+    m[(1, 2)] += 30.0;
+    m[(2, 1)] -= 30.0;
+
+    let arnoldi = Arnoldi::new(&m, 3, KrylovTarget::LargestMagnitude).unwrap();
+    let mut expected: Vec<_> = m
+        .clone()
+        .schur()
+        .complex_eigenvalues()
+        .iter()
+        .cloned()
+        .collect();
+    expected.sort_by(|a, b| b.norm().partial_cmp(&a.norm()).unwrap());
+
+    for i in 0..3 {
+        assert_relative_eq!(
+            arnoldi.eigenvalues[i].norm(),
+            expected[i].norm(),
+            epsilon = 1.0e-7
+        );
+    }
+
+    let mc = m.map(|e| Complex::new(e, 0.0));
+
+    for (val, vec) in arnoldi
+        .eigenvalues
+        .iter()
+        .zip(arnoldi.eigenvectors.column_iter())
+    {
+        let vec = vec.into_owned();
+        assert_relative_eq!(&mc * &vec, &vec * *val, epsilon = 1.0e-6);
+    }
+}
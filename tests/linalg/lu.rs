@@ -156,3 +156,58 @@ mod proptest_tests {
     gen_tests!(complex, complex_f64(), RandComplex<f64>);
     gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
 }
+
+#[test]
+fn simd_lu() {
+    use na::{Matrix6, SimdValue, Vector6};
+    use simba::simd::{SimdBool, WideF64x4};
+
+    let mut ms: Vec<_> = (0..4).map(|_| Matrix6::<f64>::new_random()).collect();
+    // Make the second lane singular.
+    ms[1].set_column(3, &Vector6::zeros());
+    let b = Vector6::<f64>::new_random();
+
+    let pack = |i, j| WideF64x4::from([ms[0][(i, j)], ms[1][(i, j)], ms[2][(i, j)], ms[3][(i, j)]]);
+    let lu = Matrix6::from_fn(pack).simd_lu();
+    let (x, mask) = lu.solve(&b.map(WideF64x4::splat));
+    let (p, l, u) = (lu.p(), lu.l(), lu.u());
+    let determinant = lu.determinant();
+
+    assert_eq!(mask.bitmask(), 0b1101);
+    assert_eq!(lu.is_invertible().bitmask(), 0b1101);
+
+    for (lane, m) in ms.iter().enumerate() {
+        let p = p.map(|e| e.extract(lane));
+        let l = l.map(|e| e.extract(lane));
+        let u = u.map(|e| e.extract(lane));
+
+        assert_relative_eq!(p * m, l * u, epsilon = 1.0e-7);
+        assert_relative_eq!(determinant.extract(lane), m.determinant(), epsilon = 1.0e-7);
+
+        if lane != 1 {
+            let x = x.map(|e| e.extract(lane));
+            assert_relative_eq!(m * x, b, epsilon = 1.0e-7);
+        }
+    }
+}
+
+#[test]
+fn lu_blocked() {
+    use na::DMatrix;
+
+    // Large enough to use the blocked algorithm.
+    for &(nrows, ncols) in &[(150, 150), (170, 130), (130, 170)] {
+        let m = DMatrix::<f64>::new_random(nrows, ncols);
+        let lu = m.clone().lu();
+        let (p, l, u) = lu.unpack();
+        let mut lu = l * u;
+        p.inv_permute_rows(&mut lu);
+
+        assert_relative_eq!(m, lu, epsilon = 1.0e-9);
+    }
+
+    let m = DMatrix::<f64>::new_random(150, 150);
+    let b = DMatrix::<f64>::new_random(150, 3);
+    let x = m.clone().lu().solve(&b).unwrap();
+    assert_relative_eq!(m * x, b, epsilon = 1.0e-7);
+}
@@ -1,13 +1,17 @@
 mod balancing;
+mod band;
 mod bidiagonal;
 mod cholesky;
 mod col_piv_qr;
 mod convolution;
 mod eigen;
+mod exact;
 mod exp;
+mod fft;
 mod full_piv_lu;
 mod hessenberg;
 mod inverse;
+mod krylov;
 mod lu;
 mod pow;
 mod qr;
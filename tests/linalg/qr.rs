@@ -116,3 +116,64 @@ macro_rules! gen_tests(
 
 gen_tests!(complex, complex_f64(), RandComplex<f64>);
 gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+
+#[test]
+fn simd_qr() {
+    use na::{Matrix4, SimdValue, Vector4};
+    use simba::simd::{SimdBool, WideF64x4};
+
+    let mut ms: Vec<_> = (0..4).map(|_| Matrix4::<f64>::new_random()).collect();
+    // Make the last lane singular.
+    ms[3].set_column(0, &Vector4::zeros());
+    let b = Vector4::<f64>::new_random();
+
+    let pack = |i, j| WideF64x4::from([ms[0][(i, j)], ms[1][(i, j)], ms[2][(i, j)], ms[3][(i, j)]]);
+    let qr = Matrix4::from_fn(pack).simd_qr();
+    let (x, mask) = qr.solve(&b.map(WideF64x4::splat));
+    let (q, r) = (qr.q(), qr.r());
+
+    assert_eq!(mask.bitmask(), 0b0111);
+
+    for (lane, m) in ms.iter().enumerate() {
+        let q = q.map(|e| e.extract(lane));
+        let r = r.map(|e| e.extract(lane));
+
+        assert_relative_eq!(q.transpose() * q, Matrix4::identity(), epsilon = 1.0e-7);
+        assert_eq!(r, r.upper_triangle());
+        assert_relative_eq!(q * r, m, epsilon = 1.0e-7);
+
+        if lane != 3 {
+            let x = x.map(|e| e.extract(lane));
+            assert_relative_eq!(m * x, b, epsilon = 1.0e-7);
+        }
+    }
+}
+
+#[test]
+fn qr_blocked() {
+    use na::{Complex, DMatrix};
+
+    // Large enough to use the blocked algorithm.
+    for &(nrows, ncols) in &[(150, 150), (170, 130), (130, 170)] {
+        let m = DMatrix::<f64>::new_random(nrows, ncols);
+        let (q, r) = m.clone().qr().unpack();
+
+        assert_eq!(r, r.upper_triangle());
+        assert_relative_eq!(
+            q.transpose() * &q,
+            DMatrix::identity(q.ncols(), q.ncols()),
+            epsilon = 1.0e-9
+        );
+        assert_relative_eq!(q * r, m, epsilon = 1.0e-9);
+    }
+
+    let m =
+        DMatrix::<f64>::new_random(140, 120).zip_map(&DMatrix::new_random(140, 120), Complex::new);
+    let (q, r) = m.clone().qr().unpack();
+    assert_relative_eq!(
+        q.adjoint() * &q,
+        DMatrix::identity(120, 120),
+        epsilon = 1.0e-9
+    );
+    assert_relative_eq!(q * r, m, epsilon = 1.0e-9);
+}